@@ -0,0 +1,228 @@
+/*
+ * Builders for the types of request bodies, so that code creating them keeps
+ * compiling when the API adds optional fields. A builder starts from the
+ * defaults of the schema and fails to build without its required fields.
+ */
+
+use std::collections::{btree_map::Entry, BTreeMap};
+
+use anyhow::Result;
+use serde_json::Value;
+
+use crate::{clean_name, samples, struct_name};
+
+/// A field of a generated struct.
+pub struct Field {
+    /// The property in the API.
+    pub name: String,
+    pub prop: String,
+    /// The Rust type, as rendered in the types module.
+    pub rt: String,
+}
+
+/// The schemas of request bodies and of the types they contain, by the name of
+/// their generated type.
+pub fn request_bodies(api: &openapiv3::OpenAPI) -> Result<BTreeMap<String, Value>> {
+    let doc = serde_json::to_value(api)?;
+    let schemas = doc
+        .pointer("/components/schemas")
+        .cloned()
+        .unwrap_or_default();
+
+    let mut bodies = BTreeMap::new();
+    for item in doc
+        .get("paths")
+        .and_then(|p| p.as_object())
+        .into_iter()
+        .flatten()
+        .filter_map(|(_, item)| item.as_object())
+    {
+        for content in item
+            .values()
+            .filter_map(|o| o.pointer("/requestBody/content"))
+            .filter_map(|c| c.as_object())
+        {
+            for mt in content.values() {
+                if let Some(schema) = mt.get("schema") {
+                    contained(schema, &schemas, &mut bodies);
+                }
+            }
+        }
+    }
+
+    Ok(bodies)
+}
+
+/// Add the schemas referenced in `v` to `bodies`, and those they reference.
+fn contained(v: &Value, schemas: &Value, bodies: &mut BTreeMap<String, Value>) {
+    if let Some(name) = samples::ref_name(v) {
+        let sn = struct_name(&clean_name(name));
+        if let Entry::Vacant(e) = bodies.entry(sn) {
+            e.insert(schemas[name].clone());
+            contained(&schemas[name], schemas, bodies);
+        }
+        return;
+    }
+
+    match v {
+        Value::Object(o) => o.values().for_each(|v| contained(v, schemas, bodies)),
+        Value::Array(a) => a.iter().for_each(|v| contained(v, schemas, bodies)),
+        _ => {}
+    }
+}
+
+/// A Rust expression for a field, from the builder or else the default value
+/// in the schema.
+fn or_default(prop: &str, rt: &str, default: &Value) -> String {
+    match default {
+        Value::Bool(b) if rt == "bool" => format!("self.{}.unwrap_or({})", prop, b),
+        Value::String(s) if s.is_empty() && rt == "String" => {
+            format!("self.{}.unwrap_or_default()", prop)
+        }
+        Value::String(s) if rt == "String" => {
+            format!("self.{}.unwrap_or_else(|| {:?}.to_string())", prop, s)
+        }
+        Value::Array(a) if a.is_empty() && rt.starts_with("Vec<") => {
+            format!("self.{}.unwrap_or_default()", prop)
+        }
+        _ => format!(
+            "match self.{} {{ Some(value) => value, None => \
+             serde_json::from_value(serde_json::json!({}))? }}",
+            prop, default
+        ),
+    }
+}
+
+/// The builder of a struct with `fields`, generated from `schema`.
+pub fn render_builder(sn: &str, fields: &[Field], schema: &Value) -> String {
+    let mut out = String::new();
+
+    let mut a = |s: &str| {
+        out.push_str(s);
+        out.push('\n');
+    };
+
+    let required: Vec<&str> = schema
+        .get("required")
+        .and_then(|r| r.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|r| r.as_str())
+        .collect();
+    let builder = format!("{}Builder", sn);
+
+    a(&format!("impl {} {{", sn));
+    a(&format!("/// A builder for [`{}`].", sn));
+    a(&format!("pub fn builder() -> {} {{", builder));
+    a("Default::default()");
+    a("}");
+    a("}");
+    a("");
+
+    a(&format!(
+        "/// A builder of [`{}`] values, which starts from the defaults of the API",
+        sn
+    ));
+    a("/// and fails to build without the required fields.");
+    a("#[derive(Clone, Debug, Default)]");
+    a(&format!("pub struct {} {{", builder));
+    for f in fields {
+        a(&format!("{}: Option<{}>,", f.prop, f.rt));
+    }
+    a("}");
+    a("");
+
+    a(&format!("impl {} {{", builder));
+    for f in fields {
+        // Optional types are set from what they wrap.
+        let inner =
+            f.rt.strip_prefix("Option<")
+                .and_then(|rt| rt.strip_suffix('>'));
+        let (arg, value) = match inner {
+            Some(inner) => (inner, "Some(value.into())"),
+            None => (f.rt.as_str(), "value.into()"),
+        };
+        a(&format!("/// Sets [`{}::{}`].", sn, f.prop));
+        a(&format!(
+            "pub fn {}(mut self, value: impl Into<{}>) -> Self {{",
+            f.prop, arg
+        ));
+        a(&format!("self.{} = Some({});", f.prop, value));
+        a("self");
+        a("}");
+        a("");
+    }
+
+    a(&format!(
+        "/// Builds the [`{}`], failing if a required field is not set.",
+        sn
+    ));
+    a(&format!("pub fn build(self) -> anyhow::Result<{}> {{", sn));
+    a(&format!("Ok({} {{", sn));
+    for f in fields {
+        let default = schema.pointer(&format!("/properties/{}/default", f.name));
+        let value = match default {
+            Some(default) => or_default(&f.prop, &f.rt, default),
+            None if required.contains(&f.name.as_str()) => format!(
+                r#"self.{}.ok_or_else(|| anyhow::anyhow!("{} requires {}"))?"#,
+                f.prop, sn, f.prop
+            ),
+            None => format!("self.{}.unwrap_or_default()", f.prop),
+        };
+        a(&format!("{}: {},", f.prop, value));
+    }
+    a("})");
+    a("}");
+    a("}");
+    a("");
+
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::{render_builder, Field};
+
+    #[test]
+    fn builder() {
+        let field = |name: &str, rt: &str| Field {
+            name: name.to_string(),
+            prop: name.to_string(),
+            rt: rt.to_string(),
+        };
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string" },
+                "start": { "type": "boolean", "default": true },
+                "nics": { "$ref": "#/components/schemas/Nics", "default": { "type": "default" } },
+                "tags": { "type": "array", "items": { "type": "string" } }
+            },
+            "required": ["name"]
+        });
+        let out = render_builder(
+            "Create",
+            &[
+                field("name", "String"),
+                field("start", "bool"),
+                field("nics", "Option<Nics>"),
+                field("tags", "Vec<String>"),
+            ],
+            &schema,
+        );
+
+        for expected in [
+            "pub fn builder() -> CreateBuilder {",
+            "pub fn nics(mut self, value: impl Into<Nics>) -> Self {",
+            "self.nics = Some(Some(value.into()));",
+            r#"name: self.name.ok_or_else(|| anyhow::anyhow!("Create requires name"))?,"#,
+            "start: self.start.unwrap_or(true),",
+            r#"None => serde_json::from_value(serde_json::json!({"type":"default"}))? },"#,
+            "tags: self.tags.unwrap_or_default(),",
+        ] {
+            assert!(out.contains(expected), "{} is not in:\n{}", expected, out);
+        }
+    }
+}
@@ -6,18 +6,41 @@ pub fn generate_client() -> String {
     CLIENT_FUNCTIONS.to_string()
 }
 
+/*
+ * The same client over `reqwest::blocking`, for the `blocking` feature:
+ */
+pub fn generate_blocking_client() -> String {
+    BLOCKING_CLIENT_FUNCTIONS.to_string()
+}
+
 const CLIENT_FUNCTIONS: &str = r#"
-use std::env;
+use std::{
+    env,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
 /// Entrypoint for interacting with the API client.
 #[derive(Clone)]
 pub struct Client {
     host: String,
     token: String,
+    session: Option<Arc<Session>>,
 
     client: reqwest::Client,
 }
 
+/// The local silo user a client logs in as, and whether it is logged in.
+/// Shared by every clone of the client.
+struct Session {
+    silo: String,
+    username: String,
+    password: String,
+    logged_in: AtomicBool,
+}
+
 impl Client {
     /// Create a new Client struct. It takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
@@ -36,6 +59,7 @@ impl Client {
                 Client {
                     host: host.to_string(),
                     token: token.to_string(),
+                    session: None,
 
                     client: c,
                 }
@@ -56,14 +80,100 @@ impl Client {
         )
     }
 
+    /// Create a new Client that logs in as a local user of `silo` and
+    /// authenticates with the session cookie it is given instead of a token.
+    /// Whenever the session has expired the client logs in again.
+    pub async fn new_with_password<H, S, U, P>(
+        host: H,
+        silo: S,
+        username: U,
+        password: P,
+    ) -> Result<Self>
+    where
+        H: ToString,
+        S: ToString,
+        U: ToString,
+        P: ToString,
+    {
+        let client = reqwest::Client::builder()
+            .cookie_provider(Arc::new(reqwest::cookie::Jar::default()))
+            .build()?;
+        let client = Client {
+            host: host.to_string(),
+            token: String::new(),
+            session: Some(Arc::new(Session {
+                silo: silo.to_string(),
+                username: username.to_string(),
+                password: password.to_string(),
+                logged_in: AtomicBool::new(false),
+            })),
+
+            client,
+        };
+        client.log_in().await?;
+        Ok(client)
+    }
+
+    /// Log in with the client's username and password. This happens
+    /// automatically when the client is created and when its session expires.
+    pub async fn log_in(&self) -> Result<()> {
+        let session = match &self.session {
+            Some(session) => session,
+            None => return Err(anyhow!("client was not created with a username and password")),
+        };
+
+        // Not sent through `request_raw`, which logs request bodies.
+        let url = format!(
+            "{}/login/{}/local",
+            self.host,
+            progenitor_support::encode_path(&session.silo),
+        );
+        let response = self
+            .client
+            .post(url)
+            .json(&serde_json::json!({
+                "username": session.username,
+                "password": session.password,
+            }))
+            .send()
+            .await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(anyhow!(
+                "logging in to silo {} as {} failed: {}",
+                session.silo,
+                session.username,
+                status
+            ));
+        }
+
+        session.logged_in.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// End the client's session. Requests fail as unauthorized afterwards
+    /// rather than logging in again, until `log_in` is called.
+    pub async fn log_out(&self) -> Result<()> {
+        self.hidden().logout().await?;
+        if let Some(session) = &self.session {
+            session.logged_in.store(false, Ordering::SeqCst);
+        }
+        Ok(())
+    }
+
     async fn url_and_auth(
         &self,
         uri: &str,
     ) -> Result<(reqwest::Url, Option<String>)> {
         let parsed_url = uri.parse::<reqwest::Url>();
 
-        let auth = format!("Bearer {}", self.token);
-        parsed_url.map(|u| (u, Some(auth))).map_err(Error::from)
+        // Clients logged in with a password authenticate with their cookie.
+        let auth = if self.session.is_some() {
+            None
+        } else {
+            Some(format!("Bearer {}", self.token))
+        };
+        parsed_url.map(|u| (u, auth)).map_err(Error::from)
     }
 
     pub async fn request_raw(
@@ -117,29 +227,56 @@ impl Client {
         Ok(req.send().await?)
     }
 
-    async fn request<Out>(
+    /// Send a request and return the status and body of the response,
+    /// whatever the status. Operations whose response type depends on the
+    /// status decode the body themselves.
+    async fn request_with_status(
         &self,
         method: reqwest::Method,
         uri: &str,
         body: Option<reqwest::Body>,
-    ) -> Result<Out>
-        where
-        Out: serde::de::DeserializeOwned + 'static + Send,
+    ) -> Result<(http::StatusCode, bytes::Bytes)>
     {
-        let response = self.response_raw(method, uri, body).await?;
+        let session = self
+            .session
+            .as_ref()
+            .filter(|s| s.logged_in.load(Ordering::SeqCst));
+        let retry_body = match session {
+            Some(_) => body.as_ref().and_then(|b| b.as_bytes()).map(|b| b.to_vec()),
+            None => None,
+        };
+
+        let mut response = self.response_raw(method.clone(), uri, body).await?;
+
+        if response.status() == http::StatusCode::UNAUTHORIZED && session.is_some() {
+            log::debug!("session expired, logging in again");
+            self.log_in().await?;
+            response = self
+                .response_raw(method, uri, retry_body.map(reqwest::Body::from))
+                .await?;
+        }
 
         let status = response.status();
 
         let response_body = response.bytes().await?;
 
+        log::debug!("response payload {}", String::from_utf8_lossy(&response_body));
+        Ok((status, response_body))
+    }
+
+    /// Send a request and return the status and body of the response if it
+    /// succeeded, or the error it was answered with.
+    async fn request_bytes(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<(http::StatusCode, bytes::Bytes)>
+    {
+        let (status, response_body) = self.request_with_status(method, uri, body).await?;
+
         if status.is_success() {
-            log::debug!("response payload {}", String::from_utf8_lossy(&response_body));
-            let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){
-                serde_json::from_str("null")
-            } else {
-                serde_json::from_slice::<Out>(&response_body)
-            };
-            parsed_response.map_err(Error::from)
+            Ok((status, response_body))
         } else {
             let error: anyhow::Error = if response_body.is_empty() {
                 anyhow!("code: {}, empty response", status)
@@ -164,6 +301,25 @@ impl Client {
         }
     }
 
+    async fn request<Out>(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<Out>
+        where
+        Out: serde::de::DeserializeOwned + 'static + Send,
+    {
+        let (status, response_body) = self.request_bytes(method, uri, body).await?;
+
+        let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){
+            serde_json::from_str("null")
+        } else {
+            serde_json::from_slice::<Out>(&response_body)
+        };
+        parsed_response.map_err(Error::from)
+    }
+
     async fn request_entity<D>(
         &self,
         method: http::Method,
@@ -234,3 +390,367 @@ impl Client {
             message,
         ).await
 }"#;
+
+const BLOCKING_CLIENT_FUNCTIONS: &str = r#"
+use std::{
+    env,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+/// Entrypoint for interacting with the API client without an async runtime.
+///
+/// It has the same operations as [`crate::Client`], but every request blocks
+/// the calling thread until it is done. It must not be used from within an
+/// async runtime.
+#[derive(Clone)]
+pub struct Client {
+    host: String,
+    token: String,
+    session: Option<Arc<Session>>,
+
+    client: reqwest::blocking::Client,
+}
+
+/// The local silo user a client logs in as, and whether it is logged in.
+/// Shared by every clone of the client.
+struct Session {
+    silo: String,
+    username: String,
+    password: String,
+    logged_in: AtomicBool,
+}
+
+impl Client {
+    /// Create a new Client struct. It takes a type that can convert into
+    /// an &str (`String` or `Vec<u8>` for example). As long as the function is
+    /// given a valid API key your requests will work.
+    pub fn new<T, H>(
+        token: T,
+        host: H,
+    ) -> Self
+    where
+        T: ToString,
+        H: ToString,
+    {
+        let client = reqwest::blocking::Client::builder().build();
+        match client {
+            Ok(c) => {
+                Client {
+                    host: host.to_string(),
+                    token: token.to_string(),
+                    session: None,
+
+                    client: c,
+                }
+            }
+            Err(e) => panic!("creating reqwest client failed: {:?}", e),
+        }
+    }
+
+    /// Create a new Client struct from environment variables: OXIDE_TOKEN and OXIDE_HOST.
+    pub fn new_from_env() -> Self
+    {
+        let token = env::var("OXIDE_TOKEN").expect("must set OXIDE_TOKEN");
+        let host = env::var("OXIDE_HOST").expect("must set OXIDE_HOST");
+
+        Client::new(
+            token,
+            host,
+        )
+    }
+
+    /// Create a new Client that logs in as a local user of `silo` and
+    /// authenticates with the session cookie it is given instead of a token.
+    /// Whenever the session has expired the client logs in again.
+    pub fn new_with_password<H, S, U, P>(
+        host: H,
+        silo: S,
+        username: U,
+        password: P,
+    ) -> Result<Self>
+    where
+        H: ToString,
+        S: ToString,
+        U: ToString,
+        P: ToString,
+    {
+        let client = reqwest::blocking::Client::builder()
+            .cookie_provider(Arc::new(reqwest::cookie::Jar::default()))
+            .build()?;
+        let client = Client {
+            host: host.to_string(),
+            token: String::new(),
+            session: Some(Arc::new(Session {
+                silo: silo.to_string(),
+                username: username.to_string(),
+                password: password.to_string(),
+                logged_in: AtomicBool::new(false),
+            })),
+
+            client,
+        };
+        client.log_in()?;
+        Ok(client)
+    }
+
+    /// Log in with the client's username and password. This happens
+    /// automatically when the client is created and when its session expires.
+    pub fn log_in(&self) -> Result<()> {
+        let session = match &self.session {
+            Some(session) => session,
+            None => return Err(anyhow!("client was not created with a username and password")),
+        };
+
+        // Not sent through `request_raw`, which logs request bodies.
+        let url = format!(
+            "{}/login/{}/local",
+            self.host,
+            progenitor_support::encode_path(&session.silo),
+        );
+        let response = self
+            .client
+            .post(url)
+            .json(&serde_json::json!({
+                "username": session.username,
+                "password": session.password,
+            }))
+            .send()?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(anyhow!(
+                "logging in to silo {} as {} failed: {}",
+                session.silo,
+                session.username,
+                status
+            ));
+        }
+
+        session.logged_in.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// End the client's session. Requests fail as unauthorized afterwards
+    /// rather than logging in again, until `log_in` is called.
+    pub fn log_out(&self) -> Result<()> {
+        self.hidden().logout()?;
+        if let Some(session) = &self.session {
+            session.logged_in.store(false, Ordering::SeqCst);
+        }
+        Ok(())
+    }
+
+    fn url_and_auth(
+        &self,
+        uri: &str,
+    ) -> Result<(reqwest::Url, Option<String>)> {
+        let parsed_url = uri.parse::<reqwest::Url>();
+
+        // Clients logged in with a password authenticate with their cookie.
+        let auth = if self.session.is_some() {
+            None
+        } else {
+            Some(format!("Bearer {}", self.token))
+        };
+        parsed_url.map(|u| (u, auth)).map_err(Error::from)
+    }
+
+    pub fn request_raw(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::blocking::Body>,
+    ) -> Result<reqwest::blocking::RequestBuilder>
+    {
+        let u = if uri.starts_with("https://") || uri.starts_with("http://") {
+            uri.to_string()
+        } else {
+            (self.host.clone() + uri).to_string()
+        };
+        let (url, auth) = self.url_and_auth(&u)?;
+
+        let mut req = self.client.request(method, url);
+
+        // Set the default headers.
+        req = req.header(
+            reqwest::header::ACCEPT,
+            reqwest::header::HeaderValue::from_static("application/json"),
+        );
+        req = req.header(
+            reqwest::header::CONTENT_TYPE,
+            reqwest::header::HeaderValue::from_static("application/json"),
+        );
+
+        if let Some(auth_str) = auth {
+            req = req.header(http::header::AUTHORIZATION, auth_str);
+        }
+
+        if let Some(body) = body {
+            if let Some(bytes) = body.as_bytes() {
+                log::debug!("body: {:?}", String::from_utf8_lossy(bytes));
+            }
+            req = req.body(body);
+        }
+        log::debug!("request: {:?}", &req);
+        Ok(req)
+    }
+
+    pub fn response_raw(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::blocking::Body>,
+    ) -> Result<reqwest::blocking::Response>
+    {
+        let req = self.request_raw(method, uri, body)?;
+        Ok(req.send()?)
+    }
+
+    /// Send a request and return the status and body of the response,
+    /// whatever the status. Operations whose response type depends on the
+    /// status decode the body themselves.
+    fn request_with_status(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::blocking::Body>,
+    ) -> Result<(http::StatusCode, bytes::Bytes)>
+    {
+        let session = self
+            .session
+            .as_ref()
+            .filter(|s| s.logged_in.load(Ordering::SeqCst));
+        let retry_body = match session {
+            Some(_) => body.as_ref().and_then(|b| b.as_bytes()).map(|b| b.to_vec()),
+            None => None,
+        };
+
+        let mut response = self.response_raw(method.clone(), uri, body)?;
+
+        if response.status() == http::StatusCode::UNAUTHORIZED && session.is_some() {
+            log::debug!("session expired, logging in again");
+            self.log_in()?;
+            response = self.response_raw(method, uri, retry_body.map(reqwest::blocking::Body::from))?;
+        }
+
+        let status = response.status();
+
+        let response_body = response.bytes()?;
+
+        log::debug!("response payload {}", String::from_utf8_lossy(&response_body));
+        Ok((status, response_body))
+    }
+
+    /// Send a request and return the status and body of the response if it
+    /// succeeded, or the error it was answered with.
+    fn request_bytes(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::blocking::Body>,
+    ) -> Result<(http::StatusCode, bytes::Bytes)>
+    {
+        let (status, response_body) = self.request_with_status(method, uri, body)?;
+
+        if status.is_success() {
+            Ok((status, response_body))
+        } else {
+            let error: anyhow::Error = if response_body.is_empty() {
+                anyhow!("code: {}, empty response", status)
+            } else {
+                // Parse the error as the error type.
+                match serde_json::from_slice::<crate::types::ErrorResponse>(&response_body) {
+                    Ok(resp) => {
+                       let e : crate::types::Error = resp.into();
+                       e.into()
+                    },
+                    Err(_) => {
+                        anyhow!(
+                            "code: {}, error: {:?}",
+                            status,
+                            String::from_utf8_lossy(&response_body),
+                        )
+                    }
+                }
+            };
+
+            Err(error)
+        }
+    }
+
+    fn request<Out>(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::blocking::Body>,
+    ) -> Result<Out>
+        where
+        Out: serde::de::DeserializeOwned + 'static + Send,
+    {
+        let (status, response_body) = self.request_bytes(method, uri, body)?;
+
+        let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){
+            serde_json::from_str("null")
+        } else {
+            serde_json::from_slice::<Out>(&response_body)
+        };
+        parsed_response.map_err(Error::from)
+    }
+
+    fn get<D>(&self, uri: &str, message: Option<reqwest::blocking::Body>) -> Result<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        self.request(
+            http::Method::GET,
+            &(self.host.to_string() + uri),
+            message,
+        )
+    }
+
+    fn post<D>(&self, uri: &str, message: Option<reqwest::blocking::Body>) -> Result<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        self.request(
+            http::Method::POST,
+            &(self.host.to_string() + uri),
+            message,
+        )
+    }
+
+    #[allow(dead_code)]
+    fn patch<D>(&self, uri: &str, message: Option<reqwest::blocking::Body>) -> Result<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        self.request(
+            http::Method::PATCH,
+            &(self.host.to_string() + uri),
+            message,
+        )
+    }
+
+    fn put<D>(&self, uri: &str, message: Option<reqwest::blocking::Body>) -> Result<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        self.request(
+            http::Method::PUT,
+            &(self.host.to_string() + uri),
+            message,
+        )
+    }
+
+    fn delete<D>(&self, uri: &str, message: Option<reqwest::blocking::Body>) -> Result<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        self.request(
+            http::Method::DELETE,
+            &(self.host.to_string() + uri),
+            message,
+        )
+}"#;
@@ -0,0 +1,536 @@
+/*
+ * Compare two versions of the API by the Rust API that would be generated
+ * from them: operations with their parameters, request and response types,
+ * and the schemas with their fields and enum variants.
+ */
+
+use std::{collections::BTreeMap, fmt};
+
+use anyhow::{bail, Result};
+use openapiv3::OpenAPI;
+
+use crate::{
+    clean_name, functions, load_api, oid_to_object_name, overrides::Overrides, struct_name, types,
+    ReferenceOrExt, TypeDetails, TypeSpace,
+};
+
+/// An operation as the generated function sees it.
+#[derive(Debug, PartialEq)]
+struct OperationShape {
+    method: String,
+    path: String,
+    /// The function's parameters, as `name: type`.
+    params: Vec<String>,
+    body: Option<String>,
+    response: String,
+}
+
+/// A schema as the generated type sees it.
+#[derive(Debug, PartialEq)]
+enum SchemaShape {
+    /// Fields and their Rust types.
+    Object(BTreeMap<String, String>),
+    Enum(Vec<String>),
+    /// How the variants are told apart, and the variants with their fields or
+    /// type.
+    Union(String, BTreeMap<String, String>),
+    /// Anything else, by its Rust type.
+    Other(String),
+}
+
+struct ApiShape {
+    operations: BTreeMap<String, OperationShape>,
+    schemas: BTreeMap<String, SchemaShape>,
+}
+
+impl ApiShape {
+    fn new(api: &OpenAPI) -> Result<Self> {
+        let mut ts = TypeSpace::new();
+
+        let mut schemas = BTreeMap::new();
+        if let Some(components) = &api.components {
+            let mut ids = Vec::new();
+            for (sn, s) in components.schemas.iter() {
+                let id = ts.select(Some(clean_name(sn).as_str()), s, "")?;
+                ts.populate_ref(Some(sn.as_str()), Some(id.clone()), "schema")?;
+                ids.push((sn, id));
+            }
+
+            for (sn, mut id) in ids {
+                let mut et = ts.id_to_entry.get(&id).unwrap();
+                while let TypeDetails::NamedType(tid, _) | TypeDetails::ComponentSchema(tid, _) =
+                    &et.details
+                {
+                    id = tid.clone();
+                    et = ts.id_to_entry.get(&id).unwrap();
+                }
+                let shape = match &et.details {
+                    TypeDetails::Object(props, _) => {
+                        let props = props.clone();
+                        let mut fields = BTreeMap::new();
+                        for (name, pid) in props {
+                            fields.insert(name, ts.render_type(&pid, false)?);
+                        }
+                        SchemaShape::Object(fields)
+                    }
+                    TypeDetails::Enum(variants, _) => SchemaShape::Enum(variants.clone()),
+                    TypeDetails::OneOf(one_of, schema_data) => {
+                        let (one_of, schema_data) = (one_of.clone(), schema_data.clone());
+                        let (tagging, variants) = types::union_variants(
+                            &mut ts,
+                            &one_of,
+                            &schema_data,
+                            &struct_name(&clean_name(sn)),
+                        )?;
+                        SchemaShape::Union(tagging, variants)
+                    }
+                    TypeDetails::AnyOf(ids, _) | TypeDetails::AllOf(ids, _) => {
+                        let ids = ids.clone();
+                        let mut parts = Vec::new();
+                        for id in &ids {
+                            parts.push(ts.render_type(id, false)?);
+                        }
+                        SchemaShape::Other(parts.join(" + "))
+                    }
+                    _ => SchemaShape::Other(ts.render_type(&id, false)?),
+                };
+                schemas.insert(sn.to_string(), shape);
+            }
+        }
+
+        let mut operations = BTreeMap::new();
+        for (path, item) in api.paths.iter() {
+            let item = item.item()?;
+            for (method, o) in item.iter() {
+                let op_id = match &o.operation_id {
+                    Some(op_id) => op_id.to_string(),
+                    None => crate::path_to_operation_id(path, method),
+                };
+                let (params, _) = functions::get_fn_params(&mut ts, o, false)?;
+                let body = request_body_type(&mut ts, &op_id, o)?;
+                let response = functions::describe_response_type(&op_id, &mut ts, o)?;
+                operations.insert(
+                    op_id,
+                    OperationShape {
+                        method: method.to_uppercase(),
+                        path: path.to_string(),
+                        params: params
+                            .iter()
+                            .map(|p| p.trim_end_matches(',').to_string())
+                            .collect(),
+                        body,
+                        response,
+                    },
+                );
+            }
+        }
+
+        Ok(ApiShape {
+            operations,
+            schemas,
+        })
+    }
+}
+
+/// The Rust type of an operation's JSON request body, if it has one.
+fn request_body_type(
+    ts: &mut TypeSpace,
+    op_id: &str,
+    o: &openapiv3::Operation,
+) -> Result<Option<String>> {
+    let object_name = format!("{} request", oid_to_object_name(op_id));
+    match &o.request_body {
+        Some(openapiv3::ReferenceOr::Reference { reference }) => {
+            let id = ts.select_ref(Some(&clean_name(&object_name)), reference)?;
+            Ok(Some(ts.render_type(&id, false)?))
+        }
+        Some(openapiv3::ReferenceOr::Item(body)) => {
+            match body.content.first().and_then(|(_, mt)| mt.schema.as_ref()) {
+                Some(s) => {
+                    let id = ts.select(Some(&object_name), s, "")?;
+                    Ok(Some(ts.render_type(&id, false)?))
+                }
+                None => Ok(Some(
+                    body.content.keys().cloned().collect::<Vec<_>>().join(", "),
+                )),
+            }
+        }
+        None => Ok(None),
+    }
+}
+
+/// One difference between two versions of the API.
+#[derive(Debug, PartialEq)]
+pub struct Change {
+    /// Whether code using the generated crate may stop compiling or working.
+    pub breaking: bool,
+    pub description: String,
+}
+
+/// The differences between two versions of the API.
+#[derive(Debug, Default)]
+pub struct Report {
+    pub changes: Vec<Change>,
+}
+
+impl Report {
+    fn breaking(&mut self, description: String) {
+        self.changes.push(Change {
+            breaking: true,
+            description,
+        });
+    }
+
+    fn compatible(&mut self, description: String) {
+        self.changes.push(Change {
+            breaking: false,
+            description,
+        });
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.changes.is_empty() {
+            return writeln!(f, "no changes");
+        }
+        for (breaking, title) in [(true, "Breaking changes"), (false, "Non-breaking changes")] {
+            let changes: Vec<&Change> = self
+                .changes
+                .iter()
+                .filter(|c| c.breaking == breaking)
+                .collect();
+            if changes.is_empty() {
+                continue;
+            }
+            writeln!(f, "{} ({}):", title, changes.len())?;
+            for change in changes {
+                writeln!(f, "  {}", change.description)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/*
+ * Everything in the generated crate is exhaustive: added fields break struct
+ * literals and added variants break matches, so only new operations and types
+ * and moved endpoints are compatible.
+ */
+pub fn diff(old: &OpenAPI, new: &OpenAPI) -> Result<Report> {
+    let old = ApiShape::new(old)?;
+    let new = ApiShape::new(new)?;
+    let mut report = Report::default();
+
+    for (op_id, o) in &old.operations {
+        let n = match new.operations.get(op_id) {
+            Some(n) => n,
+            None => {
+                report.breaking(format!(
+                    "operation {} ({} {}) removed",
+                    op_id, o.method, o.path
+                ));
+                continue;
+            }
+        };
+        if (&o.method, &o.path) != (&n.method, &n.path) {
+            report.compatible(format!(
+                "operation {} moved from {} {} to {} {}",
+                op_id, o.method, o.path, n.method, n.path
+            ));
+        }
+        if o.params != n.params {
+            report.breaking(format!(
+                "operation {} parameters changed from ({}) to ({})",
+                op_id,
+                o.params.join(", "),
+                n.params.join(", ")
+            ));
+        }
+        if o.body != n.body {
+            let body = |b: &Option<String>| b.clone().unwrap_or_else(|| "none".to_string());
+            report.breaking(format!(
+                "operation {} request body changed from {} to {}",
+                op_id,
+                body(&o.body),
+                body(&n.body)
+            ));
+        }
+        if o.response != n.response {
+            report.breaking(format!(
+                "operation {} response changed from {} to {}",
+                op_id, o.response, n.response
+            ));
+        }
+    }
+    for (op_id, n) in &new.operations {
+        if !old.operations.contains_key(op_id) {
+            report.compatible(format!(
+                "operation {} ({} {}) added",
+                op_id, n.method, n.path
+            ));
+        }
+    }
+
+    for (name, o) in &old.schemas {
+        let n = match new.schemas.get(name) {
+            Some(n) => n,
+            None => {
+                report.breaking(format!("type {} removed", name));
+                continue;
+            }
+        };
+        match (o, n) {
+            (SchemaShape::Object(o), SchemaShape::Object(n)) => {
+                for (field, ot) in o {
+                    match n.get(field) {
+                        None => report.breaking(format!("type {} field {} removed", name, field)),
+                        Some(nt) if nt != ot => report.breaking(format!(
+                            "type {} field {} changed from {} to {}",
+                            name, field, ot, nt
+                        )),
+                        Some(_) => {}
+                    }
+                }
+                for (field, nt) in n {
+                    if !o.contains_key(field) {
+                        report.breaking(format!("type {} field {}: {} added", name, field, nt));
+                    }
+                }
+            }
+            (SchemaShape::Enum(o), SchemaShape::Enum(n)) => {
+                for variant in o.iter().filter(|v| !n.contains(v)) {
+                    report.breaking(format!("type {} variant {} removed", name, variant));
+                }
+                for variant in n.iter().filter(|v| !o.contains(v)) {
+                    report.breaking(format!("type {} variant {} added", name, variant));
+                }
+            }
+            (SchemaShape::Union(ot, o), SchemaShape::Union(nt, n)) => {
+                if ot != nt {
+                    report.breaking(format!(
+                        "type {} tagging changed from {} to {}",
+                        name, ot, nt
+                    ));
+                }
+                for (variant, os) in o {
+                    match n.get(variant) {
+                        None => {
+                            report.breaking(format!("type {} variant {} removed", name, variant))
+                        }
+                        Some(ns) if ns != os => report.breaking(format!(
+                            "type {} variant {}{} changed to {}{}",
+                            name, variant, os, variant, ns
+                        )),
+                        Some(_) => {}
+                    }
+                }
+                for (variant, ns) in n {
+                    if !o.contains_key(variant) {
+                        report.breaking(format!("type {} variant {}{} added", name, variant, ns));
+                    }
+                }
+            }
+            (SchemaShape::Other(o), SchemaShape::Other(n)) if o != n => {
+                report.breaking(format!("type {} changed from {} to {}", name, o, n))
+            }
+            (o, n) if o != n => report.breaking(format!("type {} changed", name)),
+            _ => {}
+        }
+    }
+    for name in new.schemas.keys() {
+        if !old.schemas.contains_key(name) {
+            report.compatible(format!("type {} added", name));
+        }
+    }
+
+    Ok(report)
+}
+
+/*
+ * `generator diff [--overrides OVERRIDES] [--lenient] OLD NEW`
+ */
+pub fn main(args: impl Iterator<Item = String>) -> Result<()> {
+    let mut opts = getopts::Options::new();
+    opts.optopt(
+        "",
+        "overrides",
+        "Operation and type overrides to apply to both definitions (TOML)",
+        "OVERRIDES",
+    );
+    opts.optflag(
+        "",
+        "lenient",
+        "Leave out operations and types the generator does not support, with a warning",
+    );
+    let usage = || opts.usage("generator diff [options] OLD NEW");
+
+    let args = match opts.parse(args) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}", usage());
+            bail!(e);
+        }
+    };
+    let (old, new) = match args.free.as_slice() {
+        [old, new] => (old, new),
+        _ => {
+            eprintln!("{}", usage());
+            bail!("expected the old and the new definition");
+        }
+    };
+
+    let overrides = match args.opt_str("overrides") {
+        Some(p) => Some(Overrides::load(p)?),
+        None => None,
+    };
+    let old = load_api(old, overrides.as_ref(), args.opt_present("lenient"))?;
+    let new = load_api(new, overrides.as_ref(), args.opt_present("lenient"))?;
+
+    print!("{}", diff(&old, &new)?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use anyhow::Result;
+
+    use super::diff;
+
+    fn api(widget: serde_json::Value, create_params: serde_json::Value) -> openapiv3::OpenAPI {
+        serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.3",
+            "info": { "title": "t", "version": "1" },
+            "paths": {
+                "/widgets/{name}": {
+                    "get": {
+                        "operationId": "widget_view",
+                        "parameters": [
+                            { "in": "path", "name": "name", "required": true,
+                              "schema": { "type": "string" } }
+                        ],
+                        "responses": {
+                            "200": {
+                                "description": "ok",
+                                "content": { "application/json": {
+                                    "schema": { "$ref": "#/components/schemas/Widget" }
+                                } }
+                            }
+                        }
+                    },
+                    "post": {
+                        "operationId": "widget_create",
+                        "parameters": create_params,
+                        "responses": { "204": { "description": "created" } }
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "Widget": widget,
+                    "Color": { "type": "string", "enum": ["red", "green"] }
+                }
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn report() -> Result<()> {
+        let name = serde_json::json!({ "in": "path", "name": "name", "required": true,
+                                       "schema": { "type": "string" } });
+        let old = api(
+            serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "name": { "type": "string" },
+                    "size": { "type": "integer", "format": "int32" }
+                },
+                "required": ["name", "size"]
+            }),
+            serde_json::json!([name]),
+        );
+        let new = api(
+            serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "name": { "type": "string" },
+                    "size": { "type": "integer", "format": "int64" },
+                    "color": { "$ref": "#/components/schemas/Color" }
+                },
+                "required": ["name", "size"]
+            }),
+            serde_json::json!([
+                name,
+                { "in": "query", "name": "dry_run", "required": true,
+                  "schema": { "type": "boolean" } }
+            ]),
+        );
+
+        assert!(diff(&old, &old)?.changes.is_empty());
+
+        let report = diff(&old, &new)?;
+        let descriptions: Vec<(bool, &str)> = report
+            .changes
+            .iter()
+            .map(|c| (c.breaking, c.description.as_str()))
+            .collect();
+        assert_eq!(
+            descriptions,
+            vec![
+                (
+                    true,
+                    "operation widget_create parameters changed from (name: &str) to (dry_run: \
+                     bool, name: &str)"
+                ),
+                (true, "type Widget field size changed from i32 to i64"),
+                (
+                    true,
+                    "type Widget field color: Option<crate::types::Color> added"
+                ),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unions() -> Result<()> {
+        let widget = |description: &str, side: &str| {
+            let variant = |shape: &str, size: &str, format: &str| {
+                serde_json::json!({
+                    "type": "object",
+                    "description": format!("{} {}", description, shape),
+                    "properties": {
+                        "type": { "type": "string", "enum": [shape] },
+                        size: { "type": "integer", "format": format }
+                    },
+                    "required": ["type", size]
+                })
+            };
+            let widget = serde_json::json!({
+                "description": description,
+                "oneOf": [variant("round", "radius", "int32"), variant("square", "side", side)]
+            });
+            api(widget, serde_json::json!([]))
+        };
+        let old = widget("A widget", "int32");
+
+        let described = widget("A gadget", "int32");
+        assert!(diff(&old, &described)?.changes.is_empty());
+
+        let report = diff(&old, &widget("A widget", "int64"))?;
+        let descriptions: Vec<(bool, &str)> = report
+            .changes
+            .iter()
+            .map(|c| (c.breaking, c.description.as_str()))
+            .collect();
+        assert_eq!(
+            descriptions,
+            vec![(
+                true,
+                "type Widget variant Square { side: i32 } changed to Square { side: i64 }"
+            )]
+        );
+        Ok(())
+    }
+}
@@ -4,22 +4,42 @@ use anyhow::{bail, Result};
 use inflector::cases::snakecase::to_snake_case;
 
 use crate::{
-    clean_fn_name, clean_name, get_parameter_data, oid_to_object_name, path_to_operation_id,
-    struct_name, template::parse, ExtractJsonMediaType, ParameterDataExt, ReferenceOrExt, TypeId,
-    TypeSpace,
+    clean_fn_name, clean_name, get_parameter_data, oid_to_object_name,
+    operations::{Operation, Parameter},
+    path_to_operation_id, struct_name,
+    template::parse,
+    ExtractJsonMediaType, ParameterDataExt, ReferenceOrExt, TypeId, TypeSpace,
 };
 
 /*
- * Generate a function for each Operation.
+ * The generated functions, grouped by tag.
+ */
+pub struct Files {
+    pub tags: BTreeMap<String, String>,
+    /// The same functions for the blocking client.
+    pub blocking_tags: BTreeMap<String, String>,
+    /// Types the functions need that are not in the spec, such as the
+    /// responses of operations that answer with more than one type.
+    pub types: String,
+    /// Every generated function, for the registry of operations.
+    pub operations: Vec<Operation>,
+}
+
+/*
+ * Generate a function for each Operation, once for the async client and once
+ * for the blocking one.
  */
 pub fn generate_files(
     api: &openapiv3::OpenAPI,
     ts: &mut TypeSpace,
     parameters: &BTreeMap<String, &openapiv3::Parameter>,
-) -> Result<(BTreeMap<String, String>, openapiv3::OpenAPI)> {
+) -> Result<(Files, openapiv3::OpenAPI)> {
     let mut new_api = api.clone();
 
     let mut tag_files: BTreeMap<String, String> = Default::default();
+    let mut blocking_tag_files: BTreeMap<String, String> = Default::default();
+    let mut response_types = String::new();
+    let mut operations: Vec<Operation> = Vec::new();
 
     let mut fn_names: Vec<String> = Default::default();
     for (pn, path) in api.paths.iter() {
@@ -72,27 +92,44 @@ pub fn generate_files(
             if let Some(o) = tag_files.get(&tag) {
                 out = o.to_string();
             }
+            let mut blocking_out = String::new();
+            if let Some(o) = blocking_tag_files.get(&tag) {
+                blocking_out = o.to_string();
+            }
 
-            let mut a = |s: &str| {
-                out.push_str(s);
-                out.push('\n');
-            };
+            let print_fn = |out: &mut String,
+                            blocking: bool,
+                            docs: &str,
+                            bounds: &Vec<String>,
+                            fn_params_str: &Vec<String>,
+                            body_param: &Option<String>,
+                            response_type: &str,
+                            template: &str,
+                            fn_inner: &str,
+                            fn_name: &str| {
+                let mut a = |s: &str| {
+                    out.push_str(s);
+                    out.push('\n');
+                };
 
-            let mut print_fn = |docs: &str,
-                                bounds: &Vec<String>,
-                                fn_params_str: &Vec<String>,
-                                body_param: &Option<String>,
-                                response_type: &str,
-                                template: &str,
-                                fn_inner: &str,
-                                fn_name: &str| {
                 // Print the function docs.
                 a(docs);
 
+                let asyncness = if blocking { "" } else { "async " };
                 if bounds.is_empty() {
-                    a(&format!("pub async fn {}(", fn_name,));
+                    a(&format!("pub {}fn {}(", asyncness, fn_name,));
                 } else {
-                    a(&format!("pub async fn {}<{}>(", fn_name, bounds.join(", ")));
+                    // Every type parameter is a request body.
+                    let bounds: Vec<String> = bounds
+                        .iter()
+                        .map(|b| format!("{}: Into<{}>", b, body_type(blocking)))
+                        .collect();
+                    a(&format!(
+                        "pub {}fn {}<{}>(",
+                        asyncness,
+                        fn_name,
+                        bounds.join(", ")
+                    ));
                 }
                 a("&self,");
 
@@ -121,7 +158,7 @@ pub fn generate_files(
             let (body_param, body_func) = if let Some(b) = &o.request_body {
                 if let Ok(b) = b.item() {
                     if b.is_binary()? {
-                        bounds.push("B: Into<reqwest::Body>".to_string());
+                        bounds.push("B".to_string());
                         (Some("B".to_string()), Some("body".to_string()))
                     } else {
                         let (ct, mt) = b.content.first().unwrap();
@@ -183,7 +220,7 @@ pub fn generate_files(
                         } else if let Some(s) = &mt.schema {
                             let tid = ts.select(None, s, "")?;
                             let rt = ts.render_type(&tid, false)?;
-                            bounds.push("T: Into<reqwest::Body>".to_string());
+                            bounds.push("T".to_string());
                             if rt == "String" {
                                 (Some("T".to_string()), Some("body".to_string()))
                             } else {
@@ -217,11 +254,26 @@ pub fn generate_files(
             let tmp = parse(p)?;
             let template = tmp.compile(query_params);
 
+            check_error_responses(api, o, &oid);
+
             /*
-             * Get the response type.
+             * Get the response type. Operations that answer with a different
+             * type depending on the status get an enum of their responses.
              */
+            let mut response_enum = get_response_enum(&od, ts, o)?;
+            if let Some(e) = &mut response_enum {
+                e.errors = typed_error_responses(api, o);
+            }
             let (mut response_type, _tid, inner_response_type, pagination_property) =
-                get_response_type(&od, ts, o)?;
+                match &response_enum {
+                    Some(e) => (
+                        format!("crate::types::{}", e.name),
+                        crate::TypeId(0),
+                        "".to_string(),
+                        "".to_string(),
+                    ),
+                    None => get_response_type(&od, ts, o)?,
+                };
 
             // We shouldn't ever have an optional response type, thats just annoying.
             if response_type.starts_with("Option<") {
@@ -231,15 +283,36 @@ pub fn generate_files(
                     .to_string();
             }
 
-            let fn_inner = get_fn_inner(
-                &oid,
-                m,
-                &body_func,
-                &response_type,
-                &inner_response_type,
-                &pagination_property,
-                false,
-            )?;
+            let (fn_inner, blocking_fn_inner) = if let Some(e) = &response_enum {
+                response_types.push_str(&e.render());
+                (
+                    e.fn_inner(m, &body_func, false),
+                    e.fn_inner(m, &body_func, true),
+                )
+            } else {
+                (
+                    get_fn_inner(
+                        &oid,
+                        m,
+                        &body_func,
+                        &response_type,
+                        &inner_response_type,
+                        &pagination_property,
+                        false,
+                        false,
+                    )?,
+                    get_fn_inner(
+                        &oid,
+                        m,
+                        &body_func,
+                        &response_type,
+                        &inner_response_type,
+                        &pagination_property,
+                        false,
+                        true,
+                    )?,
+                )
+            };
 
             // Get the function without the function inners.
             // This is specifically for Ramp.
@@ -280,8 +353,34 @@ pub fn generate_files(
             }
             fn_names.push(fn_name.clone() + &tag);
 
+            let upstream_id = o
+                .extensions
+                .get(crate::overrides::UPSTREAM_OPERATION_ID)
+                .and_then(|id| id.as_str());
+            let operation = |fn_name: &str, fn_params_str: &[String]| Operation {
+                operation_id: upstream_id.unwrap_or(&op_id).to_string(),
+                renamed_operation_id: upstream_id.map(|_| op_id.to_string()),
+                tag: tag.to_string(),
+                function: fn_name.to_string(),
+                method: m.to_string(),
+                path: p.to_string(),
+                parameters: operation_parameters(o, fn_params_str),
+                body: body_param.as_ref().map(|b| {
+                    if bounds.contains(b) {
+                        body_type(false).to_string()
+                    } else {
+                        b.trim_start_matches('&').to_string()
+                    }
+                }),
+                response: frt.to_string(),
+                paginated: !pagination_property.is_empty(),
+            };
+            operations.push(operation(&fn_name, &fn_params_str));
+
             // Print our standard function.
             print_fn(
+                &mut out,
+                false,
                 &docs,
                 &bounds,
                 &fn_params_str,
@@ -291,6 +390,18 @@ pub fn generate_files(
                 &fn_inner,
                 &fn_name,
             );
+            print_fn(
+                &mut blocking_out,
+                true,
+                &docs,
+                &bounds,
+                &fn_params_str,
+                &body_param,
+                &frt,
+                &template,
+                &blocking_fn_inner,
+                &fn_name,
+            );
 
             // TODO: Remove this code once we have restored examples. Leaving this here for now
             // will be useful to know how the examples were being generated
@@ -366,6 +477,17 @@ pub fn generate_files(
                     &inner_response_type,
                     &pagination_property,
                     true,
+                    false,
+                )?;
+                let blocking_fn_inner = get_fn_inner(
+                    &oid,
+                    m,
+                    &body_func,
+                    &response_type,
+                    &inner_response_type,
+                    &pagination_property,
+                    true,
+                    true,
                 )?;
 
                 let mut fn_name = oid
@@ -397,9 +519,12 @@ pub fn generate_files(
                     fn_name = format!("{}_all", fn_name);
                 }
                 fn_names.push(fn_name.clone() + &tag);
+                operations.push(operation(&fn_name, &fn_params_str));
 
                 // Now let's print the new function.
                 print_fn(
+                    &mut out,
+                    false,
                     &docs,
                     &bounds,
                     &fn_params_str,
@@ -409,6 +534,18 @@ pub fn generate_files(
                     &fn_inner,
                     &fn_name,
                 );
+                print_fn(
+                    &mut blocking_out,
+                    true,
+                    &docs,
+                    &bounds,
+                    &fn_params_str,
+                    &body_param,
+                    &frt,
+                    &template,
+                    &blocking_fn_inner,
+                    &fn_name,
+                );
             }
 
             new_api
@@ -417,6 +554,7 @@ pub fn generate_files(
                 .insert(pn.to_string(), openapiv3::ReferenceOr::Item(new_op.clone()));
 
             // Add this to our map of functions based on the tag name.
+            blocking_tag_files.insert(tag.to_string(), blocking_out.to_string());
             tag_files.insert(tag, out.to_string());
 
             Ok(())
@@ -432,7 +570,44 @@ pub fn generate_files(
         gen(pn.as_str(), "TRACE", op.trace.as_ref(), &mut new_op)?;
     }
 
-    Ok((tag_files, new_api))
+    Ok((
+        Files {
+            tags: tag_files,
+            blocking_tags: blocking_tag_files,
+            types: response_types,
+            operations,
+        },
+        new_api,
+    ))
+}
+
+/*
+ * The expression for the body of a request.
+ */
+fn request_body(body_func: &Option<String>, blocking: bool) -> String {
+    if let Some(f) = &body_func {
+        if f == "json" {
+            format!(
+                "Some({}::from(serde_json::to_vec(body)?))",
+                body_type(blocking)
+            )
+        } else {
+            "Some(body.into())".to_string()
+        }
+    } else {
+        "None".to_string()
+    }
+}
+
+/*
+ * The type request bodies are converted into.
+ */
+fn body_type(blocking: bool) -> &'static str {
+    if blocking {
+        "reqwest::blocking::Body"
+    } else {
+        "reqwest::Body"
+    }
 }
 
 fn get_response_type_from_object(
@@ -517,8 +692,19 @@ fn get_response_type(
         ));
     }
 
-    // Get the first response.
-    let first = o.responses.responses.first().unwrap();
+    // Get the first response that means success, error responses are all
+    // parsed as `crate::types::Error`.
+    let first = match success_responses(o).first() {
+        Some(first) => *first,
+        None => {
+            return Ok((
+                "()".to_string(),
+                crate::TypeId(0),
+                "".to_string(),
+                "".to_string(),
+            ))
+        }
+    };
     if let Ok(i) = first.1.item() {
         if i.content.is_empty() {
             // Return empty.
@@ -598,6 +784,270 @@ fn get_response_type(
     ))
 }
 
+/*
+ * The responses of an operation for a successful (2xx) status, by code with
+ * explicit codes before ranges.
+ */
+fn success_responses(
+    o: &openapiv3::Operation,
+) -> Vec<(
+    &openapiv3::StatusCode,
+    &openapiv3::ReferenceOr<openapiv3::Response>,
+)> {
+    let mut success: Vec<_> = o
+        .responses
+        .responses
+        .iter()
+        .filter(|(code, _)| match code {
+            openapiv3::StatusCode::Code(n) => (200..300).contains(n),
+            openapiv3::StatusCode::Range(n) => *n == 2,
+        })
+        .collect();
+    success.sort_by_key(|(code, _)| *code);
+    success
+}
+
+/*
+ * The responses of an operation that answers with a different type depending
+ * on the status.
+ */
+struct ResponseEnum {
+    name: String,
+    oid: String,
+    /// The status of each variant, its name, and the type of its body.
+    variants: Vec<(openapiv3::StatusCode, String, Option<String>)>,
+    /// The error statuses whose body is an `Error`.
+    errors: Vec<openapiv3::StatusCode>,
+}
+
+impl ResponseEnum {
+    fn render(&self) -> String {
+        let mut out = String::new();
+        out.push_str(&format!(
+            "/// The responses of `{}`, by status.\n",
+            self.oid
+        ));
+        out.push_str("#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]\n");
+        out.push_str("#[serde(rename_all = \"snake_case\")]\n");
+        out.push_str(&format!("pub enum {} {{\n", self.name));
+        for (code, variant, rt) in &self.variants {
+            out.push_str(&format!("    /// `{}`\n", code));
+            match rt {
+                Some(rt) => out.push_str(&format!("    {}({}),\n", variant, rt)),
+                None => out.push_str(&format!("    {},\n", variant)),
+            }
+        }
+        out.push_str("}\n\n");
+        out
+    }
+
+    /*
+     * Send the request and decode the body as the variant for the status.
+     */
+    fn fn_inner(&self, m: &str, body_func: &Option<String>, blocking: bool) -> String {
+        let mut out = format!(
+            "let (status, resp) = self.client.request_with_status(http::Method::{}, &url, \
+             {}){}?;\n",
+            m.to_uppercase(),
+            request_body(body_func, blocking),
+            if blocking { "" } else { ".await" },
+        );
+        out.push_str("match status.as_u16() {\n");
+        for (code, variant, rt) in &self.variants {
+            let pattern = status_pattern(code);
+            if rt.is_some() {
+                out.push_str(&format!(
+                    "{} => Ok(crate::types::{}::{}(serde_json::from_slice(&resp)?)),\n",
+                    pattern, self.name, variant
+                ));
+            } else {
+                out.push_str(&format!(
+                    "{} => Ok(crate::types::{}::{}),\n",
+                    pattern, self.name, variant
+                ));
+            }
+        }
+        for code in &self.errors {
+            out.push_str(&format!(
+                "{} => Err(crate::types::Error::from(serde_json::from_slice::<\
+                 crate::types::ErrorResponse>(&resp)?).into()),\n",
+                status_pattern(code)
+            ));
+        }
+        out.push_str(
+            "code => Err(anyhow::anyhow!(\"unexpected status {} from {}\", code, url)),\n",
+        );
+        out.push('}');
+        out
+    }
+}
+
+fn status_pattern(code: &openapiv3::StatusCode) -> String {
+    match code {
+        openapiv3::StatusCode::Code(n) => n.to_string(),
+        openapiv3::StatusCode::Range(n) => format!("{}00..={}99", n, n),
+    }
+}
+
+/*
+ * The enum of an operation's responses, if it has more than one successful
+ * response and they do not all have the same type.
+ */
+fn get_response_enum(
+    od: &str,
+    ts: &mut TypeSpace,
+    o: &openapiv3::Operation,
+) -> Result<Option<ResponseEnum>> {
+    let success = success_responses(o);
+    if success.len() < 2 {
+        return Ok(None);
+    }
+
+    let mut variants = Vec::new();
+    for (code, r) in success {
+        let mut single = o.clone();
+        single.responses.responses = Default::default();
+        single.responses.responses.insert(code.clone(), r.clone());
+        let (mut rt, _, _, _) = get_response_type(od, ts, &single)?;
+        if rt.starts_with("Option<") {
+            rt = rt
+                .trim_start_matches("Option<")
+                .trim_end_matches('>')
+                .to_string();
+        }
+
+        let variant = match code {
+            openapiv3::StatusCode::Code(n) => http::StatusCode::from_u16(*n)
+                .ok()
+                .and_then(|s| s.canonical_reason())
+                .map(|reason| struct_name(&reason.to_lowercase()))
+                .unwrap_or_else(|| format!("Status{}", n)),
+            openapiv3::StatusCode::Range(_) => "Success".to_string(),
+        };
+        variants.push((code.clone(), variant, Some(rt).filter(|rt| rt != "()")));
+    }
+    if variants.iter().all(|(_, _, rt)| *rt == variants[0].2) {
+        return Ok(None);
+    }
+
+    let name = struct_name(&format!("{} response", oid_to_object_name(od)));
+    if ts.name_to_id.keys().any(|n| struct_name(n) == name) {
+        bail!(
+            "the responses of {} would be named {}, which is already a type",
+            od,
+            name
+        );
+    }
+
+    Ok(Some(ResponseEnum {
+        name,
+        oid: od.to_string(),
+        variants,
+        errors: Vec::new(),
+    }))
+}
+
+/*
+ * The response type of an operation's function, spelling out the variants of
+ * a response enum.
+ */
+pub fn describe_response_type(
+    od: &str,
+    ts: &mut TypeSpace,
+    o: &openapiv3::Operation,
+) -> Result<String> {
+    if let Some(e) = get_response_enum(od, ts, o)? {
+        let variants: Vec<String> = e
+            .variants
+            .iter()
+            .map(|(code, _, rt)| match rt {
+                Some(rt) => format!("{}: {}", code, rt),
+                None => code.to_string(),
+            })
+            .collect();
+        return Ok(format!(
+            "crate::types::{} ({})",
+            e.name,
+            variants.join(" | ")
+        ));
+    }
+
+    let (rt, _, _, _) = get_response_type(od, ts, o)?;
+    Ok(rt)
+}
+
+/*
+ * The name of the schema of a response's JSON body, following a reference to
+ * the `responses` components.
+ */
+fn response_schema_name(
+    api: &openapiv3::OpenAPI,
+    r: &openapiv3::ReferenceOr<openapiv3::Response>,
+) -> Option<String> {
+    let response = match r {
+        openapiv3::ReferenceOr::Reference { reference } => {
+            let name = reference.trim_start_matches("#/components/responses/");
+            api.components.as_ref()?.responses.get(name)?.as_item()?
+        }
+        openapiv3::ReferenceOr::Item(response) => response,
+    };
+    match response.content.get("application/json")?.schema.as_ref()? {
+        openapiv3::ReferenceOr::Reference { reference } => Some(
+            reference
+                .trim_start_matches("#/components/schemas/")
+                .to_string(),
+        ),
+        openapiv3::ReferenceOr::Item(_) => Some("an inline schema".to_string()),
+    }
+}
+
+fn error_responses(
+    o: &openapiv3::Operation,
+) -> impl Iterator<
+    Item = (
+        &openapiv3::StatusCode,
+        &openapiv3::ReferenceOr<openapiv3::Response>,
+    ),
+> {
+    o.responses.responses.iter().filter(|(code, _)| match code {
+        openapiv3::StatusCode::Code(n) => *n >= 400,
+        openapiv3::StatusCode::Range(n) => *n >= 4,
+    })
+}
+
+/*
+ * The error statuses of an operation that answer with an `Error`, exact
+ * statuses before ranges so that every match arm is reachable.
+ */
+fn typed_error_responses(
+    api: &openapiv3::OpenAPI,
+    o: &openapiv3::Operation,
+) -> Vec<openapiv3::StatusCode> {
+    let mut errors: Vec<openapiv3::StatusCode> = error_responses(o)
+        .filter(|(_, r)| response_schema_name(api, r).as_deref() == Some("Error"))
+        .map(|(code, _)| code.clone())
+        .collect();
+    errors.sort_by_key(|code| matches!(code, openapiv3::StatusCode::Range(_)));
+    errors
+}
+
+/*
+ * Errors are always parsed as `crate::types::Error`, so say so when an
+ * operation declares an error response with a different body.
+ */
+fn check_error_responses(api: &openapiv3::OpenAPI, o: &openapiv3::Operation, oid: &str) {
+    for (code, r) in error_responses(o) {
+        if let Some(name) = response_schema_name(api, r) {
+            if name != "Error" {
+                println!(
+                    "[warn] {} responds to {} with {}, it will be parsed as Error",
+                    oid, code, name
+                );
+            }
+        }
+    }
+}
+
 fn sort_parameters(o: &openapiv3::Operation) -> Result<BTreeMap<String, openapiv3::Parameter>> {
     let mut parameters = BTreeMap::new();
 
@@ -623,7 +1073,7 @@ fn sort_parameters(o: &openapiv3::Operation) -> Result<BTreeMap<String, openapiv
 }
 
 #[allow(clippy::type_complexity)]
-fn get_fn_params(
+pub fn get_fn_params(
     ts: &mut TypeSpace,
     o: &openapiv3::Operation,
     all_pages: bool,
@@ -745,23 +1195,21 @@ fn get_fn_inner(
     inner_response_type: &str,
     pagination_property: &str,
     all_pages: bool,
+    blocking: bool,
 ) -> Result<String> {
-    let body = if let Some(f) = &body_func {
-        if f == "json" {
-            "Some(reqwest::Body::from(serde_json::to_vec(body)?))"
-        } else {
-            "Some(body.into())"
-        }
-    } else {
-        "None"
-    };
+    let body = request_body(body_func, blocking);
+    // The blocking client's functions return their result directly.
+    let await_ = if blocking { "" } else { ".await" };
 
     if all_pages && pagination_property.is_empty() {
-        return Ok(format!("self.client.get_all_pages(&url, {}).await", body));
+        return Ok(format!(
+            "self.client.get_all_pages(&url, {}){}",
+            body, await_
+        ));
     } else if all_pages {
         // We will do a custom function here.
         let inner = format!(
-            r#"let mut resp: {} = self.client.{}(&url, {}).await?;
+            r#"let mut resp: {} = self.client.{}(&url, {}){}?;
 
             let mut {} = resp.{};
             let mut page = resp.next_page;
@@ -769,9 +1217,9 @@ fn get_fn_inner(
             // Paginate if we should.
             while !page.is_empty() {{
                 if !url.contains('?') {{
-                    resp = self.client.{}(&format!("{{}}?page={{}}", url, page), {}).await?;
+                    resp = self.client.{}(&format!("{{}}?page_token={{}}", url, page), {}){}?;
                 }} else {{
-                    resp = self.client.{}(&format!("{{}}&page={{}}", url, page), {}).await?;
+                    resp = self.client.{}(&format!("{{}}&page_token={{}}", url, page), {}){}?;
                 }}
 
 
@@ -789,12 +1237,15 @@ fn get_fn_inner(
             response_type,
             m.to_lowercase(),
             body,
+            await_,
             pagination_property,
             pagination_property,
             m.to_lowercase(),
             body,
+            await_,
             m.to_lowercase(),
             body,
+            await_,
             pagination_property,
             pagination_property,
             pagination_property,
@@ -817,21 +1268,23 @@ fn get_fn_inner(
     {
         if inner_response_type.is_empty() {
             return Ok(format!(
-                "self.client.{}(&url, {}).await",
+                "self.client.{}(&url, {}){}",
                 m.to_lowercase(),
-                body
+                body,
+                await_
             ));
         }
 
         // Okay we have an inner response type, let's return that instead.
         return Ok(format!(
-            r#"let resp: {} = self.client.{}(&url, {}).await?;
+            r#"let resp: {} = self.client.{}(&url, {}){}?;
 
                 // Return our response data.
                 Ok(resp.{})"#,
             response_type,
             m.to_lowercase(),
             body,
+            await_,
             pagination_property
         ));
     }
@@ -840,13 +1293,16 @@ fn get_fn_inner(
         bail!("function {} should be authenticated", oid);
     }
 
-    Ok(r#"self.client.post_media(
+    Ok(format!(
+        r#"self.client.post_media(
             &url,
-            Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+            Some({}::from(serde_json::to_vec(body)?)),
             crate::utils::MediaType::Json,
             crate::auth::AuthenticationConstraint::JWT,
-        ).await"#
-        .to_string())
+        ){}"#,
+        body_type(blocking),
+        await_
+    ))
 }
 
 fn get_fn_docs(
@@ -976,6 +1432,206 @@ fn get_fn_docs_all(o: &openapiv3::Operation, m: &str, p: &str, fn_name: &str) ->
     Ok(out.trim().to_string())
 }
 
+/*
+ * The parameters of the operation that a function takes, with the types of
+ * its arguments.
+ */
+fn operation_parameters(o: &openapiv3::Operation, fn_params_str: &[String]) -> Vec<Parameter> {
+    let args: BTreeMap<&str, &str> = fn_params_str
+        .iter()
+        .filter_map(|p| p.trim_end_matches(',').split_once(": "))
+        .collect();
+
+    let mut parameters = Vec::new();
+    for param in o.parameters.iter().filter_map(|p| p.item().ok()) {
+        let (location, parameter_data) = match param {
+            openapiv3::Parameter::Path { parameter_data, .. } => ("Path", parameter_data),
+            openapiv3::Parameter::Query { parameter_data, .. } => ("Query", parameter_data),
+            openapiv3::Parameter::Header { parameter_data, .. } => ("Header", parameter_data),
+            openapiv3::Parameter::Cookie { parameter_data, .. } => ("Cookie", parameter_data),
+        };
+        let nam = to_snake_case(&parameter_data.name);
+        // Functions that fetch every page leave out the page parameters.
+        let rt = match args
+            .get(nam.as_str())
+            .or_else(|| args.get(format!("{}_", nam).as_str()))
+        {
+            Some(rt) => rt,
+            None => continue,
+        };
+        parameters.push(Parameter {
+            name: parameter_data.name.to_string(),
+            location,
+            rt: rt.to_string(),
+            required: parameter_data.required,
+        });
+    }
+
+    parameters
+}
+
 fn is_page_param(s: &str) -> bool {
     s == "next_page" || s == "page_token" || s == "limit"
 }
+
+#[cfg(test)]
+mod test {
+    use anyhow::Result;
+
+    use super::{generate_files, get_response_enum};
+    use crate::TypeSpace;
+
+    #[test]
+    fn response_enum() -> Result<()> {
+        let op: openapiv3::Operation = serde_json::from_value(serde_json::json!({
+            "operationId": "instance_migrate",
+            "responses": {
+                "4XX": { "$ref": "#/components/responses/Error" },
+                "201": {
+                    "description": "migrated",
+                    "content": { "application/json": { "schema": { "type": "string" } } }
+                },
+                "202": {
+                    "description": "migration enqueued",
+                    "content": { "application/json": { "schema": { "type": "integer" } } }
+                },
+                "204": { "description": "already there" }
+            }
+        }))?;
+
+        let mut ts = TypeSpace::new();
+        let e = get_response_enum("instance_migrate", &mut ts, &op)?.unwrap();
+        assert_eq!(e.name, "InstanceMigrateResponse");
+        let variants: Vec<(String, String, Option<String>)> = e
+            .variants
+            .iter()
+            .map(|(code, v, rt)| (code.to_string(), v.to_string(), rt.clone()))
+            .collect();
+        assert_eq!(
+            variants,
+            vec![
+                ("201".into(), "Created".into(), Some("String".into())),
+                ("202".into(), "Accepted".into(), Some("i64".into())),
+                ("204".into(), "NoContent".into(), None),
+            ]
+        );
+
+        let inner = e.fn_inner("POST", &None, false);
+        assert!(inner.contains("request_with_status(http::Method::POST, &url, None).await?"));
+        assert!(inner.contains(
+            "202 => Ok(crate::types::InstanceMigrateResponse::Accepted(serde_json::from_slice(&\
+             resp)?)),"
+        ));
+        assert!(inner.contains("204 => Ok(crate::types::InstanceMigrateResponse::NoContent),"));
+        assert!(!e.fn_inner("POST", &None, true).contains(".await"));
+
+        // The same type for every status needs no enum.
+        let mut op = op;
+        let created = op.responses.responses[1].clone();
+        op.responses.responses[2] = created;
+        op.responses.responses.truncate(3);
+        assert!(get_response_enum("instance_migrate", &mut ts, &op)?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn response_enum_generated() -> Result<()> {
+        let api: openapiv3::OpenAPI = serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.3",
+            "info": { "title": "test", "version": "0.0.1" },
+            "paths": {
+                "/instances/{instance_name}/migrate": {
+                    "post": {
+                        "operationId": "instance_migrate",
+                        "tags": ["instances"],
+                        "parameters": [{
+                            "in": "path",
+                            "name": "instance_name",
+                            "required": true,
+                            "schema": { "type": "string" }
+                        }],
+                        "responses": {
+                            "5XX": { "$ref": "#/components/responses/Error" },
+                            "404": { "$ref": "#/components/responses/Error" },
+                            "409": {
+                                "description": "conflict",
+                                "content": {
+                                    "application/json": { "schema": { "type": "string" } }
+                                }
+                            },
+                            "201": {
+                                "description": "migrated",
+                                "content": {
+                                    "application/json": { "schema": { "type": "string" } }
+                                }
+                            },
+                            "202": {
+                                "description": "migration enqueued",
+                                "content": {
+                                    "application/json": { "schema": { "type": "integer" } }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            "components": {
+                "responses": {
+                    "Error": {
+                        "description": "Error",
+                        "content": {
+                            "application/json": {
+                                "schema": { "$ref": "#/components/schemas/Error" }
+                            }
+                        }
+                    }
+                },
+                "schemas": {
+                    "Error": {
+                        "type": "object",
+                        "properties": { "message": { "type": "string" } }
+                    }
+                }
+            }
+        }))?;
+
+        let mut ts = TypeSpace::new();
+        let (files, _) = generate_files(&api, &mut ts, &Default::default())?;
+
+        assert!(files.types.contains("pub enum InstanceMigrateResponse {"));
+        assert!(files.types.contains("Created(String),"));
+        assert!(files.types.contains("Accepted(i64),"));
+
+        let error =
+            "Err(crate::types::Error::from(serde_json::from_slice::<crate::types::ErrorResponse>(&\
+             resp)?).into()),";
+        for (file, asyncness) in [
+            (&files.tags["instances"], ".await"),
+            (&files.blocking_tags["instances"], ""),
+        ] {
+            assert!(file.contains("-> Result<crate::types::InstanceMigrateResponse> {"));
+            assert!(file.contains(&format!(
+                "self.client.request_with_status(http::Method::POST, &url, None){}?;",
+                asyncness
+            )));
+            let arms: Vec<&str> = file
+                .lines()
+                .map(|l| l.trim())
+                .filter(|l| l.contains(" => "))
+                .collect();
+            assert_eq!(
+                arms,
+                vec![
+                    "201 => Ok(crate::types::InstanceMigrateResponse::Created(serde_json::from_slice(&resp)?)),",
+                    "202 => Ok(crate::types::InstanceMigrateResponse::Accepted(serde_json::from_slice(&resp)?)),",
+                    &format!("404 => {}", error),
+                    &format!("500..=599 => {}", error),
+                    "code => Err(anyhow::anyhow!(\"unexpected status {} from {}\", code, url)),",
+                ]
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -1,19 +1,27 @@
+mod builders;
 mod client;
+mod diff;
 mod functions;
+mod mock;
+mod operations;
+mod overrides;
+mod samples;
 mod template;
+mod type_tests;
 mod types;
 mod types_templates;
 mod utils;
+mod validate;
 
 use std::{
-    collections::{BTreeMap, HashMap, HashSet},
+    collections::{BTreeMap, HashMap},
     ffi::OsStr,
-    fs::{File, OpenOptions},
+    fs::OpenOptions,
     io::Write,
     path::{Path, PathBuf},
 };
 
-use anyhow::{bail, Result};
+use anyhow::{bail, Context, Result};
 use inflector::cases::{
     pascalcase::to_pascal_case, snakecase::to_snake_case, titlecase::to_title_case,
 };
@@ -41,20 +49,51 @@ where
     for<'de> T: Deserialize<'de>,
 {
     let p = p.as_ref();
-    let f = File::open(p)?;
-    if let Some(ext) = p.extension() {
-        if ext == OsStr::new("yaml") || ext == OsStr::new("yml") {
-            return Ok(serde_yaml::from_reader(f)?);
-        }
+    let s = std::fs::read_to_string(p).with_context(|| format!("reading {}", p.display()))?;
+    // Documents that are not named after their format are JSON if they look
+    // like it.
+    let yaml = match p.extension() {
+        Some(ext) if ext == OsStr::new("yaml") || ext == OsStr::new("yml") => true,
+        Some(ext) if ext == OsStr::new("json") => false,
+        _ => !s.trim_start().starts_with('{'),
+    };
+    if yaml {
+        serde_yaml::from_str(&s).with_context(|| format!("parsing {} as YAML", p.display()))
+    } else {
+        serde_json::from_str(&s).with_context(|| format!("parsing {} as JSON", p.display()))
     }
-    Ok(serde_json::from_reader(f)?)
 }
 
-fn load_api<P>(p: P) -> Result<OpenAPI>
+/*
+ * Load an API definition, apply the overrides, and check it for what the
+ * generator does not support. That is an error, unless `lenient` is set and
+ * the operations and types using it can be left out instead.
+ */
+fn load_api<P>(p: P, overrides: Option<&overrides::Overrides>, lenient: bool) -> Result<OpenAPI>
 where
     P: AsRef<Path>,
 {
-    let api: OpenAPI = load(p)?;
+    let p = p.as_ref();
+    let mut api: OpenAPI = load(p)?;
+    if let Some(overrides) = overrides {
+        api = overrides.apply(api)?;
+    }
+
+    let problems = validate::validate(&api)?;
+    if !problems.is_empty() {
+        if lenient {
+            api = validate::skip_unsupported(api, &problems)?;
+        } else {
+            for problem in &problems {
+                eprintln!("{}", problem);
+            }
+            bail!(
+                "{} has {} unsupported constructs; pass --lenient to leave out what uses them",
+                p.display(),
+                problems.len()
+            );
+        }
+    }
 
     if api.openapi != "3.0.3" {
         /*
@@ -113,52 +152,22 @@ where
      * to immediately affect our code generation.
      */
 
-    let mut opids = HashSet::new();
-    for p in api.paths.iter() {
-        match p.1 {
-            openapiv3::ReferenceOr::Reference { reference: _ } => {
-                bail!("path {} uses reference, unsupported", p.0);
-            }
-            openapiv3::ReferenceOr::Item(item) => {
-                /*
-                 * Make sure every operation has an operation ID, and that each
-                 * operation ID is only used once in the document.
-                 */
-                let mut id = |o: Option<&openapiv3::Operation>| -> Result<()> {
-                    if let Some(o) = o {
-                        if let Some(oid) = o.operation_id.as_ref() {
-                            if !opids.insert(oid.to_string()) {
-                                bail!("duplicate operation ID: {}", oid);
-                            }
-
-                            if !o.servers.is_empty() {
-                                println!("op {}: servers, unsupported", oid);
-                            }
-
-                            if o.security.is_some() {
-                                //println!("op {}: security, unsupported", oid);
-                            }
-
-                            if o.responses.default.is_some() {
-                                println!("op {}: has response default", oid);
-                            }
-                        }
+    // Path references and duplicate operation ids were validated above.
+    for (_, item) in api.paths.iter() {
+        if let openapiv3::ReferenceOr::Item(item) = item {
+            for (_, o) in item.iter() {
+                if let Some(oid) = o.operation_id.as_ref() {
+                    if !o.servers.is_empty() {
+                        println!("op {}: servers, unsupported", oid);
                     }
 
-                    Ok(())
-                };
-
-                id(item.get.as_ref())?;
-                id(item.put.as_ref())?;
-                id(item.post.as_ref())?;
-                id(item.delete.as_ref())?;
-                id(item.options.as_ref())?;
-                id(item.head.as_ref())?;
-                id(item.patch.as_ref())?;
-                id(item.trace.as_ref())?;
+                    if o.security.is_some() {
+                        //println!("op {}: security, unsupported", oid);
+                    }
 
-                if !item.servers.is_empty() {
-                    bail!("path {} has servers; unsupported", p.0);
+                    if o.responses.default.is_some() {
+                        println!("op {}: has response default", oid);
+                    }
                 }
             }
         }
@@ -188,6 +197,9 @@ impl ParameterDataExt for openapiv3::ParameterData {
                 match s {
                     openapiv3::ReferenceOr::Reference { reference } => {
                         let tid = ts.select_ref(Some(name), reference.as_str())?;
+                        if !types::has_display(ts, &tid) {
+                            bail!("parameter {} is a union without a string form", self.name);
+                        }
                         let t = ts.render_type(&tid, false)?;
                         if t == "String" {
                             return Ok("&str".to_string());
@@ -274,7 +286,7 @@ impl ParameterDataExt for openapiv3::ParameterData {
                                         "dateTime" => "chrono::DateTime<chrono::Utc>".to_string(),
                                         "ipv4" => "std::net::Ipv4Addr".to_string(),
                                         "ipv6" => "std::net::Ipv6Addr".to_string(),
-                                        "ip" => "&str".to_string(),
+                                        "ip" => "std::net::IpAddr".to_string(),
                                         "uri" => "&url::Url".to_string(),
                                         "uri-template" => "&str".to_string(),
                                         "url" => "&url::Url".to_string(),
@@ -352,7 +364,17 @@ impl ParameterDataExt for openapiv3::ParameterData {
 
                                 format.to_string()
                             }
-                            openapiv3::SchemaKind::OneOf { one_of: _ } => "&str".to_string(), /* TODO: make this smarter. */
+                            SchemaKind::OneOf { one_of: _ } => {
+                                let tid =
+                                    ts.select_schema(Some(&clean_name(&self.name)), s, "", "")?;
+                                if !types::has_display(ts, &tid) {
+                                    bail!(
+                                        "parameter {} is a union without a string form",
+                                        self.name
+                                    );
+                                }
+                                ts.render_type(&tid, false)?
+                            }
                             openapiv3::SchemaKind::Any(_) => "&str".to_string(), /* TODO: make this smarter. */
                             x => bail!("unexpected type {:#?}", x),
                         }
@@ -1826,7 +1848,14 @@ impl TypeSpace {
                             )),
                             "ip" => Ok((
                                 Some(uid.to_string()),
-                                TypeDetails::Basic("String".to_string(), s.schema_data.clone()),
+                                TypeDetails::Basic(
+                                    if s.schema_data.nullable {
+                                        "Option<std::net::IpAddr>".to_string()
+                                    } else {
+                                        "std::net::IpAddr".to_string()
+                                    },
+                                    s.schema_data.clone(),
+                                ),
                             )),
                             "uri" => Ok((
                                 Some(uid.to_string()),
@@ -1878,6 +1907,18 @@ impl TypeSpace {
                     TypeDetails::Basic("f64".to_string(), s.schema_data.clone()),
                 )),
                 openapiv3::Type::Integer(it) => {
+                    // Byte counts get a newtype that enforces the i64::MAX
+                    // limit and displays human-readable sizes.
+                    if name == Some("byte count") {
+                        return Ok((
+                            Some(uid.to_string()),
+                            TypeDetails::Basic(
+                                "crate::types::ByteCount".to_string(),
+                                s.schema_data.clone(),
+                            ),
+                        ));
+                    }
+
                     let format = match &it.format {
                         openapiv3::VariantOrUnknownOrEmpty::Item(
                             openapiv3::IntegerFormat::Int32,
@@ -2006,7 +2047,7 @@ impl TypeSpace {
 
                 Ok((
                     Some(one_of_name),
-                    TypeDetails::OneOf(one_of.clone(), Default::default()),
+                    TypeDetails::OneOf(one_of.clone(), s.schema_data.clone()),
                 ))
             }
             openapiv3::SchemaKind::AnyOf { any_of } => {
@@ -2286,6 +2327,11 @@ pub fn render_param(
             // TODO: do something for empty(?)
             continue;
         }
+        // Values serde would not get back from the variant, like `TCP`. Values
+        // that already are variant names are the tags of unions.
+        if struct_name(e) != *e && serde_snake_case(&struct_name(e)) != *e {
+            a(&format!(r#"#[serde(rename = "{}")]"#, e));
+        }
         a(&format!("{},", struct_name(e)));
     }
     if !required && default.is_none() && do_fallthrough_etc {
@@ -2381,7 +2427,73 @@ pub fn render_param(
     out.to_string()
 }
 
-fn gen(api: &OpenAPI, tags: Vec<String>) -> Result<String> {
+/// The name serde gives a variant with `rename_all = "snake_case"`.
+fn serde_snake_case(variant: &str) -> String {
+    let mut s = String::new();
+    for (i, c) in variant.char_indices() {
+        if i > 0 && c.is_uppercase() {
+            s.push('_');
+        }
+        s.push(c.to_ascii_lowercase());
+    }
+    s
+}
+
+/*
+ * Modules of the generated crate that are written by hand, with their docs.
+ */
+const HELPER_MODULES: &[(&str, &str)] = &[
+    (
+        "image_import",
+        "Import local image files and image URLs as images, verifying size and digest.",
+    ),
+    (
+        "disk_ops",
+        "Snapshot, clone and move disks, waiting for each state transition.",
+    ),
+    (
+        "snapshot_retention",
+        "Plan and apply hourly, daily and weekly snapshot retention policies.",
+    ),
+    (
+        "manifest",
+        "Plan and apply declarative YAML or JSON descriptions of a project.",
+    ),
+    (
+        "subnet_planner",
+        "Allocate non-overlapping VPC subnet blocks and report address utilization.",
+    ),
+    (
+        "ip_ranges",
+        "Merge, subtract and overlap-check IP pool ranges and report pool utilization.",
+    ),
+    (
+        "route_table",
+        "Resolve, validate and look up destinations in a VPC router's routes offline.",
+    ),
+    (
+        "nic_ops",
+        "Add network interfaces, swap the primary and move interfaces between subnets.",
+    ),
+    (
+        "subnet_inventory",
+        "Map the addresses in a VPC's subnets to their instances and count free addresses.",
+    ),
+    (
+        "hardware_inventory",
+        "Snapshot racks, sleds and service IP ranges, and diff two snapshots.",
+    ),
+    (
+        "saml_onboarding",
+        "Build SAML identity provider create requests from IdP metadata XML and PEM keys.",
+    ),
+    (
+        "silo_bootstrap",
+        "Create a silo with its identity provider, roles, organization and project, or nothing.",
+    ),
+];
+
+fn gen(api: &OpenAPI, tags: &[String]) -> Result<String> {
     let mut out = String::new();
 
     let mut a = |s: &str| {
@@ -2402,50 +2514,31 @@ fn gen(api: &OpenAPI, tags: Vec<String>) -> Result<String> {
     a("");
     a("#[cfg(test)]");
     a("mod tests;");
+    a("#[cfg(test)]");
+    a("mod types_tests;");
     // Hopefully there is never a "tag" named after these reserved libs.
+    a("pub mod operations;");
     a("pub mod types;");
     a("#[doc(hidden)]");
     a("pub mod utils;");
+    a("#[cfg(feature = \"blocking\")]");
+    a("#[cfg_attr(docsrs, doc(cfg(feature = \"blocking\")))]");
+    a("pub mod blocking;");
+
+    /*
+     * Hand-written helpers that compose the generated functions. They live in
+     * the crate next to the generated files and are never overwritten.
+     */
+    for (module, docs) in HELPER_MODULES {
+        a(&format!("/// {}", docs));
+        a(&format!("pub mod {};", module));
+    }
 
     /*
      * Import the module for each tag.
      * Tags are how functions are grouped.
      */
-    for tag in api.tags.iter() {
-        if !tags.contains(&to_snake_case(&clean_name(&tag.name))) {
-            // Return early do nothing!
-            // This fixes Zoom and DocuSign where they list tags that have no associated functions.
-            continue;
-        }
-
-        let mut docs = "".to_string();
-        if let Some(d) = &tag.description {
-            docs = format!("{}.", d.trim_end_matches('.'));
-        }
-        if let Some(e) = &tag.external_docs {
-            if !e.url.is_empty() {
-                docs = format!("{}\n\nFROM: {}", docs, e.url);
-            }
-        }
-        docs = docs.trim().to_string();
-
-        if !docs.is_empty() {
-            a(&format!("/// {}", docs.replace('\n', "\n///"),));
-        }
-        a(&format!(
-            "pub mod {};",
-            to_snake_case(&clean_name(&tag.name))
-        ));
-    }
-    if api.tags.is_empty() {
-        // If the spec didn't call out tags explicitly, we need to use the
-        // ones we found ourselves.
-        for tag in tags.iter() {
-            if !tag.is_empty() {
-                a(&format!("pub mod {};", to_snake_case(&clean_name(tag))));
-            }
-        }
-    }
+    a(&gen_tag_modules(api, tags));
 
     a("");
 
@@ -2486,6 +2579,16 @@ fn gen(api: &OpenAPI, tags: Vec<String>) -> Result<String> {
     a("}");
     a("");
 
+    a("/// Returns whether an API error means the object does not exist (yet).");
+    a("#[allow(dead_code)]");
+    a("pub(crate) fn is_not_found(e: &Error) -> bool {");
+    a("    matches!(");
+    a("        e.downcast_ref::<types::Error>(),");
+    a("        Some(types::Error::ObjectNotFound { .. })");
+    a("    )");
+    a("}");
+    a("");
+
     a("");
 
     // Print the client template.
@@ -2497,6 +2600,74 @@ fn gen(api: &OpenAPI, tags: Vec<String>) -> Result<String> {
      * Generate a function for each tag.
      * Tags are how functions are grouped.
      */
+    a(&gen_tag_accessors(api, tags));
+
+    a("}");
+
+    Ok(out)
+}
+
+/*
+ * Declare the module for each tag.
+ */
+fn gen_tag_modules(api: &OpenAPI, tags: &[String]) -> String {
+    let mut out = String::new();
+
+    let mut a = |s: &str| {
+        out.push_str(s);
+        out.push('\n');
+    };
+
+    for tag in api.tags.iter() {
+        if !tags.contains(&to_snake_case(&clean_name(&tag.name))) {
+            // Return early do nothing!
+            // This fixes Zoom and DocuSign where they list tags that have no associated functions.
+            continue;
+        }
+
+        let mut docs = "".to_string();
+        if let Some(d) = &tag.description {
+            docs = format!("{}.", d.trim_end_matches('.'));
+        }
+        if let Some(e) = &tag.external_docs {
+            if !e.url.is_empty() {
+                docs = format!("{}\n\nFROM: {}", docs, e.url);
+            }
+        }
+        docs = docs.trim().to_string();
+
+        if !docs.is_empty() {
+            a(&format!("/// {}", docs.replace('\n', "\n///"),));
+        }
+        a(&format!(
+            "pub mod {};",
+            to_snake_case(&clean_name(&tag.name))
+        ));
+    }
+    if api.tags.is_empty() {
+        // If the spec didn't call out tags explicitly, we need to use the
+        // ones we found ourselves.
+        for tag in tags.iter() {
+            if !tag.is_empty() {
+                a(&format!("pub mod {};", to_snake_case(&clean_name(tag))));
+            }
+        }
+    }
+
+    out
+}
+
+/*
+ * Return a reference to the struct of each tag from the client.
+ */
+fn gen_tag_accessors(api: &OpenAPI, tags: &[String]) -> String {
+    let mut out = String::new();
+
+    let mut a = |s: &str| {
+        out.push_str(s);
+        out.push('\n');
+    };
+
     for tag in api.tags.iter() {
         if !tags.contains(&to_snake_case(&tag.name)) {
             // Return early do nothing!
@@ -2551,9 +2722,79 @@ fn gen(api: &OpenAPI, tags: Vec<String>) -> Result<String> {
         }
     }
 
+    out
+}
+
+/*
+ * The `blocking` module: the same tags over the blocking client.
+ */
+fn gen_blocking(api: &OpenAPI, tags: &[String]) -> String {
+    let mut out = String::new();
+
+    let mut a = |s: &str| {
+        out.push_str(s);
+        out.push('\n');
+    };
+
+    a("//! A client that blocks the calling thread instead of returning futures,");
+    a("//! for programs that have no async runtime.");
+    a("//!");
+    a("//! It has the same tags and operations as the async [`Client`](crate::Client).");
+    a("//! The hand-written helpers are only available on the async client.");
+    a("//!");
+    a("//! ```no_run");
+    a("//! use oxide_api::blocking::Client;");
+    a("//!");
+    a("//! let client = Client::new_from_env();");
+    a("//! let image = client.images_global().images_get_image(\"ubuntu-22-04\")?;");
+    a("//! println!(\"{}\", image.id);");
+    a("//! # Ok::<(), anyhow::Error>(())");
+    a("//! ```");
+    a("");
+    a(&gen_tag_modules(api, tags));
+    a("");
+    a("use anyhow::{anyhow, Error, Result};");
+    a("");
+    a("use crate::progenitor_support;");
+    a("");
+    a(&crate::client::generate_blocking_client());
+    a("");
+    a(&gen_tag_accessors(api, tags));
     a("}");
 
-    Ok(out)
+    out
+}
+
+/*
+ * The file for a tag: its struct, holding a client, and its functions.
+ */
+fn gen_tag_file(client: &str, tag: &str, content: &str) -> String {
+    format!(
+        r#"use anyhow::Result;
+
+use {};
+
+pub struct {} {{
+    pub client: Client,
+}}
+
+impl {} {{
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self
+    {{
+        {} {{
+            client,
+        }}
+    }}
+
+    {}
+}}"#,
+        client,
+        struct_name(tag),
+        struct_name(tag),
+        struct_name(tag),
+        content,
+    )
 }
 
 fn struct_name(s: &str) -> String {
@@ -2755,6 +2996,13 @@ fn oid_to_object_name(s: &str) -> String {
 }
 
 fn main() -> Result<()> {
+    if std::env::args().nth(1).as_deref() == Some("diff") {
+        return diff::main(std::env::args().skip(2));
+    }
+    if std::env::args().nth(1).as_deref() == Some("mock") {
+        return mock::main(std::env::args().skip(2));
+    }
+
     let mut opts = getopts::Options::new();
     opts.parsing_style(getopts::ParsingStyle::StopAtFirstFree);
     opts.reqopt(
@@ -2768,6 +3016,17 @@ fn main() -> Result<()> {
     opts.reqopt("v", "", "Target Rust crate version", "VERSION");
     opts.reqopt("d", "", "Target Rust crate description", "DESCRIPTION");
     opts.reqopt("", "spec-link", "Link to the spec", "SPEC_LINK");
+    opts.optopt(
+        "",
+        "overrides",
+        "Operation and type overrides to apply to the definition (TOML)",
+        "OVERRIDES",
+    );
+    opts.optflag(
+        "",
+        "lenient",
+        "Leave out operations and types the generator does not support, with a warning",
+    );
     opts.optflag("", "debug", "Print debug output");
 
     let args = match opts.parse(std::env::args().skip(1)) {
@@ -2786,7 +3045,11 @@ fn main() -> Result<()> {
 
     let input_spec = args.opt_str("i").unwrap();
 
-    let api = load_api(&input_spec)?;
+    let overrides = match args.opt_str("overrides") {
+        Some(p) => Some(overrides::Overrides::load(p)?),
+        None => None,
+    };
+    let api = load_api(&input_spec, overrides.as_ref(), args.opt_present("lenient"))?;
 
     let debug = |s: &str| {
         if args.opt_present("debug") {
@@ -3106,7 +3369,7 @@ fn main() -> Result<()> {
     tags.sort_unstable();
     tags.dedup();
 
-    let fail = match gen(&api, tags) {
+    let fail = match gen(&api, &tags) {
         Ok(out) => {
             let description = args.opt_str("d").unwrap();
 
@@ -3134,9 +3397,11 @@ license = "MIT"
 
 [dependencies]
 anyhow = "1"
+base64 = "^0.13"
 bytes = {{ version = "1", features = ["serde"] }}
 chrono = {{ version = "0.4", features = ["serde"] }}
 chrono-humanize = "^0.2.1"
+csv = "^1.1"
 dirs = {{ version = "^4.0.0", optional = true }}
 http = "^0.2.4"
 hyperx = "1"
@@ -3144,17 +3409,23 @@ ipnetwork = "^0.18"
 log = {{ version = "^0.4", features = ["serde"] }}
 mime = "0.3"
 parse-display = "^0.5"
+pem = "^1.1"
 percent-encoding = "2.1"
-reqwest = {{ version = "0.11", default-features = false, features = ["json", "multipart", "rustls-tls"] }}
+reqwest = {{ version = "0.11", default-features = false, features = ["cookies", "json", "multipart", "rustls-tls"] }}
+roxmltree = "^0.15"
 schemars = {{ version = "0.8", features = ["bytes", "chrono", "url", "uuid"] }}
 serde = {{ version = "1", features = ["derive"] }}
 serde_json = "1"
+serde_yaml = "^0.8"
 serde_with = "1"
 serde_urlencoded = "^0.7"
+sha2 = "^0.10"
 tabled = {{ version = "0.5.0", features = ["color"] }}
 thiserror = "^1"
+tokio = {{ version = "1.8.0", features = ["rt", "time"] }}
 url = {{ version = "2", features = ["serde"] }}
 uuid = {{ version = "^0.8", features = ["serde", "v4"] }}
+x509-parser = "^0.14"
 
 [dev-dependencies]
 base64 = "^0.13"
@@ -3165,6 +3436,8 @@ pretty_assertions = "1"
 tokio = {{ version = "1.8.0", features = ["full"] }}
 
 [features]
+# a client that blocks instead of returning futures
+blocking = ["reqwest/blocking"]
 # enable etag-based http_cache functionality
 httpcache = ["dirs"]
 
@@ -3221,13 +3494,39 @@ rustdoc-args = ["--cfg", "docsrs"]
             let types = types::generate_types(&api, &mut ts)?;
             let mut typesrs = src.clone();
             typesrs.push("types.rs");
-            save(typesrs, types.as_str())?;
+            save(&typesrs, types.as_str())?;
+
+            /*
+             * Create the tests of the types:
+             */
+            let type_tests = type_tests::generate_type_tests(&api, &mut ts)?;
+            let mut type_testsrs = src.clone();
+            type_testsrs.push("types_tests.rs");
+            save(type_testsrs, type_tests.as_str())?;
 
             /*
              * Create the Rust source files for each of the tags functions:
              */
             let fail = match functions::generate_files(&api, &mut ts, &parameters) {
                 Ok((files, _new_api)) => {
+                    /*
+                     * Add the types only the functions define, such as the
+                     * responses of operations with more than one:
+                     */
+                    if !files.types.is_empty() {
+                        save(&typesrs, &format!("{}\n{}", types, files.types))?;
+                    }
+
+                    /*
+                     * Create the registry of the operations the functions call:
+                     */
+                    let mut operationsrs = src.clone();
+                    operationsrs.push("operations.rs");
+                    save(
+                        operationsrs,
+                        &operations::generate_operations(&files.operations),
+                    )?;
+
                     let mut extension: HashMap<String, String> = HashMap::new();
                     extension.insert(
                         "install".to_string(),
@@ -3257,36 +3556,31 @@ let client = Client::new_from_env();"#
                     //      .insert("x-rust".to_string(), serde_json::json!(extension));
 
                     // We have a map of our files, let's write to them.
-                    for (f, content) in files {
+                    for (f, content) in files.tags {
                         let mut tagrs = src.clone();
                         tagrs.push(format!("{}.rs", to_snake_case(&clean_name(&f))));
 
-                        let output = format!(
-                            r#"use anyhow::Result;
-
-use crate::Client;
-
-pub struct {} {{
-    pub client: Client,
-}}
+                        save(tagrs, gen_tag_file("crate::Client", &f, &content).as_str())?;
+                    }
 
-impl {} {{
-    #[doc(hidden)]
-    pub fn new(client: Client) -> Self
-    {{
-        {} {{
-            client,
-        }}
-    }}
+                    /*
+                     * Create the blocking module, with the same files for
+                     * each tag over the blocking client:
+                     */
+                    let mut blocking = src.clone();
+                    blocking.push("blocking");
+                    std::fs::create_dir_all(&blocking)?;
+                    let mut blockingrs = blocking.clone();
+                    blockingrs.push("mod.rs");
+                    save(blockingrs, gen_blocking(&api, &tags).as_str())?;
+                    for (f, content) in files.blocking_tags {
+                        let mut tagrs = blocking.clone();
+                        tagrs.push(format!("{}.rs", to_snake_case(&clean_name(&f))));
 
-    {}
-}}"#,
-                            struct_name(&f),
-                            struct_name(&f),
-                            struct_name(&f),
-                            content,
-                        );
-                        save(tagrs, output.as_str())?;
+                        save(
+                            tagrs,
+                            gen_tag_file("crate::blocking::Client", &f, &content).as_str(),
+                        )?;
                     }
 
                     // TODO: Remove this code once we have restored examples. Leaving this here for now
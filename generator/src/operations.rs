@@ -0,0 +1,213 @@
+/*
+ * Describe every generated method in a static registry, so that users can map
+ * requests back to operations without the API definition at runtime.
+ */
+
+/// A parameter of a generated method.
+#[derive(Debug, PartialEq)]
+pub struct Parameter {
+    /// The name in the API.
+    pub name: String,
+    /// The variant of `ParameterLocation`.
+    pub location: &'static str,
+    pub rt: String,
+    pub required: bool,
+}
+
+/// A generated method and the operation it calls.
+#[derive(Debug, PartialEq)]
+pub struct Operation {
+    /// The id in the API definition, before any override.
+    pub operation_id: String,
+    pub renamed_operation_id: Option<String>,
+    pub tag: String,
+    pub function: String,
+    pub method: String,
+    pub path: String,
+    pub parameters: Vec<Parameter>,
+    pub body: Option<String>,
+    pub response: String,
+    pub paginated: bool,
+}
+
+pub fn generate_operations(operations: &[Operation]) -> String {
+    let mut out = OPERATIONS.to_string();
+    let mut a = |s: &str| {
+        out.push_str(s);
+        out.push('\n');
+    };
+
+    a("");
+    a("/// Every method of the client, in the order of the paths of the API definition.");
+    a("pub static ALL: &[OperationInfo] = &[");
+    for o in operations {
+        a("OperationInfo {");
+        a(&format!("operation_id: {:?},", o.operation_id));
+        a(&format!(
+            "renamed_operation_id: {:?},",
+            o.renamed_operation_id
+        ));
+        a(&format!("tag: {:?},", o.tag));
+        a(&format!("function: {:?},", o.function));
+        a(&format!("method: {:?},", o.method));
+        a(&format!("path: {:?},", o.path));
+        a("parameters: &[");
+        for p in &o.parameters {
+            a(&format!(
+                "ParameterInfo {{ name: {:?}, location: ParameterLocation::{}, rust_type: {:?}, \
+                 required: {} }},",
+                p.name, p.location, p.rt, p.required
+            ));
+        }
+        a("],");
+        a(&format!("body_type: {:?},", o.body));
+        a(&format!("response_type: {:?},", o.response));
+        a(&format!("paginated: {},", o.paginated));
+        a("},");
+    }
+    a("];");
+
+    out
+}
+
+const OPERATIONS: &str = r##"//! Metadata of every method of the client: the operation it calls, where its
+//! parameters go and the types it sends and returns. It maps request paths
+//! back to operations without the API definition.
+
+/// Where a parameter goes in the request.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParameterLocation {
+    Path,
+    Query,
+    Header,
+    Cookie,
+}
+
+/// A parameter of an operation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParameterInfo {
+    /// The name in the API, which is also the name in the path template.
+    pub name: &'static str,
+    pub location: ParameterLocation,
+    /// The Rust type of the method's argument.
+    pub rust_type: &'static str,
+    pub required: bool,
+}
+
+/// A method of the client and the operation it calls.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OperationInfo {
+    /// The id in the API definition.
+    pub operation_id: &'static str,
+    /// The id the overrides gave the operation instead, which the generated
+    /// code is named after.
+    pub renamed_operation_id: Option<&'static str>,
+    /// The tag of the operation, which is also the client's accessor for the
+    /// method: `client.{tag}().{function}()`.
+    pub tag: &'static str,
+    pub function: &'static str,
+    /// The HTTP method, in upper case.
+    pub method: &'static str,
+    /// The path template, with parameters in braces.
+    pub path: &'static str,
+    /// The parameters of the method. Those that fetch every page leave out
+    /// the pagination parameters.
+    pub parameters: &'static [ParameterInfo],
+    /// The Rust type of the request body, if there is one.
+    pub body_type: Option<&'static str>,
+    pub response_type: &'static str,
+    /// Whether the operation answers with pages of items.
+    pub paginated: bool,
+}
+
+impl OperationInfo {
+    /// The values of the path parameters if `path` matches the path template,
+    /// ignoring any query string.
+    pub fn match_path(&self, path: &str) -> Option<Vec<(&'static str, String)>> {
+        let path = path.split('?').next().unwrap_or_default();
+        let segments: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+        let template: Vec<&'static str> = self.path.trim_start_matches('/').split('/').collect();
+        if template.len() != segments.len() {
+            return None;
+        }
+
+        let mut params = Vec::new();
+        for (t, s) in template.iter().zip(&segments) {
+            match t.strip_prefix('{').and_then(|t| t.strip_suffix('}')) {
+                Some(param) => params.push((
+                    param,
+                    percent_encoding::percent_decode_str(s)
+                        .decode_utf8_lossy()
+                        .to_string(),
+                )),
+                None if t == s => {}
+                None => return None,
+            }
+        }
+        Some(params)
+    }
+
+    fn literals(&self) -> usize {
+        self.path.split('/').filter(|s| !s.starts_with('{')).count()
+    }
+}
+
+/// The operation a request calls, preferring literal path segments over
+/// parameters. The method is compared without regard to case.
+///
+/// Operations with a method fetching a single page and one fetching every
+/// page are found as the former.
+pub fn find(method: &str, path: &str) -> Option<&'static OperationInfo> {
+    let mut best: Option<&'static OperationInfo> = None;
+    for o in ALL.iter().filter(|o| o.method.eq_ignore_ascii_case(method)) {
+        if o.match_path(path).is_some() && best.map(|b| o.literals() > b.literals()).unwrap_or(true) {
+            best = Some(o);
+        }
+    }
+    best
+}
+
+/// The methods calling the operation with `operation_id` in the API
+/// definition.
+pub fn by_operation_id(operation_id: &str) -> impl Iterator<Item = &'static OperationInfo> + '_ {
+    ALL.iter().filter(move |o| o.operation_id == operation_id)
+}
+"##;
+
+#[cfg(test)]
+mod test {
+    use super::{generate_operations, Operation, Parameter};
+
+    #[test]
+    fn operations() {
+        let out = generate_operations(&[Operation {
+            operation_id: "organization_view".to_string(),
+            renamed_operation_id: Some("organizations_get_organization".to_string()),
+            tag: "organizations".to_string(),
+            function: "get".to_string(),
+            method: "GET".to_string(),
+            path: "/organizations/{organization_name}".to_string(),
+            parameters: vec![Parameter {
+                name: "organization_name".to_string(),
+                location: "Path",
+                rt: "&str".to_string(),
+                required: true,
+            }],
+            body: None,
+            response: "crate::types::Organization".to_string(),
+            paginated: false,
+        }]);
+
+        for expected in [
+            r#"operation_id: "organization_view","#,
+            r#"renamed_operation_id: Some("organizations_get_organization"),"#,
+            r#"path: "/organizations/{organization_name}","#,
+            "location: ParameterLocation::Path",
+            r#"rust_type: "&str""#,
+            "body_type: None,",
+            r#"response_type: "crate::types::Organization","#,
+        ] {
+            assert!(out.contains(expected), "{} is not in:\n{}", expected, out);
+        }
+    }
+}
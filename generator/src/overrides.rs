@@ -0,0 +1,265 @@
+/*
+ * Overrides applied to the OpenAPI document before generating code: renamed
+ * and re-tagged operations, hidden operations, renamed types, and the string
+ * forms of unions.
+ */
+
+use std::{collections::BTreeMap, path::Path};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Overrides {
+    /// Keyed by the upstream operation id.
+    #[serde(default)]
+    pub operations: BTreeMap<String, OperationOverride>,
+    /// New names of schemas, keyed by the upstream schema name.
+    #[serde(default)]
+    pub types: BTreeMap<String, String>,
+    /**
+     * Unions written as `variant=value` on the command line, keyed by the
+     * upstream schema name. Variants spelled other than their tag map to
+     * their spelling.
+     */
+    #[serde(default)]
+    pub string_forms: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum OperationOverride {
+    /// Just rename the operation.
+    Id(String),
+    Full {
+        #[serde(default)]
+        id: Option<String>,
+        #[serde(default)]
+        tag: Option<String>,
+        #[serde(default)]
+        hidden: bool,
+    },
+}
+
+/// The operation extension keeping the upstream id of a renamed operation.
+pub const UPSTREAM_OPERATION_ID: &str = "x-upstream-operation-id";
+
+const METHODS: &[&str] = &[
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+impl Overrides {
+    pub fn load<P: AsRef<Path>>(p: P) -> Result<Self> {
+        let p = p.as_ref();
+        let s = std::fs::read_to_string(p).with_context(|| format!("reading {}", p.display()))?;
+        toml::from_str(&s).with_context(|| format!("parsing {}", p.display()))
+    }
+
+    /**
+     * Apply the overrides to the API. Overrides for operations or types
+     * the API does not have are reported, since they usually mean upstream
+     * renamed something.
+     */
+    pub fn apply(&self, api: openapiv3::OpenAPI) -> Result<openapiv3::OpenAPI> {
+        let mut doc = serde_json::to_value(api)?;
+
+        let mut unused: Vec<&String> = self.operations.keys().collect();
+        if let Some(paths) = doc.get_mut("paths").and_then(|p| p.as_object_mut()) {
+            // Keys not starting with a slash are extensions.
+            for (_, item) in paths.iter_mut().filter(|(k, _)| k.starts_with('/')) {
+                let item = match item.as_object_mut() {
+                    Some(item) => item,
+                    None => continue,
+                };
+                for method in METHODS {
+                    let op_id = match item
+                        .get(*method)
+                        .and_then(|o| o.get("operationId"))
+                        .and_then(|id| id.as_str())
+                    {
+                        Some(op_id) => op_id.to_string(),
+                        None => continue,
+                    };
+                    let o = match self.operations.get(&op_id) {
+                        Some(o) => o,
+                        None => continue,
+                    };
+                    unused.retain(|u| **u != op_id);
+
+                    let (id, tag, hidden) = match o {
+                        OperationOverride::Id(id) => (Some(id), None, false),
+                        OperationOverride::Full { id, tag, hidden } => {
+                            (id.as_ref(), tag.as_ref(), *hidden)
+                        }
+                    };
+                    if hidden {
+                        item.remove(*method);
+                        continue;
+                    }
+                    let op = item.get_mut(*method).unwrap();
+                    if let Some(id) = id {
+                        op["operationId"] = serde_json::json!(id);
+                        op[UPSTREAM_OPERATION_ID] = serde_json::json!(op_id);
+                    }
+                    if let Some(tag) = tag {
+                        op["tags"] = serde_json::json!([tag]);
+                    }
+                }
+            }
+            // Drop paths left without any operations.
+            paths.retain(|k, item| {
+                !k.starts_with('/')
+                    || item
+                        .as_object()
+                        .map(|item| METHODS.iter().any(|m| item.contains_key(*m)))
+                        .unwrap_or(true)
+            });
+        }
+        for op_id in unused {
+            println!("override for unknown operation {}", op_id);
+        }
+
+        // Before the renames, since these are keyed by the upstream names.
+        for (name, aliases) in &self.string_forms {
+            match doc.pointer_mut(&format!("/components/schemas/{}", name)) {
+                Some(schema) => {
+                    schema[crate::types::STRING_FORM] = serde_json::json!(aliases);
+                }
+                None => println!("override for unknown type {}", name),
+            }
+        }
+
+        if !self.types.is_empty() {
+            let schemas = doc
+                .pointer_mut("/components/schemas")
+                .and_then(|s| s.as_object_mut());
+            if let Some(schemas) = schemas {
+                for (from, to) in &self.types {
+                    match schemas.remove(from) {
+                        Some(schema) => {
+                            schemas.insert(to.to_string(), schema);
+                        }
+                        None => println!("override for unknown type {}", from),
+                    }
+                }
+            }
+            rename_refs(&mut doc, &self.types);
+        }
+
+        Ok(serde_json::from_value(doc)?)
+    }
+}
+
+/// Point every `$ref` to a renamed schema at its new name.
+fn rename_refs(v: &mut serde_json::Value, types: &BTreeMap<String, String>) {
+    match v {
+        serde_json::Value::Object(map) => {
+            for (k, v) in map.iter_mut() {
+                if k == "$ref" {
+                    let to = v
+                        .as_str()
+                        .and_then(|r| r.strip_prefix("#/components/schemas/"))
+                        .and_then(|name| types.get(name));
+                    if let Some(to) = to {
+                        *v = serde_json::json!(format!("#/components/schemas/{}", to));
+                    }
+                } else {
+                    rename_refs(v, types);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for v in items {
+                rename_refs(v, types);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use anyhow::Result;
+
+    use super::Overrides;
+
+    #[test]
+    fn apply() -> Result<()> {
+        let api: openapiv3::OpenAPI = serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.3",
+            "info": { "title": "t", "version": "1" },
+            "paths": {
+                "/racks": {
+                    "get": {
+                        "operationId": "rack_list",
+                        "tags": ["system"],
+                        "responses": {
+                            "200": {
+                                "description": "ok",
+                                "content": {
+                                    "application/json": {
+                                        "schema": { "$ref": "#/components/schemas/Rack" }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    "post": {
+                        "operationId": "rack_create",
+                        "responses": {}
+                    }
+                },
+                "/console": {
+                    "get": { "operationId": "console_page", "responses": {} }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "Rack": { "type": "object" }
+                }
+            }
+        }))?;
+        let overrides: Overrides = toml::from_str(
+            r#"
+[operations]
+rack_list = { id = "hardware_racks_get", tag = "racks" }
+rack_create = "hardware_racks_post"
+console_page = { hidden = true }
+gone = "still_here"
+
+[types]
+Rack = "HardwareRack"
+
+[string_forms]
+Rack = { internet_gateway = "inetgw" }
+"#,
+        )?;
+
+        let api = overrides.apply(api)?;
+        let racks = api.paths.paths["/racks"].as_item().unwrap();
+        let get = racks.get.as_ref().unwrap();
+        assert_eq!(get.operation_id.as_deref(), Some("hardware_racks_get"));
+        assert_eq!(get.tags, vec!["racks".to_string()]);
+        assert_eq!(
+            get.extensions[super::UPSTREAM_OPERATION_ID],
+            serde_json::json!("rack_list")
+        );
+        let post = racks.post.as_ref().unwrap();
+        assert_eq!(post.operation_id.as_deref(), Some("hardware_racks_post"));
+        assert!(!api.paths.paths.contains_key("/console"));
+
+        let schemas = &api.components.as_ref().unwrap().schemas;
+        assert!(schemas.contains_key("HardwareRack"));
+        assert!(!schemas.contains_key("Rack"));
+        let rack = schemas["HardwareRack"].as_item().unwrap();
+        assert_eq!(
+            rack.schema_data.extensions[crate::types::STRING_FORM],
+            serde_json::json!({ "internet_gateway": "inetgw" })
+        );
+        let json = serde_json::to_string(&api)?;
+        assert!(json.contains("#/components/schemas/HardwareRack"));
+
+        Ok(())
+    }
+}
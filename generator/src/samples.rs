@@ -0,0 +1,185 @@
+/*
+ * Build values that are valid for the schemas of the API definition, from
+ * their examples or their constraints. The mock answers with minimal ones and
+ * the generated tests round-trip full ones.
+ */
+
+use serde_json::Value;
+
+/// The name of the schema a `$ref` points to.
+pub fn ref_name(v: &Value) -> Option<&str> {
+    v.get("$ref")?
+        .as_str()?
+        .strip_prefix("#/components/schemas/")
+}
+
+/// How deeply objects and arrays of full values nest before only the required
+/// parts are built, so that recursive schemas end.
+const FULL_DEPTH: usize = 8;
+
+/// A value with only the required properties, and empty or zero otherwise.
+pub fn minimal(schemas: &Value, schema: &Value) -> Value {
+    sample(schemas, schema, false, 0)
+}
+
+/// A value with every property and array set to something that is not empty
+/// or zero, so that nothing is skipped when it is serialized again.
+pub fn full(schemas: &Value, schema: &Value) -> Value {
+    sample(schemas, schema, true, 0)
+}
+
+/// Full values of each enum value and each variant of a union.
+pub fn variants(schemas: &Value, schema: &Value) -> Vec<Value> {
+    if let Some(name) = ref_name(schema) {
+        return variants(schemas, &schemas[name]);
+    }
+    if schema.get("example").is_none() {
+        if let Some(values) = schema.get("enum").and_then(|e| e.as_array()) {
+            return values.clone();
+        }
+        for union in ["oneOf", "anyOf"] {
+            if let Some(variants) = schema.get(union).and_then(|u| u.as_array()) {
+                return variants.iter().map(|v| full(schemas, v)).collect();
+            }
+        }
+    }
+    vec![full(schemas, schema)]
+}
+
+fn sample(schemas: &Value, schema: &Value, full: bool, depth: usize) -> Value {
+    if depth > 2 * FULL_DEPTH {
+        return Value::Null;
+    }
+    let full = full && depth < FULL_DEPTH;
+    if let Some(name) = ref_name(schema) {
+        return sample(schemas, &schemas[name], full, depth);
+    }
+    if let Some(example) = schema.get("example") {
+        return example.clone();
+    }
+    if let Some(first) = schema.get("enum").and_then(|e| e.get(0)) {
+        return first.clone();
+    }
+    for union in ["allOf", "oneOf", "anyOf"] {
+        if let Some(first) = schema.get(union).and_then(|u| u.get(0)) {
+            return sample(schemas, first, full, depth);
+        }
+    }
+
+    match schema.get("type").and_then(|t| t.as_str()) {
+        Some("object") => {
+            let mut object = serde_json::Map::new();
+            let properties = schema.get("properties").and_then(|p| p.as_object());
+            let required = schema.get("required").and_then(|r| r.as_array());
+            for (name, property) in properties.into_iter().flatten() {
+                if full || required.into_iter().flatten().any(|r| r == name) {
+                    let value = sample(schemas, property, full, depth + 1);
+                    object.insert(name.to_string(), value);
+                }
+            }
+            match schema.get("additionalProperties") {
+                Some(values) if full && values.is_object() => {
+                    let value = sample(schemas, values, full, depth + 1);
+                    object.insert("key".to_string(), value);
+                }
+                _ => {}
+            }
+            Value::Object(object)
+        }
+        Some("array") => match schema.get("items") {
+            Some(items) if full => Value::Array(vec![sample(schemas, items, full, depth + 1)]),
+            _ => Value::Array(Vec::new()),
+        },
+        Some("string") => {
+            let format = schema.get("format").and_then(|f| f.as_str());
+            let s = match (format, full) {
+                (Some("date-time"), false) => "1970-01-01T00:00:00Z",
+                (Some("date-time"), true) => "2022-06-01T12:30:00Z",
+                (Some("uuid"), false) => "00000000-0000-0000-0000-000000000000",
+                (Some("uuid"), true) => "6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71",
+                (Some("ip"), false) | (Some("ipv4"), false) => "0.0.0.0",
+                (Some("ip"), true) | (Some("ipv4"), true) => "10.0.0.1",
+                (Some("ipv6"), false) => "::",
+                (Some("ipv6"), true) => "fd00::1",
+                (Some("uri"), _) => "http://localhost/",
+                (_, false) => "",
+                (_, true) => "sample",
+            };
+            Value::String(s.to_string())
+        }
+        Some("integer") => match schema.get("minimum") {
+            Some(minimum) if !full || minimum.as_f64() != Some(0.0) => minimum.clone(),
+            _ => Value::from(if full { 1 } else { 0 }),
+        },
+        // Not a whole number, which floats would serialize with a fraction.
+        Some("number") => Value::from(if full { 1.5 } else { 0.0 }),
+        Some("boolean") => Value::Bool(full),
+        _ => Value::Null,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::{full, minimal, variants};
+
+    #[test]
+    fn samples() {
+        let schemas = json!({
+            "Rack": {
+                "type": "object",
+                "properties": {
+                    "id": { "type": "string", "format": "uuid" },
+                    "subnet": { "$ref": "#/components/schemas/Ipv4Net" },
+                    "state": { "$ref": "#/components/schemas/RackState" },
+                    "size": { "type": "integer", "minimum": 0 },
+                    "tags": { "type": "array", "items": { "type": "string" } },
+                    "parent": { "allOf": [{ "$ref": "#/components/schemas/Rack" }] }
+                },
+                "required": ["id", "subnet", "state", "size"]
+            },
+            "Ipv4Net": { "type": "string", "example": "10.0.0.0/8" },
+            "RackState": {
+                "oneOf": [
+                    { "type": "string", "enum": ["up", "down"] },
+                    {
+                        "type": "object",
+                        "properties": { "failed": { "type": "string" } },
+                        "required": ["failed"]
+                    }
+                ]
+            }
+        });
+
+        assert_eq!(
+            minimal(&schemas, &schemas["Rack"]),
+            json!({
+                "id": "00000000-0000-0000-0000-000000000000",
+                "subnet": "10.0.0.0/8",
+                "state": "up",
+                "size": 0,
+            })
+        );
+
+        let rack = full(&schemas, &schemas["Rack"]);
+        assert_eq!(rack["size"], 1);
+        assert_eq!(rack["tags"], json!(["sample"]));
+        assert_eq!(rack["parent"]["tags"], json!(["sample"]));
+
+        // The nesting ends with the required properties only.
+        let mut deepest = &rack;
+        while deepest.get("parent").is_some() {
+            deepest = &deepest["parent"];
+        }
+        assert_eq!(deepest.get("tags"), None);
+
+        assert_eq!(
+            variants(
+                &schemas,
+                &json!({ "$ref": "#/components/schemas/RackState" })
+            ),
+            vec![json!("up"), json!({ "failed": "sample" })]
+        );
+    }
+}
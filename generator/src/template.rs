@@ -474,11 +474,41 @@ pub fn generate_docs(
 //!
 //! let oxide = Client::new_from_env();
 //! ```
+//!
+//! Clients can also log in as a local silo user and authenticate with the
+//! session cookie instead of a token. They log in again when the session
+//! expires.
+//!
+//! ```no_run
+//! use {}::Client;
+//!
+//! async fn connect() -> anyhow::Result<Client> {{
+//!     Client::new_with_password(
+//!         "https://oxide.sys.example.com",
+//!         "corp",
+//!         "alice",
+//!         "hunter2",
+//!     )
+//!     .await
+//! }}
+//! ```
+//!
+//! Programs without an async runtime can enable the `blocking` feature and use
+//! `blocking::Client`, which has the same operations but waits for each
+//! response instead of returning a future.
+//!
+//! ```toml
+//! [dependencies]
+//! {} = {{ version = "{}", features = ["blocking"] }}
+//! ```
 //!"#,
         info,
         name.replace('_', "-").to_lowercase(),
         version,
         name,
         name,
+        name,
+        name.replace('_', "-").to_lowercase(),
+        version,
     )
 }
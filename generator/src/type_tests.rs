@@ -0,0 +1,165 @@
+/*
+ * Generate a test for each schema of the API definition: sample values of the
+ * schema go through the generated type and back to JSON unchanged, and match
+ * the JSON schema of the type.
+ */
+
+use anyhow::Result;
+use inflector::cases::snakecase::to_snake_case;
+use openapiv3::OpenAPI;
+
+use crate::{samples, TypeSpace};
+
+pub fn generate_type_tests(api: &OpenAPI, ts: &mut TypeSpace) -> Result<String> {
+    let doc = serde_json::to_value(api)?;
+    let schemas = doc
+        .pointer("/components/schemas")
+        .cloned()
+        .unwrap_or_default();
+
+    let mut out = TYPE_TESTS.to_string();
+    let mut a = |s: &str| {
+        out.push_str(s);
+        out.push('\n');
+    };
+
+    for (sn, schema) in schemas.as_object().into_iter().flatten() {
+        let id = ts.select_ref(None, &format!("#/components/schemas/{}", sn))?;
+        let rt = if sn == "Error" {
+            // The API's error is named after the `Error` enum it converts into.
+            "crate::types::ErrorResponse".to_string()
+        } else {
+            ts.render_type(&id, false)?
+        };
+        // Schemas of basic types have nothing of their own to test.
+        if !rt.starts_with("crate::types::") {
+            continue;
+        }
+
+        a("");
+        a("#[test]");
+        a(&format!(
+            "fn test_round_trip_{}() {{",
+            to_snake_case(rt.trim_start_matches("crate::types::"))
+        ));
+        a(&format!("    round_trip::<{}>(&[", rt));
+        for value in samples::variants(&schemas, schema) {
+            a(&format!("        r##\"{}\"##,", value));
+        }
+        a("    ]);");
+        a("}");
+    }
+
+    Ok(out)
+}
+
+const TYPE_TESTS: &str = r##"//! Round trips of samples of every type through JSON, generated from the
+//! examples and schemas of the API definition.
+
+use pretty_assertions::assert_eq;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+fn round_trip<T>(samples: &[&str])
+where
+    T: DeserializeOwned + Serialize + schemars::JsonSchema,
+{
+    let schema = serde_json::to_value(schemars::schema_for!(T)).unwrap();
+    for sample in samples {
+        let expected: Value = serde_json::from_str(sample).unwrap();
+        let value: T = serde_json::from_value(expected.clone())
+            .unwrap_or_else(|e| panic!("deserializing {}: {}", sample, e));
+        let actual = serde_json::to_value(&value).unwrap();
+        assert_eq!(actual, expected);
+        if let Err(e) = validate(&schema, &schema, &actual) {
+            panic!("{} does not match the schema: {}", sample, e);
+        }
+    }
+}
+
+/// Check a value against the parts of JSON schema that schemars generates.
+fn validate(root: &Value, schema: &Value, value: &Value) -> Result<(), String> {
+    if let Some(reference) = schema.get("$ref").and_then(|r| r.as_str()) {
+        let name = reference.trim_start_matches("#/definitions/");
+        return validate(root, &root["definitions"][name], value);
+    }
+
+    if let Some(types) = schema.get("type") {
+        let types: Vec<&str> = match types {
+            Value::Array(types) => types.iter().filter_map(|t| t.as_str()).collect(),
+            t => t.as_str().into_iter().collect(),
+        };
+        let matches = |t: &&str| match *t {
+            "null" => value.is_null(),
+            "boolean" => value.is_boolean(),
+            "integer" => value.is_i64() || value.is_u64(),
+            "number" => value.is_number(),
+            "string" => value.is_string(),
+            "array" => value.is_array(),
+            "object" => value.is_object(),
+            _ => true,
+        };
+        if !types.iter().any(matches) {
+            return Err(format!("{} is not of type {:?}", value, types));
+        }
+    }
+    if let Some(values) = schema.get("enum").and_then(|e| e.as_array()) {
+        if !values.contains(value) {
+            return Err(format!("{} is not one of {:?}", value, values));
+        }
+    }
+    if let Some(constant) = schema.get("const") {
+        if constant != value {
+            return Err(format!("{} is not {}", value, constant));
+        }
+    }
+
+    if let Some(object) = value.as_object() {
+        let properties = schema.get("properties").and_then(|p| p.as_object());
+        for required in schema.get("required").and_then(|r| r.as_array()).into_iter().flatten() {
+            if !object.contains_key(required.as_str().unwrap_or_default()) {
+                return Err(format!("{} is missing {}", value, required));
+            }
+        }
+        for (k, v) in object {
+            match (properties.and_then(|p| p.get(k)), schema.get("additionalProperties")) {
+                (Some(property), _) => validate(root, property, v)?,
+                (None, Some(Value::Bool(false))) => {
+                    return Err(format!("{} has unknown property {}", value, k));
+                }
+                (None, Some(additional)) => validate(root, additional, v)?,
+                (None, None) => {}
+            }
+        }
+    }
+    if let (Some(items), Some(array)) = (schema.get("items"), value.as_array()) {
+        for item in array {
+            validate(root, items, item)?;
+        }
+    }
+
+    if let Some(all) = schema.get("allOf").and_then(|a| a.as_array()) {
+        for s in all {
+            validate(root, s, value)?;
+        }
+    }
+    if let Some(any) = schema.get("anyOf").and_then(|a| a.as_array()) {
+        if !any.iter().any(|s| validate(root, s, value).is_ok()) {
+            return Err(format!("{} matches none of {}", value, Value::from(any.clone())));
+        }
+    }
+    if let Some(one) = schema.get("oneOf").and_then(|o| o.as_array()) {
+        let matching = one.iter().filter(|s| validate(root, s, value).is_ok()).count();
+        if matching != 1 {
+            return Err(format!(
+                "{} matches {} of {}",
+                value,
+                matching,
+                Value::from(one.clone())
+            ));
+        }
+    }
+
+    Ok(())
+}
+"##;
@@ -1,9 +1,9 @@
-use std::{cmp::Ordering, collections::BTreeMap};
+use std::collections::BTreeMap;
 
 use anyhow::{bail, Result};
 use inflector::cases::snakecase::to_snake_case;
 
-use crate::{render_param, struct_name, TypeDetails, TypeId, TypeSpace};
+use crate::{builders::Field, render_param, struct_name, TypeDetails, TypeId, TypeSpace};
 
 /*
  * Declare named types we know about:
@@ -19,6 +19,8 @@ pub fn generate_types(api: &openapiv3::OpenAPI, ts: &mut TypeSpace) -> Result<St
     // Make sure we don't generate duplicate types.
     let mut seen: BTreeMap<String, bool> = BTreeMap::new();
 
+    let bodies = crate::builders::request_bodies(api)?;
+
     a("//! The data types sent to and returned from the API client.");
     a("    use parse_display::{Display, FromStr};");
     a("    use schemars::JsonSchema;");
@@ -47,8 +49,16 @@ pub fn generate_types(api: &openapiv3::OpenAPI, ts: &mut TypeSpace) -> Result<St
             } else if sn == "Error" {
                 a(crate::types_templates::ERROR);
                 sn = "ErrorResponse".to_string();
-            } else if sn == "Datum" {
-                a(crate::types_templates::DATUM);
+            } else if sn == "ByteCount" {
+                a(crate::types_templates::BYTE_COUNT);
+
+                continue;
+            } else if sn == "Ipv4Range" {
+                a(crate::types_templates::IPV4_RANGE);
+
+                continue;
+            } else if sn == "Ipv6Range" {
+                a(crate::types_templates::IPV6_RANGE);
 
                 continue;
             }
@@ -70,7 +80,9 @@ pub fn generate_types(api: &openapiv3::OpenAPI, ts: &mut TypeSpace) -> Result<St
                     a(&p);
                 }
                 TypeDetails::Placeholder(..) => {}
-                TypeDetails::OneOf(omap, _) => a(&do_one_of_type(ts, omap, sn)),
+                TypeDetails::OneOf(omap, schema_data) => {
+                    a(&do_one_of_type(ts, omap, schema_data, sn)?)
+                }
                 TypeDetails::AnyOf(omap, _) => a(&do_all_of_type(ts, omap, sn)),
                 TypeDetails::AllOf(omap, _) => a(&do_all_of_type(ts, omap, sn)),
                 TypeDetails::Object(omap, schema_data) => {
@@ -106,10 +118,11 @@ pub fn generate_types(api: &openapiv3::OpenAPI, ts: &mut TypeSpace) -> Result<St
                         && sn != "DiskCreate"
                         && sn != "GlobalImageCreate"
                         && sn != "SamlIdentityProviderCreate"
-                        && sn != "Ipv4Range"
-                        && sn != "Ipv6Range"
+                        && sn != "ExternalIp"
+                        && sn != "NetworkInterface"
                         && sn != "Binint64"
                         && sn != "Bindouble"
+                        && sn != "Measurement"
                     {
                         a("Default,");
                     }
@@ -123,20 +136,29 @@ pub fn generate_types(api: &openapiv3::OpenAPI, ts: &mut TypeSpace) -> Result<St
                     // If possible we want the order to be id, name, description,
                     // then everything else.
                     // Let's shoot for that.
+                    let mut fields = Vec::new();
                     let try_first = vec!["id", "name", "description"];
                     for f in try_first.iter() {
                         if let Some(tid) = omap.get(&f.to_string()) {
-                            a(&render_property(ts, tid, f, &desc, &sn)?);
+                            let (p, field) = render_property(ts, tid, f, &desc, &sn)?;
+                            a(&p);
+                            fields.push(field);
                             omap.remove(&f.to_string());
                         }
                     }
 
                     for (name, tid) in omap.iter() {
-                        a(&render_property(ts, tid, name, &desc, &sn)?);
+                        let (p, field) = render_property(ts, tid, name, &desc, &sn)?;
+                        a(&p);
+                        fields.push(field);
                     }
                     a("}");
                     a("");
 
+                    if let Some(schema) = bodies.get(&sn) {
+                        a(&crate::builders::render_builder(&sn, &fields, schema));
+                    }
+
                     if sn == "Distribution" {
                         a("impl fmt::Display for Distribution {");
                         a("    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {");
@@ -163,7 +185,12 @@ pub fn generate_types(api: &openapiv3::OpenAPI, ts: &mut TypeSpace) -> Result<St
     // Iterate over anything we missed.
     if let Some(components) = &api.components {
         for (_i, (sn, s)) in components.schemas.iter().enumerate() {
-            if sn == "Ipv6Net" || sn == "Ipv4Net" {
+            if sn == "Ipv6Net"
+                || sn == "Ipv4Net"
+                || sn == "ByteCount"
+                || sn == "Ipv4Range"
+                || sn == "Ipv6Range"
+            {
                 continue;
             }
 
@@ -200,7 +227,7 @@ fn render_property(
     name: &str,
     desc: &str,
     sn: &str,
-) -> Result<String> {
+) -> Result<(String, Field)> {
     let mut out = String::new();
 
     let mut a = |s: &str| {
@@ -313,7 +340,7 @@ fn render_property(
             a(r#"#[serde(default,
                                     skip_serializing_if = "crate::utils::zero_f64",
                                     deserialize_with = "crate::utils::deserialize_null_f64::deserialize","#);
-        } else if rt == "u32" || rt == "u64" {
+        } else if rt == "u32" || rt == "u64" || rt == "crate::types::ByteCount" {
             a(r#"#[serde(default,"#);
         } else if let TypeDetails::Enum(_, sd) = &te.details {
             // We for sure have a default for every single enum, even
@@ -367,7 +394,10 @@ fn render_property(
         }
 
         // Hide things from the table that don't implement display.
-        if (rt.starts_with("Vec<") || rt.starts_with("Option<") || rt == "VpcFirewallRuleFilter")
+        if (rt.starts_with("Vec<")
+            || rt.starts_with("Option<")
+            || rt == "VpcFirewallRuleFilter"
+            || !has_display(ts, tid))
             && sn != "VpcFirewallRuleFilter"
         {
             a(r#"#[header(hidden = true)]"#);
@@ -378,245 +408,422 @@ fn render_property(
         }
 
         a(&format!("pub {}: {},", prop, rt));
+
+        Ok((
+            out,
+            Field {
+                name: name.to_string(),
+                prop,
+                rt,
+            },
+        ))
     } else {
         bail!("rendering type {} {:?} failed", name, tid);
     }
-
-    Ok(out.to_string())
 }
 
-fn do_one_of_type(
-    ts: &mut TypeSpace,
-    one_of: &[openapiv3::ReferenceOr<openapiv3::Schema>],
-    sn: String,
-) -> String {
-    let mut out = String::new();
+/// Unions written by hand, with string forms and representations that the
+/// schemas do not describe.
+const ONE_OF_TEMPLATES: &[(&str, &str)] = &[
+    ("IpNet", crate::types_templates::IP_NET),
+    ("IpRange", crate::types_templates::IP_RANGE),
+];
+
+/// The schema extension giving a union a `variant=value` string form, for
+/// `Display` and `FromStr`. It maps variants to their spelling in the string
+/// form when that is not the tag.
+pub const STRING_FORM: &str = "x-string-form";
+
+/// How the variants of a union are told apart in JSON.
+#[derive(Debug, PartialEq)]
+enum Tagging {
+    /// `{"<tag>": "<variant>", "<content>": <value>}`.
+    Adjacent { tag: String, content: String },
+    /// `{"<tag>": "<variant>", <fields>...}`.
+    Internal { tag: String },
+    /// `{"<variant>": <value>}`.
+    External,
+    /// Whichever variant the value deserializes as first.
+    Untagged,
+}
 
-    let mut a = |s: &str| {
-        out.push_str(s);
-        out.push('\n');
-    };
+#[derive(Debug)]
+struct Variant {
+    name: String,
+    /// How the variant is spelled in JSON, if it is spelled at all.
+    value: Option<String>,
+    description: Option<String>,
+    /// The fields of an internally tagged variant, or the one type of any
+    /// other variant.
+    fields: Vec<(String, TypeId)>,
+}
 
-    if sn == "IpNet" {
-        return crate::types_templates::IP_NET.to_string();
+/// The type an id ends up naming, through references to other types.
+fn resolve<'a>(ts: &'a TypeSpace, tid: &TypeId) -> Option<&'a crate::TypeEntry> {
+    let mut te = ts.id_to_entry.get(tid)?;
+    while let TypeDetails::NamedType(tid, _) | TypeDetails::ComponentSchema(tid, _) = &te.details {
+        te = ts.id_to_entry.get(tid)?;
     }
+    Some(te)
+}
 
-    if sn == "IpRange" {
-        return crate::types_templates::IP_RANGE.to_string();
+/// The value of an enum with only one, which is what tags look like.
+fn single_value(ts: &TypeSpace, tid: &TypeId) -> Option<String> {
+    match &resolve(ts, tid)?.details {
+        TypeDetails::Enum(values, _) if values.len() == 1 => Some(values[0].to_string()),
+        _ => None,
     }
+}
 
-    if sn == "DiskSource" {
-        return crate::types_templates::DISK_SOURCE.to_string();
-    }
+fn is_optional(ts: &TypeSpace, tid: &TypeId) -> bool {
+    matches!(
+        ts.id_to_entry.get(tid).map(|te| &te.details),
+        Some(TypeDetails::Optional(..))
+    )
+}
 
-    if sn == "Datum" {
-        return crate::types_templates::DATUM.to_string();
+/// Whether the type of a property implements `Display`, which only unions may
+/// not.
+pub fn has_display(ts: &TypeSpace, tid: &TypeId) -> bool {
+    match resolve(ts, tid) {
+        Some(te) => match &te.details {
+            TypeDetails::OneOf(_, schema_data) => {
+                let name = te.name.as_deref().map(struct_name).unwrap_or_default();
+                schema_data.extensions.contains_key(STRING_FORM)
+                    || ONE_OF_TEMPLATES.iter().any(|(n, _)| *n == name)
+            }
+            _ => true,
+        },
+        None => true,
     }
+}
 
-    let mut tag = "";
-    let mut content = "";
-    let mut omap: Vec<crate::TypeId> = Default::default();
+/*
+ * Work out how the variants of a union are told apart: by a discriminator or
+ * a property every variant has with a single value, by their one property, or
+ * not at all.
+ */
+fn one_of_variants(
+    ts: &mut TypeSpace,
+    one_of: &[openapiv3::ReferenceOr<openapiv3::Schema>],
+    schema_data: &openapiv3::SchemaData,
+    sn: &str,
+) -> Result<(Tagging, Vec<Variant>)> {
+    let mut ids = Vec::new();
     for one in one_of {
-        let itid = ts.select(Some(&sn), one, "").unwrap();
-        omap.push(itid);
+        let id = ts.select(Some(sn), one, "")?;
+        if !ids.iter().any(|(i, _)| *i == id) {
+            ids.push((id, one));
+        }
     }
 
-    omap.sort_unstable();
-    omap.dedup();
-
-    let mut is_enum = false;
-
-    for itid in omap.iter() {
-        // Determine if we can do anything fancy with the resulting enum and flatten it.
-        let et = ts.id_to_entry.get(itid).unwrap();
-
-        if let TypeDetails::Object(o, _) = &et.details {
-            // Iterate over the properties of the object and try to find a tag.
-            for (name, prop) in o.iter() {
-                let pet = ts.id_to_entry.get(prop).unwrap();
-                // Check if we have an enum of one.
-                if let TypeDetails::Enum(e, _) = &pet.details {
-                    is_enum = true;
-                    if e.len() == 1 {
-                        // We have an enum of one so we can use that as the tag.
-                        tag = name;
-                        continue;
-                    }
-                } else if o.len() == 2 {
-                    content = name;
+    let objects: Vec<Option<(BTreeMap<String, TypeId>, openapiv3::SchemaData)>> = ids
+        .iter()
+        .map(|(id, _)| match resolve(ts, id).map(|te| &te.details) {
+            Some(TypeDetails::Object(props, sd)) => Some((props.clone(), sd.clone())),
+            _ => None,
+        })
+        .collect();
+    let objects: Option<Vec<_>> = objects.into_iter().collect();
+
+    if let Some(objects) = &objects {
+        let discriminator = schema_data
+            .discriminator
+            .as_ref()
+            .map(|d| d.property_name.to_string());
+        let tag = discriminator.or_else(|| {
+            let (first, _) = objects.first()?;
+            first
+                .keys()
+                .find(|name| {
+                    objects.iter().all(|(props, _)| {
+                        props
+                            .get(*name)
+                            .and_then(|tid| single_value(ts, tid))
+                            .is_some()
+                    })
+                })
+                .cloned()
+        });
+
+        if let Some(tag) = tag {
+            let mut variants = Vec::new();
+            for (props, sd) in objects {
+                let value = match props.get(&tag).and_then(|tid| single_value(ts, tid)) {
+                    Some(value) => value,
+                    None => bail!("a variant of {} has no single value for {}", sn, tag),
+                };
+                variants.push(Variant {
+                    name: struct_name(&value),
+                    value: Some(value),
+                    description: sd.description.clone(),
+                    fields: props
+                        .iter()
+                        .filter(|(name, _)| **name != tag)
+                        .map(|(name, tid)| (name.to_string(), tid.clone()))
+                        .collect(),
+                });
+            }
+
+            // The variants with data all have it in the same required field.
+            let mut contents: Vec<&str> = variants
+                .iter()
+                .flat_map(|v| v.fields.iter().map(|(name, _)| name.as_str()))
+                .collect();
+            contents.dedup();
+            let adjacent = contents.len() == 1
+                && variants.iter().all(|v| {
+                    v.fields.len() <= 1 && v.fields.iter().all(|(_, tid)| !is_optional(ts, tid))
+                });
+            let tagging = if adjacent {
+                Tagging::Adjacent {
+                    tag,
+                    content: contents[0].to_string(),
                 }
+            } else {
+                Tagging::Internal { tag }
+            };
+            return Ok((tagging, variants));
+        }
+
+        let mut names: Vec<&String> = objects.iter().flat_map(|(props, _)| props.keys()).collect();
+        names.sort_unstable();
+        names.dedup();
+        if objects.iter().all(|(props, _)| props.len() == 1) && names.len() == objects.len() {
+            let variants = objects
+                .iter()
+                .map(|(props, sd)| {
+                    let (name, tid) = props.iter().next().unwrap();
+                    Variant {
+                        name: struct_name(name),
+                        value: Some(name.to_string()),
+                        description: sd.description.clone(),
+                        fields: vec![(name.to_string(), tid.clone())],
+                    }
+                })
+                .collect();
+            return Ok((Tagging::External, variants));
+        }
+    }
+
+    let mut variants = Vec::new();
+    for (i, (id, one)) in ids.iter().enumerate() {
+        let name = match one {
+            openapiv3::ReferenceOr::Reference { reference } => {
+                struct_name(reference.rsplit('/').next().unwrap_or_default())
             }
+            openapiv3::ReferenceOr::Item(s) => match &s.schema_data.title {
+                Some(title) => struct_name(title),
+                None => format!("Variant{}", i),
+            },
+        };
+        variants.push(Variant {
+            name,
+            value: None,
+            description: None,
+            fields: vec![(String::new(), id.clone())],
+        });
+    }
+    Ok((Tagging::Untagged, variants))
+}
+
+/// The variants of a union, which must not share a tag or a name.
+fn distinct_variants(
+    ts: &mut TypeSpace,
+    one_of: &[openapiv3::ReferenceOr<openapiv3::Schema>],
+    schema_data: &openapiv3::SchemaData,
+    sn: &str,
+) -> Result<(Tagging, Vec<Variant>)> {
+    let (tagging, variants) = one_of_variants(ts, one_of, schema_data, sn)?;
+    for (i, v) in variants.iter().enumerate() {
+        if let Some(value) = &v.value {
+            if variants[..i].iter().any(|o| o.value.as_ref() == Some(value)) {
+                bail!("{} has more than one variant tagged {}", sn, value);
+            }
+        }
+        if variants[..i].iter().any(|o| o.name == v.name) {
+            bail!("{} has more than one variant named {}", sn, v.name);
         }
     }
+    Ok((tagging, variants))
+}
 
-    a("#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]");
-    if !tag.is_empty() {
-        a("#[serde(rename_all = \"snake_case\")]");
-        a(&format!("#[serde(tag = \"{}\"", tag));
-        if !content.is_empty() {
-            a(&format!(", content = \"{}\"", content));
+/*
+ * The variants of the union generated for `sn`, by name, with their fields or
+ * type, and how they are told apart. Hand-written unions have none.
+ */
+pub fn union_variants(
+    ts: &mut TypeSpace,
+    one_of: &[openapiv3::ReferenceOr<openapiv3::Schema>],
+    schema_data: &openapiv3::SchemaData,
+    sn: &str,
+) -> Result<(String, BTreeMap<String, String>)> {
+    if ONE_OF_TEMPLATES.iter().any(|(name, _)| *name == sn) {
+        return Ok((String::new(), BTreeMap::new()));
+    }
+
+    let (tagging, variants) = distinct_variants(ts, one_of, schema_data, sn)?;
+    let mut shapes = BTreeMap::new();
+    for v in variants {
+        let mut fields = Vec::new();
+        for (name, tid) in &v.fields {
+            let rt = ts.render_type(tid, true)?;
+            fields.push(match tagging {
+                Tagging::Internal { .. } => format!("{}: {}", to_snake_case(name), rt),
+                _ => rt,
+            });
         }
-        a(")]");
+        let shape = match (&tagging, fields.is_empty()) {
+            (_, true) => String::new(),
+            (Tagging::Internal { .. }, false) => format!(" {{ {} }}", fields.join(", ")),
+            (_, false) => format!("({})", fields.join(", ")),
+        };
+        shapes.insert(v.name, shape);
     }
-    a(&format!("pub enum {} {{", sn));
+    Ok((format!("{:?}", tagging), shapes))
+}
 
-    let mut types_strings: BTreeMap<String, (String, String)> = Default::default();
+fn do_one_of_type(
+    ts: &mut TypeSpace,
+    one_of: &[openapiv3::ReferenceOr<openapiv3::Schema>],
+    schema_data: &openapiv3::SchemaData,
+    sn: String,
+) -> Result<String> {
+    if let Some((_, template)) = ONE_OF_TEMPLATES.iter().find(|(name, _)| *name == sn) {
+        return Ok(template.to_string());
+    }
 
-    let mut prop_types: Vec<(String, String)> = Default::default();
+    let mut out = String::new();
 
-    for tid in omap.iter() {
-        let et = ts.id_to_entry.get(tid).unwrap();
-        if let TypeDetails::Object(o, _) = &et.details {
-            let mut name = String::new();
-            for (key, prop) in o.iter() {
-                let pet = ts.id_to_entry.get(prop).unwrap();
-                // Check if we have an enum of one.
-                if let TypeDetails::Enum(e, _) = &pet.details {
-                    if e.len() == 1 {
-                        let prop = struct_name(&e[0]);
-                        let mut sep = "";
-                        // We have an enum of one so we can use that as the tag.
-                        if o.len() == 1 {
-                            a(&format!("{},", prop));
-                        } else if o.len() == 2 {
-                            a(&format!("{}(", prop));
-                            sep = "(..)";
-                        } else if o.len() > 2 {
-                            a(&format!("{} {{", prop));
-                            sep = "{..}";
-                        }
-                        types_strings.insert(prop.clone(), (e[0].to_string(), sep.to_string()));
-                        name = prop.clone();
-                        break;
-                    }
-                } else if o.len() == 1 {
-                    a(&format!("{}(", struct_name(key)));
-                    types_strings.insert(key.clone(), (key.to_string(), "(..)".to_string()));
-                    name = key.clone();
-                }
-            }
-            for (n, prop) in o.iter() {
-                let pet = ts.id_to_entry.get(prop).unwrap();
-                // Check if we have an enum of one.
-                if let TypeDetails::Enum(e, _) = &pet.details {
-                    if e.len() == 1 {
-                        continue;
-                    }
-                }
+    let mut a = |s: &str| {
+        out.push_str(s);
+        out.push('\n');
+    };
 
-                if o.len() <= 2 {
-                    let t = ts.render_type(prop, true).unwrap();
-                    prop_types.push((name.to_string(), t.to_string()));
-                    a(&format!("{},", t));
-                } else {
-                    let t = ts.render_type(prop, true).unwrap();
-                    prop_types.push((name.to_string(), t.to_string()));
-                    a(&format!("{}: {},", n, t));
-                }
-            }
+    let (tagging, variants) = distinct_variants(ts, one_of, schema_data, &sn)?;
 
-            match o.len().cmp(&2) {
-                Ordering::Less => {
-                    if !is_enum {
-                        a("),");
-                    }
-                }
-                Ordering::Equal => {
-                    a("),");
+    if let Some(description) = &schema_data.description {
+        a(&format!("/// {}", description.replace('\n', "\n/// ")));
+    }
+    a("#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]");
+    match &tagging {
+        Tagging::Adjacent { tag, content } => {
+            a(r#"#[serde(rename_all = "snake_case")]"#);
+            a(&format!(
+                r#"#[serde(tag = "{}", content = "{}")]"#,
+                tag, content
+            ));
+        }
+        Tagging::Internal { tag } => {
+            a(r#"#[serde(rename_all = "snake_case")]"#);
+            a(&format!(r#"#[serde(tag = "{}")]"#, tag));
+        }
+        Tagging::External => a(r#"#[serde(rename_all = "snake_case")]"#),
+        Tagging::Untagged => a("#[serde(untagged)]"),
+    }
+    a(&format!("pub enum {} {{", sn));
+    for v in &variants {
+        if let Some(description) = &v.description {
+            a(&format!("/// {}", description.replace('\n', "\n/// ")));
+        }
+        if let Some(value) = &v.value {
+            if crate::serde_snake_case(&v.name) != *value {
+                a(&format!(r#"#[serde(rename = "{}")]"#, value));
+            }
+        }
+        if v.fields.is_empty() {
+            a(&format!("{},", v.name));
+        } else if let Tagging::Internal { .. } = tagging {
+            a(&format!("{} {{", v.name));
+            for (name, tid) in &v.fields {
+                let field = to_snake_case(name);
+                if field != *name {
+                    a(&format!(r#"#[serde(rename = "{}")]"#, name));
                 }
-                Ordering::Greater => {
-                    a("},");
+                let mut rt = ts.render_type(tid, true)?;
+                if is_optional(ts, tid) {
+                    let empty = if rt == "String" {
+                        "String::is_empty"
+                    } else if rt.starts_with("Vec<") {
+                        "Vec::is_empty"
+                    } else {
+                        if !rt.starts_with("Option<") {
+                            rt = format!("Option<{}>", rt);
+                        }
+                        "Option::is_none"
+                    };
+                    a(&format!(
+                        r#"#[serde(default, skip_serializing_if = "{}")]"#,
+                        empty
+                    ));
                 }
+                a(&format!("{}: {},", field, rt));
             }
+            a("},");
+        } else {
+            let (_, tid) = &v.fields[0];
+            a(&format!("{}({}),", v.name, ts.render_type(tid, true)?));
         }
     }
-
     a("}");
     a("");
 
-    if !tag.is_empty() && !content.is_empty() {
-        // Handle display and to_string differently.
-        // Now we need to implement display for the enum.
-        a(&format!("impl fmt::Display for {} {{", sn));
-        a("fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {");
-        a("   let j = serde_json::json!(self);");
-        a(&format!(
-            "   let mut tag: String = \
-             serde_json::from_value(j[\"{}\"].clone()).unwrap_or_default();",
-            tag
-        ));
-        if sn == "DiskSource" {
-            a(&format!(
-                "   let mut content: String = match serde_json::from_value(j[\"{}\"].clone()) {{ \
-                 Ok(v) => v, Err(_) => {{ let int : i64 = \
-                 serde_json::from_value(j[\"{}\"].clone()).unwrap_or_default(); format!(\"{{}}\", \
-                 int) }} }};",
-                content, content
-            ));
-        } else {
-            a(&format!(
-                "   let mut content: String = \
-                 serde_json::from_value(j[\"{}\"].clone()).unwrap_or_default();",
-                content
-            ));
+    let aliases: BTreeMap<String, String> = match schema_data.extensions.get(STRING_FORM) {
+        Some(aliases) => serde_json::from_value(aliases.clone())?,
+        None => Default::default(),
+    };
+    let spelling = |v: &Variant| {
+        let value = v.value.clone().unwrap_or_default();
+        aliases.get(&value).cloned().unwrap_or(value)
+    };
+
+    if schema_data.extensions.contains_key(STRING_FORM) {
+        let mut has_string_form =
+            matches!(tagging, Tagging::Adjacent { .. } | Tagging::Internal { .. });
+        for v in &variants {
+            for (_, tid) in &v.fields {
+                let rt = ts.render_type(tid, true)?;
+                has_string_form &=
+                    v.fields.len() == 1 && !rt.starts_with("Vec<") && !rt.starts_with("Option<");
+            }
+        }
+        if !has_string_form {
+            bail!("{} cannot have a `variant=value` string form", sn);
         }
-        a(" if content.is_empty() {");
-        a(&format!(
-            "let map: std::collections::HashMap<String, String> = \
-             serde_json::from_value(j[\"{}\"].clone()).unwrap_or_default();",
-            content
-        ));
-        a("if let Some((_, v)) = map.iter().next() { content = v.to_string(); }");
-        a("}");
-        a("if tag == \"internet_gateway\" { tag = \"inetgw\".to_string(); }");
-        a("     write!(f, \"{}={}\",tag, content)");
-        a("}");
-        a("}");
-        a("");
 
-        // Let's implement FromStr for clap so we can use enums there.
-        a(&format!("impl std::str::FromStr for {} {{", sn));
-        a("type Err = anyhow::Error;");
-        a("fn from_str(s: &str) -> Result<Self, Self::Err> {");
-        a("    let parts = s.split('=').collect::<Vec<&str>>();");
-        a("    if parts.len() != 2 {");
-        a(&format!(
-            r#"        anyhow::bail!("invalid format for {}, got {{}}", s);"#,
-            sn
-        ));
-        a("    }");
-        a("    let tag = parts[0].to_string();");
-        a("    let content = parts[1].to_string();");
-        a("    let mut j = String::new();");
-        for (name, p) in prop_types.iter() {
-            let mut k = to_snake_case(name);
-            if k == "internet_gateway" {
-                k = "inetgw".to_string();
+        let pattern = |v: &Variant, binding: &str| match (&tagging, v.fields.first()) {
+            (_, None) => format!("{}::{}", sn, v.name),
+            (Tagging::Internal { .. }, Some((name, _))) => {
+                format!(
+                    "{}::{} {{ {}: {} }}",
+                    sn,
+                    v.name,
+                    to_snake_case(name),
+                    binding
+                )
             }
-            a(&format!("if tag == \"{}\" {{", k));
-            a("j = format!(r#\"{{");
-            a(&format!("\"{}\": \"{}\",", tag, to_snake_case(name)));
-            if p == "String" || p.starts_with("Vec<") {
-                a(&format!("\"{}\": \"{{}}\"", content));
-                a("        }}\"#, content);");
+            _ => format!("{}::{}({})", sn, v.name, binding),
+        };
+
+        a(&format!("impl fmt::Display for {} {{", sn));
+        a("fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {");
+        a("match self {");
+        for v in &variants {
+            if v.fields.is_empty() {
+                a(&format!(
+                    r#"{} => write!(f, "{}"),"#,
+                    pattern(v, ""),
+                    spelling(v)
+                ));
             } else {
-                a(&format!("\"{}\": {{}}", content));
                 a(&format!(
-                    "        }}}}\"#, serde_json::json!({}::from_str(&content).unwrap()));",
-                    p
+                    r#"{} => write!(f, "{}={{}}", value),"#,
+                    pattern(v, "value"),
+                    spelling(v)
                 ));
             }
-            a("}");
         }
-        a("    let result = serde_json::from_str(&j)?;");
-        a("    Ok(result)");
-        a("}");
         a("}");
-    } else {
-        // Now we need to implement display for the enum.
-        a(&format!("impl fmt::Display for {} {{", sn));
-        a("fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {");
-        a("   write!(f, \"{}\", serde_json::json!(self))");
         a("}");
         a("}");
         a("");
@@ -625,43 +832,85 @@ fn do_one_of_type(
         a(&format!("impl std::str::FromStr for {} {{", sn));
         a("type Err = anyhow::Error;");
         a("fn from_str(s: &str) -> Result<Self, Self::Err> {");
-        a("   Ok(serde_json::from_str(s)?)");
+        a("let (tag, value) = match s.split_once('=') {");
+        a("Some((tag, value)) => (tag, Some(value)),");
+        a("None => (s, None),");
+        a("};");
+        a("Ok(match (tag, value) {");
+        for v in &variants {
+            match v.fields.first() {
+                None => a(&format!(
+                    r#"("{}", None) => {},"#,
+                    spelling(v),
+                    pattern(v, "")
+                )),
+                Some((_, tid)) => {
+                    let value = if ts.render_type(tid, true)? == "String" {
+                        "value.to_string()".to_string()
+                    } else {
+                        format!(
+                            r#"value.parse().map_err(|e| anyhow::anyhow!("invalid {} `{{}}`: {{}}", value, e))?"#,
+                            spelling(v)
+                        )
+                    };
+                    a(&format!(
+                        r#"("{}", Some(value)) => {},"#,
+                        spelling(v),
+                        pattern(v, &value)
+                    ));
+                }
+            }
+        }
+        a(&format!(
+            r#"_ => anyhow::bail!("invalid format for {}, got {{}}", s),"#,
+            sn
+        ));
+        a("})");
         a("}");
         a("}");
+        a("");
     }
 
-    if !tag.is_empty() {
-        let mut values: Vec<String> = Vec::new();
-        // If we have a tag let's create the variant types.
+    if let Tagging::Adjacent { .. } | Tagging::Internal { .. } = tagging {
+        let mut sorted: Vec<&Variant> = variants.iter().collect();
+        sorted.sort_by_key(|v| &v.name);
+
         a(&format!("impl {} {{", sn));
         a("pub fn variants() -> Vec<String> {");
         a("    vec![");
-        for (name, _) in types_strings.iter() {
-            let mut k = to_snake_case(name);
-            if k == "internet_gateway" {
-                k = "inetgw".to_string();
-            }
-            a(&format!("        \"{}\".to_string(),", k));
-            values.push(name.to_string());
+        for v in &sorted {
+            a(&format!("        \"{}\".to_string(),", spelling(v)));
         }
         a("    ]");
         a("}");
         a("}");
 
-        // Now we want to render a new enum for this type.
-        let render = render_param(
-            &format!("{}Type", sn),
-            &values,
-            false,
-            &format!("The types for {}.", sn),
-            None,
-            false,
-        );
-
-        a(&render);
+        // Now we want to render a new enum for this type, unless the API
+        // already has one.
+        let type_name = format!("{}Type", sn);
+        let declared = ts
+            .id_to_entry
+            .values()
+            .any(|te| te.name.as_deref().map(struct_name).as_ref() == Some(&type_name));
+        if !declared {
+            let values: Vec<String> = sorted
+                .iter()
+                .map(|v| v.value.clone().unwrap_or_else(|| v.name.to_string()))
+                .collect();
+            let render = render_param(
+                &type_name,
+                &values,
+                false,
+                &format!("The types for {}.", sn),
+                None,
+                false,
+            );
+
+            a(&render);
+        }
     }
 
-    out
+    Ok(out)
 }
 
 fn do_all_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> String {
@@ -726,3 +975,175 @@ fn do_all_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
 
     out
 }
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use anyhow::Result;
+
+    use super::{do_one_of_type, one_of_variants, Tagging};
+    use crate::{TypeDetails, TypeSpace};
+
+    #[test]
+    fn one_of() -> Result<()> {
+        let schemas: BTreeMap<String, openapiv3::ReferenceOr<openapiv3::Schema>> =
+            serde_json::from_value(serde_json::json!({
+                "Target": {
+                    "x-string-form": { "internet_gateway": "inetgw" },
+                    "oneOf": [
+                        {
+                            "type": "object",
+                            "properties": {
+                                "type": { "type": "string", "enum": ["ip"] },
+                                "value": { "type": "string", "format": "ip" }
+                            },
+                            "required": ["type", "value"]
+                        },
+                        {
+                            "type": "object",
+                            "properties": {
+                                "type": { "type": "string", "enum": ["internet_gateway"] },
+                                "value": { "type": "string" }
+                            },
+                            "required": ["type", "value"]
+                        },
+                        {
+                            "type": "object",
+                            "properties": {
+                                "type": { "type": "string", "enum": ["drop"] }
+                            },
+                            "required": ["type"]
+                        }
+                    ]
+                },
+                "Range": {
+                    "oneOf": [
+                        {
+                            "type": "object",
+                            "properties": {
+                                "kind": { "type": "string", "enum": ["range_to"] },
+                                "end": { "type": "integer", "format": "int64" }
+                            },
+                            "required": ["kind", "end"]
+                        },
+                        {
+                            "type": "object",
+                            "properties": {
+                                "kind": { "type": "string", "enum": ["range_from"] },
+                                "start": { "type": "integer", "format": "int64" }
+                            },
+                            "required": ["kind", "start"]
+                        }
+                    ]
+                },
+                "Source": {
+                    "oneOf": [
+                        {
+                            "type": "object",
+                            "properties": { "url": { "type": "string" } },
+                            "required": ["url"]
+                        },
+                        {
+                            "type": "object",
+                            "properties": { "data": { "type": "string" } },
+                            "required": ["data"]
+                        }
+                    ]
+                },
+                "Mixed": {
+                    "oneOf": [
+                        { "title": "count", "type": "integer", "format": "int64" },
+                        { "$ref": "#/components/schemas/Source" }
+                    ]
+                }
+            }))?;
+
+        let mut ts = TypeSpace::new();
+        for (name, s) in &schemas {
+            let id = ts.select(Some(name), s, "")?;
+            ts.populate_ref(Some(name), Some(id), "schema")?;
+        }
+
+        let mut unions = Vec::new();
+        for te in ts.id_to_entry.values() {
+            if let TypeDetails::OneOf(one_of, schema_data) = &te.details {
+                unions.push((
+                    te.name.clone().unwrap(),
+                    one_of.clone(),
+                    schema_data.clone(),
+                ));
+            }
+        }
+        let mut tagging = Vec::new();
+        for (name, one_of, schema_data) in &unions {
+            let (t, variants) = one_of_variants(&mut ts, one_of, schema_data, name)?;
+            let names: Vec<String> = variants.into_iter().map(|v| v.name).collect();
+            tagging.push((name.to_string(), t, names));
+        }
+        tagging.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            tagging,
+            vec![
+                (
+                    "Mixed".to_string(),
+                    Tagging::Untagged,
+                    vec!["Count".to_string(), "Source".to_string()]
+                ),
+                (
+                    "Range".to_string(),
+                    Tagging::Internal {
+                        tag: "kind".to_string()
+                    },
+                    vec!["RangeTo".to_string(), "RangeFrom".to_string()]
+                ),
+                (
+                    "Source".to_string(),
+                    Tagging::External,
+                    vec!["Url".to_string(), "Data".to_string()]
+                ),
+                (
+                    "Target".to_string(),
+                    Tagging::Adjacent {
+                        tag: "type".to_string(),
+                        content: "value".to_string()
+                    },
+                    vec![
+                        "Ip".to_string(),
+                        "InternetGateway".to_string(),
+                        "Drop".to_string()
+                    ]
+                ),
+            ]
+        );
+
+        for (name, one_of, schema_data) in &unions {
+            let out = do_one_of_type(&mut ts, one_of, schema_data, name.to_string())?;
+            match name.as_str() {
+                "Target" => {
+                    assert!(out.contains("Ip(std::net::IpAddr),"));
+                    assert!(out.contains(
+                        r#"Target::InternetGateway(value) => write!(f, "inetgw={}", value),"#
+                    ));
+                    assert!(out.contains(r#"("drop", None) => Target::Drop,"#));
+                    assert!(out.contains("pub enum TargetType {"));
+                }
+                "Range" => {
+                    assert!(out.contains(r#"#[serde(tag = "kind")]"#));
+                    assert!(out.contains("RangeTo {\nend: i64,\n},"));
+                    assert!(!out.contains("impl fmt::Display"));
+                }
+                "Source" => {
+                    assert!(out.contains("Url(String),"));
+                    assert!(!out.contains("variants()"));
+                }
+                _ => {
+                    assert!(out.contains("#[serde(untagged)]"));
+                    assert!(out.contains("Count(i64),\nSource(Source),"));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
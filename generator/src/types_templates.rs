@@ -330,30 +330,165 @@ impl JsonSchema for Ipv6Net {
     }
 }"##;
 
-pub const IP_RANGE: &str = r#"#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub const IPV4_RANGE: &str = r#"/// A non-decreasing IPv4 address range, inclusive of both ends.
+///
+/// The first address must be less than or equal to the last address.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Tabled)]
+#[serde(try_from = "AnyIpv4Range")]
+pub struct Ipv4Range {
+    first: std::net::Ipv4Addr,
+    last: std::net::Ipv4Addr,
+}
+
+impl Ipv4Range {
+    /// Returns an error if `first` comes after `last`.
+    pub fn new(first: std::net::Ipv4Addr, last: std::net::Ipv4Addr) -> anyhow::Result<Self> {
+        if first > last {
+            anyhow::bail!(
+                "invalid IPv4 range {}-{}: the first address must not come after the last",
+                first,
+                last
+            );
+        }
+        Ok(Ipv4Range { first, last })
+    }
+
+    pub fn first_address(&self) -> std::net::Ipv4Addr {
+        self.first
+    }
+
+    pub fn last_address(&self) -> std::net::Ipv4Addr {
+        self.last
+    }
+}
+
+/// An unvalidated `Ipv4Range`, as it comes off the wire.
+#[derive(Deserialize)]
+struct AnyIpv4Range {
+    first: std::net::Ipv4Addr,
+    last: std::net::Ipv4Addr,
+}
+
+impl std::convert::TryFrom<AnyIpv4Range> for Ipv4Range {
+    type Error = anyhow::Error;
+
+    fn try_from(r: AnyIpv4Range) -> Result<Self, Self::Error> {
+        Ipv4Range::new(r.first, r.last)
+    }
+}"#;
+
+pub const IPV6_RANGE: &str = r#"/// A non-decreasing IPv6 address range, inclusive of both ends.
+///
+/// The first address must be less than or equal to the last address.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Tabled)]
+#[serde(try_from = "AnyIpv6Range")]
+pub struct Ipv6Range {
+    first: std::net::Ipv6Addr,
+    last: std::net::Ipv6Addr,
+}
+
+impl Ipv6Range {
+    /// Returns an error if `first` comes after `last`.
+    pub fn new(first: std::net::Ipv6Addr, last: std::net::Ipv6Addr) -> anyhow::Result<Self> {
+        if first > last {
+            anyhow::bail!(
+                "invalid IPv6 range {}-{}: the first address must not come after the last",
+                first,
+                last
+            );
+        }
+        Ok(Ipv6Range { first, last })
+    }
+
+    pub fn first_address(&self) -> std::net::Ipv6Addr {
+        self.first
+    }
+
+    pub fn last_address(&self) -> std::net::Ipv6Addr {
+        self.last
+    }
+}
+
+/// An unvalidated `Ipv6Range`, as it comes off the wire.
+#[derive(Deserialize)]
+struct AnyIpv6Range {
+    first: std::net::Ipv6Addr,
+    last: std::net::Ipv6Addr,
+}
+
+impl std::convert::TryFrom<AnyIpv6Range> for Ipv6Range {
+    type Error = anyhow::Error;
+
+    fn try_from(r: AnyIpv6Range) -> Result<Self, Self::Error> {
+        Ipv6Range::new(r.first, r.last)
+    }
+}"#;
+
+pub const IP_RANGE: &str = r#"/// A range of IPv4 or IPv6 addresses, inclusive of both ends.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
 pub enum IpRange {
-    Ipv4Range,
-    #[default]
-    Ipv6Range
+    V4(Ipv4Range),
+    V6(Ipv6Range),
 }
 
-impl fmt::Display for IpRange {
-fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-   write!(f, "{}", serde_json::json!(self))
+impl Default for IpRange {
+    fn default() -> Self {
+        IpRange::V4(Ipv4Range {
+            first: std::net::Ipv4Addr::UNSPECIFIED,
+            last: std::net::Ipv4Addr::UNSPECIFIED,
+        })
+    }
+}
+
+impl From<Ipv4Range> for IpRange {
+    fn from(r: Ipv4Range) -> IpRange {
+        IpRange::V4(r)
+    }
 }
+
+impl From<Ipv6Range> for IpRange {
+    fn from(r: Ipv6Range) -> IpRange {
+        IpRange::V6(r)
+    }
 }
 
-impl std::str::FromStr for IpRange {
-type Err = anyhow::Error;
-fn from_str(s: &str) -> Result<Self, Self::Err> {
-   Ok(serde_json::from_str(s)?)
+impl fmt::Display for IpRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IpRange::V4(r) => write!(f, "{}-{}", r.first, r.last),
+            IpRange::V6(r) => write!(f, "{}-{}", r.first, r.last),
+        }
+    }
 }
+
+impl std::str::FromStr for IpRange {
+    type Err = anyhow::Error;
+
+    /// Parses `first-last`, e.g. `192.168.1.10-192.168.1.20`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (first, last) = s
+            .split_once('-')
+            .ok_or_else(|| anyhow::anyhow!("invalid ip range `{}`, expected `first-last`", s))?;
+        let first: std::net::IpAddr = first.trim().parse()?;
+        let last: std::net::IpAddr = last.trim().parse()?;
+        match (first, last) {
+            (std::net::IpAddr::V4(first), std::net::IpAddr::V4(last)) => {
+                Ok(IpRange::V4(Ipv4Range::new(first, last)?))
+            }
+            (std::net::IpAddr::V6(first), std::net::IpAddr::V6(last)) => {
+                Ok(IpRange::V6(Ipv6Range::new(first, last)?))
+            }
+            _ => anyhow::bail!("ip range `{}` mixes IPv4 and IPv6 addresses", s),
+        }
+    }
 }"#;
 
 pub const IP_NET: &str = r#"/// An `IpNet` represents an IP network, either IPv4 or IPv6.
 #[derive(
     Clone, Copy, Debug, Deserialize, PartialEq, Hash, JsonSchema, Serialize,
 )]
+#[serde(untagged)]
 pub enum IpNet {
     V4(Ipv4Net),
     V6(Ipv6Net),
@@ -406,237 +541,175 @@ impl std::str::FromStr for IpNet {
 }
 "#;
 
-pub const DISK_SOURCE: &str = r##"#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-#[serde(rename_all = "snake_case")]
-#[serde(tag = "type")]
-pub enum DiskSource {
-    Blank { block_size: i64 },
-    Snapshot { snapshot_id: String },
-    Image { image_id: String },
-    GlobalImage { image_id: String },
-}
+pub const BYTE_COUNT: &str = r##"/// A count of bytes, typically used either for memory or storage capacity
+///
+/// The maximum supported byte count is [`i64::MAX`].  This makes it somewhat inconvenient to define constructors: a u32 constructor can be infallible, but an i64 constructor can fail (if the value is negative) and a u64 constructor can fail (if the value is larger than i64::MAX).  We provide all of these for consumers' convenience.
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+#[serde(try_from = "u64", into = "u64")]
+pub struct ByteCount(u64);
 
-impl fmt::Display for DiskSource {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let j = serde_json::json!(self);
-        let mut tag: String = serde_json::from_value(j["type"].clone()).unwrap_or_default();
+impl ByteCount {
+    const KIB: u64 = 1 << 10;
+    const MIB: u64 = 1 << 20;
+    const GIB: u64 = 1 << 30;
+    const TIB: u64 = 1 << 40;
 
-        let mut value = "image_id";
-        if tag == *"blank" {
-            value = "block_size";
-        };
-        if tag == *"snapshot" {
-            value = "snapshot_id";
-        };
+    pub fn from_kib(kib: u32) -> ByteCount {
+        ByteCount(u64::from(kib) * Self::KIB)
+    }
 
-        let mut content: String = match serde_json::from_value(j[value].clone()) {
-            Ok(v) => v,
-            Err(_) => {
-                let int: i64 = serde_json::from_value(j[value].clone()).unwrap_or_default();
-                format!("{}", int)
-            }
-        };
-        if content.is_empty() {
-            let map: std::collections::HashMap<String, String> =
-                serde_json::from_value(j[value].clone()).unwrap_or_default();
-            if let Some((_, v)) = map.iter().next() {
-                content = v.to_string();
-            }
-        }
-        if tag == "internet_gateway" {
-            tag = "inetgw".to_string();
-        }
-        write!(f, "{}={}", tag, content)
+    pub fn from_mib(mib: u32) -> ByteCount {
+        ByteCount(u64::from(mib) * Self::MIB)
     }
-}
 
-impl std::str::FromStr for DiskSource {
-    type Err = anyhow::Error;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts = s.split('=').collect::<Vec<&str>>();
-        if parts.len() != 2 {
-            anyhow::bail!("invalid format for DiskSource, got {}", s);
-        }
-        let tag = parts[0].to_string();
-        let content = parts[1].to_string();
-        let mut j = String::new();
-        if tag == "blank" {
-            j = format!(
-                r#"{{
-"type": "blank",
-"block_size": {}
-        }}"#,
-                serde_json::json!(i64::from_str(&content).unwrap())
-            );
-        }
-        if tag == "snapshot" {
-            j = format!(
-                r#"{{
-"type": "snapshot",
-"snapshot_id": "{}"
-        }}"#,
-                content
-            );
-        }
-        if tag == "image" {
-            j = format!(
-                r#"{{
-"type": "image",
-"image_id": "{}"
-        }}"#,
-                content
-            );
-        }
-        if tag == "global_image" {
-            j = format!(
-                r#"{{
-"type": "global_image",
-"image_id": "{}"
-        }}"#,
-                content
-            );
-        }
-        let result = serde_json::from_str(&j)?;
-        Ok(result)
+    pub fn from_gib(gib: u32) -> ByteCount {
+        ByteCount(u64::from(gib) * Self::GIB)
+    }
+
+    pub fn to_bytes(&self) -> u64 {
+        self.0
+    }
+
+    pub fn to_whole_kib(&self) -> u64 {
+        self.0 / Self::KIB
+    }
+
+    pub fn to_whole_mib(&self) -> u64 {
+        self.0 / Self::MIB
+    }
+
+    pub fn to_whole_gib(&self) -> u64 {
+        self.0 / Self::GIB
+    }
+
+    pub fn to_whole_tib(&self) -> u64 {
+        self.0 / Self::TIB
     }
 }
-impl DiskSource {
-    pub fn variants() -> Vec<String> {
-        vec![
-            "blank".to_string(),
-            "global_image".to_string(),
-            "image".to_string(),
-            "snapshot".to_string(),
-        ]
+
+impl From<u32> for ByteCount {
+    fn from(bytes: u32) -> ByteCount {
+        ByteCount(u64::from(bytes))
     }
 }
-/**
- * The types for DiskSource.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Tabled)]
-#[serde(rename_all = "snake_case")]
-pub enum DiskSourceType {
-    Blank,
-    GlobalImage,
-    Image,
-    Snapshot,
+
+impl std::convert::TryFrom<u64> for ByteCount {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: u64) -> Result<Self, Self::Error> {
+        if bytes > i64::MAX as u64 {
+            anyhow::bail!("byte count {} is larger than the maximum of {}", bytes, i64::MAX);
+        }
+        Ok(ByteCount(bytes))
+    }
 }
 
-impl std::fmt::Display for DiskSourceType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            DiskSourceType::Blank => "blank",
-            DiskSourceType::GlobalImage => "global_image",
-            DiskSourceType::Image => "image",
-            DiskSourceType::Snapshot => "snapshot",
+impl std::convert::TryFrom<i64> for ByteCount {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: i64) -> Result<Self, Self::Error> {
+        if bytes < 0 {
+            anyhow::bail!("byte count {} is negative", bytes);
         }
-        .fmt(f)
+        Ok(ByteCount(bytes as u64))
     }
 }
 
-impl Default for DiskSourceType {
-    fn default() -> DiskSourceType {
-        DiskSourceType::Blank
+impl From<ByteCount> for u64 {
+    fn from(b: ByteCount) -> u64 {
+        b.0
     }
 }
-impl std::str::FromStr for DiskSourceType {
-    type Err = anyhow::Error;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s == "blank" {
-            return Ok(DiskSourceType::Blank);
-        }
-        if s == "global_image" {
-            return Ok(DiskSourceType::GlobalImage);
-        }
-        if s == "image" {
-            return Ok(DiskSourceType::Image);
-        }
-        if s == "snapshot" {
-            return Ok(DiskSourceType::Snapshot);
-        }
-        anyhow::bail!("invalid string for DiskSourceType: {}", s);
-    }
-}
-"##;
-
-pub const DATUM: &str = r##"#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Tabled)]
-#[serde(rename_all = "snake_case")]
-pub enum Datum {
-    Bool,
-    Bytes,
-    CumulativeF64,
-    CumulativeI64,
-    F64,
-    HistogramF64,
-    HistogramI64,
-    I64,
-    String,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Datum {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Datum::Bool => "bool",
-            Datum::Bytes => "bytes",
-            Datum::CumulativeF64 => "cumulative_f_64",
-            Datum::CumulativeI64 => "cumulative_i_64",
-            Datum::F64 => "f_64",
-            Datum::HistogramF64 => "histogram_f_64",
-            Datum::HistogramI64 => "histogram_i_64",
-            Datum::I64 => "i_64",
-            Datum::String => "string",
-            Datum::Noop => "",
-            Datum::FallthroughString => "*",
-        }
-        .fmt(f)
+
+impl From<ByteCount> for i64 {
+    fn from(b: ByteCount) -> i64 {
+        // Every constructor enforces the i64::MAX limit.
+        b.0 as i64
     }
 }
 
-impl Default for Datum {
-    fn default() -> Datum {
-        Datum::Bool
+impl fmt::Display for ByteCount {
+    /// Formats the count in the largest binary unit that represents it
+    /// exactly, e.g. `20 GiB` or `1536 MiB`, so the output parses back.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let units = [
+            (Self::TIB, "TiB"),
+            (Self::GIB, "GiB"),
+            (Self::MIB, "MiB"),
+            (Self::KIB, "KiB"),
+        ];
+        for (size, unit) in units {
+            // Every unit is a power of two.
+            if self.0 >= size && self.0.trailing_zeros() >= size.trailing_zeros() {
+                return write!(f, "{} {}", self.0 / size, unit);
+            }
+        }
+        write!(f, "{} B", self.0)
     }
 }
-impl std::str::FromStr for Datum {
+
+impl std::str::FromStr for ByteCount {
     type Err = anyhow::Error;
+
+    /// Parses a count with an optional unit suffix, e.g. `20GiB`, `512M` or
+    /// `1073741824`. `K`, `M`, `G` and `T` (with or without `iB`) are binary
+    /// units, `KB`, `MB`, `GB` and `TB` are decimal units.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s == "bool" {
-            return Ok(Datum::Bool);
-        }
-        if s == "bytes" {
-            return Ok(Datum::Bytes);
-        }
-        if s == "cumulative_f_64" {
-            return Ok(Datum::CumulativeF64);
-        }
-        if s == "cumulative_i_64" {
-            return Ok(Datum::CumulativeI64);
-        }
-        if s == "f_64" {
-            return Ok(Datum::F64);
-        }
-        if s == "histogram_f_64" {
-            return Ok(Datum::HistogramF64);
-        }
-        if s == "histogram_i_64" {
-            return Ok(Datum::HistogramI64);
-        }
-        if s == "i_64" {
-            return Ok(Datum::I64);
-        }
-        if s == "string" {
-            return Ok(Datum::String);
+        let s = s.trim();
+        let split = s
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(s.len());
+        let (digits, unit) = s.split_at(split);
+        if digits.is_empty() {
+            anyhow::bail!("invalid byte count {:?}: missing number", s);
+        }
+        let count: u64 = digits.parse()?;
+
+        let multiplier: u64 = match unit.trim().to_ascii_lowercase().as_str() {
+            "" | "b" => 1,
+            "k" | "kib" => Self::KIB,
+            "m" | "mib" => Self::MIB,
+            "g" | "gib" => Self::GIB,
+            "t" | "tib" => Self::TIB,
+            "kb" => 1_000,
+            "mb" => 1_000_000,
+            "gb" => 1_000_000_000,
+            "tb" => 1_000_000_000_000,
+            u => anyhow::bail!("invalid byte count {:?}: unknown unit {:?}", s, u),
+        };
+
+        match count.checked_mul(multiplier) {
+            Some(bytes) => <ByteCount as std::convert::TryFrom<u64>>::try_from(bytes),
+            None => anyhow::bail!("byte count {:?} overflows", s),
         }
-        anyhow::bail!("invalid string for Datum: {}", s);
     }
 }
-impl Datum {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Datum::Noop)
+
+impl JsonSchema for ByteCount {
+    fn schema_name() -> String {
+        "ByteCount".to_string()
     }
-}
-"##;
+
+    fn json_schema(
+        _: &mut schemars::gen::SchemaGenerator,
+    ) -> schemars::schema::Schema {
+        schemars::schema::Schema::Object(
+            schemars::schema::SchemaObject {
+                metadata: Some(Box::new(schemars::schema::Metadata {
+                    description: Some("A count of bytes, typically used either for memory or storage capacity".to_string()),
+                    ..Default::default()
+                })),
+                instance_type: Some(schemars::schema::SingleOrVec::Single(Box::new(schemars::schema::InstanceType::Integer))),
+                format: Some("uint64".to_string()),
+                number: Some(Box::new(schemars::schema::NumberValidation {
+                    minimum: Some(0.0),
+                    maximum: Some(i64::MAX as f64),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            }
+        )
+    }
+}"##;
@@ -0,0 +1,657 @@
+/*
+ * Check an API definition for what the generator does not support, so that
+ * everything is reported at once with where it is in the definition, rather
+ * than as the first error generation runs into. With `--lenient`, operations
+ * and types using unsupported constructs are left out instead.
+ */
+
+use std::{collections::BTreeSet, fmt};
+
+use anyhow::{bail, Result};
+use openapiv3::OpenAPI;
+use serde_json::Value;
+
+use crate::{clean_name, struct_name, TypeSpace};
+
+/// String formats `TypeSpace::select_schema` and parameters know.
+const STRING_FORMATS: &[&str] = &[
+    "date-time",
+    "date",
+    "password",
+    "byte",
+    "binary",
+    "float",
+    "int64",
+    "uint64",
+    "google-fieldmask",
+    "google-datetime",
+    "ISO 8601 date-time",
+    "Promo date-time",
+    "dateTime",
+    "ipv4",
+    "ipv6",
+    "ip",
+    "uri",
+    "uri-template",
+    "url",
+    "email",
+    "uuid",
+    "hostname",
+    "time",
+];
+
+const INTEGER_FORMATS: &[&str] = &[
+    "int8", "int16", "int32", "int64", "uint", "uint8", "uint16", "uint32", "uint64",
+];
+
+const ALL_METHODS: &[&str] = &[
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+/// Methods the generated client has functions for.
+const METHODS: &[&str] = &["get", "put", "post", "delete", "patch"];
+
+/// Something the generator does not support.
+#[derive(Debug, PartialEq)]
+pub struct Problem {
+    /// A JSON pointer to the construct in the definition.
+    pub pointer: String,
+    pub message: String,
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}: {}", self.pointer, self.message)
+    }
+}
+
+/// A JSON pointer one level down.
+fn child(pointer: &str, key: &str) -> String {
+    format!("{}/{}", pointer, key.replace('~', "~0").replace('/', "~1"))
+}
+
+/// The path of a JSON pointer, unescaped.
+fn keys(pointer: &str) -> Vec<String> {
+    pointer
+        .split('/')
+        .skip(1)
+        .map(|k| k.replace("~1", "/").replace("~0", "~"))
+        .collect()
+}
+
+struct Checker<'a> {
+    doc: &'a Value,
+    /// The component schemas, to tell the variants of unions apart.
+    ts: TypeSpace,
+    problems: Vec<Problem>,
+}
+
+impl<'a> Checker<'a> {
+    fn report(&mut self, pointer: &str, message: impl Into<String>) {
+        self.problems.push(Problem {
+            pointer: pointer.to_string(),
+            message: message.into(),
+        });
+    }
+
+    /// What a `$ref` in the document points to.
+    fn resolve<'v>(&self, v: &'v Value) -> Option<&'v Value>
+    where
+        'a: 'v,
+    {
+        match v.get("$ref").and_then(|r| r.as_str()) {
+            Some(r) => self.doc.pointer(r.strip_prefix('#')?),
+            None => Some(v),
+        }
+    }
+
+    fn schema(&mut self, pointer: &str, s: &Value) {
+        if let Some(r) = s.get("$ref").and_then(|r| r.as_str()) {
+            if self.resolve(s).is_none() {
+                self.report(pointer, format!("reference to missing {}", r));
+            }
+            return;
+        }
+        if s.get("not").is_some() {
+            self.report(pointer, "`not` schemas are not supported");
+        }
+
+        let before = self.problems.len();
+        let enumerated = s.get("enum").map(|e| e.is_array()).unwrap_or(false);
+        let format = s.get("format").and_then(|f| f.as_str());
+        match (s.get("type").and_then(|t| t.as_str()), format) {
+            (Some("string"), Some(f)) if !enumerated && !STRING_FORMATS.contains(&f) => {
+                self.report(pointer, format!("unknown string format `{}`", f))
+            }
+            (Some("integer"), Some(f)) if !INTEGER_FORMATS.contains(&f) => {
+                self.report(pointer, format!("unknown integer format `{}`", f))
+            }
+            _ => {}
+        }
+
+        for (key, value) in s.as_object().into_iter().flatten() {
+            match key.as_str() {
+                "items" => self.schema(&child(pointer, key), value),
+                "additionalProperties" if value.is_object() => {
+                    self.schema(&child(pointer, key), value)
+                }
+                "properties" => {
+                    for (name, property) in value.as_object().into_iter().flatten() {
+                        self.schema(&child(&child(pointer, key), name), property);
+                    }
+                }
+                "allOf" | "oneOf" | "anyOf" => {
+                    for (i, variant) in value.as_array().into_iter().flatten().enumerate() {
+                        self.schema(&child(&child(pointer, key), &i.to_string()), variant);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Unions with problems in their variants cannot be told apart anyway.
+        if s.get("oneOf").is_some() && self.problems.len() == before {
+            self.union(pointer, s);
+        }
+    }
+
+    /// Whether the variants of a union have distinct tags and names.
+    fn union(&mut self, pointer: &str, s: &Value) {
+        let schema: openapiv3::Schema = match serde_json::from_value(s.clone()) {
+            Ok(schema) => schema,
+            Err(e) => return self.report(pointer, format!("invalid union: {}", e)),
+        };
+        if let openapiv3::SchemaKind::OneOf { one_of } = &schema.schema_kind {
+            let name = keys(pointer).pop().unwrap_or_default();
+            if let Err(e) = crate::types::union_variants(
+                &mut self.ts,
+                one_of,
+                &schema.schema_data,
+                &struct_name(&clean_name(&name)),
+            ) {
+                self.report(pointer, e.to_string());
+            }
+        }
+    }
+
+    fn parameter(&mut self, pointer: &str, p: &Value) {
+        if let Some(r) = p.get("$ref").and_then(|r| r.as_str()) {
+            if !r.starts_with("#/components/parameters/") || self.resolve(p).is_none() {
+                self.report(pointer, format!("unsupported parameter reference {}", r));
+            }
+            return;
+        }
+
+        let default_style = match p.get("in").and_then(|i| i.as_str()) {
+            Some("path") | Some("header") => "simple",
+            _ => "form",
+        };
+        if let Some(style) = p.get("style").and_then(|s| s.as_str()) {
+            if style != default_style {
+                self.report(
+                    &child(pointer, "style"),
+                    format!("the `{}` parameter style is not supported", style),
+                );
+            }
+        }
+
+        let schema = match p.get("schema") {
+            Some(schema) => schema,
+            None => {
+                self.report(pointer, "parameters without a schema are not supported");
+                return;
+            }
+        };
+        let pointer = child(pointer, "schema");
+        let s = match self.resolve(schema) {
+            Some(s) => s,
+            None => return self.schema(&pointer, schema),
+        };
+        if s.get("oneOf").is_some() && s.get(crate::types::STRING_FORM).is_none() {
+            self.report(
+                &pointer,
+                "union parameters need a string form, see [string_forms] in the overrides",
+            );
+        }
+        if schema.get("$ref").is_some() {
+            return;
+        }
+        if s.get("pattern").is_some() {
+            self.report(&pointer, "patterns are not supported in parameters");
+        }
+        match s.get("type").and_then(|t| t.as_str()) {
+            Some("object") => self.report(&pointer, "object parameters are not supported"),
+            None if s.get("allOf").is_some() || s.get("anyOf").is_some() => {
+                self.report(&pointer, "allOf and anyOf parameters are not supported")
+            }
+            _ => {}
+        }
+        self.schema(&pointer, s);
+    }
+
+    /// The media types of a request or response body.
+    fn content(&mut self, pointer: &str, body: &Value) {
+        if body.get("$ref").is_some() {
+            if self.resolve(body).is_none() {
+                self.report(pointer, "reference to a missing body");
+            }
+            return;
+        }
+        let pointer = child(pointer, "content");
+        for (ct, mt) in body
+            .get("content")
+            .and_then(|c| c.as_object())
+            .into_iter()
+            .flatten()
+        {
+            let pointer = child(&pointer, ct);
+            if mt
+                .get("encoding")
+                .and_then(|e| e.as_object())
+                .map(|e| !e.is_empty())
+                == Some(true)
+            {
+                self.report(
+                    &child(&pointer, "encoding"),
+                    "media type encodings are not supported",
+                );
+            }
+            let schema = match mt.get("schema") {
+                Some(schema) => schema,
+                None => continue,
+            };
+            if ct == "application/octet-stream" && schema.get("$ref").is_none() {
+                let plain = schema.get("type") == Some(&serde_json::json!("string"))
+                    && schema.get("format") == Some(&serde_json::json!("binary"))
+                    && [
+                        "nullable",
+                        "default",
+                        "discriminator",
+                        "minLength",
+                        "maxLength",
+                        "pattern",
+                        "enum",
+                    ]
+                    .iter()
+                    .all(|k| match schema.get(*k) {
+                        None | Some(Value::Null) => true,
+                        Some(Value::Bool(b)) => !b,
+                        Some(_) => false,
+                    });
+                if !plain {
+                    self.report(
+                        &child(&pointer, "schema"),
+                        "binary bodies must be plain strings of format `binary`",
+                    );
+                }
+            }
+            self.schema(&child(&pointer, "schema"), schema);
+        }
+    }
+
+    fn operation(&mut self, pointer: &str, method: &str, o: &Value, ids: &mut BTreeSet<String>) {
+        if !METHODS.contains(&method) {
+            self.report(
+                pointer,
+                format!("`{}` operations are not supported", method.to_uppercase()),
+            );
+            return;
+        }
+        if let Some(id) = o.get("operationId").and_then(|id| id.as_str()) {
+            if !ids.insert(id.to_string()) {
+                self.report(
+                    &child(pointer, "operationId"),
+                    format!("duplicate operation id {}", id),
+                );
+            }
+        }
+        for (i, p) in o
+            .get("parameters")
+            .and_then(|p| p.as_array())
+            .into_iter()
+            .flatten()
+            .enumerate()
+        {
+            self.parameter(&child(&child(pointer, "parameters"), &i.to_string()), p);
+        }
+        if let Some(body) = o.get("requestBody") {
+            self.content(&child(pointer, "requestBody"), body);
+        }
+        for (code, response) in o
+            .get("responses")
+            .and_then(|r| r.as_object())
+            .into_iter()
+            .flatten()
+        {
+            self.content(&child(&child(pointer, "responses"), code), response);
+        }
+    }
+}
+
+/// Everything in the API the generator does not support.
+pub fn validate(api: &OpenAPI) -> Result<Vec<Problem>> {
+    let doc = serde_json::to_value(api)?;
+    let mut ts = TypeSpace::new();
+    // Schemas the generator fails on are reported below.
+    for (name, s) in api.components.iter().flat_map(|c| c.schemas.iter()) {
+        if let Ok(id) = ts.select(Some(clean_name(name).as_str()), s, "") {
+            ts.populate_ref(Some(name.as_str()), Some(id), "schema")?;
+        }
+    }
+    let mut checker = Checker {
+        doc: &doc,
+        ts,
+        problems: Vec::new(),
+    };
+
+    let components = doc.get("components").cloned().unwrap_or_default();
+    for (name, s) in components
+        .get("schemas")
+        .and_then(|s| s.as_object())
+        .into_iter()
+        .flatten()
+    {
+        checker.schema(&child("/components/schemas", name), s);
+    }
+    for (name, p) in components
+        .get("parameters")
+        .and_then(|p| p.as_object())
+        .into_iter()
+        .flatten()
+    {
+        let pointer = child("/components/parameters", name);
+        if p.get("$ref").is_some() {
+            checker.report(&pointer, "references between parameters are not supported");
+        } else {
+            checker.parameter(&pointer, p);
+        }
+    }
+    for kind in ["requestBodies", "responses"] {
+        for (name, body) in components
+            .get(kind)
+            .and_then(|b| b.as_object())
+            .into_iter()
+            .flatten()
+        {
+            checker.content(&child(&child("/components", kind), name), body);
+        }
+    }
+
+    let mut ids = BTreeSet::new();
+    // Keys not starting with a slash are extensions.
+    for (path, item) in doc
+        .get("paths")
+        .and_then(|p| p.as_object())
+        .into_iter()
+        .flatten()
+        .filter(|(k, _)| k.starts_with('/'))
+    {
+        let pointer = child("/paths", path);
+        if item.get("$ref").is_some() {
+            checker.report(&pointer, "path item references are not supported");
+            continue;
+        }
+        if item
+            .get("servers")
+            .and_then(|s| s.as_array())
+            .map(|s| s.is_empty())
+            == Some(false)
+        {
+            checker.report(
+                &child(&pointer, "servers"),
+                "servers on paths are not supported",
+            );
+        }
+        for (method, o) in item.as_object().into_iter().flatten() {
+            if ALL_METHODS.contains(&method.as_str()) {
+                checker.operation(&child(&pointer, method), method, o, &mut ids);
+            }
+        }
+    }
+
+    Ok(checker.problems)
+}
+
+/// Whether any `$ref` in the value is one of the references.
+fn refers_to(v: &Value, refs: &BTreeSet<String>) -> bool {
+    match v {
+        Value::Object(map) => map.iter().any(|(k, v)| {
+            if k == "$ref" {
+                v.as_str().map(|r| refs.contains(r)).unwrap_or(false)
+            } else {
+                refers_to(v, refs)
+            }
+        }),
+        Value::Array(items) => items.iter().any(|v| refers_to(v, refs)),
+        _ => false,
+    }
+}
+
+/*
+ * Leave out of the API the operations and components with problems, and the
+ * ones that refer to those, warning about each. Problems anywhere else cannot
+ * be left out.
+ */
+pub fn skip_unsupported(api: OpenAPI, problems: &[Problem]) -> Result<OpenAPI> {
+    let mut doc = serde_json::to_value(api)?;
+
+    let mut components = BTreeSet::new();
+    let mut paths = BTreeSet::new();
+    let mut operations = BTreeSet::new();
+    for problem in problems {
+        println!("[warn] {}", problem);
+        match keys(&problem.pointer).as_slice() {
+            [c, kind, name, ..] if c == "components" => {
+                components.insert(format!("#/components/{}/{}", kind, name));
+            }
+            [p, path, method, ..] if p == "paths" && ALL_METHODS.contains(&method.as_str()) => {
+                operations.insert((path.to_string(), method.to_string()));
+            }
+            [p, path, ..] if p == "paths" => {
+                paths.insert(path.to_string());
+            }
+            _ => bail!("{} cannot be skipped", problem),
+        }
+    }
+
+    // Components using skipped ones go too, until none are left to skip.
+    let all = doc.get("components").cloned().unwrap_or_default();
+    loop {
+        let mut more = BTreeSet::new();
+        for (kind, named) in all.as_object().into_iter().flatten() {
+            for (name, v) in named.as_object().into_iter().flatten() {
+                let r = format!("#/components/{}/{}", kind, name);
+                if !components.contains(&r) && refers_to(v, &components) {
+                    more.insert(r);
+                }
+            }
+        }
+        if more.is_empty() {
+            break;
+        }
+        components.extend(more);
+    }
+    for r in &components {
+        println!("[warn] skipping {}", r);
+        let k = keys(r.trim_start_matches('#'));
+        if let Some(named) = doc
+            .pointer_mut(&child("/components", &k[1]))
+            .and_then(|n| n.as_object_mut())
+        {
+            named.remove(&k[2]);
+        }
+    }
+
+    if let Some(items) = doc.get_mut("paths").and_then(|p| p.as_object_mut()) {
+        items.retain(|path, item| {
+            if !path.starts_with('/') {
+                return true;
+            }
+            if paths.contains(path) {
+                println!("[warn] skipping {}", path);
+                return false;
+            }
+            if let Some(item) = item.as_object_mut() {
+                item.retain(|method, o| {
+                    let skip = operations.contains(&(path.to_string(), method.to_string()))
+                        || (METHODS.contains(&method.as_str()) && refers_to(o, &components));
+                    if skip {
+                        println!("[warn] skipping {} {}", method.to_uppercase(), path);
+                    }
+                    !skip
+                });
+                // Drop paths left without any operations.
+                return METHODS.iter().any(|m| item.contains_key(*m));
+            }
+            true
+        });
+    }
+
+    Ok(serde_json::from_value(doc)?)
+}
+
+#[cfg(test)]
+mod test {
+    use anyhow::Result;
+
+    use super::{skip_unsupported, validate};
+
+    #[test]
+    fn problems() -> Result<()> {
+        let api: openapiv3::OpenAPI = serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.3",
+            "info": { "title": "t", "version": "1" },
+            "paths": {
+                "/racks": {
+                    "get": {
+                        "operationId": "rack_list",
+                        "parameters": [
+                            { "in": "query", "name": "sort_by", "style": "deepObject",
+                              "schema": { "type": "string" } }
+                        ],
+                        "responses": {}
+                    },
+                    "post": {
+                        "operationId": "rack_create",
+                        "requestBody": {
+                            "content": { "application/json": {
+                                "schema": { "$ref": "#/components/schemas/Rack" }
+                            } }
+                        },
+                        "responses": {}
+                    }
+                },
+                "/sleds/{id}": {
+                    "get": {
+                        "operationId": "sled_view",
+                        "parameters": [
+                            { "in": "path", "name": "id", "required": true,
+                              "schema": { "type": "string", "format": "uuid" } }
+                        ],
+                        "responses": {}
+                    },
+                    "head": { "operationId": "sled_head", "responses": {} }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "Rack": {
+                        "type": "object",
+                        "properties": { "sled": { "$ref": "#/components/schemas/Sled" } }
+                    },
+                    "Sled": {
+                        "type": "object",
+                        "properties": { "serial": { "type": "string", "format": "ean-13" } }
+                    },
+                    "Size": { "type": "integer", "format": "int32" }
+                }
+            }
+        }))?;
+
+        let problems: Vec<String> = validate(&api)?.iter().map(|p| p.to_string()).collect();
+        assert_eq!(
+            problems,
+            vec![
+                "#/components/schemas/Sled/properties/serial: unknown string format `ean-13`",
+                "#/paths/~1racks/get/parameters/0/style: the `deepObject` parameter style is not \
+                 supported",
+                "#/paths/~1sleds~1{id}/head: `HEAD` operations are not supported",
+            ]
+        );
+
+        let problems = validate(&api)?;
+        let api = skip_unsupported(api, &problems)?;
+        assert!(validate(&api)?.is_empty());
+        let paths: Vec<&String> = api.paths.paths.keys().collect();
+        assert_eq!(paths, vec!["/sleds/{id}"]);
+        let schemas: Vec<&String> = api.components.as_ref().unwrap().schemas.keys().collect();
+        assert_eq!(schemas, vec!["Size"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn unions() -> Result<()> {
+        let shape = |kind: &str, format: &str| {
+            serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "kind": { "type": "string", "enum": [kind] },
+                    "size": { "type": "integer", "format": format }
+                },
+                "required": ["kind", "size"]
+            })
+        };
+        let api: openapiv3::OpenAPI = serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.3",
+            "info": { "title": "t", "version": "1" },
+            "paths": {
+                "/shapes": {
+                    "get": {
+                        "operationId": "shape_list",
+                        "responses": {
+                            "200": {
+                                "description": "ok",
+                                "content": { "application/json": {
+                                    "schema": { "$ref": "#/components/schemas/Shapes" }
+                                } }
+                            }
+                        }
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "Shape": { "oneOf": [shape("round", "int32"), shape("round", "int64")] },
+                    "Shapes": {
+                        "type": "object",
+                        "properties": {
+                            "items": { "type": "array",
+                                       "items": { "$ref": "#/components/schemas/Shape" } }
+                        }
+                    },
+                    "Tile": { "oneOf": [shape("square", "int32"), shape("Square", "int32")] },
+                    "Wheel": { "oneOf": [shape("round", "int32"), shape("spoked", "int32")] }
+                }
+            }
+        }))?;
+
+        let problems: Vec<String> = validate(&api)?.iter().map(|p| p.to_string()).collect();
+        assert_eq!(
+            problems,
+            vec![
+                "#/components/schemas/Shape: Shape has more than one variant tagged round",
+                "#/components/schemas/Tile: Tile has more than one variant named Square",
+            ]
+        );
+
+        let problems = validate(&api)?;
+        let api = skip_unsupported(api, &problems)?;
+        assert!(validate(&api)?.is_empty());
+        assert!(api.paths.paths.is_empty());
+        let schemas: Vec<&String> = api.components.as_ref().unwrap().schemas.keys().collect();
+        assert_eq!(schemas, vec!["Wheel"]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+//! An in-process mock of the Oxide API, generated from the same definition
+//! as the client.
+//!
+//! Every operation is served. Resources created through the API are kept in
+//! memory, so they can be listed, paginated, viewed, updated and deleted, and
+//! the usual errors are returned for resources that do not exist or already
+//! do. Other operations answer with a valid value of their response type.
+//! Faults can be injected into any operation.
+//!
+//! ```
+//! use oxide_api::types::OrganizationCreate;
+//! use oxide_api_mock::{Fault, MockServer};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> anyhow::Result<()> {
+//! let server = MockServer::start().await?;
+//! let oxide = oxide_api::Client::new("", server.url());
+//!
+//! let org = oxide
+//!     .organizations()
+//!     .post(&OrganizationCreate {
+//!         name: "maze-war".to_string(),
+//!         description: "Maze War".to_string(),
+//!     })
+//!     .await?;
+//! assert_eq!(org.name, "maze-war");
+//!
+//! server.inject(
+//!     "organizations_get_organization",
+//!     Fault::error(503, "ServiceUnavailable"),
+//! );
+//! assert!(oxide.organizations().get("maze-war").await.is_err());
+//! # Ok(())
+//! # }
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+mod routes;
+mod server;
+
+pub use server::{Fault, MockServer, RecordedRequest};
@@ -0,0 +1,1908 @@
+//! The operations of the API and the shape of the values they return.
+
+use crate::server::{Kind, Route};
+
+pub(crate) const ROUTES: &[Route] = &[
+    Route {
+        method: "GET",
+        path: "/by-id/disks/{id}",
+        operation_id: "disk_view_by_id",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("Disk"),
+    },
+    Route {
+        method: "GET",
+        path: "/by-id/global-images/{id}",
+        operation_id: "image_global_view_by_id",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("GlobalImage"),
+    },
+    Route {
+        method: "GET",
+        path: "/by-id/images/{id}",
+        operation_id: "image_view_by_id",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("Image"),
+    },
+    Route {
+        method: "GET",
+        path: "/by-id/instances/{id}",
+        operation_id: "instance_view_by_id",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("Instance"),
+    },
+    Route {
+        method: "GET",
+        path: "/by-id/network-interfaces/{id}",
+        operation_id: "instance_network_interface_view_by_id",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("NetworkInterface"),
+    },
+    Route {
+        method: "GET",
+        path: "/by-id/organizations/{id}",
+        operation_id: "organization_view_by_id",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("Organization"),
+    },
+    Route {
+        method: "GET",
+        path: "/by-id/projects/{id}",
+        operation_id: "project_view_by_id",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("Project"),
+    },
+    Route {
+        method: "GET",
+        path: "/by-id/snapshots/{id}",
+        operation_id: "snapshot_view_by_id",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("Snapshot"),
+    },
+    Route {
+        method: "GET",
+        path: "/by-id/vpc-router-routes/{id}",
+        operation_id: "vpc_router_route_view_by_id",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("RouterRoute"),
+    },
+    Route {
+        method: "GET",
+        path: "/by-id/vpc-routers/{id}",
+        operation_id: "vpc_router_view_by_id",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("VpcRouter"),
+    },
+    Route {
+        method: "GET",
+        path: "/by-id/vpc-subnets/{id}",
+        operation_id: "vpc_subnet_view_by_id",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("VpcSubnet"),
+    },
+    Route {
+        method: "GET",
+        path: "/by-id/vpcs/{id}",
+        operation_id: "vpc_view_by_id",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("Vpc"),
+    },
+    Route {
+        method: "POST",
+        path: "/device/auth",
+        operation_id: "device_auth_request",
+        kind: Kind::Other,
+        status: 200,
+        response: None,
+    },
+    Route {
+        method: "POST",
+        path: "/device/confirm",
+        operation_id: "device_auth_confirm",
+        kind: Kind::Other,
+        status: 204,
+        response: None,
+    },
+    Route {
+        method: "POST",
+        path: "/device/token",
+        operation_id: "device_access_token",
+        kind: Kind::Other,
+        status: 200,
+        response: None,
+    },
+    Route {
+        method: "GET",
+        path: "/global/policy",
+        operation_id: "global_policy_view",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("FleetRolePolicy"),
+    },
+    Route {
+        method: "PUT",
+        path: "/global/policy",
+        operation_id: "global_policy_update",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("FleetRolePolicy"),
+    },
+    Route {
+        method: "GET",
+        path: "/hardware/racks",
+        operation_id: "hardware_racks_get",
+        kind: Kind::List,
+        status: 200,
+        response: Some("Rack"),
+    },
+    Route {
+        method: "GET",
+        path: "/hardware/racks/{rack_id}",
+        operation_id: "hardware_racks_get_rack",
+        kind: Kind::View,
+        status: 200,
+        response: Some("Rack"),
+    },
+    Route {
+        method: "GET",
+        path: "/hardware/sleds",
+        operation_id: "hardware_sleds_get",
+        kind: Kind::List,
+        status: 200,
+        response: Some("Sled"),
+    },
+    Route {
+        method: "GET",
+        path: "/hardware/sleds/{sled_id}",
+        operation_id: "hardware_sleds_get_sled",
+        kind: Kind::View,
+        status: 200,
+        response: Some("Sled"),
+    },
+    Route {
+        method: "GET",
+        path: "/images",
+        operation_id: "images_get",
+        kind: Kind::List,
+        status: 200,
+        response: Some("GlobalImage"),
+    },
+    Route {
+        method: "POST",
+        path: "/images",
+        operation_id: "images_post",
+        kind: Kind::Create,
+        status: 201,
+        response: Some("GlobalImage"),
+    },
+    Route {
+        method: "GET",
+        path: "/images/{image_name}",
+        operation_id: "images_get_image",
+        kind: Kind::View,
+        status: 200,
+        response: Some("GlobalImage"),
+    },
+    Route {
+        method: "DELETE",
+        path: "/images/{image_name}",
+        operation_id: "images_delete_image",
+        kind: Kind::Delete,
+        status: 204,
+        response: Some("GlobalImage"),
+    },
+    Route {
+        method: "GET",
+        path: "/ip-pools",
+        operation_id: "ip_pools_get",
+        kind: Kind::List,
+        status: 200,
+        response: Some("IpPool"),
+    },
+    Route {
+        method: "POST",
+        path: "/ip-pools",
+        operation_id: "ip_pools_post",
+        kind: Kind::Create,
+        status: 201,
+        response: Some("IpPool"),
+    },
+    Route {
+        method: "GET",
+        path: "/ip-pools/{pool_name}",
+        operation_id: "ip_pools_get_ip_pool",
+        kind: Kind::View,
+        status: 200,
+        response: Some("IpPool"),
+    },
+    Route {
+        method: "PUT",
+        path: "/ip-pools/{pool_name}",
+        operation_id: "ip_pools_put_ip_pool",
+        kind: Kind::Update,
+        status: 200,
+        response: Some("IpPool"),
+    },
+    Route {
+        method: "DELETE",
+        path: "/ip-pools/{pool_name}",
+        operation_id: "ip_pools_delete_ip_pool",
+        kind: Kind::Delete,
+        status: 204,
+        response: Some("IpPool"),
+    },
+    Route {
+        method: "GET",
+        path: "/ip-pools/{pool_name}/ranges",
+        operation_id: "ip_pool_ranges_get",
+        kind: Kind::List,
+        status: 200,
+        response: Some("IpPoolRange"),
+    },
+    Route {
+        method: "POST",
+        path: "/ip-pools/{pool_name}/ranges/add",
+        operation_id: "ip_pool_ranges_add",
+        kind: Kind::Other,
+        status: 201,
+        response: Some("IpPoolRange"),
+    },
+    Route {
+        method: "POST",
+        path: "/ip-pools/{pool_name}/ranges/remove",
+        operation_id: "ip_pool_ranges_delete",
+        kind: Kind::Other,
+        status: 204,
+        response: None,
+    },
+    Route {
+        method: "GET",
+        path: "/ip-pools-service/{rack_id}",
+        operation_id: "ip_pool_service_view",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("IpPool"),
+    },
+    Route {
+        method: "GET",
+        path: "/ip-pools-service/{rack_id}/ranges",
+        operation_id: "ip_pool_service_range_list",
+        kind: Kind::List,
+        status: 200,
+        response: Some("IpPoolRange"),
+    },
+    Route {
+        method: "POST",
+        path: "/ip-pools-service/{rack_id}/ranges/add",
+        operation_id: "ip_pool_service_range_add",
+        kind: Kind::Other,
+        status: 201,
+        response: Some("IpPoolRange"),
+    },
+    Route {
+        method: "POST",
+        path: "/ip-pools-service/{rack_id}/ranges/remove",
+        operation_id: "ip_pool_service_range_remove",
+        kind: Kind::Other,
+        status: 204,
+        response: None,
+    },
+    Route {
+        method: "POST",
+        path: "/login",
+        operation_id: "spoof_login",
+        kind: Kind::Other,
+        status: 200,
+        response: None,
+    },
+    Route {
+        method: "GET",
+        path: "/login/{silo_name}/{provider_name}",
+        operation_id: "login",
+        kind: Kind::Other,
+        status: 200,
+        response: None,
+    },
+    Route {
+        method: "POST",
+        path: "/login/{silo_name}/{provider_name}",
+        operation_id: "consume_credentials",
+        kind: Kind::Other,
+        status: 200,
+        response: None,
+    },
+    Route {
+        method: "POST",
+        path: "/logout",
+        operation_id: "logout",
+        kind: Kind::Other,
+        status: 200,
+        response: None,
+    },
+    Route {
+        method: "GET",
+        path: "/organizations",
+        operation_id: "organizations_get",
+        kind: Kind::List,
+        status: 200,
+        response: Some("Organization"),
+    },
+    Route {
+        method: "POST",
+        path: "/organizations",
+        operation_id: "organizations_post",
+        kind: Kind::Create,
+        status: 201,
+        response: Some("Organization"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}",
+        operation_id: "organizations_get_organization",
+        kind: Kind::View,
+        status: 200,
+        response: Some("Organization"),
+    },
+    Route {
+        method: "PUT",
+        path: "/organizations/{organization_name}",
+        operation_id: "organizations_put_organization",
+        kind: Kind::Update,
+        status: 200,
+        response: Some("Organization"),
+    },
+    Route {
+        method: "DELETE",
+        path: "/organizations/{organization_name}",
+        operation_id: "organizations_delete_organization",
+        kind: Kind::Delete,
+        status: 204,
+        response: Some("Organization"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/policy",
+        operation_id: "organization_get_policy",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("OrganizationRolePolicy"),
+    },
+    Route {
+        method: "PUT",
+        path: "/organizations/{organization_name}/policy",
+        operation_id: "organization_put_policy",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("OrganizationRolePolicy"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/projects",
+        operation_id: "organization_projects_get",
+        kind: Kind::List,
+        status: 200,
+        response: Some("Project"),
+    },
+    Route {
+        method: "POST",
+        path: "/organizations/{organization_name}/projects",
+        operation_id: "organization_projects_post",
+        kind: Kind::Create,
+        status: 201,
+        response: Some("Project"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}",
+        operation_id: "organization_projects_get_project",
+        kind: Kind::View,
+        status: 200,
+        response: Some("Project"),
+    },
+    Route {
+        method: "PUT",
+        path: "/organizations/{organization_name}/projects/{project_name}",
+        operation_id: "organization_projects_put_project",
+        kind: Kind::Update,
+        status: 200,
+        response: Some("Project"),
+    },
+    Route {
+        method: "DELETE",
+        path: "/organizations/{organization_name}/projects/{project_name}",
+        operation_id: "organization_projects_delete_project",
+        kind: Kind::Delete,
+        status: 204,
+        response: Some("Project"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/disks",
+        operation_id: "project_disks_get",
+        kind: Kind::List,
+        status: 200,
+        response: Some("Disk"),
+    },
+    Route {
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/disks",
+        operation_id: "project_disks_post",
+        kind: Kind::Create,
+        status: 201,
+        response: Some("Disk"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/disks/{disk_name}",
+        operation_id: "project_disks_get_disk",
+        kind: Kind::View,
+        status: 200,
+        response: Some("Disk"),
+    },
+    Route {
+        method: "DELETE",
+        path: "/organizations/{organization_name}/projects/{project_name}/disks/{disk_name}",
+        operation_id: "project_disks_delete_disk",
+        kind: Kind::Delete,
+        status: 204,
+        response: Some("Disk"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/disks/{disk_name}/\
+               metrics/{metric_name}",
+        operation_id: "disk_metrics_list",
+        kind: Kind::List,
+        status: 200,
+        response: Some("Measurement"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/images",
+        operation_id: "project_images_get",
+        kind: Kind::List,
+        status: 200,
+        response: Some("Image"),
+    },
+    Route {
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/images",
+        operation_id: "project_images_post",
+        kind: Kind::Create,
+        status: 201,
+        response: Some("Image"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/images/{image_name}",
+        operation_id: "project_images_get_image",
+        kind: Kind::View,
+        status: 200,
+        response: Some("Image"),
+    },
+    Route {
+        method: "DELETE",
+        path: "/organizations/{organization_name}/projects/{project_name}/images/{image_name}",
+        operation_id: "project_images_delete_image",
+        kind: Kind::Delete,
+        status: 204,
+        response: Some("Image"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances",
+        operation_id: "project_instances_get",
+        kind: Kind::List,
+        status: 200,
+        response: Some("Instance"),
+    },
+    Route {
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances",
+        operation_id: "project_instances_post",
+        kind: Kind::Create,
+        status: 201,
+        response: Some("Instance"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}",
+        operation_id: "project_instances_get_instance",
+        kind: Kind::View,
+        status: 200,
+        response: Some("Instance"),
+    },
+    Route {
+        method: "DELETE",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}",
+        operation_id: "project_instances_delete_instance",
+        kind: Kind::Delete,
+        status: 204,
+        response: Some("Instance"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/disks",
+        operation_id: "instance_disks_get",
+        kind: Kind::List,
+        status: 200,
+        response: Some("Disk"),
+    },
+    Route {
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/disks/attach",
+        operation_id: "instance_disks_attach",
+        kind: Kind::Other,
+        status: 202,
+        response: Some("Disk"),
+    },
+    Route {
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/disks/detach",
+        operation_id: "instance_disks_detach",
+        kind: Kind::Other,
+        status: 202,
+        response: Some("Disk"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/external-ips",
+        operation_id: "instance_external_ip_list",
+        kind: Kind::List,
+        status: 200,
+        response: Some("ExternalIp"),
+    },
+    Route {
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/migrate",
+        operation_id: "project_instances_migrate_instance",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("Instance"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/network-interfaces",
+        operation_id: "instance_network_interfaces_get",
+        kind: Kind::List,
+        status: 200,
+        response: Some("NetworkInterface"),
+    },
+    Route {
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/network-interfaces",
+        operation_id: "instance_network_interfaces_post",
+        kind: Kind::Create,
+        status: 201,
+        response: Some("NetworkInterface"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/network-interfaces/{interface_name}",
+        operation_id: "instance_network_interfaces_get_interface",
+        kind: Kind::View,
+        status: 200,
+        response: Some("NetworkInterface"),
+    },
+    Route {
+        method: "PUT",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/network-interfaces/{interface_name}",
+        operation_id: "instance_network_interfaces_put_interface",
+        kind: Kind::Update,
+        status: 200,
+        response: Some("NetworkInterface"),
+    },
+    Route {
+        method: "DELETE",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/network-interfaces/{interface_name}",
+        operation_id: "instance_network_interfaces_delete_interface",
+        kind: Kind::Delete,
+        status: 204,
+        response: Some("NetworkInterface"),
+    },
+    Route {
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/reboot",
+        operation_id: "project_instances_instance_reboot",
+        kind: Kind::Other,
+        status: 202,
+        response: Some("Instance"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/serial-console",
+        operation_id: "project_instances_instance_serial_get",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("InstanceSerialConsoleData"),
+    },
+    Route {
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/start",
+        operation_id: "project_instances_instance_start",
+        kind: Kind::Other,
+        status: 202,
+        response: Some("Instance"),
+    },
+    Route {
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/stop",
+        operation_id: "project_instances_instance_stop",
+        kind: Kind::Other,
+        status: 202,
+        response: Some("Instance"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/policy",
+        operation_id: "organization_projects_get_project_policy",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("ProjectRolePolicy"),
+    },
+    Route {
+        method: "PUT",
+        path: "/organizations/{organization_name}/projects/{project_name}/policy",
+        operation_id: "organization_projects_put_project_policy",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("ProjectRolePolicy"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/snapshots",
+        operation_id: "project_snapshots_get",
+        kind: Kind::List,
+        status: 200,
+        response: Some("Snapshot"),
+    },
+    Route {
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/snapshots",
+        operation_id: "project_snapshots_post",
+        kind: Kind::Create,
+        status: 201,
+        response: Some("Snapshot"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/snapshots/\
+               {snapshot_name}",
+        operation_id: "project_snapshots_get_snapshot",
+        kind: Kind::View,
+        status: 200,
+        response: Some("Snapshot"),
+    },
+    Route {
+        method: "DELETE",
+        path: "/organizations/{organization_name}/projects/{project_name}/snapshots/\
+               {snapshot_name}",
+        operation_id: "project_snapshots_delete_snapshot",
+        kind: Kind::Delete,
+        status: 204,
+        response: Some("Snapshot"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs",
+        operation_id: "project_vpcs_get",
+        kind: Kind::List,
+        status: 200,
+        response: Some("Vpc"),
+    },
+    Route {
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs",
+        operation_id: "project_vpcs_post",
+        kind: Kind::Create,
+        status: 201,
+        response: Some("Vpc"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}",
+        operation_id: "project_vpcs_get_vpc",
+        kind: Kind::View,
+        status: 200,
+        response: Some("Vpc"),
+    },
+    Route {
+        method: "PUT",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}",
+        operation_id: "project_vpcs_put_vpc",
+        kind: Kind::Update,
+        status: 200,
+        response: Some("Vpc"),
+    },
+    Route {
+        method: "DELETE",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}",
+        operation_id: "project_vpcs_delete_vpc",
+        kind: Kind::Delete,
+        status: 204,
+        response: Some("Vpc"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/\
+               firewall/rules",
+        operation_id: "vpc_firewall_rules_get",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("VpcFirewallRules"),
+    },
+    Route {
+        method: "PUT",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/\
+               firewall/rules",
+        operation_id: "vpc_firewall_rules_put",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("VpcFirewallRules"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/routers",
+        operation_id: "vpc_routers_get",
+        kind: Kind::List,
+        status: 200,
+        response: Some("VpcRouter"),
+    },
+    Route {
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/routers",
+        operation_id: "vpc_routers_post",
+        kind: Kind::Create,
+        status: 201,
+        response: Some("VpcRouter"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/routers/\
+               {router_name}",
+        operation_id: "vpc_routers_get_router",
+        kind: Kind::View,
+        status: 200,
+        response: Some("VpcRouter"),
+    },
+    Route {
+        method: "PUT",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/routers/\
+               {router_name}",
+        operation_id: "vpc_routers_put_router",
+        kind: Kind::Update,
+        status: 200,
+        response: Some("VpcRouter"),
+    },
+    Route {
+        method: "DELETE",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/routers/\
+               {router_name}",
+        operation_id: "vpc_routers_delete_router",
+        kind: Kind::Delete,
+        status: 204,
+        response: Some("VpcRouter"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/routers/\
+               {router_name}/routes",
+        operation_id: "routers_routes_get",
+        kind: Kind::List,
+        status: 200,
+        response: Some("RouterRoute"),
+    },
+    Route {
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/routers/\
+               {router_name}/routes",
+        operation_id: "routers_routes_post",
+        kind: Kind::Create,
+        status: 201,
+        response: Some("RouterRoute"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/routers/\
+               {router_name}/routes/{route_name}",
+        operation_id: "routers_routes_get_route",
+        kind: Kind::View,
+        status: 200,
+        response: Some("RouterRoute"),
+    },
+    Route {
+        method: "PUT",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/routers/\
+               {router_name}/routes/{route_name}",
+        operation_id: "routers_routes_put_route",
+        kind: Kind::Update,
+        status: 200,
+        response: Some("RouterRoute"),
+    },
+    Route {
+        method: "DELETE",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/routers/\
+               {router_name}/routes/{route_name}",
+        operation_id: "routers_routes_delete_route",
+        kind: Kind::Delete,
+        status: 204,
+        response: Some("RouterRoute"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/subnets",
+        operation_id: "vpc_subnets_get",
+        kind: Kind::List,
+        status: 200,
+        response: Some("VpcSubnet"),
+    },
+    Route {
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/subnets",
+        operation_id: "vpc_subnets_post",
+        kind: Kind::Create,
+        status: 201,
+        response: Some("VpcSubnet"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/subnets/\
+               {subnet_name}",
+        operation_id: "vpc_subnets_get_subnet",
+        kind: Kind::View,
+        status: 200,
+        response: Some("VpcSubnet"),
+    },
+    Route {
+        method: "PUT",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/subnets/\
+               {subnet_name}",
+        operation_id: "vpc_subnets_put_subnet",
+        kind: Kind::Update,
+        status: 200,
+        response: Some("VpcSubnet"),
+    },
+    Route {
+        method: "DELETE",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/subnets/\
+               {subnet_name}",
+        operation_id: "vpc_subnets_delete_subnet",
+        kind: Kind::Delete,
+        status: 204,
+        response: Some("VpcSubnet"),
+    },
+    Route {
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/subnets/\
+               {subnet_name}/network-interfaces",
+        operation_id: "subnet_network_interfaces_get",
+        kind: Kind::List,
+        status: 200,
+        response: Some("NetworkInterface"),
+    },
+    Route {
+        method: "GET",
+        path: "/policy",
+        operation_id: "policy_get",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("SiloRolePolicy"),
+    },
+    Route {
+        method: "PUT",
+        path: "/policy",
+        operation_id: "policy_put",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("SiloRolePolicy"),
+    },
+    Route {
+        method: "GET",
+        path: "/roles",
+        operation_id: "roles_get",
+        kind: Kind::List,
+        status: 200,
+        response: Some("Role"),
+    },
+    Route {
+        method: "GET",
+        path: "/roles/{role_name}",
+        operation_id: "roles_get_role",
+        kind: Kind::View,
+        status: 200,
+        response: Some("Role"),
+    },
+    Route {
+        method: "GET",
+        path: "/sagas",
+        operation_id: "sagas_get",
+        kind: Kind::List,
+        status: 200,
+        response: Some("Saga"),
+    },
+    Route {
+        method: "GET",
+        path: "/sagas/{saga_id}",
+        operation_id: "sagas_get_saga",
+        kind: Kind::View,
+        status: 200,
+        response: Some("Saga"),
+    },
+    Route {
+        method: "GET",
+        path: "/session/me",
+        operation_id: "session_me",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("User"),
+    },
+    Route {
+        method: "GET",
+        path: "/session/me/sshkeys",
+        operation_id: "sshkeys_get",
+        kind: Kind::List,
+        status: 200,
+        response: Some("SshKey"),
+    },
+    Route {
+        method: "POST",
+        path: "/session/me/sshkeys",
+        operation_id: "sshkeys_post",
+        kind: Kind::Create,
+        status: 201,
+        response: Some("SshKey"),
+    },
+    Route {
+        method: "GET",
+        path: "/session/me/sshkeys/{ssh_key_name}",
+        operation_id: "sshkeys_get_key",
+        kind: Kind::View,
+        status: 200,
+        response: Some("SshKey"),
+    },
+    Route {
+        method: "DELETE",
+        path: "/session/me/sshkeys/{ssh_key_name}",
+        operation_id: "sshkeys_delete_key",
+        kind: Kind::Delete,
+        status: 204,
+        response: Some("SshKey"),
+    },
+    Route {
+        method: "GET",
+        path: "/silos",
+        operation_id: "silos_get",
+        kind: Kind::List,
+        status: 200,
+        response: Some("Silo"),
+    },
+    Route {
+        method: "POST",
+        path: "/silos",
+        operation_id: "silos_post",
+        kind: Kind::Create,
+        status: 201,
+        response: Some("Silo"),
+    },
+    Route {
+        method: "GET",
+        path: "/silos/{silo_name}",
+        operation_id: "silos_get_silo",
+        kind: Kind::View,
+        status: 200,
+        response: Some("Silo"),
+    },
+    Route {
+        method: "DELETE",
+        path: "/silos/{silo_name}",
+        operation_id: "silos_delete_silo",
+        kind: Kind::Delete,
+        status: 204,
+        response: Some("Silo"),
+    },
+    Route {
+        method: "GET",
+        path: "/silos/{silo_name}/identity-providers",
+        operation_id: "silos_get_identity_providers",
+        kind: Kind::List,
+        status: 200,
+        response: Some("IdentityProvider"),
+    },
+    Route {
+        method: "GET",
+        path: "/silos/{silo_name}/policy",
+        operation_id: "silos_get_silo_policy",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("SiloRolePolicy"),
+    },
+    Route {
+        method: "PUT",
+        path: "/silos/{silo_name}/policy",
+        operation_id: "silos_put_silo_policy",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("SiloRolePolicy"),
+    },
+    Route {
+        method: "POST",
+        path: "/silos/{silo_name}/saml-identity-providers",
+        operation_id: "silo_saml_idp_create",
+        kind: Kind::Other,
+        status: 201,
+        response: Some("SamlIdentityProvider"),
+    },
+    Route {
+        method: "GET",
+        path: "/silos/{silo_name}/saml-identity-providers/{provider_name}",
+        operation_id: "silo_saml_idp_fetch",
+        kind: Kind::Other,
+        status: 200,
+        response: Some("SamlIdentityProvider"),
+    },
+    Route {
+        method: "GET",
+        path: "/system/user",
+        operation_id: "system_user_list",
+        kind: Kind::List,
+        status: 200,
+        response: Some("UserBuiltin"),
+    },
+    Route {
+        method: "GET",
+        path: "/system/user/{user_name}",
+        operation_id: "system_user_view",
+        kind: Kind::View,
+        status: 200,
+        response: Some("UserBuiltin"),
+    },
+    Route {
+        method: "GET",
+        path: "/timeseries/schema",
+        operation_id: "timeseries_schema_get",
+        kind: Kind::List,
+        status: 200,
+        response: Some("TimeseriesSchema"),
+    },
+    Route {
+        method: "POST",
+        path: "/updates/refresh",
+        operation_id: "updates_refresh",
+        kind: Kind::Other,
+        status: 204,
+        response: None,
+    },
+    Route {
+        method: "GET",
+        path: "/users",
+        operation_id: "silo_users_get",
+        kind: Kind::List,
+        status: 200,
+        response: Some("User"),
+    },
+];
+
+/// A valid value of each schema, and the names of all its properties.
+pub(crate) const TEMPLATES: &[(&str, &str, &[&str])] = &[
+    ("BinRangedouble", r##"{"end":0.0,"type":"range_to"}"##, &[]),
+    ("BinRangeint64", r##"{"end":0,"type":"range_to"}"##, &[]),
+    (
+        "Bindouble",
+        r##"{"count":0,"range":{"end":0.0,"type":"range_to"}}"##,
+        &["count", "range"],
+    ),
+    (
+        "Binint64",
+        r##"{"count":0,"range":{"end":0,"type":"range_to"}}"##,
+        &["count", "range"],
+    ),
+    ("BlockSize", r##"512"##, &[]),
+    ("ByteCount", r##"0"##, &[]),
+    (
+        "Cumulativedouble",
+        r##"{"start_time":"1970-01-01T00:00:00Z","value":0.0}"##,
+        &["start_time", "value"],
+    ),
+    (
+        "Cumulativeint64",
+        r##"{"start_time":"1970-01-01T00:00:00Z","value":0}"##,
+        &["start_time", "value"],
+    ),
+    ("Datum", r##"{"datum":false,"type":"bool"}"##, &[]),
+    ("DatumType", r##""bool""##, &[]),
+    (
+        "DerEncodedKeyPair",
+        r##"{"private_key":"","public_cert":""}"##,
+        &["private_key", "public_cert"],
+    ),
+    (
+        "DeviceAccessTokenRequest",
+        r##"{"client_id":"00000000-0000-0000-0000-000000000000","device_code":"","grant_type":""}"##,
+        &["client_id", "device_code", "grant_type"],
+    ),
+    (
+        "DeviceAuthRequest",
+        r##"{"client_id":"00000000-0000-0000-0000-000000000000"}"##,
+        &["client_id"],
+    ),
+    ("DeviceAuthVerify", r##"{"user_code":""}"##, &["user_code"]),
+    ("Digest", r##"{"type":"sha256","value":""}"##, &[]),
+    (
+        "Disk",
+        r##"{"block_size":0,"description":"","device_path":"","id":"00000000-0000-0000-0000-000000000000","name":"","project_id":"00000000-0000-0000-0000-000000000000","size":0,"state":{"state":"creating"},"time_created":"1970-01-01T00:00:00Z","time_modified":"1970-01-01T00:00:00Z"}"##,
+        &[
+            "block_size",
+            "description",
+            "device_path",
+            "id",
+            "image_id",
+            "name",
+            "project_id",
+            "size",
+            "snapshot_id",
+            "state",
+            "time_created",
+            "time_modified",
+        ],
+    ),
+    (
+        "DiskCreate",
+        r##"{"description":"","disk_source":{"block_size":512,"type":"blank"},"name":"","size":0}"##,
+        &["description", "disk_source", "name", "size"],
+    ),
+    ("DiskIdentifier", r##"{"name":""}"##, &["name"]),
+    (
+        "DiskResultsPage",
+        r##"{"items":[]}"##,
+        &["items", "next_page"],
+    ),
+    ("DiskSource", r##"{"block_size":512,"type":"blank"}"##, &[]),
+    ("DiskState", r##"{"state":"creating"}"##, &[]),
+    (
+        "Distribution",
+        r##"{"name":"","version":""}"##,
+        &["name", "version"],
+    ),
+    (
+        "Error",
+        r##"{"message":"","request_id":""}"##,
+        &["error_code", "message", "request_id"],
+    ),
+    (
+        "ExternalIp",
+        r##"{"ip":"0.0.0.0","kind":"ephemeral"}"##,
+        &["ip", "kind"],
+    ),
+    ("ExternalIpCreate", r##"{"type":"ephemeral"}"##, &[]),
+    (
+        "ExternalIpResultsPage",
+        r##"{"items":[]}"##,
+        &["items", "next_page"],
+    ),
+    (
+        "FieldSchema",
+        r##"{"name":"","source":"target","ty":"string"}"##,
+        &["name", "source", "ty"],
+    ),
+    ("FieldSource", r##""target""##, &[]),
+    ("FieldType", r##""string""##, &[]),
+    ("FleetRole", r##""admin""##, &[]),
+    (
+        "FleetRolePolicy",
+        r##"{"role_assignments":[]}"##,
+        &["role_assignments"],
+    ),
+    (
+        "FleetRoleRoleAssignment",
+        r##"{"identity_id":"00000000-0000-0000-0000-000000000000","identity_type":"silo_user","role_name":"admin"}"##,
+        &["identity_id", "identity_type", "role_name"],
+    ),
+    (
+        "GlobalImage",
+        r##"{"block_size":0,"description":"","distribution":"","id":"00000000-0000-0000-0000-000000000000","name":"","size":0,"time_created":"1970-01-01T00:00:00Z","time_modified":"1970-01-01T00:00:00Z","version":""}"##,
+        &[
+            "block_size",
+            "description",
+            "digest",
+            "distribution",
+            "id",
+            "name",
+            "size",
+            "time_created",
+            "time_modified",
+            "url",
+            "version",
+        ],
+    ),
+    (
+        "GlobalImageCreate",
+        r##"{"block_size":512,"description":"","distribution":{"name":"","version":""},"name":"","source":{"type":"url","url":""}}"##,
+        &[
+            "block_size",
+            "description",
+            "distribution",
+            "name",
+            "source",
+        ],
+    ),
+    (
+        "GlobalImageResultsPage",
+        r##"{"items":[]}"##,
+        &["items", "next_page"],
+    ),
+    (
+        "Histogramdouble",
+        r##"{"bins":[],"n_samples":0,"start_time":"1970-01-01T00:00:00Z"}"##,
+        &["bins", "n_samples", "start_time"],
+    ),
+    (
+        "Histogramint64",
+        r##"{"bins":[],"n_samples":0,"start_time":"1970-01-01T00:00:00Z"}"##,
+        &["bins", "n_samples", "start_time"],
+    ),
+    (
+        "IdentityProvider",
+        r##"{"description":"","id":"00000000-0000-0000-0000-000000000000","name":"","provider_type":"saml","time_created":"1970-01-01T00:00:00Z","time_modified":"1970-01-01T00:00:00Z"}"##,
+        &[
+            "description",
+            "id",
+            "name",
+            "provider_type",
+            "time_created",
+            "time_modified",
+        ],
+    ),
+    (
+        "IdentityProviderResultsPage",
+        r##"{"items":[]}"##,
+        &["items", "next_page"],
+    ),
+    ("IdentityProviderType", r##""saml""##, &[]),
+    ("IdentityType", r##""silo_user""##, &[]),
+    ("IdpMetadataSource", r##"{"type":"url","url":""}"##, &[]),
+    (
+        "Image",
+        r##"{"block_size":0,"description":"","id":"00000000-0000-0000-0000-000000000000","name":"","project_id":"00000000-0000-0000-0000-000000000000","size":0,"time_created":"1970-01-01T00:00:00Z","time_modified":"1970-01-01T00:00:00Z"}"##,
+        &[
+            "block_size",
+            "description",
+            "digest",
+            "id",
+            "name",
+            "project_id",
+            "size",
+            "time_created",
+            "time_modified",
+            "url",
+            "version",
+        ],
+    ),
+    (
+        "ImageCreate",
+        r##"{"block_size":512,"description":"","name":"","source":{"type":"url","url":""}}"##,
+        &["block_size", "description", "name", "source"],
+    ),
+    (
+        "ImageResultsPage",
+        r##"{"items":[]}"##,
+        &["items", "next_page"],
+    ),
+    ("ImageSource", r##"{"type":"url","url":""}"##, &[]),
+    (
+        "Instance",
+        r##"{"description":"","hostname":"","id":"00000000-0000-0000-0000-000000000000","memory":0,"name":"","ncpus":0,"project_id":"00000000-0000-0000-0000-000000000000","run_state":"creating","time_created":"1970-01-01T00:00:00Z","time_modified":"1970-01-01T00:00:00Z","time_run_state_updated":"1970-01-01T00:00:00Z"}"##,
+        &[
+            "description",
+            "hostname",
+            "id",
+            "memory",
+            "name",
+            "ncpus",
+            "project_id",
+            "run_state",
+            "time_created",
+            "time_modified",
+            "time_run_state_updated",
+        ],
+    ),
+    ("InstanceCpuCount", r##"0"##, &[]),
+    (
+        "InstanceCreate",
+        r##"{"description":"","hostname":"","memory":0,"name":"","ncpus":0}"##,
+        &[
+            "description",
+            "disks",
+            "external_ips",
+            "hostname",
+            "memory",
+            "name",
+            "ncpus",
+            "network_interfaces",
+            "start",
+            "user_data",
+        ],
+    ),
+    (
+        "InstanceDiskAttachment",
+        r##"{"description":"","disk_source":{"block_size":512,"type":"blank"},"name":"","size":0,"type":"create"}"##,
+        &[],
+    ),
+    (
+        "InstanceMigrate",
+        r##"{"dst_sled_id":"00000000-0000-0000-0000-000000000000"}"##,
+        &["dst_sled_id"],
+    ),
+    (
+        "InstanceNetworkInterfaceAttachment",
+        r##"{"params":[],"type":"create"}"##,
+        &[],
+    ),
+    (
+        "InstanceResultsPage",
+        r##"{"items":[]}"##,
+        &["items", "next_page"],
+    ),
+    (
+        "InstanceSerialConsoleData",
+        r##"{"data":[],"last_byte_offset":0}"##,
+        &["data", "last_byte_offset"],
+    ),
+    ("InstanceState", r##""creating""##, &[]),
+    ("IpKind", r##""ephemeral""##, &[]),
+    ("IpNet", r##""192.168.1.0/24""##, &[]),
+    (
+        "IpPool",
+        r##"{"description":"","id":"00000000-0000-0000-0000-000000000000","name":"","time_created":"1970-01-01T00:00:00Z","time_modified":"1970-01-01T00:00:00Z"}"##,
+        &[
+            "description",
+            "id",
+            "name",
+            "project_id",
+            "time_created",
+            "time_modified",
+        ],
+    ),
+    (
+        "IpPoolCreate",
+        r##"{"description":"","name":""}"##,
+        &["description", "name", "organization", "project"],
+    ),
+    (
+        "IpPoolRange",
+        r##"{"id":"00000000-0000-0000-0000-000000000000","range":{"first":"0.0.0.0","last":"0.0.0.0"},"time_created":"1970-01-01T00:00:00Z"}"##,
+        &["id", "range", "time_created"],
+    ),
+    (
+        "IpPoolRangeResultsPage",
+        r##"{"items":[]}"##,
+        &["items", "next_page"],
+    ),
+    (
+        "IpPoolResultsPage",
+        r##"{"items":[]}"##,
+        &["items", "next_page"],
+    ),
+    ("IpPoolUpdate", r##"{}"##, &["description", "name"]),
+    ("IpRange", r##"{"first":"0.0.0.0","last":"0.0.0.0"}"##, &[]),
+    ("Ipv4Net", r##""192.168.1.0/24""##, &[]),
+    (
+        "Ipv4Range",
+        r##"{"first":"0.0.0.0","last":"0.0.0.0"}"##,
+        &["first", "last"],
+    ),
+    ("Ipv6Net", r##""fd12:3456::/64""##, &[]),
+    (
+        "Ipv6Range",
+        r##"{"first":"::","last":"::"}"##,
+        &["first", "last"],
+    ),
+    ("L4PortRange", r##""22""##, &[]),
+    ("MacAddr", r##""ff:ff:ff:ff:ff:ff""##, &[]),
+    (
+        "Measurement",
+        r##"{"datum":{"datum":false,"type":"bool"},"timestamp":"1970-01-01T00:00:00Z"}"##,
+        &["datum", "timestamp"],
+    ),
+    (
+        "MeasurementResultsPage",
+        r##"{"items":[]}"##,
+        &["items", "next_page"],
+    ),
+    ("Name", r##""""##, &[]),
+    (
+        "NetworkInterface",
+        r##"{"description":"","id":"00000000-0000-0000-0000-000000000000","instance_id":"00000000-0000-0000-0000-000000000000","ip":"0.0.0.0","mac":"ff:ff:ff:ff:ff:ff","name":"","primary":false,"subnet_id":"00000000-0000-0000-0000-000000000000","time_created":"1970-01-01T00:00:00Z","time_modified":"1970-01-01T00:00:00Z","vpc_id":"00000000-0000-0000-0000-000000000000"}"##,
+        &[
+            "description",
+            "id",
+            "instance_id",
+            "ip",
+            "mac",
+            "name",
+            "primary",
+            "subnet_id",
+            "time_created",
+            "time_modified",
+            "vpc_id",
+        ],
+    ),
+    (
+        "NetworkInterfaceCreate",
+        r##"{"description":"","name":"","subnet_name":"","vpc_name":""}"##,
+        &["description", "ip", "name", "subnet_name", "vpc_name"],
+    ),
+    (
+        "NetworkInterfaceResultsPage",
+        r##"{"items":[]}"##,
+        &["items", "next_page"],
+    ),
+    (
+        "NetworkInterfaceUpdate",
+        r##"{}"##,
+        &["description", "name", "primary"],
+    ),
+    ("NodeName", r##""""##, &[]),
+    (
+        "Organization",
+        r##"{"description":"","id":"00000000-0000-0000-0000-000000000000","name":"","time_created":"1970-01-01T00:00:00Z","time_modified":"1970-01-01T00:00:00Z"}"##,
+        &["description", "id", "name", "time_created", "time_modified"],
+    ),
+    (
+        "OrganizationCreate",
+        r##"{"description":"","name":""}"##,
+        &["description", "name"],
+    ),
+    (
+        "OrganizationResultsPage",
+        r##"{"items":[]}"##,
+        &["items", "next_page"],
+    ),
+    ("OrganizationRole", r##""admin""##, &[]),
+    (
+        "OrganizationRolePolicy",
+        r##"{"role_assignments":[]}"##,
+        &["role_assignments"],
+    ),
+    (
+        "OrganizationRoleRoleAssignment",
+        r##"{"identity_id":"00000000-0000-0000-0000-000000000000","identity_type":"silo_user","role_name":"admin"}"##,
+        &["identity_id", "identity_type", "role_name"],
+    ),
+    ("OrganizationUpdate", r##"{}"##, &["description", "name"]),
+    (
+        "Project",
+        r##"{"description":"","id":"00000000-0000-0000-0000-000000000000","name":"","organization_id":"00000000-0000-0000-0000-000000000000","time_created":"1970-01-01T00:00:00Z","time_modified":"1970-01-01T00:00:00Z"}"##,
+        &[
+            "description",
+            "id",
+            "name",
+            "organization_id",
+            "time_created",
+            "time_modified",
+        ],
+    ),
+    (
+        "ProjectCreate",
+        r##"{"description":"","name":""}"##,
+        &["description", "name"],
+    ),
+    (
+        "ProjectResultsPage",
+        r##"{"items":[]}"##,
+        &["items", "next_page"],
+    ),
+    ("ProjectRole", r##""admin""##, &[]),
+    (
+        "ProjectRolePolicy",
+        r##"{"role_assignments":[]}"##,
+        &["role_assignments"],
+    ),
+    (
+        "ProjectRoleRoleAssignment",
+        r##"{"identity_id":"00000000-0000-0000-0000-000000000000","identity_type":"silo_user","role_name":"admin"}"##,
+        &["identity_id", "identity_type", "role_name"],
+    ),
+    ("ProjectUpdate", r##"{}"##, &["description", "name"]),
+    (
+        "Rack",
+        r##"{"id":"00000000-0000-0000-0000-000000000000","time_created":"1970-01-01T00:00:00Z","time_modified":"1970-01-01T00:00:00Z"}"##,
+        &["id", "time_created", "time_modified"],
+    ),
+    (
+        "RackResultsPage",
+        r##"{"items":[]}"##,
+        &["items", "next_page"],
+    ),
+    (
+        "Role",
+        r##"{"description":"","name":""}"##,
+        &["description", "name"],
+    ),
+    ("RoleName", r##""""##, &[]),
+    (
+        "RoleResultsPage",
+        r##"{"items":[]}"##,
+        &["items", "next_page"],
+    ),
+    (
+        "RouteDestination",
+        r##"{"type":"ip","value":"0.0.0.0"}"##,
+        &[],
+    ),
+    ("RouteTarget", r##"{"type":"ip","value":"0.0.0.0"}"##, &[]),
+    (
+        "RouterRoute",
+        r##"{"description":"","destination":{"type":"ip","value":"0.0.0.0"},"id":"00000000-0000-0000-0000-000000000000","kind":"default","name":"","target":{"type":"ip","value":"0.0.0.0"},"time_created":"1970-01-01T00:00:00Z","time_modified":"1970-01-01T00:00:00Z","vpc_router_id":"00000000-0000-0000-0000-000000000000"}"##,
+        &[
+            "description",
+            "destination",
+            "id",
+            "kind",
+            "name",
+            "target",
+            "time_created",
+            "time_modified",
+            "vpc_router_id",
+        ],
+    ),
+    (
+        "RouterRouteCreateParams",
+        r##"{"description":"","destination":{"type":"ip","value":"0.0.0.0"},"name":"","target":{"type":"ip","value":"0.0.0.0"}}"##,
+        &["description", "destination", "name", "target"],
+    ),
+    ("RouterRouteKind", r##""default""##, &[]),
+    (
+        "RouterRouteResultsPage",
+        r##"{"items":[]}"##,
+        &["items", "next_page"],
+    ),
+    (
+        "RouterRouteUpdateParams",
+        r##"{"destination":{"type":"ip","value":"0.0.0.0"},"target":{"type":"ip","value":"0.0.0.0"}}"##,
+        &["description", "destination", "name", "target"],
+    ),
+    (
+        "Saga",
+        r##"{"id":"00000000-0000-0000-0000-000000000000","state":{"state":"running"}}"##,
+        &["id", "state"],
+    ),
+    (
+        "SagaErrorInfo",
+        r##"{"error":"action_failed","source_error":null}"##,
+        &[],
+    ),
+    (
+        "SagaResultsPage",
+        r##"{"items":[]}"##,
+        &["items", "next_page"],
+    ),
+    ("SagaState", r##"{"state":"running"}"##, &[]),
+    (
+        "SamlIdentityProvider",
+        r##"{"acs_url":"","description":"","id":"00000000-0000-0000-0000-000000000000","idp_entity_id":"","name":"","slo_url":"","sp_client_id":"","technical_contact_email":"","time_created":"1970-01-01T00:00:00Z","time_modified":"1970-01-01T00:00:00Z"}"##,
+        &[
+            "acs_url",
+            "description",
+            "id",
+            "idp_entity_id",
+            "name",
+            "public_cert",
+            "slo_url",
+            "sp_client_id",
+            "technical_contact_email",
+            "time_created",
+            "time_modified",
+        ],
+    ),
+    (
+        "SamlIdentityProviderCreate",
+        r##"{"acs_url":"","description":"","idp_entity_id":"","idp_metadata_source":{"type":"url","url":""},"name":"","slo_url":"","sp_client_id":"","technical_contact_email":""}"##,
+        &[
+            "acs_url",
+            "description",
+            "group_attribute_name",
+            "idp_entity_id",
+            "idp_metadata_source",
+            "name",
+            "signing_keypair",
+            "slo_url",
+            "sp_client_id",
+            "technical_contact_email",
+        ],
+    ),
+    (
+        "Silo",
+        r##"{"description":"","discoverable":false,"id":"00000000-0000-0000-0000-000000000000","name":"","time_created":"1970-01-01T00:00:00Z","time_modified":"1970-01-01T00:00:00Z","user_provision_type":"fixed"}"##,
+        &[
+            "description",
+            "discoverable",
+            "id",
+            "name",
+            "time_created",
+            "time_modified",
+            "user_provision_type",
+        ],
+    ),
+    (
+        "SiloCreate",
+        r##"{"description":"","discoverable":false,"name":"","user_provision_type":"fixed"}"##,
+        &[
+            "admin_group_name",
+            "description",
+            "discoverable",
+            "name",
+            "user_provision_type",
+        ],
+    ),
+    (
+        "SiloResultsPage",
+        r##"{"items":[]}"##,
+        &["items", "next_page"],
+    ),
+    ("SiloRole", r##""admin""##, &[]),
+    (
+        "SiloRolePolicy",
+        r##"{"role_assignments":[]}"##,
+        &["role_assignments"],
+    ),
+    (
+        "SiloRoleRoleAssignment",
+        r##"{"identity_id":"00000000-0000-0000-0000-000000000000","identity_type":"silo_user","role_name":"admin"}"##,
+        &["identity_id", "identity_type", "role_name"],
+    ),
+    (
+        "Sled",
+        r##"{"id":"00000000-0000-0000-0000-000000000000","service_address":"","time_created":"1970-01-01T00:00:00Z","time_modified":"1970-01-01T00:00:00Z"}"##,
+        &["id", "service_address", "time_created", "time_modified"],
+    ),
+    (
+        "SledResultsPage",
+        r##"{"items":[]}"##,
+        &["items", "next_page"],
+    ),
+    (
+        "Snapshot",
+        r##"{"description":"","disk_id":"00000000-0000-0000-0000-000000000000","id":"00000000-0000-0000-0000-000000000000","name":"","project_id":"00000000-0000-0000-0000-000000000000","size":0,"time_created":"1970-01-01T00:00:00Z","time_modified":"1970-01-01T00:00:00Z"}"##,
+        &[
+            "description",
+            "disk_id",
+            "id",
+            "name",
+            "project_id",
+            "size",
+            "time_created",
+            "time_modified",
+        ],
+    ),
+    (
+        "SnapshotCreate",
+        r##"{"description":"","disk":"","name":""}"##,
+        &["description", "disk", "name"],
+    ),
+    (
+        "SnapshotResultsPage",
+        r##"{"items":[]}"##,
+        &["items", "next_page"],
+    ),
+    ("SpoofLoginBody", r##"{"username":""}"##, &["username"]),
+    (
+        "SshKey",
+        r##"{"description":"","id":"00000000-0000-0000-0000-000000000000","name":"","public_key":"","silo_user_id":"00000000-0000-0000-0000-000000000000","time_created":"1970-01-01T00:00:00Z","time_modified":"1970-01-01T00:00:00Z"}"##,
+        &[
+            "description",
+            "id",
+            "name",
+            "public_key",
+            "silo_user_id",
+            "time_created",
+            "time_modified",
+        ],
+    ),
+    (
+        "SshKeyCreate",
+        r##"{"description":"","name":"","public_key":""}"##,
+        &["description", "name", "public_key"],
+    ),
+    (
+        "SshKeyResultsPage",
+        r##"{"items":[]}"##,
+        &["items", "next_page"],
+    ),
+    ("TimeseriesName", r##""""##, &[]),
+    (
+        "TimeseriesSchema",
+        r##"{"created":"1970-01-01T00:00:00Z","datum_type":"bool","field_schema":[],"timeseries_name":""}"##,
+        &["created", "datum_type", "field_schema", "timeseries_name"],
+    ),
+    (
+        "TimeseriesSchemaResultsPage",
+        r##"{"items":[]}"##,
+        &["items", "next_page"],
+    ),
+    (
+        "User",
+        r##"{"display_name":"","id":"00000000-0000-0000-0000-000000000000"}"##,
+        &["display_name", "id"],
+    ),
+    (
+        "UserBuiltin",
+        r##"{"description":"","id":"00000000-0000-0000-0000-000000000000","name":"","time_created":"1970-01-01T00:00:00Z","time_modified":"1970-01-01T00:00:00Z"}"##,
+        &["description", "id", "name", "time_created", "time_modified"],
+    ),
+    (
+        "UserBuiltinResultsPage",
+        r##"{"items":[]}"##,
+        &["items", "next_page"],
+    ),
+    ("UserProvisionType", r##""fixed""##, &[]),
+    (
+        "UserResultsPage",
+        r##"{"items":[]}"##,
+        &["items", "next_page"],
+    ),
+    (
+        "Vpc",
+        r##"{"description":"","dns_name":"","id":"00000000-0000-0000-0000-000000000000","ipv6_prefix":"fd12:3456::/64","name":"","project_id":"00000000-0000-0000-0000-000000000000","system_router_id":"00000000-0000-0000-0000-000000000000","time_created":"1970-01-01T00:00:00Z","time_modified":"1970-01-01T00:00:00Z"}"##,
+        &[
+            "description",
+            "dns_name",
+            "id",
+            "ipv6_prefix",
+            "name",
+            "project_id",
+            "system_router_id",
+            "time_created",
+            "time_modified",
+        ],
+    ),
+    (
+        "VpcCreate",
+        r##"{"description":"","dns_name":"","name":""}"##,
+        &["description", "dns_name", "ipv6_prefix", "name"],
+    ),
+    (
+        "VpcFirewallRule",
+        r##"{"action":"allow","description":"","direction":"inbound","filters":{},"id":"00000000-0000-0000-0000-000000000000","name":"","priority":0,"status":"disabled","targets":[],"time_created":"1970-01-01T00:00:00Z","time_modified":"1970-01-01T00:00:00Z","vpc_id":"00000000-0000-0000-0000-000000000000"}"##,
+        &[
+            "action",
+            "description",
+            "direction",
+            "filters",
+            "id",
+            "name",
+            "priority",
+            "status",
+            "targets",
+            "time_created",
+            "time_modified",
+            "vpc_id",
+        ],
+    ),
+    ("VpcFirewallRuleAction", r##""allow""##, &[]),
+    ("VpcFirewallRuleDirection", r##""inbound""##, &[]),
+    (
+        "VpcFirewallRuleFilter",
+        r##"{}"##,
+        &["hosts", "ports", "protocols"],
+    ),
+    (
+        "VpcFirewallRuleHostFilter",
+        r##"{"type":"vpc","value":""}"##,
+        &[],
+    ),
+    ("VpcFirewallRuleProtocol", r##""TCP""##, &[]),
+    ("VpcFirewallRuleStatus", r##""disabled""##, &[]),
+    (
+        "VpcFirewallRuleTarget",
+        r##"{"type":"vpc","value":""}"##,
+        &[],
+    ),
+    (
+        "VpcFirewallRuleUpdate",
+        r##"{"action":"allow","description":"","direction":"inbound","filters":{},"name":"","priority":0,"status":"disabled","targets":[]}"##,
+        &[
+            "action",
+            "description",
+            "direction",
+            "filters",
+            "name",
+            "priority",
+            "status",
+            "targets",
+        ],
+    ),
+    (
+        "VpcFirewallRuleUpdateParams",
+        r##"{"rules":[]}"##,
+        &["rules"],
+    ),
+    ("VpcFirewallRules", r##"{"rules":[]}"##, &["rules"]),
+    (
+        "VpcResultsPage",
+        r##"{"items":[]}"##,
+        &["items", "next_page"],
+    ),
+    (
+        "VpcRouter",
+        r##"{"description":"","id":"00000000-0000-0000-0000-000000000000","kind":"system","name":"","time_created":"1970-01-01T00:00:00Z","time_modified":"1970-01-01T00:00:00Z","vpc_id":"00000000-0000-0000-0000-000000000000"}"##,
+        &[
+            "description",
+            "id",
+            "kind",
+            "name",
+            "time_created",
+            "time_modified",
+            "vpc_id",
+        ],
+    ),
+    (
+        "VpcRouterCreate",
+        r##"{"description":"","name":""}"##,
+        &["description", "name"],
+    ),
+    ("VpcRouterKind", r##""system""##, &[]),
+    (
+        "VpcRouterResultsPage",
+        r##"{"items":[]}"##,
+        &["items", "next_page"],
+    ),
+    ("VpcRouterUpdate", r##"{}"##, &["description", "name"]),
+    (
+        "VpcSubnet",
+        r##"{"description":"","id":"00000000-0000-0000-0000-000000000000","ipv4_block":"192.168.1.0/24","ipv6_block":"fd12:3456::/64","name":"","time_created":"1970-01-01T00:00:00Z","time_modified":"1970-01-01T00:00:00Z","vpc_id":"00000000-0000-0000-0000-000000000000"}"##,
+        &[
+            "description",
+            "id",
+            "ipv4_block",
+            "ipv6_block",
+            "name",
+            "time_created",
+            "time_modified",
+            "vpc_id",
+        ],
+    ),
+    (
+        "VpcSubnetCreate",
+        r##"{"description":"","ipv4_block":"192.168.1.0/24","name":""}"##,
+        &["description", "ipv4_block", "ipv6_block", "name"],
+    ),
+    (
+        "VpcSubnetResultsPage",
+        r##"{"items":[]}"##,
+        &["items", "next_page"],
+    ),
+    ("VpcSubnetUpdate", r##"{}"##, &["description", "name"]),
+    ("VpcUpdate", r##"{}"##, &["description", "dns_name", "name"]),
+    ("IdSortMode", r##""id_ascending""##, &[]),
+    ("NameSortMode", r##""name_ascending""##, &[]),
+    ("NameOrIdSortMode", r##""name_ascending""##, &[]),
+    ("DiskMetricName", r##""activated""##, &[]),
+];
@@ -0,0 +1,535 @@
+//! The mock server and its in-memory store.
+
+use std::{
+    collections::BTreeMap,
+    convert::Infallible,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use anyhow::Result;
+use hyper::{
+    service::{make_service_fn, service_fn},
+    Body, Request, Response, Server, StatusCode,
+};
+use serde_json::{json, Value};
+
+use crate::routes::{ROUTES, TEMPLATES};
+
+/// What the server does for an operation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Kind {
+    List,
+    Create,
+    View,
+    Update,
+    Delete,
+    Other,
+}
+
+#[derive(Debug)]
+pub(crate) struct Route {
+    pub method: &'static str,
+    pub path: &'static str,
+    pub operation_id: &'static str,
+    pub kind: Kind,
+    pub status: u16,
+    pub response: Option<&'static str>,
+}
+
+/// A failure to return from an operation instead of handling it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fault {
+    /// The status to answer with, or 0 to handle the request after the delay.
+    pub status: u16,
+    pub error_code: String,
+    pub message: String,
+    pub delay: Duration,
+    /// How many requests fail, or all of them if `None`.
+    pub times: Option<usize>,
+}
+
+impl Fault {
+    /// Answer with `status` and an error response with `error_code`.
+    pub fn error(status: u16, error_code: &str) -> Self {
+        Fault {
+            status,
+            error_code: error_code.to_string(),
+            message: format!("injected {}", error_code),
+            delay: Duration::ZERO,
+            times: None,
+        }
+    }
+
+    /// Handle requests normally, but only after `delay`.
+    pub fn delay(delay: Duration) -> Self {
+        Fault {
+            status: 0,
+            error_code: String::new(),
+            message: String::new(),
+            delay,
+            times: None,
+        }
+    }
+
+    /// Only affect the next `times` requests, so none if `times` is 0.
+    pub fn times(mut self, times: usize) -> Self {
+        self.times = Some(times);
+        self
+    }
+}
+
+/// A request the server received.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    pub operation_id: String,
+}
+
+#[derive(Default)]
+struct State {
+    /// Resources by the path of their collection, then by name.
+    collections: BTreeMap<String, BTreeMap<String, Value>>,
+    /// The schema of the resources of each collection.
+    schemas: BTreeMap<String, &'static str>,
+    faults: BTreeMap<String, Fault>,
+    requests: Vec<RecordedRequest>,
+    next_request: u64,
+}
+
+/// The mock server. It stops when dropped.
+pub struct MockServer {
+    addr: SocketAddr,
+    state: Arc<Mutex<State>>,
+    shutdown: Option<tokio::sync::oneshot::Sender<()>>,
+}
+
+impl MockServer {
+    /// Start serving on a free port of the loopback interface.
+    pub async fn start() -> Result<Self> {
+        let state = Arc::new(Mutex::new(State::default()));
+        let service_state = state.clone();
+        let make_service = make_service_fn(move |_| {
+            let state = service_state.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |req| {
+                    let state = state.clone();
+                    async move { Ok::<_, Infallible>(handle(&state, req).await) }
+                }))
+            }
+        });
+
+        let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+        let server = Server::try_bind(&([127, 0, 0, 1], 0).into())?.serve(make_service);
+        let addr = server.local_addr();
+        tokio::spawn(server.with_graceful_shutdown(async {
+            rx.await.ok();
+        }));
+
+        Ok(MockServer {
+            addr,
+            state,
+            shutdown: Some(tx),
+        })
+    }
+
+    /// The URL to create clients with.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Make requests to the operation fail or wait, replacing any fault it had.
+    pub fn inject(&self, operation_id: &str, fault: Fault) {
+        let mut state = self.state.lock().unwrap();
+        state.faults.insert(operation_id.to_string(), fault);
+    }
+
+    pub fn clear_faults(&self) {
+        self.state.lock().unwrap().faults.clear();
+    }
+
+    /// Every request received so far, oldest first.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    /// The resource at `path`, if it exists.
+    pub fn get(&self, path: &str) -> Option<Value> {
+        let (collection, name) = path.rsplit_once('/')?;
+        let state = self.state.lock().unwrap();
+        state.collections.get(collection)?.get(name).cloned()
+    }
+
+    /// Add a resource to the collection at `path`, without any checks. It is
+    /// stored under its `name`.
+    pub fn insert(&self, path: &str, resource: Value) {
+        let name = resource["name"].as_str().unwrap_or_default().to_string();
+        let mut state = self.state.lock().unwrap();
+        state
+            .collections
+            .entry(path.to_string())
+            .or_default()
+            .insert(name, resource);
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        if let Some(tx) = self.shutdown.take() {
+            tx.send(()).ok();
+        }
+    }
+}
+
+/// The route for a request, preferring literal path segments over
+/// parameters, and the values of its parameters.
+fn find_route(
+    method: &str,
+    path: &str,
+) -> Option<(&'static Route, BTreeMap<&'static str, String>)> {
+    let segments: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+    let mut best: Option<(usize, &'static Route, BTreeMap<&'static str, String>)> = None;
+    for route in ROUTES.iter().filter(|r| r.method == method) {
+        let template: Vec<&'static str> = route.path.trim_start_matches('/').split('/').collect();
+        if template.len() != segments.len() {
+            continue;
+        }
+        let mut params = BTreeMap::new();
+        let mut literals = 0;
+        let matches = template.iter().zip(&segments).all(|(t, s)| {
+            if let Some(param) = t.strip_prefix('{').and_then(|t| t.strip_suffix('}')) {
+                params.insert(param, decode(s));
+                true
+            } else {
+                literals += 1;
+                t == s
+            }
+        });
+        if matches && best.as_ref().map(|(l, _, _)| literals > *l).unwrap_or(true) {
+            best = Some((literals, route, params));
+        }
+    }
+    best.map(|(_, route, params)| (route, params))
+}
+
+fn decode(s: &str) -> String {
+    percent_encoding::percent_decode_str(s)
+        .decode_utf8_lossy()
+        .to_string()
+}
+
+fn template(schema: &str) -> Value {
+    TEMPLATES
+        .iter()
+        .find(|(name, _, _)| *name == schema)
+        .map(|(_, template, _)| serde_json::from_str(template).unwrap())
+        .unwrap_or(Value::Null)
+}
+
+fn properties(schema: &str) -> &'static [&'static str] {
+    TEMPLATES
+        .iter()
+        .find(|(name, _, _)| *name == schema)
+        .map(|(_, _, properties)| *properties)
+        .unwrap_or_default()
+}
+
+fn respond(status: u16, body: Option<Value>) -> Response<Body> {
+    let mut response = Response::builder()
+        .status(StatusCode::from_u16(status).unwrap_or(StatusCode::OK))
+        .header("content-type", "application/json");
+    if status == 204 {
+        response = response.status(StatusCode::NO_CONTENT);
+        return response.body(Body::empty()).unwrap();
+    }
+    let body = body.map(|b| b.to_string()).unwrap_or_default();
+    response.body(Body::from(body)).unwrap()
+}
+
+fn error(status: u16, request_id: u64, error_code: &str, message: String) -> Response<Body> {
+    respond(
+        status,
+        Some(json!({
+            "request_id": request_id.to_string(),
+            "error_code": error_code,
+            "message": message,
+        })),
+    )
+}
+
+/// The singular of a collection's name, as used for the ids of its resources
+/// in their children.
+fn singular(collection: &str) -> &str {
+    collection.strip_suffix('s').unwrap_or(collection)
+}
+
+async fn handle(state: &Mutex<State>, req: Request<Body>) -> Response<Body> {
+    let method = req.method().to_string();
+    let path = req.uri().path().to_string();
+    let query: BTreeMap<String, String> =
+        serde_urlencoded::from_str(req.uri().query().unwrap_or_default()).unwrap_or_default();
+
+    let (route, params) = match find_route(&method, &path) {
+        Some(found) => found,
+        None => {
+            return error(
+                404,
+                0,
+                "NotFound",
+                format!("no operation {} {}", method, path),
+            );
+        }
+    };
+
+    let (request_id, fault) = {
+        let mut state = state.lock().unwrap();
+        state.next_request += 1;
+        state.requests.push(RecordedRequest {
+            method: method.to_string(),
+            path: path.to_string(),
+            operation_id: route.operation_id.to_string(),
+        });
+        let fault = match state.faults.remove(route.operation_id) {
+            // A fault for no requests affects none.
+            None | Some(Fault { times: Some(0), .. }) => None,
+            Some(mut fault) => {
+                if let Some(times) = &mut fault.times {
+                    *times -= 1;
+                }
+                if fault.times != Some(0) {
+                    state
+                        .faults
+                        .insert(route.operation_id.to_string(), fault.clone());
+                }
+                Some(fault)
+            }
+        };
+        (state.next_request, fault)
+    };
+    if let Some(fault) = fault {
+        tokio::time::sleep(fault.delay).await;
+        if fault.status != 0 {
+            return error(fault.status, request_id, &fault.error_code, fault.message);
+        }
+    }
+
+    let body = match hyper::body::to_bytes(req.into_body()).await {
+        Ok(bytes) if !bytes.is_empty() => serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+        _ => Value::Null,
+    };
+
+    let mut state = state.lock().unwrap();
+    let not_found = |what: &str| {
+        error(
+            404,
+            request_id,
+            "ObjectNotFound",
+            format!("not found: {}", what),
+        )
+    };
+    let now = chrono::Utc::now().to_rfc3339();
+
+    match route.kind {
+        Kind::List => {
+            let mut items: Vec<Value> = state
+                .collections
+                .get(&path)
+                .map(|c| c.values().cloned().collect())
+                .unwrap_or_default();
+            let sort_by = query
+                .get("sort_by")
+                .map(|s| s.as_str())
+                .unwrap_or("name_ascending");
+            let key = if sort_by.starts_with("id") {
+                "id"
+            } else {
+                "name"
+            };
+            let sort_key = |v: &Value| v[key].as_str().unwrap_or_default().to_string();
+            items.sort_by_key(sort_key);
+            if sort_by.ends_with("descending") {
+                items.reverse();
+            }
+            if let Some(token) = query.get("page_token") {
+                let after = |v: &Value| {
+                    if sort_by.ends_with("descending") {
+                        sort_key(v) < *token
+                    } else {
+                        sort_key(v) > *token
+                    }
+                };
+                items.retain(after);
+            }
+            let limit = query
+                .get("limit")
+                .and_then(|l| l.parse().ok())
+                .filter(|l| *l > 0)
+                .unwrap_or(100);
+            let next_page = if items.len() > limit {
+                items.truncate(limit);
+                items.last().map(sort_key)
+            } else {
+                None
+            };
+            respond(
+                route.status,
+                Some(json!({ "items": items, "next_page": next_page })),
+            )
+        }
+        Kind::Create => {
+            let schema = route.response.unwrap_or_default();
+            let name = body["name"].as_str().unwrap_or_default().to_string();
+
+            // Every resource the collection is in must exist.
+            let segments: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+            let mut resource = template(schema);
+            for i in (1..segments.len()).step_by(2) {
+                let collection = format!("/{}", segments[..i].join("/"));
+                match find_route("GET", &collection) {
+                    Some((
+                        Route {
+                            kind: Kind::List, ..
+                        },
+                        _,
+                    )) => {}
+                    _ => continue,
+                }
+                match state
+                    .collections
+                    .get(&collection)
+                    .and_then(|c| c.get(segments[i]))
+                {
+                    Some(parent) => {
+                        let id_field = format!("{}_id", singular(segments[i - 1]));
+                        if resource.get(&id_field).is_some() {
+                            resource[&id_field] = parent["id"].clone();
+                        }
+                    }
+                    None => {
+                        return not_found(&format!(
+                            "{} {:?}",
+                            singular(segments[i - 1]),
+                            segments[i]
+                        ));
+                    }
+                }
+            }
+
+            let collection = state.collections.entry(path.to_string()).or_default();
+            if collection.contains_key(&name) {
+                return error(
+                    400,
+                    request_id,
+                    "ObjectAlreadyExists",
+                    format!(
+                        "already exists: {} {:?}",
+                        singular(segments[segments.len() - 1]),
+                        name
+                    ),
+                );
+            }
+            if let (Some(resource), Some(body)) = (resource.as_object_mut(), body.as_object()) {
+                for (k, v) in body {
+                    if properties(schema).contains(&k.as_str()) {
+                        resource.insert(k.to_string(), v.clone());
+                    }
+                }
+            }
+            for (field, value) in [
+                ("id", json!(uuid::Uuid::new_v4().to_string())),
+                ("time_created", json!(now)),
+                ("time_modified", json!(now)),
+            ] {
+                if properties(schema).contains(&field) {
+                    resource[field] = value;
+                }
+            }
+            collection.insert(name, resource.clone());
+            state.schemas.insert(path.to_string(), schema);
+            respond(route.status, Some(resource))
+        }
+        Kind::View | Kind::Update | Kind::Delete => {
+            let (collection, name) = path.rsplit_once('/').unwrap_or_default();
+            let name = decode(name);
+            let what = format!(
+                "{} {:?}",
+                singular(collection.rsplit('/').next().unwrap_or_default()),
+                name
+            );
+            let resources = state.collections.entry(collection.to_string()).or_default();
+            let resource = match resources.get(&name) {
+                Some(resource) => resource.clone(),
+                None => return not_found(&what),
+            };
+            match route.kind {
+                Kind::View => respond(route.status, Some(resource)),
+                Kind::Delete => {
+                    resources.remove(&name);
+                    respond(route.status, None)
+                }
+                _ => {
+                    let mut updated = resource;
+                    if let Some(body) = body.as_object() {
+                        for (k, v) in body {
+                            if !v.is_null() && updated.get(k).is_some() {
+                                updated[k] = v.clone();
+                            }
+                        }
+                    }
+                    if updated.get("time_modified").is_some() {
+                        updated["time_modified"] = json!(now);
+                    }
+                    let new_name = updated["name"].as_str().unwrap_or(&name).to_string();
+                    if new_name != name && resources.contains_key(&new_name) {
+                        return error(
+                            400,
+                            request_id,
+                            "ObjectAlreadyExists",
+                            format!("already exists: {:?}", new_name),
+                        );
+                    }
+                    resources.remove(&name);
+                    resources.insert(new_name, updated.clone());
+                    respond(route.status, Some(updated))
+                }
+            }
+        }
+        Kind::Other => {
+            let schema = match route.response {
+                Some(schema) => schema,
+                None => return respond(route.status, None),
+            };
+
+            // Actions on a resource answer with the resource.
+            if let Some((parent, _)) = path.rsplit_once('/') {
+                if let Some((collection, name)) = parent.rsplit_once('/') {
+                    if state.schemas.get(collection) == Some(&schema) {
+                        return match state.collections[collection].get(&decode(name)) {
+                            Some(resource) => respond(route.status, Some(resource.clone())),
+                            None => not_found(&decode(name)),
+                        };
+                    }
+                }
+            }
+
+            // Lookups by id search every collection of the schema.
+            if let Some(id) = params.get("id") {
+                let found = state
+                    .collections
+                    .iter()
+                    .filter(|(c, _)| state.schemas.get(c.as_str()) == Some(&schema))
+                    .flat_map(|(_, resources)| resources.values())
+                    .find(|r| r["id"] == json!(id))
+                    .cloned();
+                return match found {
+                    Some(resource) => respond(route.status, Some(resource)),
+                    None => not_found(&format!("{} with id {:?}", schema, id)),
+                };
+            }
+
+            respond(route.status, Some(template(schema)))
+        }
+    }
+}
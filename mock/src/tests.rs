@@ -0,0 +1,661 @@
+use std::{future::Future, time::Duration};
+
+use oxide_api::{
+    disk_ops::{DiskOps, Wait},
+    image_import::{ImageImport, ImportSource},
+    silo_bootstrap::SiloBootstrap,
+    types::{
+        ByteCount, DiskCreate, DiskSource, Error, IdpMetadataSource, InstanceCreate,
+        NameOrIdSortMode, NetworkInterfaceCreate, OrganizationCreate, ProjectCreate,
+        SamlIdentityProviderCreate, SiloCreate, UserProvisionType,
+    },
+};
+use serde_json::{json, Value};
+
+use crate::{Fault, MockServer};
+
+fn org(name: &str) -> OrganizationCreate {
+    OrganizationCreate {
+        name: name.to_string(),
+        description: format!("The {} organization", name),
+    }
+}
+
+fn project(name: &str) -> ProjectCreate {
+    ProjectCreate {
+        name: name.to_string(),
+        description: String::new(),
+    }
+}
+
+fn api_error(e: anyhow::Error) -> Error {
+    e.downcast().unwrap()
+}
+
+#[tokio::test]
+async fn test_mock_crud() {
+    let server = MockServer::start().await.unwrap();
+    let oxide = oxide_api::Client::new("", server.url());
+
+    let created = oxide.organizations().post(&org("maze-war")).await.unwrap();
+    assert_eq!(created.name, "maze-war");
+    assert_eq!(created.description, "The maze-war organization");
+    assert!(!created.id.is_empty());
+    assert_eq!(
+        oxide.organizations().get("maze-war").await.unwrap(),
+        created
+    );
+    assert_eq!(
+        oxide.organizations().view(&created.id).await.unwrap(),
+        created
+    );
+
+    let p = oxide
+        .projects()
+        .post("maze-war", &project("prod"))
+        .await
+        .unwrap();
+    assert_eq!(p.organization_id, created.id);
+    assert_eq!(
+        server.get("/organizations/maze-war/projects/prod").unwrap()["id"],
+        p.id.as_str()
+    );
+
+    oxide.projects().delete("maze-war", "prod").await.unwrap();
+    assert!(server
+        .get("/organizations/maze-war/projects/prod")
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_mock_errors() {
+    let server = MockServer::start().await.unwrap();
+    let oxide = oxide_api::Client::new("", server.url());
+
+    let e = oxide.organizations().get("missing").await.unwrap_err();
+    assert!(matches!(api_error(e), Error::ObjectNotFound { .. }));
+    let e = oxide
+        .projects()
+        .post("missing", &project("prod"))
+        .await
+        .unwrap_err();
+    assert!(matches!(api_error(e), Error::ObjectNotFound { .. }));
+
+    oxide.organizations().post(&org("maze-war")).await.unwrap();
+    let e = oxide
+        .organizations()
+        .post(&org("maze-war"))
+        .await
+        .unwrap_err();
+    assert!(matches!(api_error(e), Error::ObjectAlreadyExists { .. }));
+}
+
+#[tokio::test]
+async fn test_mock_pagination() {
+    let server = MockServer::start().await.unwrap();
+    let oxide = oxide_api::Client::new("", server.url());
+
+    for i in 0..5 {
+        oxide
+            .organizations()
+            .post(&org(&format!("org-{}", i)))
+            .await
+            .unwrap();
+    }
+
+    let page = oxide
+        .organizations()
+        .get_page(2, "org-1", NameOrIdSortMode::NameAscending)
+        .await
+        .unwrap();
+    let names: Vec<String> = page.into_iter().map(|o| o.name).collect();
+    assert_eq!(names, ["org-2", "org-3"]);
+
+    let all = oxide
+        .organizations()
+        .get_all(NameOrIdSortMode::NameDescending)
+        .await
+        .unwrap();
+    let names: Vec<String> = all.into_iter().map(|o| o.name).collect();
+    assert_eq!(names, ["org-4", "org-3", "org-2", "org-1", "org-0"]);
+
+    // The pages themselves, which the client does not expose.
+    let mut token = String::new();
+    let mut pages = 0;
+    loop {
+        let url = format!(
+            "{}/organizations?limit=2&page_token={}",
+            server.url(),
+            token
+        );
+        let page: serde_json::Value = reqwest::get(&url).await.unwrap().json().await.unwrap();
+        pages += 1;
+        match page["next_page"].as_str() {
+            Some(next) => token = next.to_string(),
+            None => break,
+        }
+    }
+    assert_eq!(pages, 3);
+}
+
+#[tokio::test]
+async fn test_mock_faults() {
+    let server = MockServer::start().await.unwrap();
+    let oxide = oxide_api::Client::new("", server.url());
+    oxide.organizations().post(&org("maze-war")).await.unwrap();
+
+    server.inject(
+        "organizations_get_organization",
+        Fault::error(503, "ServiceUnavailable").times(1),
+    );
+    assert!(oxide.organizations().get("maze-war").await.is_err());
+    assert!(oxide.organizations().get("maze-war").await.is_ok());
+
+    server.inject(
+        "organizations_get_organization",
+        Fault::error(503, "ServiceUnavailable").times(0),
+    );
+    assert!(oxide.organizations().get("maze-war").await.is_ok());
+
+    server.inject(
+        "organizations_get_organization",
+        Fault::delay(Duration::from_millis(50)),
+    );
+    let start = std::time::Instant::now();
+    oxide.organizations().get("maze-war").await.unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(50));
+    server.clear_faults();
+
+    let operations: Vec<String> = server
+        .requests()
+        .into_iter()
+        .map(|r| r.operation_id)
+        .collect();
+    assert_eq!(
+        operations,
+        [
+            "organizations_post",
+            "organizations_get_organization",
+            "organizations_get_organization",
+            "organizations_get_organization",
+            "organizations_get_organization",
+        ]
+    );
+}
+
+const PROJECT: &str = "/organizations/maze-war/projects/prod";
+
+/// Change fields of the resource at `path` behind the API's back, if it exists.
+fn update(server: &MockServer, path: &str, fields: Value) {
+    let mut resource = match server.get(path) {
+        Some(resource) => resource,
+        None => return,
+    };
+    for (k, v) in fields.as_object().unwrap() {
+        resource[k] = v.clone();
+    }
+    server.insert(path.rsplit_once('/').unwrap().0, resource);
+}
+
+/// A project with the detached disk `data`.
+async fn disk_project(server: &MockServer, oxide: &oxide_api::Client) {
+    oxide.organizations().post(&org("maze-war")).await.unwrap();
+    oxide
+        .projects()
+        .post("maze-war", &project("prod"))
+        .await
+        .unwrap();
+    let disk = DiskCreate::builder()
+        .name("data")
+        .description("")
+        .disk_source(DiskSource::Blank { block_size: 512 })
+        .size(ByteCount::from_gib(1))
+        .build()
+        .unwrap();
+    oxide.disks().post("maze-war", "prod", &disk).await.unwrap();
+    update(
+        server,
+        &format!("{}/disks/data", PROJECT),
+        json!({ "state": { "state": "detached" } }),
+    );
+}
+
+/// Add the instance `name` in `run_state`, with `data` attached if `attached`.
+async fn instance(
+    server: &MockServer,
+    oxide: &oxide_api::Client,
+    name: &str,
+    run_state: &str,
+    attached: bool,
+) {
+    let body = InstanceCreate::builder()
+        .name(name)
+        .description("")
+        .hostname(name)
+        .memory(ByteCount::from_gib(1))
+        .ncpus(1u16)
+        .build()
+        .unwrap();
+    let created = oxide
+        .instances()
+        .post("maze-war", "prod", &body)
+        .await
+        .unwrap();
+    let path = format!("{}/instances/{}", PROJECT, name);
+    update(server, &path, json!({ "run_state": run_state }));
+    if attached {
+        update(
+            server,
+            &format!("{}/disks/data", PROJECT),
+            json!({ "state": { "state": "attached", "instance": created.id } }),
+        );
+    }
+}
+
+/// Plays the part of the control plane while `f` runs: instances stop and
+/// start, `data` attaches and detaches and new disks are ready right away,
+/// except that attaching to the instances in `refuse` never happens.
+async fn with_control_plane<T>(
+    server: &MockServer,
+    refuse: &[&str],
+    f: impl Future<Output = T>,
+) -> T {
+    let disk = format!("{}/disks/data", PROJECT);
+    let control_plane = async {
+        let mut seen = 0;
+        loop {
+            let requests = server.requests();
+            for r in &requests[seen..] {
+                if let Some(instance) = r.path.strip_suffix("/stop") {
+                    update(server, instance, json!({ "run_state": "stopped" }));
+                } else if let Some(instance) = r.path.strip_suffix("/start") {
+                    update(server, instance, json!({ "run_state": "running" }));
+                } else if let Some(instance) = r.path.strip_suffix("/disks/attach") {
+                    let id = server.get(instance).unwrap()["id"].clone();
+                    if !refuse.iter().any(|name| instance.ends_with(name)) {
+                        let state = json!({ "state": "attached", "instance": id });
+                        update(server, &disk, json!({ "state": state }));
+                    }
+                } else if r.path.ends_with("/disks/detach") {
+                    update(server, &disk, json!({ "state": { "state": "detached" } }));
+                }
+            }
+            seen = requests.len();
+
+            let url = format!("{}{}/disks", server.url(), PROJECT);
+            let disks: Value = reqwest::get(&url).await.unwrap().json().await.unwrap();
+            for d in disks["items"].as_array().unwrap() {
+                if d["state"]["state"] == "creating" {
+                    let path = format!("{}/disks/{}", PROJECT, d["name"].as_str().unwrap());
+                    update(server, &path, json!({ "state": { "state": "detached" } }));
+                }
+            }
+
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    };
+
+    tokio::select! {
+        v = f => v,
+        _ = control_plane => unreachable!(),
+    }
+}
+
+fn disk_ops(oxide: &oxide_api::Client) -> DiskOps {
+    let mut ops = DiskOps::new(oxide.clone(), "maze-war", "prod");
+    ops.wait = Wait {
+        interval: Duration::from_millis(5),
+        timeout: Duration::from_millis(500),
+    };
+    ops
+}
+
+fn run_state(server: &MockServer, name: &str) -> Value {
+    server
+        .get(&format!("{}/instances/{}", PROJECT, name))
+        .unwrap()["run_state"]
+        .clone()
+}
+
+fn starts(server: &MockServer) -> Vec<String> {
+    server
+        .requests()
+        .into_iter()
+        .filter(|r| r.path.ends_with("/start"))
+        .map(|r| r.path.trim_start_matches(PROJECT).to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_disk_ops_clone() {
+    let server = MockServer::start().await.unwrap();
+    let oxide = oxide_api::Client::new("", server.url());
+    disk_project(&server, &oxide).await;
+
+    let copy = with_control_plane(&server, &[], disk_ops(&oxide).clone_disk("data", "copy"))
+        .await
+        .unwrap();
+    assert_eq!(copy.name, "copy");
+    assert_eq!(copy.size, ByteCount::from_gib(1));
+    let source = server.get(&format!("{}/snapshots/copy-source", PROJECT));
+    assert_eq!(source.unwrap()["name"], "copy-source");
+}
+
+#[tokio::test]
+async fn test_disk_ops_wait_for_faulted_disk() {
+    let server = MockServer::start().await.unwrap();
+    let oxide = oxide_api::Client::new("", server.url());
+    disk_project(&server, &oxide).await;
+    update(
+        &server,
+        &format!("{}/disks/data", PROJECT),
+        json!({ "state": { "state": "faulted" } }),
+    );
+
+    let e = disk_ops(&oxide)
+        .wait_for_disk_settled("data")
+        .await
+        .unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "disk data is faulted while waiting for it to be detached or attached"
+    );
+}
+
+#[tokio::test]
+async fn test_disk_ops_move() {
+    let server = MockServer::start().await.unwrap();
+    let oxide = oxide_api::Client::new("", server.url());
+    disk_project(&server, &oxide).await;
+    instance(&server, &oxide, "db1", "running", true).await;
+    instance(&server, &oxide, "db2", "stopped", false).await;
+
+    let disk = with_control_plane(
+        &server,
+        &[],
+        disk_ops(&oxide).move_disk("data", "db1", "db2"),
+    )
+    .await
+    .unwrap();
+    let db2 = server.get(&format!("{}/instances/db2", PROJECT)).unwrap();
+    assert_eq!(
+        json!(disk.state),
+        json!({ "state": "attached", "instance": db2["id"] })
+    );
+    assert_eq!(run_state(&server, "db1"), "running");
+    assert_eq!(run_state(&server, "db2"), "stopped");
+}
+
+#[tokio::test]
+async fn test_disk_ops_move_reattaches() {
+    let server = MockServer::start().await.unwrap();
+    let oxide = oxide_api::Client::new("", server.url());
+    disk_project(&server, &oxide).await;
+    instance(&server, &oxide, "db1", "running", true).await;
+    instance(&server, &oxide, "db2", "running", false).await;
+
+    let ops = disk_ops(&oxide);
+    let e = with_control_plane(&server, &["db2"], ops.move_disk("data", "db1", "db2"))
+        .await
+        .unwrap_err();
+    assert!(e.to_string().contains("attached to db2"), "{:#}", e);
+
+    let db1 = server.get(&format!("{}/instances/db1", PROJECT)).unwrap();
+    let disk = server.get(&format!("{}/disks/data", PROJECT)).unwrap();
+    assert_eq!(
+        disk["state"],
+        json!({ "state": "attached", "instance": db1["id"] })
+    );
+    assert_eq!(
+        starts(&server),
+        ["/instances/db1/start", "/instances/db2/start"]
+    );
+}
+
+#[tokio::test]
+async fn test_disk_ops_move_restarts_after_failed_reattach() {
+    let server = MockServer::start().await.unwrap();
+    let oxide = oxide_api::Client::new("", server.url());
+    disk_project(&server, &oxide).await;
+    instance(&server, &oxide, "db1", "running", true).await;
+    instance(&server, &oxide, "db2", "running", false).await;
+
+    // Failed requests are still recorded, so the control plane must not
+    // attach the disk for them either.
+    server.inject(
+        "instance_disks_attach",
+        Fault::error(503, "ServiceUnavailable"),
+    );
+    let ops = disk_ops(&oxide);
+    let e = with_control_plane(
+        &server,
+        &["db1", "db2"],
+        ops.move_disk("data", "db1", "db2"),
+    )
+    .await
+    .unwrap_err();
+    assert!(
+        e.to_string()
+            .starts_with("disk data is detached: reattaching it to db1 failed after:"),
+        "{:#}",
+        e
+    );
+
+    assert_eq!(
+        starts(&server),
+        ["/instances/db1/start", "/instances/db2/start"]
+    );
+    assert_eq!(run_state(&server, "db1"), "running");
+    assert_eq!(run_state(&server, "db2"), "running");
+}
+
+#[tokio::test]
+async fn test_disk_ops_move_restarts_after_failed_stop() {
+    let server = MockServer::start().await.unwrap();
+    let oxide = oxide_api::Client::new("", server.url());
+    disk_project(&server, &oxide).await;
+    instance(&server, &oxide, "db1", "running", true).await;
+
+    let ops = disk_ops(&oxide);
+    let e = with_control_plane(&server, &[], ops.move_disk("data", "db1", "missing"))
+        .await
+        .unwrap_err();
+    assert_eq!(e.to_string(), "stopping instance missing");
+    assert_eq!(run_state(&server, "db1"), "running");
+    assert_eq!(starts(&server), ["/instances/db1/start"]);
+}
+
+#[tokio::test]
+async fn test_disk_ops_move_restarts_every_instance() {
+    let server = MockServer::start().await.unwrap();
+    let oxide = oxide_api::Client::new("", server.url());
+    disk_project(&server, &oxide).await;
+    instance(&server, &oxide, "db1", "running", true).await;
+    instance(&server, &oxide, "db2", "running", false).await;
+
+    server.inject(
+        "project_instances_instance_start",
+        Fault::error(503, "ServiceUnavailable").times(1),
+    );
+    let ops = disk_ops(&oxide);
+    let e = with_control_plane(&server, &[], ops.move_disk("data", "db1", "db2"))
+        .await
+        .unwrap_err();
+    assert!(
+        e.to_string().starts_with("starting instance db1 failed"),
+        "{:#}",
+        e
+    );
+    assert_eq!(
+        starts(&server),
+        ["/instances/db1/start", "/instances/db2/start"]
+    );
+    assert_eq!(run_state(&server, "db2"), "running");
+}
+
+#[tokio::test]
+async fn test_nic_ops_keep_both_errors() {
+    let server = MockServer::start().await.unwrap();
+    let oxide = oxide_api::Client::new("", server.url());
+    disk_project(&server, &oxide).await;
+    instance(&server, &oxide, "fw-1", "running", false).await;
+
+    server.inject(
+        "instance_network_interfaces_post",
+        Fault::error(503, "ServiceUnavailable"),
+    );
+    server.inject(
+        "project_instances_instance_start",
+        Fault::error(503, "ServiceUnavailable").times(1),
+    );
+    let body = NetworkInterfaceCreate::builder()
+        .name("net1")
+        .description("")
+        .vpc_name("default")
+        .subnet_name("dmz")
+        .build()
+        .unwrap();
+    let wait = disk_ops(&oxide).wait;
+    let instances = oxide.instances();
+    let e = with_control_plane(
+        &server,
+        &[],
+        instances.add_nic_and_wait("fw-1", "maze-war", "prod", &body, &wait),
+    )
+    .await
+    .unwrap_err();
+    assert!(
+        e.to_string().starts_with("starting instance fw-1 failed"),
+        "{:#}",
+        e
+    );
+    assert!(
+        format!("{:#}", e).contains("adding network interface net1 to fw-1"),
+        "{:#}",
+        e
+    );
+}
+
+#[tokio::test]
+async fn test_image_import_deletes_on_timeout() {
+    let server = MockServer::start().await.unwrap();
+    let oxide = oxide_api::Client::new("", server.url());
+    disk_project(&server, &oxide).await;
+
+    let mut path = std::env::temp_dir();
+    path.push(format!("oxide-mock-import-{}.raw", uuid::Uuid::new_v4()));
+    std::fs::write(&path, vec![0u8; 4096]).unwrap();
+
+    // The mock never reports a digest, so the import times out.
+    let import = ImageImport {
+        name: "alpine".to_string(),
+        source: ImportSource::File {
+            path: path.clone(),
+            bind: "127.0.0.1:0".parse().unwrap(),
+            advertise_host: "127.0.0.1".to_string(),
+        },
+        poll_interval: Duration::from_millis(5),
+        timeout: Duration::from_millis(50),
+        ..Default::default()
+    };
+    let e = oxide
+        .images()
+        .import("maze-war", "prod", &import)
+        .await
+        .unwrap_err();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(
+        e.to_string(),
+        "timed out waiting for image alpine to report its digest"
+    );
+    assert!(server.get(&format!("{}/images/alpine", PROJECT)).is_none());
+}
+
+fn pilot() -> SiloBootstrap {
+    let idp = SamlIdentityProviderCreate::builder()
+        .name("corp")
+        .description("")
+        .acs_url("https://pilot.sys.example/login/pilot/saml/corp")
+        .idp_entity_id("https://idp.example/")
+        .idp_metadata_source(IdpMetadataSource::Url {
+            url: "https://idp.example/metadata".to_string(),
+        })
+        .slo_url("https://pilot.sys.example/logout")
+        .sp_client_id("pilot")
+        .technical_contact_email("ops@example.com")
+        .build()
+        .unwrap();
+    SiloBootstrap {
+        silo: SiloCreate::builder()
+            .name("pilot")
+            .description("")
+            .discoverable(true)
+            .user_provision_type(UserProvisionType::Jit)
+            .build()
+            .unwrap(),
+        identity_provider: Some(idp),
+        organization: Some(org("pilot")),
+        project: Some(project("default")),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_bootstrap_silo_rollback() {
+    let server = MockServer::start().await.unwrap();
+    let oxide = oxide_api::Client::new("", server.url());
+    let silo_client = |_: &_| async { Ok(oxide.clone()) };
+
+    server.inject(
+        "organization_projects_post",
+        Fault::error(503, "ServiceUnavailable"),
+    );
+    let e = oxide
+        .silos()
+        .bootstrap_silo(&pilot(), silo_client)
+        .await
+        .unwrap_err();
+    assert_eq!(e.to_string(), "creating project pilot/default");
+    assert!(server.get("/organizations/pilot").is_none());
+    assert!(server.get("/silos/pilot").is_none());
+
+    // Every step is retried once the fault is gone.
+    server.clear_faults();
+    let silo = oxide
+        .silos()
+        .bootstrap_silo(&pilot(), silo_client)
+        .await
+        .unwrap();
+    assert_eq!(silo.project.unwrap().name, "default");
+}
+
+#[tokio::test]
+async fn test_bootstrap_silo_rollback_continues() {
+    let server = MockServer::start().await.unwrap();
+    let oxide = oxide_api::Client::new("", server.url());
+
+    server.inject(
+        "organization_projects_post",
+        Fault::error(503, "ServiceUnavailable"),
+    );
+    server.inject(
+        "organizations_delete_organization",
+        Fault::error(503, "ServiceUnavailable"),
+    );
+    let e = oxide
+        .silos()
+        .bootstrap_silo(&pilot(), |_| async { Ok(oxide.clone()) })
+        .await
+        .unwrap_err();
+    assert!(
+        e.to_string()
+            .starts_with("rolling back failed too: deleting organization pilot:"),
+        "{:#}",
+        e
+    );
+    assert!(format!("{:#}", e).contains("creating project pilot/default"));
+
+    // The silo is deleted all the same.
+    assert!(server.get("/organizations/pilot").is_some());
+    assert!(server.get("/silos/pilot").is_none());
+}
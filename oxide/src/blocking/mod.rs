@@ -0,0 +1,620 @@
+//! A client that blocks the calling thread instead of returning futures,
+//! for programs that have no async runtime.
+//!
+//! It has the same tags and operations as the async [`Client`](crate::Client).
+//! The hand-written helpers are only available on the async client.
+//!
+//! ```no_run
+//! use oxide_api::blocking::Client;
+//!
+//! let client = Client::new_from_env();
+//! let image = client.images_global().images_get_image("ubuntu-22-04")?;
+//! println!("{}", image.id);
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+/// Virtual disks are used to store instance-local data which includes the operating system.
+///
+///FROM: http://oxide.computer/docs/#xxx
+pub mod disks;
+/// TODO operations that will not ship to customers.
+///
+///FROM: http://oxide.computer/docs/#xxx
+pub mod hidden;
+/// Images are read-only Virtual Disks that may be used to boot Virtual Machines.
+///
+///FROM: http://oxide.computer/docs/#xxx
+pub mod images;
+/// Images are read-only Virtual Disks that may be used to boot Virtual Machines. These images are scoped globally.
+///
+///FROM: http://oxide.computer/docs/#xxx
+pub mod images_global;
+/// Virtual machine instances are the basic unit of computation. These operations are used for provisioning, controlling, and destroying instances.
+///
+///FROM: http://oxide.computer/docs/#xxx
+pub mod instances;
+/// IP Pools contain external IP addresses that can be assigned to virtual machine Instances.
+///
+///FROM: http://oxide.computer/docs/#xxx
+pub mod ip_pools;
+/// Authentication endpoints.
+///
+///FROM: http://oxide.computer/docs/#xxx
+pub mod login;
+/// Metrics provide insight into the operation of the Oxide deployment. These include telemetry on hardware and software components that can be used to understand the current state as well as to diagnose issues.
+///
+///FROM: http://oxide.computer/docs/#xxx
+pub mod metrics;
+/// Organizations represent a subset of users and projects in an Oxide deployment.
+///
+///FROM: http://oxide.computer/docs/#xxx
+pub mod organizations;
+/// System-wide IAM policy.
+///
+///FROM: http://oxide.computer/docs/#xxx
+pub mod policy;
+/// Projects are a grouping of associated resources such as instances and disks within an organization for purposes of billing and access control.
+///
+///FROM: http://oxide.computer/docs/#xxx
+pub mod projects;
+/// These operations pertain to hardware inventory and management. Racks are the unit of expansion of an Oxide deployment. Racks are in turn composed of sleds, switches, power supplies, and a cabled backplane.
+///
+///FROM: http://oxide.computer/docs/#xxx
+pub mod racks;
+/// Roles are a component of Identity and Access Management (IAM) that allow a user or agent account access to additional permissions.
+///
+///FROM: http://oxide.computer/docs/#xxx
+pub mod roles;
+/// Routers direct the flow of network traffic into, out of, and within a VPC via routes.
+///
+///FROM: http://oxide.computer/docs/#xxx
+pub mod routers;
+/// Routes define router policy.
+///
+///FROM: http://oxide.computer/docs/#xxx
+pub mod routes;
+/// Sagas are the abstraction used to represent multi-step operations within the Oxide deployment. These operations can be used to query saga status and report errors.
+///
+///FROM: http://oxide.computer/docs/#xxx
+pub mod sagas;
+/// Silos represent a logical partition of users and resources.
+///
+///FROM: http://oxide.computer/docs/#xxx
+pub mod silos;
+/// This tag should be moved into hardware.
+///
+///FROM: http://oxide.computer/docs/#xxx
+pub mod sleds;
+/// Snapshots of Virtual Disks at a particular point in time.
+///
+///FROM: http://oxide.computer/docs/#xxx
+pub mod snapshots;
+/// Public SSH keys for an individual user.
+///
+///FROM: http://oxide.computer/docs/#xxx
+pub mod sshkeys;
+/// This tag should be moved into a generic network tag.
+///
+///FROM: http://oxide.computer/docs/#xxx
+pub mod subnets;
+/// Internal system information.
+///
+///FROM: http://oxide.computer/docs/#xxx
+pub mod system;
+/// This tag should be moved into a operations tag.
+///
+///FROM: http://oxide.computer/docs/#xxx
+pub mod updates;
+/// A Virtual Private Cloud (VPC) is an isolated network environment that should probaby be moved into a more generic networking tag.
+///
+///FROM: http://oxide.computer/docs/#xxx
+pub mod vpcs;
+
+use std::{
+    env,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use anyhow::{anyhow, Error, Result};
+
+use crate::progenitor_support;
+
+/// Entrypoint for interacting with the API client without an async runtime.
+///
+/// It has the same operations as [`crate::Client`], but every request blocks
+/// the calling thread until it is done. It must not be used from within an
+/// async runtime.
+#[derive(Clone)]
+pub struct Client {
+    host: String,
+    token: String,
+    session: Option<Arc<Session>>,
+
+    client: reqwest::blocking::Client,
+}
+
+/// The local silo user a client logs in as, and whether it is logged in.
+/// Shared by every clone of the client.
+struct Session {
+    silo: String,
+    username: String,
+    password: String,
+    logged_in: AtomicBool,
+}
+
+impl Client {
+    /// Create a new Client struct. It takes a type that can convert into
+    /// an &str (`String` or `Vec<u8>` for example). As long as the function is
+    /// given a valid API key your requests will work.
+    pub fn new<T, H>(token: T, host: H) -> Self
+    where
+        T: ToString,
+        H: ToString,
+    {
+        let client = reqwest::blocking::Client::builder().build();
+        match client {
+            Ok(c) => Client {
+                host: host.to_string(),
+                token: token.to_string(),
+                session: None,
+
+                client: c,
+            },
+            Err(e) => panic!("creating reqwest client failed: {:?}", e),
+        }
+    }
+
+    /// Create a new Client struct from environment variables: OXIDE_TOKEN and OXIDE_HOST.
+    pub fn new_from_env() -> Self {
+        let token = env::var("OXIDE_TOKEN").expect("must set OXIDE_TOKEN");
+        let host = env::var("OXIDE_HOST").expect("must set OXIDE_HOST");
+
+        Client::new(token, host)
+    }
+
+    /// Create a new Client that logs in as a local user of `silo` and
+    /// authenticates with the session cookie it is given instead of a token.
+    /// Whenever the session has expired the client logs in again.
+    pub fn new_with_password<H, S, U, P>(host: H, silo: S, username: U, password: P) -> Result<Self>
+    where
+        H: ToString,
+        S: ToString,
+        U: ToString,
+        P: ToString,
+    {
+        let client = reqwest::blocking::Client::builder()
+            .cookie_provider(Arc::new(reqwest::cookie::Jar::default()))
+            .build()?;
+        let client = Client {
+            host: host.to_string(),
+            token: String::new(),
+            session: Some(Arc::new(Session {
+                silo: silo.to_string(),
+                username: username.to_string(),
+                password: password.to_string(),
+                logged_in: AtomicBool::new(false),
+            })),
+
+            client,
+        };
+        client.log_in()?;
+        Ok(client)
+    }
+
+    /// Log in with the client's username and password. This happens
+    /// automatically when the client is created and when its session expires.
+    pub fn log_in(&self) -> Result<()> {
+        let session = match &self.session {
+            Some(session) => session,
+            None => {
+                return Err(anyhow!(
+                    "client was not created with a username and password"
+                ))
+            }
+        };
+
+        // Not sent through `request_raw`, which logs request bodies.
+        let url = format!(
+            "{}/login/{}/local",
+            self.host,
+            progenitor_support::encode_path(&session.silo),
+        );
+        let response = self
+            .client
+            .post(url)
+            .json(&serde_json::json!({
+                "username": session.username,
+                "password": session.password,
+            }))
+            .send()?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(anyhow!(
+                "logging in to silo {} as {} failed: {}",
+                session.silo,
+                session.username,
+                status
+            ));
+        }
+
+        session.logged_in.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// End the client's session. Requests fail as unauthorized afterwards
+    /// rather than logging in again, until `log_in` is called.
+    pub fn log_out(&self) -> Result<()> {
+        self.hidden().logout()?;
+        if let Some(session) = &self.session {
+            session.logged_in.store(false, Ordering::SeqCst);
+        }
+        Ok(())
+    }
+
+    fn url_and_auth(&self, uri: &str) -> Result<(reqwest::Url, Option<String>)> {
+        let parsed_url = uri.parse::<reqwest::Url>();
+
+        // Clients logged in with a password authenticate with their cookie.
+        let auth = if self.session.is_some() {
+            None
+        } else {
+            Some(format!("Bearer {}", self.token))
+        };
+        parsed_url.map(|u| (u, auth)).map_err(Error::from)
+    }
+
+    pub fn request_raw(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::blocking::Body>,
+    ) -> Result<reqwest::blocking::RequestBuilder> {
+        let u = if uri.starts_with("https://") || uri.starts_with("http://") {
+            uri.to_string()
+        } else {
+            (self.host.clone() + uri).to_string()
+        };
+        let (url, auth) = self.url_and_auth(&u)?;
+
+        let mut req = self.client.request(method, url);
+
+        // Set the default headers.
+        req = req.header(
+            reqwest::header::ACCEPT,
+            reqwest::header::HeaderValue::from_static("application/json"),
+        );
+        req = req.header(
+            reqwest::header::CONTENT_TYPE,
+            reqwest::header::HeaderValue::from_static("application/json"),
+        );
+
+        if let Some(auth_str) = auth {
+            req = req.header(http::header::AUTHORIZATION, auth_str);
+        }
+
+        if let Some(body) = body {
+            if let Some(bytes) = body.as_bytes() {
+                log::debug!("body: {:?}", String::from_utf8_lossy(bytes));
+            }
+            req = req.body(body);
+        }
+        log::debug!("request: {:?}", &req);
+        Ok(req)
+    }
+
+    pub fn response_raw(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::blocking::Body>,
+    ) -> Result<reqwest::blocking::Response> {
+        let req = self.request_raw(method, uri, body)?;
+        Ok(req.send()?)
+    }
+
+    /// Send a request and return the status and body of the response,
+    /// whatever the status. Operations whose response type depends on the
+    /// status decode the body themselves.
+    fn request_with_status(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::blocking::Body>,
+    ) -> Result<(http::StatusCode, bytes::Bytes)> {
+        let session = self
+            .session
+            .as_ref()
+            .filter(|s| s.logged_in.load(Ordering::SeqCst));
+        let retry_body = match session {
+            Some(_) => body.as_ref().and_then(|b| b.as_bytes()).map(|b| b.to_vec()),
+            None => None,
+        };
+
+        let mut response = self.response_raw(method.clone(), uri, body)?;
+
+        if response.status() == http::StatusCode::UNAUTHORIZED && session.is_some() {
+            log::debug!("session expired, logging in again");
+            self.log_in()?;
+            response =
+                self.response_raw(method, uri, retry_body.map(reqwest::blocking::Body::from))?;
+        }
+
+        let status = response.status();
+
+        let response_body = response.bytes()?;
+
+        log::debug!(
+            "response payload {}",
+            String::from_utf8_lossy(&response_body)
+        );
+        Ok((status, response_body))
+    }
+
+    /// Send a request and return the status and body of the response if it
+    /// succeeded, or the error it was answered with.
+    fn request_bytes(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::blocking::Body>,
+    ) -> Result<(http::StatusCode, bytes::Bytes)> {
+        let (status, response_body) = self.request_with_status(method, uri, body)?;
+
+        if status.is_success() {
+            Ok((status, response_body))
+        } else {
+            let error: anyhow::Error = if response_body.is_empty() {
+                anyhow!("code: {}, empty response", status)
+            } else {
+                // Parse the error as the error type.
+                match serde_json::from_slice::<crate::types::ErrorResponse>(&response_body) {
+                    Ok(resp) => {
+                        let e: crate::types::Error = resp.into();
+                        e.into()
+                    }
+                    Err(_) => {
+                        anyhow!(
+                            "code: {}, error: {:?}",
+                            status,
+                            String::from_utf8_lossy(&response_body),
+                        )
+                    }
+                }
+            };
+
+            Err(error)
+        }
+    }
+
+    fn request<Out>(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::blocking::Body>,
+    ) -> Result<Out>
+    where
+        Out: serde::de::DeserializeOwned + 'static + Send,
+    {
+        let (status, response_body) = self.request_bytes(method, uri, body)?;
+
+        let parsed_response = if status == http::StatusCode::NO_CONTENT
+            || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
+        {
+            serde_json::from_str("null")
+        } else {
+            serde_json::from_slice::<Out>(&response_body)
+        };
+        parsed_response.map_err(Error::from)
+    }
+
+    fn get<D>(&self, uri: &str, message: Option<reqwest::blocking::Body>) -> Result<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        self.request(http::Method::GET, &(self.host.to_string() + uri), message)
+    }
+
+    fn post<D>(&self, uri: &str, message: Option<reqwest::blocking::Body>) -> Result<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        self.request(http::Method::POST, &(self.host.to_string() + uri), message)
+    }
+
+    #[allow(dead_code)]
+    fn patch<D>(&self, uri: &str, message: Option<reqwest::blocking::Body>) -> Result<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        self.request(http::Method::PATCH, &(self.host.to_string() + uri), message)
+    }
+
+    fn put<D>(&self, uri: &str, message: Option<reqwest::blocking::Body>) -> Result<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        self.request(http::Method::PUT, &(self.host.to_string() + uri), message)
+    }
+
+    fn delete<D>(&self, uri: &str, message: Option<reqwest::blocking::Body>) -> Result<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        self.request(
+            http::Method::DELETE,
+            &(self.host.to_string() + uri),
+            message,
+        )
+    }
+
+    /// Virtual disks are used to store instance-local data which includes the operating system.
+    ///
+    ///FROM: http://oxide.computer/docs/#xxx
+    pub fn disks(&self) -> disks::Disks {
+        disks::Disks::new(self.clone())
+    }
+
+    /// TODO operations that will not ship to customers.
+    ///
+    ///FROM: http://oxide.computer/docs/#xxx
+    pub fn hidden(&self) -> hidden::Hidden {
+        hidden::Hidden::new(self.clone())
+    }
+
+    /// Images are read-only Virtual Disks that may be used to boot Virtual Machines.
+    ///
+    ///FROM: http://oxide.computer/docs/#xxx
+    pub fn images(&self) -> images::Images {
+        images::Images::new(self.clone())
+    }
+
+    /// Images are read-only Virtual Disks that may be used to boot Virtual Machines. These images are scoped globally.
+    ///
+    ///FROM: http://oxide.computer/docs/#xxx
+    pub fn images_global(&self) -> images_global::ImagesGlobal {
+        images_global::ImagesGlobal::new(self.clone())
+    }
+
+    /// Virtual machine instances are the basic unit of computation. These operations are used for provisioning, controlling, and destroying instances.
+    ///
+    ///FROM: http://oxide.computer/docs/#xxx
+    pub fn instances(&self) -> instances::Instances {
+        instances::Instances::new(self.clone())
+    }
+
+    /// IP Pools contain external IP addresses that can be assigned to virtual machine Instances.
+    ///
+    ///FROM: http://oxide.computer/docs/#xxx
+    pub fn ip_pools(&self) -> ip_pools::IpPools {
+        ip_pools::IpPools::new(self.clone())
+    }
+
+    /// Authentication endpoints.
+    ///
+    ///FROM: http://oxide.computer/docs/#xxx
+    pub fn login(&self) -> login::Login {
+        login::Login::new(self.clone())
+    }
+
+    /// Metrics provide insight into the operation of the Oxide deployment. These include telemetry on hardware and software components that can be used to understand the current state as well as to diagnose issues.
+    ///
+    ///FROM: http://oxide.computer/docs/#xxx
+    pub fn metrics(&self) -> metrics::Metrics {
+        metrics::Metrics::new(self.clone())
+    }
+
+    /// Organizations represent a subset of users and projects in an Oxide deployment.
+    ///
+    ///FROM: http://oxide.computer/docs/#xxx
+    pub fn organizations(&self) -> organizations::Organizations {
+        organizations::Organizations::new(self.clone())
+    }
+
+    /// System-wide IAM policy.
+    ///
+    ///FROM: http://oxide.computer/docs/#xxx
+    pub fn policy(&self) -> policy::Policy {
+        policy::Policy::new(self.clone())
+    }
+
+    /// Projects are a grouping of associated resources such as instances and disks within an organization for purposes of billing and access control.
+    ///
+    ///FROM: http://oxide.computer/docs/#xxx
+    pub fn projects(&self) -> projects::Projects {
+        projects::Projects::new(self.clone())
+    }
+
+    /// These operations pertain to hardware inventory and management. Racks are the unit of expansion of an Oxide deployment. Racks are in turn composed of sleds, switches, power supplies, and a cabled backplane.
+    ///
+    ///FROM: http://oxide.computer/docs/#xxx
+    pub fn racks(&self) -> racks::Racks {
+        racks::Racks::new(self.clone())
+    }
+
+    /// Roles are a component of Identity and Access Management (IAM) that allow a user or agent account access to additional permissions.
+    ///
+    ///FROM: http://oxide.computer/docs/#xxx
+    pub fn roles(&self) -> roles::Roles {
+        roles::Roles::new(self.clone())
+    }
+
+    /// Routers direct the flow of network traffic into, out of, and within a VPC via routes.
+    ///
+    ///FROM: http://oxide.computer/docs/#xxx
+    pub fn routers(&self) -> routers::Routers {
+        routers::Routers::new(self.clone())
+    }
+
+    /// Routes define router policy.
+    ///
+    ///FROM: http://oxide.computer/docs/#xxx
+    pub fn routes(&self) -> routes::Routes {
+        routes::Routes::new(self.clone())
+    }
+
+    /// Sagas are the abstraction used to represent multi-step operations within the Oxide deployment. These operations can be used to query saga status and report errors.
+    ///
+    ///FROM: http://oxide.computer/docs/#xxx
+    pub fn sagas(&self) -> sagas::Sagas {
+        sagas::Sagas::new(self.clone())
+    }
+
+    /// This tag should be moved into hardware.
+    ///
+    ///FROM: http://oxide.computer/docs/#xxx
+    pub fn sleds(&self) -> sleds::Sleds {
+        sleds::Sleds::new(self.clone())
+    }
+
+    /// Silos represent a logical partition of users and resources.
+    ///
+    ///FROM: http://oxide.computer/docs/#xxx
+    pub fn silos(&self) -> silos::Silos {
+        silos::Silos::new(self.clone())
+    }
+
+    /// Snapshots of Virtual Disks at a particular point in time.
+    ///
+    ///FROM: http://oxide.computer/docs/#xxx
+    pub fn snapshots(&self) -> snapshots::Snapshots {
+        snapshots::Snapshots::new(self.clone())
+    }
+
+    /// Public SSH keys for an individual user.
+    ///
+    ///FROM: http://oxide.computer/docs/#xxx
+    pub fn sshkeys(&self) -> sshkeys::Sshkeys {
+        sshkeys::Sshkeys::new(self.clone())
+    }
+
+    /// This tag should be moved into a generic network tag.
+    ///
+    ///FROM: http://oxide.computer/docs/#xxx
+    pub fn subnets(&self) -> subnets::Subnets {
+        subnets::Subnets::new(self.clone())
+    }
+
+    /// Internal system information.
+    ///
+    ///FROM: http://oxide.computer/docs/#xxx
+    pub fn system(&self) -> system::System {
+        system::System::new(self.clone())
+    }
+
+    /// This tag should be moved into a operations tag.
+    ///
+    ///FROM: http://oxide.computer/docs/#xxx
+    pub fn updates(&self) -> updates::Updates {
+        updates::Updates::new(self.clone())
+    }
+
+    /// A Virtual Private Cloud (VPC) is an isolated network environment that should probaby be moved into a more generic networking tag.
+    ///
+    ///FROM: http://oxide.computer/docs/#xxx
+    pub fn vpcs(&self) -> vpcs::Vpcs {
+        vpcs::Vpcs::new(self.clone())
+    }
+}
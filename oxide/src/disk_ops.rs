@@ -0,0 +1,490 @@
+//! Multi-step disk workflows: snapshot, clone and move disks between
+//! instances.
+//!
+//! Each step waits for the disk or instance to reach the state the next step
+//! needs, and `move_disk` puts the disk back where it came from if attaching it
+//! to the new instance fails, so disks are not left detached.
+
+use std::{future::Future, time::Duration};
+
+use anyhow::{anyhow, bail, Context, Result};
+use tokio::time::Instant;
+
+use crate::{
+    is_not_found,
+    types::{
+        Disk, DiskCreate, DiskIdentifier, DiskSource, DiskState, Instance, InstanceState, Snapshot,
+        SnapshotCreate,
+    },
+    Client,
+};
+
+/// How often to poll and how long to wait for a state transition.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Wait {
+    pub interval: Duration,
+    pub timeout: Duration,
+}
+
+impl Default for Wait {
+    fn default() -> Self {
+        Wait {
+            interval: Duration::from_secs(2),
+            timeout: Duration::from_secs(300),
+        }
+    }
+}
+
+impl Wait {
+    /// Calls `check` every `interval` until it returns a value, giving up
+    /// after `timeout`.
+    pub async fn until<T, F, Fut>(&self, what: &str, mut check: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<Option<T>>>,
+    {
+        let start = Instant::now();
+        loop {
+            if let Some(v) = check().await? {
+                return Ok(v);
+            }
+            if start.elapsed() > self.timeout {
+                bail!("timed out after {:?} waiting for {}", self.timeout, what);
+            }
+            tokio::time::sleep(self.interval).await;
+        }
+    }
+}
+
+impl crate::instances::Instances {
+    /**
+     * Wait for an instance to reach `want`.
+     *
+     * Fails early if the instance ends up `Failed` or `Destroyed` instead.
+     */
+    pub async fn wait_for_run_state(
+        &self,
+        instance_name: &str,
+        organization_name: &str,
+        project_name: &str,
+        want: InstanceState,
+        wait: &Wait,
+    ) -> Result<Instance> {
+        let what = format!("instance {} to be {}", instance_name, want);
+        wait.until(&what, || async {
+            let instance = self
+                .get(instance_name, organization_name, project_name)
+                .await?;
+            if instance.run_state == want {
+                return Ok(Some(instance));
+            }
+            match instance.run_state {
+                InstanceState::Failed | InstanceState::Destroyed => bail!(
+                    "instance {} is {} while waiting for it to be {}",
+                    instance_name,
+                    instance.run_state,
+                    want
+                ),
+                _ => Ok(None),
+            }
+        })
+        .await
+    }
+
+    /**
+     * Stop an instance and wait until it is `Stopped`.
+     *
+     * Returns whether the instance was running beforehand, so callers know
+     * whether to start it again.
+     */
+    pub async fn stop_and_wait(
+        &self,
+        instance_name: &str,
+        organization_name: &str,
+        project_name: &str,
+        wait: &Wait,
+    ) -> Result<bool> {
+        let instance = self
+            .get(instance_name, organization_name, project_name)
+            .await?;
+        match instance.run_state {
+            InstanceState::Stopped => return Ok(false),
+            InstanceState::Stopping => {}
+            _ => {
+                self.stop(instance_name, organization_name, project_name)
+                    .await?;
+            }
+        }
+
+        self.wait_for_run_state(
+            instance_name,
+            organization_name,
+            project_name,
+            InstanceState::Stopped,
+            wait,
+        )
+        .await?;
+        Ok(instance.run_state != InstanceState::Stopping)
+    }
+
+    /**
+     * Start an instance and wait until it is `Running`.
+     */
+    pub async fn start_and_wait(
+        &self,
+        instance_name: &str,
+        organization_name: &str,
+        project_name: &str,
+        wait: &Wait,
+    ) -> Result<Instance> {
+        self.start(instance_name, organization_name, project_name)
+            .await?;
+        self.wait_for_run_state(
+            instance_name,
+            organization_name,
+            project_name,
+            InstanceState::Running,
+            wait,
+        )
+        .await
+    }
+
+    /**
+     * Start every instance in `restart` and wait for it, whatever `result` is.
+     *
+     * Instances that fail to start are added to the error of `result`, or make it one.
+     */
+    pub(crate) async fn restart_all<T>(
+        &self,
+        restart: &[&str],
+        organization_name: &str,
+        project_name: &str,
+        wait: &Wait,
+        result: Result<T>,
+    ) -> Result<T> {
+        let mut failed = Vec::new();
+        for instance_name in restart {
+            if let Err(e) = self
+                .start_and_wait(instance_name, organization_name, project_name, wait)
+                .await
+            {
+                failed.push(format!(
+                    "starting instance {} failed: {:#}",
+                    instance_name, e
+                ));
+            }
+        }
+        if failed.is_empty() {
+            return result;
+        }
+
+        let failed = failed.join("; ");
+        match result {
+            Ok(_) => Err(anyhow!(failed)),
+            Err(e) => Err(e.context(failed)),
+        }
+    }
+}
+
+/// Disk workflows scoped to a single project.
+pub struct DiskOps {
+    pub client: Client,
+    pub organization_name: String,
+    pub project_name: String,
+    pub wait: Wait,
+}
+
+impl DiskOps {
+    pub fn new<O, P>(client: Client, organization_name: O, project_name: P) -> Self
+    where
+        O: ToString,
+        P: ToString,
+    {
+        DiskOps {
+            client,
+            organization_name: organization_name.to_string(),
+            project_name: project_name.to_string(),
+            wait: Default::default(),
+        }
+    }
+
+    /// Wait until `check` accepts the disk's state. Fails early if the disk
+    /// is `Faulted` or `Destroyed`.
+    pub async fn wait_for_disk<F>(&self, disk_name: &str, what: &str, check: F) -> Result<Disk>
+    where
+        F: Fn(&DiskState) -> bool,
+    {
+        let waiting = format!("disk {} to be {}", disk_name, what);
+        self.wait
+            .until(&waiting, || async {
+                let disk = self
+                    .client
+                    .disks()
+                    .get(disk_name, &self.organization_name, &self.project_name)
+                    .await?;
+                if check(&disk.state) {
+                    return Ok(Some(disk));
+                }
+                match disk.state {
+                    DiskState::Faulted | DiskState::Destroyed => bail!(
+                        "disk {} is {} while waiting for it to be {}",
+                        disk_name,
+                        disk.state,
+                        what
+                    ),
+                    _ => Ok(None),
+                }
+            })
+            .await
+    }
+
+    /// Wait until the disk is no longer changing state, i.e. it is either
+    /// `Detached` or `Attached`.
+    pub async fn wait_for_disk_settled(&self, disk_name: &str) -> Result<Disk> {
+        self.wait_for_disk(disk_name, "detached or attached", |s| {
+            matches!(s, DiskState::Detached | DiskState::Attached(_))
+        })
+        .await
+    }
+
+    /// Snapshot a disk and wait for the snapshot to be listable and the disk
+    /// to settle again.
+    pub async fn snapshot_and_wait(
+        &self,
+        disk_name: &str,
+        snapshot_name: &str,
+    ) -> Result<Snapshot> {
+        self.wait_for_disk_settled(disk_name).await?;
+
+        let body = SnapshotCreate::builder()
+            .name(snapshot_name)
+            .description(format!("Snapshot of disk {}", disk_name))
+            .disk(disk_name)
+            .build()?;
+        let snapshots = self.client.snapshots();
+        snapshots
+            .post(&self.organization_name, &self.project_name, &body)
+            .await
+            .with_context(|| format!("snapshotting disk {}", disk_name))?;
+
+        let what = format!("snapshot {}", snapshot_name);
+        let snapshot = self
+            .wait
+            .until(&what, || async {
+                match snapshots
+                    .get(&self.organization_name, &self.project_name, snapshot_name)
+                    .await
+                {
+                    Ok(s) => Ok(Some(s)),
+                    Err(e) if is_not_found(&e) => Ok(None),
+                    Err(e) => Err(e),
+                }
+            })
+            .await?;
+
+        self.wait_for_disk_settled(disk_name).await?;
+
+        Ok(snapshot)
+    }
+
+    /// Create `new_name` as a copy of `disk_name`.
+    ///
+    /// The copy is made from a snapshot named `{new_name}-source`, which is
+    /// kept since the new disk is backed by it.
+    pub async fn clone_disk(&self, disk_name: &str, new_name: &str) -> Result<Disk> {
+        let source = self
+            .client
+            .disks()
+            .get(disk_name, &self.organization_name, &self.project_name)
+            .await?;
+        let snapshot = self
+            .snapshot_and_wait(disk_name, &format!("{}-source", new_name))
+            .await?;
+
+        let body = DiskCreate::builder()
+            .name(new_name)
+            .description(format!("Clone of disk {}", disk_name))
+            .disk_source(DiskSource::Snapshot {
+                snapshot_id: snapshot.id,
+            })
+            .size(source.size)
+            .build()?;
+        self.client
+            .disks()
+            .post(&self.organization_name, &self.project_name, &body)
+            .await
+            .with_context(|| format!("creating disk {} from snapshot", new_name))?;
+
+        self.wait_for_disk(new_name, "detached", |s| *s == DiskState::Detached)
+            .await
+    }
+
+    /// Detach a disk from an instance, stopping the instance first if it is
+    /// running. The instance is started again if it was running.
+    pub async fn detach(&self, disk_name: &str, instance_name: &str) -> Result<Disk> {
+        let instances = self.client.instances();
+        let was_running = instances
+            .stop_and_wait(
+                instance_name,
+                &self.organization_name,
+                &self.project_name,
+                &self.wait,
+            )
+            .await?;
+
+        let result = self.detach_stopped(disk_name, instance_name).await;
+
+        let restart = if was_running {
+            vec![instance_name]
+        } else {
+            vec![]
+        };
+        self.restart(&restart, result).await
+    }
+
+    /// Attach a disk to an instance, stopping the instance first if it is
+    /// running. The instance is started again if it was running.
+    pub async fn attach(&self, disk_name: &str, instance_name: &str) -> Result<Disk> {
+        let instances = self.client.instances();
+        let was_running = instances
+            .stop_and_wait(
+                instance_name,
+                &self.organization_name,
+                &self.project_name,
+                &self.wait,
+            )
+            .await?;
+
+        let result = self.attach_stopped(disk_name, instance_name).await;
+
+        let restart = if was_running {
+            vec![instance_name]
+        } else {
+            vec![]
+        };
+        self.restart(&restart, result).await
+    }
+
+    /// Move a disk from one instance to another.
+    ///
+    /// Both instances are stopped for the move and started again afterwards if
+    /// they were running, whether the move succeeded or not. If the disk
+    /// cannot be attached to `to_instance` it is reattached to `from_instance`
+    /// before the error is returned.
+    pub async fn move_disk(
+        &self,
+        disk_name: &str,
+        from_instance: &str,
+        to_instance: &str,
+    ) -> Result<Disk> {
+        let instances = self.client.instances();
+        let mut restart = Vec::new();
+        let mut stopped = Ok(());
+        for instance_name in [from_instance, to_instance] {
+            match instances
+                .stop_and_wait(
+                    instance_name,
+                    &self.organization_name,
+                    &self.project_name,
+                    &self.wait,
+                )
+                .await
+            {
+                Ok(true) => restart.push(instance_name),
+                Ok(false) => {}
+                Err(e) => {
+                    stopped = Err(e.context(format!("stopping instance {}", instance_name)));
+                    break;
+                }
+            }
+        }
+        if let Err(e) = stopped {
+            return self.restart(&restart, Err(e)).await;
+        }
+
+        let result = match self.detach_stopped(disk_name, from_instance).await {
+            Ok(_) => match self.attach_stopped(disk_name, to_instance).await {
+                Ok(disk) => Ok(disk),
+                Err(e) => {
+                    log::warn!(
+                        "attaching disk {} to {} failed, reattaching it to {}: {}",
+                        disk_name,
+                        to_instance,
+                        from_instance,
+                        e
+                    );
+                    match self.attach_stopped(disk_name, from_instance).await {
+                        Ok(_) => Err(e),
+                        Err(reattach) => Err(reattach.context(format!(
+                            "disk {} is detached: reattaching it to {} failed after: {}",
+                            disk_name, from_instance, e
+                        ))),
+                    }
+                }
+            },
+            Err(e) => Err(e),
+        };
+
+        self.restart(&restart, result).await
+    }
+
+    async fn restart(&self, restart: &[&str], result: Result<Disk>) -> Result<Disk> {
+        self.client
+            .instances()
+            .restart_all(
+                restart,
+                &self.organization_name,
+                &self.project_name,
+                &self.wait,
+                result,
+            )
+            .await
+    }
+
+    async fn detach_stopped(&self, disk_name: &str, instance_name: &str) -> Result<Disk> {
+        self.client
+            .instances()
+            .disks_detach(
+                instance_name,
+                &self.organization_name,
+                &self.project_name,
+                &DiskIdentifier {
+                    name: disk_name.to_string(),
+                },
+            )
+            .await
+            .with_context(|| format!("detaching disk {} from {}", disk_name, instance_name))?;
+
+        self.wait_for_disk(disk_name, "detached", |s| *s == DiskState::Detached)
+            .await
+    }
+
+    async fn attach_stopped(&self, disk_name: &str, instance_name: &str) -> Result<Disk> {
+        self.wait_for_disk(disk_name, "detached", |s| *s == DiskState::Detached)
+            .await?;
+
+        let instance = self
+            .client
+            .instances()
+            .get(instance_name, &self.organization_name, &self.project_name)
+            .await?;
+        self.client
+            .instances()
+            .disks_attach(
+                instance_name,
+                &self.organization_name,
+                &self.project_name,
+                &DiskIdentifier {
+                    name: disk_name.to_string(),
+                },
+            )
+            .await
+            .with_context(|| format!("attaching disk {} to {}", disk_name, instance_name))?;
+
+        let what = format!("attached to {}", instance_name);
+        self.wait_for_disk(disk_name, &what, |s| {
+            *s == DiskState::Attached(instance.id.to_string())
+        })
+        .await
+    }
+}
@@ -112,12 +112,12 @@ impl Disks {
             if !url.contains('?') {
                 resp = self
                     .client
-                    .get(&format!("{}?page={}", url, page), None)
+                    .get(&format!("{}?page_token={}", url, page), None)
                     .await?;
             } else {
                 resp = self
                     .client
-                    .get(&format!("{}&page={}", url, page), None)
+                    .get(&format!("{}&page_token={}", url, page), None)
                     .await?;
             }
 
@@ -314,12 +314,12 @@ impl Disks {
             if !url.contains('?') {
                 resp = self
                     .client
-                    .get(&format!("{}?page={}", url, page), None)
+                    .get(&format!("{}?page_token={}", url, page), None)
                     .await?;
             } else {
                 resp = self
                     .client
-                    .get(&format!("{}&page={}", url, page), None)
+                    .get(&format!("{}&page_token={}", url, page), None)
                     .await?;
             }
 
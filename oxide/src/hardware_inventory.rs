@@ -0,0 +1,313 @@
+//! Point-in-time snapshots of the rack and sled inventory, and the changes
+//! between two of them.
+//!
+//! A [`HardwareSnapshot`] records every rack with its service IP pool and
+//! ranges, and every sled. Snapshots serialize to JSON so they can be kept,
+//! and [`HardwareSnapshot::diff`] reports the racks, sleds and service ranges
+//! added or removed since an earlier one.
+//!
+//! Sleds do not say which rack they are in, so they are only joined with a
+//! rack when there is exactly one; otherwise they are listed as unassigned.
+//! The API does not report which sled an instance runs on, so there are no
+//! per-sled instance counts.
+//!
+//! ```no_run
+//! # async fn daily_report() -> anyhow::Result<()> {
+//! use oxide_api::{hardware_inventory::HardwareSnapshot, Client};
+//!
+//! let oxide = Client::new_from_env();
+//! let yesterday = HardwareSnapshot::from_path("hardware-yesterday.json")?;
+//! let today = oxide.racks().snapshot().await?;
+//! println!("{}", yesterday.diff(&today));
+//! today.save("hardware-today.json")?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    is_not_found,
+    types::{IdSortMode, IpPool, IpRange, Rack, Sled},
+};
+
+/// A rack, its service IP pool and the sleds known to be in it.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct RackInventory {
+    pub rack: Rack,
+    /// The rack's service IP pool, if it has one.
+    pub service_pool: Option<IpPool>,
+    pub service_ranges: Vec<IpRange>,
+    pub sleds: Vec<Sled>,
+}
+
+/// The racks and sleds at a point in time.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct HardwareSnapshot {
+    pub taken_at: Option<DateTime<Utc>>,
+    pub racks: Vec<RackInventory>,
+    /// Sleds that could not be joined with a rack.
+    pub unassigned_sleds: Vec<Sled>,
+}
+
+impl HardwareSnapshot {
+    /// Join racks with their sleds. `service` holds each rack's service pool
+    /// and ranges, in the same order as `racks`.
+    pub fn new(
+        taken_at: DateTime<Utc>,
+        racks: Vec<Rack>,
+        service: Vec<(Option<IpPool>, Vec<IpRange>)>,
+        mut sleds: Vec<Sled>,
+    ) -> Self {
+        sleds.sort_by_key(|s| s.id.to_string());
+        let mut racks: Vec<RackInventory> = racks
+            .into_iter()
+            .zip(service)
+            .map(|(rack, (service_pool, service_ranges))| RackInventory {
+                rack,
+                service_pool,
+                service_ranges,
+                sleds: Vec::new(),
+            })
+            .collect();
+
+        let unassigned_sleds = if let [rack] = racks.as_mut_slice() {
+            rack.sleds = sleds;
+            Vec::new()
+        } else {
+            sleds
+        };
+
+        HardwareSnapshot {
+            taken_at: Some(taken_at),
+            racks,
+            unassigned_sleds,
+        }
+    }
+
+    /// Reads a snapshot saved as JSON.
+    pub fn from_path<P: AsRef<std::path::Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let s =
+            std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        serde_json::from_str(&s).with_context(|| format!("in {}", path.display()))
+    }
+
+    /// Writes the snapshot as JSON.
+    pub fn save<P: AsRef<std::path::Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        std::fs::write(path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("writing {}", path.display()))
+    }
+
+    /// Every sled, whether or not it was joined with a rack.
+    pub fn sleds(&self) -> impl Iterator<Item = &Sled> {
+        self.racks
+            .iter()
+            .flat_map(|r| r.sleds.iter())
+            .chain(self.unassigned_sleds.iter())
+    }
+
+    /// What changed from this snapshot to `newer`.
+    pub fn diff(&self, newer: &HardwareSnapshot) -> HardwareDiff {
+        let racks = |s: &HardwareSnapshot| -> Vec<Rack> {
+            s.racks.iter().map(|r| r.rack.clone()).collect()
+        };
+        let sleds = |s: &HardwareSnapshot| -> Vec<Sled> { s.sleds().cloned().collect() };
+
+        let (added_racks, removed_racks) =
+            added_removed(&racks(self), &racks(newer), |r| r.id.to_string());
+        let (added_sleds, removed_sleds) =
+            added_removed(&sleds(self), &sleds(newer), |s| s.id.to_string());
+        let mut diff = HardwareDiff {
+            from: self.taken_at,
+            to: newer.taken_at,
+            added_racks,
+            removed_racks,
+            added_sleds,
+            removed_sleds,
+            ..Default::default()
+        };
+
+        for new_sled in newer.sleds() {
+            if let Some(old_sled) = self.sleds().find(|s| s.id == new_sled.id) {
+                if old_sled.service_address != new_sled.service_address {
+                    diff.readdressed_sleds.push(SledAddressChange {
+                        sled_id: new_sled.id.to_string(),
+                        old: old_sled.service_address.to_string(),
+                        new: new_sled.service_address.to_string(),
+                    });
+                }
+            }
+        }
+
+        for new_rack in &newer.racks {
+            let old_ranges = self
+                .racks
+                .iter()
+                .find(|r| r.rack.id == new_rack.rack.id)
+                .map(|r| r.service_ranges.as_slice())
+                .unwrap_or_default();
+            let (added, removed) =
+                added_removed(old_ranges, &new_rack.service_ranges, |r| r.to_string());
+            if !added.is_empty() || !removed.is_empty() {
+                diff.service_ranges.push(ServiceRangeChange {
+                    rack_id: new_rack.rack.id.to_string(),
+                    added,
+                    removed,
+                });
+            }
+        }
+
+        diff
+    }
+}
+
+/// The items of `new` not in `old`, and of `old` not in `new`, by key.
+fn added_removed<T, K, F>(old: &[T], new: &[T], key: F) -> (Vec<T>, Vec<T>)
+where
+    T: Clone,
+    K: PartialEq,
+    F: Fn(&T) -> K,
+{
+    let old_keys: Vec<K> = old.iter().map(&key).collect();
+    let new_keys: Vec<K> = new.iter().map(&key).collect();
+    let added = new
+        .iter()
+        .filter(|t| !old_keys.contains(&key(t)))
+        .cloned()
+        .collect();
+    let removed = old
+        .iter()
+        .filter(|t| !new_keys.contains(&key(t)))
+        .cloned()
+        .collect();
+    (added, removed)
+}
+
+/// A sled whose service address changed.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct SledAddressChange {
+    pub sled_id: String,
+    pub old: String,
+    pub new: String,
+}
+
+/// Service IP ranges added to or removed from a rack.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ServiceRangeChange {
+    pub rack_id: String,
+    pub added: Vec<IpRange>,
+    pub removed: Vec<IpRange>,
+}
+
+/// The changes between two hardware snapshots.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct HardwareDiff {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub added_racks: Vec<Rack>,
+    pub removed_racks: Vec<Rack>,
+    pub added_sleds: Vec<Sled>,
+    pub removed_sleds: Vec<Sled>,
+    pub readdressed_sleds: Vec<SledAddressChange>,
+    pub service_ranges: Vec<ServiceRangeChange>,
+}
+
+impl HardwareDiff {
+    /// Returns whether nothing changed.
+    pub fn is_empty(&self) -> bool {
+        self.added_racks.is_empty()
+            && self.removed_racks.is_empty()
+            && self.added_sleds.is_empty()
+            && self.removed_sleds.is_empty()
+            && self.readdressed_sleds.is_empty()
+            && self.service_ranges.is_empty()
+    }
+}
+
+impl fmt::Display for HardwareDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let when = |t: &Option<DateTime<Utc>>| match t {
+            Some(t) => t.to_rfc3339(),
+            None => "unknown".to_string(),
+        };
+        writeln!(
+            f,
+            "hardware changes from {} to {}",
+            when(&self.from),
+            when(&self.to)
+        )?;
+        if self.is_empty() {
+            return writeln!(f, "  none");
+        }
+        for rack in &self.added_racks {
+            writeln!(f, "  + rack {}", rack.id)?;
+        }
+        for rack in &self.removed_racks {
+            writeln!(f, "  - rack {}", rack.id)?;
+        }
+        for sled in &self.added_sleds {
+            writeln!(f, "  + sled {} ({})", sled.id, sled.service_address)?;
+        }
+        for sled in &self.removed_sleds {
+            writeln!(f, "  - sled {} ({})", sled.id, sled.service_address)?;
+        }
+        for change in &self.readdressed_sleds {
+            writeln!(
+                f,
+                "  ~ sled {}: {} -> {}",
+                change.sled_id, change.old, change.new
+            )?;
+        }
+        for change in &self.service_ranges {
+            for range in &change.added {
+                writeln!(f, "  + rack {} service range {}", change.rack_id, range)?;
+            }
+            for range in &change.removed {
+                writeln!(f, "  - rack {} service range {}", change.rack_id, range)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl crate::racks::Racks {
+    /**
+     * Take a snapshot of the racks, their service IP pools and ranges, and the sleds.
+     */
+    pub async fn snapshot(&self) -> Result<HardwareSnapshot> {
+        let taken_at = Utc::now();
+        let racks = self.get_all(IdSortMode::IdAscending).await?;
+
+        let ip_pools = self.client.ip_pools();
+        let mut service = Vec::new();
+        for rack in &racks {
+            let pool = match ip_pools.service_view(&rack.id).await {
+                Ok(pool) => Some(pool),
+                Err(e) if is_not_found(&e) => None,
+                Err(e) => return Err(e),
+            };
+            let ranges = if pool.is_some() {
+                ip_pools
+                    .service_range_list_all(&rack.id)
+                    .await?
+                    .into_iter()
+                    .map(|r| r.range)
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            service.push((pool, ranges));
+        }
+
+        let sleds = self.client.sleds().get_all(IdSortMode::IdAscending).await?;
+
+        Ok(HardwareSnapshot::new(taken_at, racks, service, sleds))
+    }
+}
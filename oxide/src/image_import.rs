@@ -0,0 +1,638 @@
+//! Import disk images into Oxide in one call.
+//!
+//! The image create endpoints only accept a URL that Nexus fetches the image
+//! from. The helpers here take care of everything around that request: serving
+//! a local raw or qcow2 file over HTTP (or checking that a remote URL supports
+//! the range requests the control plane needs), computing the sha256 digest
+//! client-side, creating the image, waiting for it to be listable and verifying
+//! its size and digest.
+//!
+//! ```no_run
+//! # async fn import() -> anyhow::Result<()> {
+//! use oxide_api::{image_import::*, types::Distribution, Client};
+//!
+//! let oxide = Client::new_from_env();
+//!
+//! let import = ImageImport {
+//!     name: "ubuntu-22-04".to_string(),
+//!     description: "Ubuntu 22.04 golden image".to_string(),
+//!     block_size: 512,
+//!     distribution: Distribution {
+//!         name: "ubuntu".to_string(),
+//!         version: "22.04".to_string(),
+//!     },
+//!     source: ImportSource::File {
+//!         path: "ubuntu-22.04.raw".into(),
+//!         bind: "0.0.0.0:0".parse()?,
+//!         advertise_host: "10.0.0.5".to_string(),
+//!     },
+//!     ..Default::default()
+//! };
+//!
+//! let image = oxide.images_global().import(&import).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, Read, Seek, SeekFrom, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread::JoinHandle,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use sha2::{Digest as _, Sha256};
+
+use crate::{
+    is_not_found,
+    types::{
+        ByteCount, Digest, Distribution, GlobalImage, GlobalImageCreate, Image, ImageCreate,
+        ImageSource, NameSortMode,
+    },
+};
+
+/// The magic bytes at the start of every qcow2 file.
+const QCOW2_MAGIC: &[u8] = b"QFI\xfb";
+
+/// Where the bytes of an imported image come from.
+#[derive(Clone, Debug)]
+pub enum ImportSource {
+    /// A local raw or qcow2 file. It is served over HTTP from `bind` for the
+    /// duration of the import, and Nexus is pointed at
+    /// `http://{advertise_host}:{port}/{file name}`, so `advertise_host` must
+    /// be reachable from the rack.
+    File {
+        path: PathBuf,
+        bind: SocketAddr,
+        advertise_host: String,
+    },
+    /// An image that is already served over HTTP. The server must answer
+    /// `HEAD` and `Range` requests. The digest Nexus reports is checked against
+    /// `sha256`, which is computed by downloading the image once if it is not
+    /// given.
+    Url { url: String, sha256: Option<String> },
+}
+
+impl Default for ImportSource {
+    fn default() -> Self {
+        ImportSource::Url {
+            url: String::new(),
+            sha256: None,
+        }
+    }
+}
+
+/// Everything needed to import an image.
+#[derive(Clone, Debug)]
+pub struct ImageImport {
+    pub name: String,
+    pub description: String,
+    pub block_size: i64,
+    /// Only used for global images.
+    pub distribution: Distribution,
+    pub source: ImportSource,
+    /// How long to wait between checks that the new image is listable.
+    pub poll_interval: Duration,
+    /// How long to wait for the new image to become listable.
+    pub timeout: Duration,
+}
+
+impl Default for ImageImport {
+    fn default() -> Self {
+        ImageImport {
+            name: String::new(),
+            description: String::new(),
+            block_size: 512,
+            distribution: Default::default(),
+            source: Default::default(),
+            poll_interval: Duration::from_secs(2),
+            timeout: Duration::from_secs(600),
+        }
+    }
+}
+
+/// What we know about the image bytes before handing them to Nexus.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImageFacts {
+    /// The size of the disk the image describes. For qcow2 files this is the
+    /// virtual size from the header, not the file size.
+    pub size: ByteCount,
+    /// Hex-encoded sha256 of the image bytes, if known.
+    pub sha256: Option<String>,
+    pub qcow2: bool,
+}
+
+impl ImageFacts {
+    /// Reads the size and sha256 digest of a local image file.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<ImageFacts> {
+        let path = path.as_ref();
+        let mut file =
+            File::open(path).with_context(|| format!("opening image {}", path.display()))?;
+        let len = file.metadata()?.len();
+
+        let mut header = Vec::new();
+        (&mut file).take(32).read_to_end(&mut header)?;
+        file.seek(SeekFrom::Start(0))?;
+
+        let mut hasher = Sha256::new();
+        let mut buf = vec![0u8; 1 << 20];
+        loop {
+            let n = file.read(&mut buf)?;
+            if n == 0 {
+                break;
+            }
+            hasher.update(&buf[..n]);
+        }
+
+        let (size, qcow2) = image_size(&header, len)?;
+        Ok(ImageFacts {
+            size,
+            sha256: Some(format!("{:x}", hasher.finalize())),
+            qcow2,
+        })
+    }
+}
+
+/// Returns the disk size described by an image given its first bytes and
+/// total length, and whether it is a qcow2 file.
+fn image_size(header: &[u8], len: u64) -> Result<(ByteCount, bool)> {
+    if header.starts_with(QCOW2_MAGIC) {
+        if header.len() < 32 {
+            bail!("qcow2 header is truncated");
+        }
+        let mut size = [0u8; 8];
+        size.copy_from_slice(&header[24..32]);
+        return Ok((
+            std::convert::TryFrom::try_from(u64::from_be_bytes(size))?,
+            true,
+        ));
+    }
+
+    Ok((std::convert::TryFrom::try_from(len)?, false))
+}
+
+fn check_block_size(facts: &ImageFacts, block_size: i64) -> Result<()> {
+    if block_size <= 0 {
+        bail!("block size must be positive, got {}", block_size);
+    }
+    let size = facts.size.to_bytes();
+    let block_size = block_size as u64;
+    if size / block_size * block_size != size {
+        bail!(
+            "image size {} is not a multiple of the {} byte block size",
+            size,
+            block_size
+        );
+    }
+    Ok(())
+}
+
+/// Whether a listed image reports everything [`verify_image`] checks. Nexus
+/// fills in the digest some time after the image is listed.
+fn has_digest(facts: &ImageFacts, digest: Option<&Digest>) -> bool {
+    facts.sha256.is_none() || digest.is_some()
+}
+
+pub(crate) fn verify_image(
+    name: &str,
+    facts: &ImageFacts,
+    size: ByteCount,
+    digest: Option<&Digest>,
+) -> Result<()> {
+    if size != facts.size {
+        bail!(
+            "image {} has size {}, expected {}",
+            name,
+            size.to_bytes(),
+            facts.size.to_bytes()
+        );
+    }
+
+    match (digest, &facts.sha256) {
+        (Some(Digest::Sha256(got)), Some(want)) if !got.eq_ignore_ascii_case(want) => {
+            bail!("image {} has sha256 {}, expected {}", name, got, want)
+        }
+        (None, Some(_)) => bail!("image {} has no digest to verify", name),
+        _ => Ok(()),
+    }
+}
+
+/// Checks that `url` can serve an image to Nexus: it must answer a `HEAD`
+/// request and honor byte range requests.
+///
+/// This does not use the API client since the URL is not an Oxide endpoint and
+/// must not receive our credentials.
+pub async fn probe_url(url: &str) -> Result<ImageFacts> {
+    let http = reqwest::Client::new();
+
+    let head = http.head(url).send().await?;
+    if !head.status().is_success() {
+        bail!("HEAD {} returned {}", url, head.status());
+    }
+
+    let range = http
+        .get(url)
+        .header(reqwest::header::RANGE, "bytes=0-31")
+        .send()
+        .await?;
+    if range.status() != http::StatusCode::PARTIAL_CONTENT {
+        bail!(
+            "{} does not support range requests: GET with a Range header returned {}",
+            url,
+            range.status()
+        );
+    }
+    let total = range
+        .headers()
+        .get(reqwest::header::CONTENT_RANGE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.rsplit('/').next())
+        .and_then(|v| v.parse::<u64>().ok())
+        .ok_or_else(|| anyhow!("{} did not return a Content-Range with a length", url))?;
+    let header = range.bytes().await?;
+
+    let (size, qcow2) = image_size(&header, total)?;
+    Ok(ImageFacts {
+        size,
+        sha256: None,
+        qcow2,
+    })
+}
+
+/// Downloads the image at `url` and returns its hex-encoded sha256 digest.
+pub async fn hash_url(url: &str) -> Result<String> {
+    let mut response = reqwest::get(url).await?;
+    if !response.status().is_success() {
+        bail!("GET {} returned {}", url, response.status());
+    }
+
+    let mut hasher = Sha256::new();
+    while let Some(chunk) = response.chunk().await? {
+        hasher.update(&chunk);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// A minimal HTTP server that serves a single file with `HEAD` and `Range`
+/// support. It stops when dropped.
+pub struct FileServer {
+    url: String,
+    shutdown: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl FileServer {
+    /// Starts serving `path` on `bind`. The file is available at
+    /// `http://{advertise_host}:{port}/{file name}`.
+    pub fn start<P: AsRef<Path>>(
+        path: P,
+        bind: SocketAddr,
+        advertise_host: &str,
+    ) -> Result<FileServer> {
+        let path = path.as_ref().to_path_buf();
+        let file_name = path
+            .file_name()
+            .and_then(|f| f.to_str())
+            .ok_or_else(|| anyhow!("image path {} has no file name", path.display()))?;
+        let route = format!("/{}", crate::progenitor_support::encode_path(file_name));
+
+        let listener = TcpListener::bind(bind)?;
+        listener.set_nonblocking(true)?;
+        let port = listener.local_addr()?.port();
+        let url = format!("http://{}:{}{}", advertise_host, port, route);
+
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let stop = shutdown.clone();
+        let handle = std::thread::spawn(move || {
+            while !stop.load(Ordering::Relaxed) {
+                match listener.accept() {
+                    Ok((stream, peer)) => {
+                        let path = path.clone();
+                        let route = route.clone();
+                        std::thread::spawn(move || {
+                            if let Err(e) = serve(stream, &path, &route) {
+                                log::debug!("serving image to {} failed: {}", peer, e);
+                            }
+                        });
+                    }
+                    Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                        std::thread::sleep(Duration::from_millis(50));
+                    }
+                    Err(e) => log::warn!("accepting image download failed: {}", e),
+                }
+            }
+        });
+
+        Ok(FileServer {
+            url,
+            shutdown,
+            handle: Some(handle),
+        })
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+impl Drop for FileServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Parses a single `bytes=` range against a file of `len` bytes into an
+/// inclusive `(start, end)` pair. Returns `None` if the range is not
+/// satisfiable.
+fn parse_range(value: &str, len: u64) -> Option<(u64, u64)> {
+    let spec = value.trim().strip_prefix("bytes=")?;
+    if spec.contains(',') {
+        return None;
+    }
+    let (start, end) = spec.split_once('-')?;
+    let (start, end) = match (start.trim(), end.trim()) {
+        ("", suffix) => {
+            let suffix: u64 = suffix.parse().ok()?;
+            if suffix == 0 {
+                return None;
+            }
+            (len.saturating_sub(suffix), len.checked_sub(1)?)
+        }
+        (start, "") => (start.parse().ok()?, len.checked_sub(1)?),
+        (start, end) => (
+            start.parse().ok()?,
+            end.parse::<u64>().ok()?.min(len.checked_sub(1)?),
+        ),
+    };
+    if start > end || start >= len {
+        return None;
+    }
+    Some((start, end))
+}
+
+fn serve(stream: TcpStream, path: &Path, route: &str) -> Result<()> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(Duration::from_secs(30)))?;
+
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let target = parts.next().unwrap_or_default().to_string();
+
+    let mut range = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("range") {
+                range = Some(value.trim().to_string());
+            }
+        }
+    }
+
+    let mut out = stream;
+    if method != "GET" && method != "HEAD" {
+        write!(
+            out,
+            "HTTP/1.1 405 Method Not Allowed\r\nAllow: GET, HEAD\r\nContent-Length: \
+             0\r\nConnection: close\r\n\r\n"
+        )?;
+        return Ok(());
+    }
+    if target != route {
+        write!(
+            out,
+            "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+        )?;
+        return Ok(());
+    }
+
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+
+    let (start, end) = match range {
+        None => {
+            write!(
+                out,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/octet-stream\r\nAccept-Ranges: \
+                 bytes\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                len
+            )?;
+            (0, len)
+        }
+        Some(r) => match parse_range(&r, len) {
+            Some((start, end)) => {
+                write!(
+                    out,
+                    "HTTP/1.1 206 Partial Content\r\nContent-Type: \
+                     application/octet-stream\r\nAccept-Ranges: bytes\r\nContent-Range: bytes \
+                     {}-{}/{}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    start,
+                    end,
+                    len,
+                    end - start + 1
+                )?;
+                (start, end + 1)
+            }
+            None => {
+                write!(
+                    out,
+                    "HTTP/1.1 416 Range Not Satisfiable\r\nContent-Range: bytes \
+                     */{}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                    len
+                )?;
+                return Ok(());
+            }
+        },
+    };
+
+    if method == "GET" {
+        file.seek(SeekFrom::Start(start))?;
+        std::io::copy(&mut file.take(end - start), &mut out)?;
+    }
+    out.flush()?;
+
+    Ok(())
+}
+
+/// Resolves the source of an import into the URL Nexus should fetch and the
+/// facts we expect the resulting image to have. The returned server, if any,
+/// must be kept alive until the image has been created.
+async fn prepare(import: &ImageImport) -> Result<(String, ImageFacts, Option<FileServer>)> {
+    let (url, facts, server) = match &import.source {
+        ImportSource::File {
+            path,
+            bind,
+            advertise_host,
+        } => {
+            let p = path.clone();
+            let facts = tokio::task::spawn_blocking(move || ImageFacts::from_file(p)).await??;
+            let server = FileServer::start(path, *bind, advertise_host)?;
+            log::info!("serving {} at {}", path.display(), server.url());
+            (server.url().to_string(), facts, Some(server))
+        }
+        ImportSource::Url { url, sha256 } => {
+            let mut facts = probe_url(url).await?;
+            facts.sha256 = Some(match sha256 {
+                Some(sha256) => sha256.to_lowercase(),
+                None => hash_url(url)
+                    .await
+                    .with_context(|| format!("computing the sha256 of {}", url))?,
+            });
+            (url.to_string(), facts, None)
+        }
+    };
+
+    if !facts.qcow2 {
+        check_block_size(&facts, import.block_size)?;
+    }
+
+    Ok((url, facts, server))
+}
+
+fn timed_out(what: &str, name: &str, listed: bool) -> anyhow::Error {
+    anyhow!(
+        "timed out waiting for {} {} to {}",
+        what,
+        name,
+        if listed {
+            "report its digest"
+        } else {
+            "be listed"
+        }
+    )
+}
+
+impl crate::images_global::ImagesGlobal {
+    /**
+     * Import a global image from a local file or a URL.
+     *
+     * This creates the image with `images_post`, waits until it shows up in
+     * `images_get_all` with a digest, if there is one to compare, and verifies
+     * its size and digest. If verification fails, or there is no digest within
+     * `import.timeout`, the image is deleted again.
+     */
+    pub async fn import(&self, import: &ImageImport) -> Result<GlobalImage> {
+        let (url, facts, server) = prepare(import).await?;
+
+        let body = GlobalImageCreate::builder()
+            .name(import.name.as_str())
+            .description(import.description.as_str())
+            .block_size(import.block_size)
+            .distribution(import.distribution.clone())
+            .source(ImageSource::Url { url })
+            .build()?;
+        self.images_post(&body).await?;
+
+        let start = Instant::now();
+        let mut listed = false;
+        let image = loop {
+            let images = self.images_get_all(NameSortMode::NameAscending).await?;
+            if let Some(image) = images.into_iter().find(|i| i.name == import.name) {
+                if has_digest(&facts, image.digest.as_ref()) {
+                    break Ok(image);
+                }
+                listed = true;
+            }
+            if start.elapsed() > import.timeout {
+                break Err(timed_out("global image", &import.name, listed));
+            }
+            tokio::time::sleep(import.poll_interval).await;
+        };
+        drop(server);
+
+        let e = match image.and_then(|image| {
+            verify_image(&image.name, &facts, image.size, image.digest.as_ref())?;
+            Ok(image)
+        }) {
+            Ok(image) => return Ok(image),
+            Err(e) => e,
+        };
+        Err(match self.images_delete_image(&import.name).await {
+            Err(d) if !is_not_found(&d) => e.context(format!(
+                "deleting global image {} failed: {}",
+                import.name, d
+            )),
+            _ => e,
+        })
+    }
+}
+
+impl crate::images::Images {
+    /**
+     * Import a project image from a local file or a URL.
+     *
+     * This creates the image with `post`, waits until it shows up in `get_all`
+     * with a digest, if there is one to compare, and verifies its size and
+     * digest. If verification fails, or there is no digest within
+     * `import.timeout`, the image is deleted again.
+     */
+    pub async fn import(
+        &self,
+        organization_name: &str,
+        project_name: &str,
+        import: &ImageImport,
+    ) -> Result<Image> {
+        let (url, facts, server) = prepare(import).await?;
+
+        let body = ImageCreate::builder()
+            .name(import.name.as_str())
+            .description(import.description.as_str())
+            .block_size(import.block_size)
+            .source(ImageSource::Url { url })
+            .build()?;
+        self.post(organization_name, project_name, &body).await?;
+
+        let start = Instant::now();
+        let mut listed = false;
+        let image = loop {
+            let images = self
+                .get_all(organization_name, project_name, NameSortMode::NameAscending)
+                .await?;
+            if let Some(image) = images.into_iter().find(|i| i.name == import.name) {
+                if has_digest(&facts, image.digest.as_ref()) {
+                    break Ok(image);
+                }
+                listed = true;
+            }
+            if start.elapsed() > import.timeout {
+                break Err(timed_out("image", &import.name, listed));
+            }
+            tokio::time::sleep(import.poll_interval).await;
+        };
+        drop(server);
+
+        let e = match image.and_then(|image| {
+            verify_image(&image.name, &facts, image.size, image.digest.as_ref())?;
+            Ok(image)
+        }) {
+            Ok(image) => return Ok(image),
+            Err(e) => e,
+        };
+        Err(
+            match self
+                .delete(&import.name, organization_name, project_name)
+                .await
+            {
+                Err(d) if !is_not_found(&d) => {
+                    e.context(format!("deleting image {} failed: {}", import.name, d))
+                }
+                _ => e,
+            },
+        )
+    }
+}
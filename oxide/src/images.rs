@@ -116,12 +116,12 @@ impl Images {
             if !url.contains('?') {
                 resp = self
                     .client
-                    .get(&format!("{}?page={}", url, page), None)
+                    .get(&format!("{}?page_token={}", url, page), None)
                     .await?;
             } else {
                 resp = self
                     .client
-                    .get(&format!("{}&page={}", url, page), None)
+                    .get(&format!("{}&page_token={}", url, page), None)
                     .await?;
             }
 
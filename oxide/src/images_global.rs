@@ -100,12 +100,12 @@ impl ImagesGlobal {
             if !url.contains('?') {
                 resp = self
                     .client
-                    .get(&format!("{}?page={}", url, page), None)
+                    .get(&format!("{}?page_token={}", url, page), None)
                     .await?;
             } else {
                 resp = self
                     .client
-                    .get(&format!("{}&page={}", url, page), None)
+                    .get(&format!("{}&page_token={}", url, page), None)
                     .await?;
             }
 
@@ -130,12 +130,12 @@ impl Instances {
             if !url.contains('?') {
                 resp = self
                     .client
-                    .get(&format!("{}?page={}", url, page), None)
+                    .get(&format!("{}?page_token={}", url, page), None)
                     .await?;
             } else {
                 resp = self
                     .client
-                    .get(&format!("{}&page={}", url, page), None)
+                    .get(&format!("{}&page_token={}", url, page), None)
                     .await?;
             }
 
@@ -320,12 +320,12 @@ impl Instances {
             if !url.contains('?') {
                 resp = self
                     .client
-                    .get(&format!("{}?page={}", url, page), None)
+                    .get(&format!("{}?page_token={}", url, page), None)
                     .await?;
             } else {
                 resp = self
                     .client
-                    .get(&format!("{}&page={}", url, page), None)
+                    .get(&format!("{}&page_token={}", url, page), None)
                     .await?;
             }
 
@@ -462,12 +462,12 @@ impl Instances {
             if !url.contains('?') {
                 resp = self
                     .client
-                    .get(&format!("{}?page={}", url, page), None)
+                    .get(&format!("{}?page_token={}", url, page), None)
                     .await?;
             } else {
                 resp = self
                     .client
-                    .get(&format!("{}&page={}", url, page), None)
+                    .get(&format!("{}&page_token={}", url, page), None)
                     .await?;
             }
 
@@ -602,12 +602,12 @@ impl Instances {
             if !url.contains('?') {
                 resp = self
                     .client
-                    .get(&format!("{}?page={}", url, page), None)
+                    .get(&format!("{}?page_token={}", url, page), None)
                     .await?;
             } else {
                 resp = self
                     .client
-                    .get(&format!("{}&page={}", url, page), None)
+                    .get(&format!("{}&page_token={}", url, page), None)
                     .await?;
             }
 
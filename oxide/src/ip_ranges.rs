@@ -0,0 +1,397 @@
+//! IP range arithmetic for IP pools.
+//!
+//! [`IpRangeSet`] keeps a set of addresses as sorted, disjoint ranges and
+//! supports union, subtraction, overlap checks and counting. On top of it,
+//! `IpPools::ranges_add_checked` and `IpPools::service_range_add_checked`
+//! refuse ranges that overlap one already in any pool, and
+//! `IpPools::utilization` reports how many of each pool's addresses are
+//! handed out to instances.
+
+use std::{
+    fmt,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
+
+use anyhow::{bail, Result};
+
+use crate::types::{IpPoolRange, IpRange, Ipv4Range, Ipv6Range, NameOrIdSortMode, NameSortMode};
+
+impl IpRange {
+    /// The first address of the range.
+    pub fn first(&self) -> IpAddr {
+        match self {
+            IpRange::V4(r) => IpAddr::V4(r.first_address()),
+            IpRange::V6(r) => IpAddr::V6(r.first_address()),
+        }
+    }
+
+    /// The last address of the range.
+    pub fn last(&self) -> IpAddr {
+        match self {
+            IpRange::V4(r) => IpAddr::V4(r.last_address()),
+            IpRange::V6(r) => IpAddr::V6(r.last_address()),
+        }
+    }
+
+    /// The number of addresses in the range, saturating at `u128::MAX` for
+    /// all of IPv6.
+    pub fn address_count(&self) -> u128 {
+        let (_, first, last) = bounds(self);
+        (last - first).saturating_add(1)
+    }
+
+    /// Returns whether `addr` is in the range.
+    pub fn contains(&self, addr: IpAddr) -> bool {
+        let (family, first, last) = bounds(self);
+        let (addr_family, addr) = to_int(addr);
+        family == addr_family && first <= addr && addr <= last
+    }
+
+    /// Returns whether the two ranges share any address.
+    pub fn overlaps(&self, other: &IpRange) -> bool {
+        let (family, first, last) = bounds(self);
+        let (other_family, other_first, other_last) = bounds(other);
+        family == other_family && first <= other_last && other_first <= last
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Family {
+    V4,
+    V6,
+}
+
+fn to_int(addr: IpAddr) -> (Family, u128) {
+    match addr {
+        IpAddr::V4(a) => (Family::V4, u32::from(a).into()),
+        IpAddr::V6(a) => (Family::V6, u128::from(a)),
+    }
+}
+
+/// The range as integers.
+fn bounds(range: &IpRange) -> (Family, u128, u128) {
+    let (family, first) = to_int(range.first());
+    let (_, last) = to_int(range.last());
+    (family, first, last)
+}
+
+fn to_range(family: Family, first: u128, last: u128) -> IpRange {
+    // Sets only ever hold ordered ranges.
+    match family {
+        Family::V4 => IpRange::V4(
+            Ipv4Range::new(Ipv4Addr::from(first as u32), Ipv4Addr::from(last as u32))
+                .expect("ordered range"),
+        ),
+        Family::V6 => IpRange::V6(
+            Ipv6Range::new(Ipv6Addr::from(first), Ipv6Addr::from(last)).expect("ordered range"),
+        ),
+    }
+}
+
+/// A set of IP addresses stored as sorted, disjoint, non-adjacent ranges.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IpRangeSet {
+    /// `(family, first, last)`, sorted.
+    ranges: Vec<(Family, u128, u128)>,
+}
+
+impl IpRangeSet {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add a range, merging it with any ranges it overlaps or touches.
+    pub fn insert(&mut self, range: &IpRange) {
+        let (family, mut first, mut last) = bounds(range);
+        let mut merged = Vec::with_capacity(self.ranges.len() + 1);
+        for &(f, a, b) in &self.ranges {
+            let touches =
+                f == family && a <= last.saturating_add(1) && first <= b.saturating_add(1);
+            if touches {
+                first = first.min(a);
+                last = last.max(b);
+            } else {
+                merged.push((f, a, b));
+            }
+        }
+        merged.push((family, first, last));
+        merged.sort_unstable();
+        self.ranges = merged;
+    }
+
+    /// Remove every address in `range` from the set, splitting ranges as
+    /// needed.
+    pub fn remove(&mut self, range: &IpRange) {
+        let (family, first, last) = bounds(range);
+        let mut kept = Vec::with_capacity(self.ranges.len() + 1);
+        for &(f, a, b) in &self.ranges {
+            if f != family || b < first || last < a {
+                kept.push((f, a, b));
+                continue;
+            }
+            if a < first {
+                kept.push((f, a, first - 1));
+            }
+            if last < b {
+                kept.push((f, last + 1, b));
+            }
+        }
+        self.ranges = kept;
+    }
+
+    /// The addresses in either set.
+    pub fn union(&self, other: &IpRangeSet) -> IpRangeSet {
+        let mut set = self.clone();
+        for range in other.ranges() {
+            set.insert(&range);
+        }
+        set
+    }
+
+    /// The addresses in this set but not in `other`.
+    pub fn subtract(&self, other: &IpRangeSet) -> IpRangeSet {
+        let mut set = self.clone();
+        for range in other.ranges() {
+            set.remove(&range);
+        }
+        set
+    }
+
+    /// The addresses in both sets.
+    pub fn intersection(&self, other: &IpRangeSet) -> IpRangeSet {
+        self.subtract(&self.subtract(other))
+    }
+
+    /// Returns whether any address in `range` is in the set.
+    pub fn overlaps(&self, range: &IpRange) -> bool {
+        self.ranges().iter().any(|r| r.overlaps(range))
+    }
+
+    /// Returns whether `addr` is in the set.
+    pub fn contains(&self, addr: IpAddr) -> bool {
+        let (family, addr) = to_int(addr);
+        self.ranges
+            .iter()
+            .any(|&(f, a, b)| f == family && a <= addr && addr <= b)
+    }
+
+    /// The number of addresses in the set, saturating at `u128::MAX`.
+    pub fn len(&self) -> u128 {
+        self.ranges.iter().fold(0u128, |n, &(_, a, b)| {
+            n.saturating_add((b - a).saturating_add(1))
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The set as ranges, IPv4 first, each in ascending order.
+    pub fn ranges(&self) -> Vec<IpRange> {
+        self.ranges
+            .iter()
+            .map(|&(f, a, b)| to_range(f, a, b))
+            .collect()
+    }
+}
+
+impl<'a> std::iter::FromIterator<&'a IpRange> for IpRangeSet {
+    fn from_iter<I: IntoIterator<Item = &'a IpRange>>(iter: I) -> Self {
+        let mut set = IpRangeSet::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+impl fmt::Display for IpRangeSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ranges: Vec<String> = self.ranges().iter().map(|r| r.to_string()).collect();
+        write!(f, "{}", ranges.join(", "))
+    }
+}
+
+/// The existing ranges `range` overlaps, as `(owner, range)` pairs.
+pub fn find_overlaps(range: &IpRange, existing: &[(String, IpRange)]) -> Vec<(String, IpRange)> {
+    existing
+        .iter()
+        .filter(|(_, r)| r.overlaps(range))
+        .cloned()
+        .collect()
+}
+
+/// How much of one pool is in use.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoolUtilization {
+    pub pool: String,
+    pub ranges: IpRangeSet,
+    /// External addresses in the pool's ranges, with the instance using each
+    /// as `organization/project/instance`.
+    pub used: Vec<(IpAddr, String)>,
+}
+
+impl PoolUtilization {
+    pub fn total(&self) -> u128 {
+        self.ranges.len()
+    }
+
+    pub fn free(&self) -> u128 {
+        self.total().saturating_sub(self.used.len() as u128)
+    }
+}
+
+/// External address usage across all IP pools.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IpPoolReport {
+    pub pools: Vec<PoolUtilization>,
+    /// External addresses that are not in any pool's ranges.
+    pub unpooled: Vec<(IpAddr, String)>,
+}
+
+impl IpPoolReport {
+    /// Assign each used address to the pool whose ranges contain it.
+    pub fn new(pools: &[(String, Vec<IpRange>)], used: &[(IpAddr, String)]) -> Self {
+        let mut report = IpPoolReport {
+            pools: pools
+                .iter()
+                .map(|(pool, ranges)| PoolUtilization {
+                    pool: pool.to_string(),
+                    ranges: ranges.iter().collect(),
+                    used: Vec::new(),
+                })
+                .collect(),
+            unpooled: Vec::new(),
+        };
+
+        for (addr, owner) in used {
+            match report.pools.iter_mut().find(|p| p.ranges.contains(*addr)) {
+                Some(pool) => pool.used.push((*addr, owner.to_string())),
+                None => report.unpooled.push((*addr, owner.to_string())),
+            }
+        }
+
+        report
+    }
+}
+
+impl fmt::Display for IpPoolReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for pool in &self.pools {
+            writeln!(
+                f,
+                "{}: {} of {} addresses in use, {} free ({})",
+                pool.pool,
+                pool.used.len(),
+                pool.total(),
+                pool.free(),
+                pool.ranges
+            )?;
+        }
+        for (addr, owner) in &self.unpooled {
+            writeln!(f, "not in any pool: {} ({})", addr, owner)?;
+        }
+        Ok(())
+    }
+}
+
+impl crate::ip_pools::IpPools {
+    /**
+     * Every range of every IP pool, as `(pool name, range)` pairs.
+     */
+    pub async fn all_ranges(&self) -> Result<Vec<(String, IpRange)>> {
+        let mut ranges = Vec::new();
+        for pool in self.get_all(NameOrIdSortMode::NameAscending).await? {
+            for r in self.ranges_get_all(&pool.name).await? {
+                ranges.push((pool.name.to_string(), r.range));
+            }
+        }
+        Ok(ranges)
+    }
+
+    /**
+     * Add a range to a pool after checking it does not overlap a range in any pool.
+     */
+    pub async fn ranges_add_checked(
+        &self,
+        pool_name: &str,
+        range: &IpRange,
+    ) -> Result<IpPoolRange> {
+        check_overlaps(range, &self.all_ranges().await?)?;
+        self.ranges_add(pool_name, range).await
+    }
+
+    /**
+     * Add a range to a rack's service pool after checking it does not overlap a range in any
+     * pool or the rack's existing service ranges.
+     */
+    pub async fn service_range_add_checked(
+        &self,
+        rack_id: &str,
+        range: &IpRange,
+    ) -> Result<IpPoolRange> {
+        let mut existing = self.all_ranges().await?;
+        for r in self.service_range_list_all(rack_id).await? {
+            existing.push((format!("rack {} services", rack_id), r.range));
+        }
+        check_overlaps(range, &existing)?;
+        self.service_range_add(rack_id, range).await
+    }
+
+    /**
+     * Report how many addresses of each pool are used by instance external IPs, across all
+     * organizations and projects.
+     */
+    pub async fn utilization(&self) -> Result<IpPoolReport> {
+        let mut pools: Vec<(String, Vec<IpRange>)> = Vec::new();
+        for (pool, range) in self.all_ranges().await? {
+            match pools.iter_mut().find(|(p, _)| *p == pool) {
+                Some((_, ranges)) => ranges.push(range),
+                None => pools.push((pool, vec![range])),
+            }
+        }
+
+        let mut used = Vec::new();
+        let instances = self.client.instances();
+        for org in self
+            .client
+            .organizations()
+            .get_all(NameOrIdSortMode::NameAscending)
+            .await?
+        {
+            for project in self
+                .client
+                .projects()
+                .get_all(&org.name, NameOrIdSortMode::NameAscending)
+                .await?
+            {
+                for instance in instances
+                    .get_all(&org.name, &project.name, NameSortMode::NameAscending)
+                    .await?
+                {
+                    let owner = format!("{}/{}/{}", org.name, project.name, instance.name);
+                    for ip in instances
+                        .external_ip_list_all(&instance.name, &org.name, &project.name)
+                        .await?
+                    {
+                        used.push((ip.ip, owner.to_string()));
+                    }
+                }
+            }
+        }
+
+        Ok(IpPoolReport::new(&pools, &used))
+    }
+}
+
+fn check_overlaps(range: &IpRange, existing: &[(String, IpRange)]) -> Result<()> {
+    let overlaps = find_overlaps(range, existing);
+    if !overlaps.is_empty() {
+        let overlaps: Vec<String> = overlaps
+            .iter()
+            .map(|(owner, r)| format!("{} in {}", r, owner))
+            .collect();
+        bail!("range {} overlaps {}", range, overlaps.join(", "));
+    }
+    Ok(())
+}
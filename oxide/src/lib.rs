@@ -56,6 +56,27 @@
 //!
 //! let oxide = Client::new_from_env();
 //! ```
+//!
+//! Clients can also log in as a local silo user and authenticate with the
+//! session cookie instead of a token. They log in again when the session
+//! expires.
+//!
+//! ```no_run
+//! use oxide_api::Client;
+//!
+//! async fn connect() -> anyhow::Result<Client> {
+//!     Client::new_with_password("https://oxide.sys.example.com", "corp", "alice", "hunter2").await
+//! }
+//! ```
+//!
+//! Programs without an async runtime can enable the `blocking` feature and use
+//! `blocking::Client`, which has the same operations but waits for each
+//! response instead of returning a future.
+//!
+//! ```toml
+//! [dependencies]
+//! oxide-api = { version = "0.1.0-rc.41", features = ["blocking"] }
+//! ```
 #![feature(derive_default_enum)]
 #![allow(clippy::too_many_arguments)]
 #![allow(clippy::nonstandard_macro_braces)]
@@ -64,14 +85,23 @@
 #![allow(missing_docs)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "blocking")]
+#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+pub mod blocking;
+/// Snapshot, clone and move disks, waiting for each state transition.
+pub mod disk_ops;
 /// Virtual disks are used to store instance-local data which includes the operating system.
 ///
 ///FROM: http://oxide.computer/docs/#xxx
 pub mod disks;
+/// Snapshot racks, sleds and service IP ranges, and diff two snapshots.
+pub mod hardware_inventory;
 /// TODO operations that will not ship to customers.
 ///
 ///FROM: http://oxide.computer/docs/#xxx
 pub mod hidden;
+/// Import local image files and image URLs as images, verifying size and digest.
+pub mod image_import;
 /// Images are read-only Virtual Disks that may be used to boot Virtual Machines.
 ///
 ///FROM: http://oxide.computer/docs/#xxx
@@ -88,14 +118,21 @@ pub mod instances;
 ///
 ///FROM: http://oxide.computer/docs/#xxx
 pub mod ip_pools;
+/// Merge, subtract and overlap-check IP pool ranges and report pool utilization.
+pub mod ip_ranges;
 /// Authentication endpoints.
 ///
 ///FROM: http://oxide.computer/docs/#xxx
 pub mod login;
+/// Plan and apply declarative YAML or JSON descriptions of a project.
+pub mod manifest;
 /// Metrics provide insight into the operation of the Oxide deployment. These include telemetry on hardware and software components that can be used to understand the current state as well as to diagnose issues.
 ///
 ///FROM: http://oxide.computer/docs/#xxx
 pub mod metrics;
+/// Add network interfaces, swap the primary and move interfaces between subnets.
+pub mod nic_ops;
+pub mod operations;
 /// Organizations represent a subset of users and projects in an Oxide deployment.
 ///
 ///FROM: http://oxide.computer/docs/#xxx
@@ -116,6 +153,8 @@ pub mod racks;
 ///
 ///FROM: http://oxide.computer/docs/#xxx
 pub mod roles;
+/// Resolve, validate and look up destinations in a VPC router's routes offline.
+pub mod route_table;
 /// Routers direct the flow of network traffic into, out of, and within a VPC via routes.
 ///
 ///FROM: http://oxide.computer/docs/#xxx
@@ -128,6 +167,10 @@ pub mod routes;
 ///
 ///FROM: http://oxide.computer/docs/#xxx
 pub mod sagas;
+/// Build SAML identity provider create requests from IdP metadata XML and PEM keys.
+pub mod saml_onboarding;
+/// Create a silo with its identity provider, roles, organization and project, or nothing.
+pub mod silo_bootstrap;
 /// Silos represent a logical partition of users and resources.
 ///
 ///FROM: http://oxide.computer/docs/#xxx
@@ -136,6 +179,8 @@ pub mod silos;
 ///
 ///FROM: http://oxide.computer/docs/#xxx
 pub mod sleds;
+/// Plan and apply hourly, daily and weekly snapshot retention policies.
+pub mod snapshot_retention;
 /// Snapshots of Virtual Disks at a particular point in time.
 ///
 ///FROM: http://oxide.computer/docs/#xxx
@@ -144,6 +189,10 @@ pub mod snapshots;
 ///
 ///FROM: http://oxide.computer/docs/#xxx
 pub mod sshkeys;
+/// Map the addresses in a VPC's subnets to their instances and count free addresses.
+pub mod subnet_inventory;
+/// Allocate non-overlapping VPC subnet blocks and report address utilization.
+pub mod subnet_planner;
 /// This tag should be moved into a generic network tag.
 ///
 ///FROM: http://oxide.computer/docs/#xxx
@@ -155,6 +204,8 @@ pub mod system;
 #[cfg(test)]
 mod tests;
 pub mod types;
+#[cfg(test)]
+mod types_tests;
 /// This tag should be moved into a operations tag.
 ///
 ///FROM: http://oxide.computer/docs/#xxx
@@ -188,17 +239,42 @@ mod progenitor_support {
     }
 }
 
-use std::env;
+/// Returns whether an API error means the object does not exist (yet).
+#[allow(dead_code)]
+pub(crate) fn is_not_found(e: &Error) -> bool {
+    matches!(
+        e.downcast_ref::<types::Error>(),
+        Some(types::Error::ObjectNotFound { .. })
+    )
+}
+
+use std::{
+    env,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
 /// Entrypoint for interacting with the API client.
 #[derive(Clone)]
 pub struct Client {
     host: String,
     token: String,
+    session: Option<Arc<Session>>,
 
     client: reqwest::Client,
 }
 
+/// The local silo user a client logs in as, and whether it is logged in.
+/// Shared by every clone of the client.
+struct Session {
+    silo: String,
+    username: String,
+    password: String,
+    logged_in: AtomicBool,
+}
+
 impl Client {
     /// Create a new Client struct. It takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
@@ -213,6 +289,7 @@ impl Client {
             Ok(c) => Client {
                 host: host.to_string(),
                 token: token.to_string(),
+                session: None,
 
                 client: c,
             },
@@ -228,11 +305,101 @@ impl Client {
         Client::new(token, host)
     }
 
+    /// Create a new Client that logs in as a local user of `silo` and
+    /// authenticates with the session cookie it is given instead of a token.
+    /// Whenever the session has expired the client logs in again.
+    pub async fn new_with_password<H, S, U, P>(
+        host: H,
+        silo: S,
+        username: U,
+        password: P,
+    ) -> Result<Self>
+    where
+        H: ToString,
+        S: ToString,
+        U: ToString,
+        P: ToString,
+    {
+        let client = reqwest::Client::builder()
+            .cookie_provider(Arc::new(reqwest::cookie::Jar::default()))
+            .build()?;
+        let client = Client {
+            host: host.to_string(),
+            token: String::new(),
+            session: Some(Arc::new(Session {
+                silo: silo.to_string(),
+                username: username.to_string(),
+                password: password.to_string(),
+                logged_in: AtomicBool::new(false),
+            })),
+
+            client,
+        };
+        client.log_in().await?;
+        Ok(client)
+    }
+
+    /// Log in with the client's username and password. This happens
+    /// automatically when the client is created and when its session expires.
+    pub async fn log_in(&self) -> Result<()> {
+        let session = match &self.session {
+            Some(session) => session,
+            None => {
+                return Err(anyhow!(
+                    "client was not created with a username and password"
+                ))
+            }
+        };
+
+        // Not sent through `request_raw`, which logs request bodies.
+        let url = format!(
+            "{}/login/{}/local",
+            self.host,
+            progenitor_support::encode_path(&session.silo),
+        );
+        let response = self
+            .client
+            .post(url)
+            .json(&serde_json::json!({
+                "username": session.username,
+                "password": session.password,
+            }))
+            .send()
+            .await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(anyhow!(
+                "logging in to silo {} as {} failed: {}",
+                session.silo,
+                session.username,
+                status
+            ));
+        }
+
+        session.logged_in.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// End the client's session. Requests fail as unauthorized afterwards
+    /// rather than logging in again, until `log_in` is called.
+    pub async fn log_out(&self) -> Result<()> {
+        self.hidden().logout().await?;
+        if let Some(session) = &self.session {
+            session.logged_in.store(false, Ordering::SeqCst);
+        }
+        Ok(())
+    }
+
     async fn url_and_auth(&self, uri: &str) -> Result<(reqwest::Url, Option<String>)> {
         let parsed_url = uri.parse::<reqwest::Url>();
 
-        let auth = format!("Bearer {}", self.token);
-        parsed_url.map(|u| (u, Some(auth))).map_err(Error::from)
+        // Clients logged in with a password authenticate with their cookie.
+        let auth = if self.session.is_some() {
+            None
+        } else {
+            Some(format!("Bearer {}", self.token))
+        };
+        parsed_url.map(|u| (u, auth)).map_err(Error::from)
     }
 
     pub async fn request_raw(
@@ -287,34 +454,57 @@ impl Client {
         Ok(req.send().await?)
     }
 
-    async fn request<Out>(
+    /// Send a request and return the status and body of the response,
+    /// whatever the status. Operations whose response type depends on the
+    /// status decode the body themselves.
+    async fn request_with_status(
         &self,
         method: reqwest::Method,
         uri: &str,
         body: Option<reqwest::Body>,
-    ) -> Result<Out>
-    where
-        Out: serde::de::DeserializeOwned + 'static + Send,
-    {
-        let response = self.response_raw(method, uri, body).await?;
+    ) -> Result<(http::StatusCode, bytes::Bytes)> {
+        let session = self
+            .session
+            .as_ref()
+            .filter(|s| s.logged_in.load(Ordering::SeqCst));
+        let retry_body = match session {
+            Some(_) => body.as_ref().and_then(|b| b.as_bytes()).map(|b| b.to_vec()),
+            None => None,
+        };
+
+        let mut response = self.response_raw(method.clone(), uri, body).await?;
+
+        if response.status() == http::StatusCode::UNAUTHORIZED && session.is_some() {
+            log::debug!("session expired, logging in again");
+            self.log_in().await?;
+            response = self
+                .response_raw(method, uri, retry_body.map(reqwest::Body::from))
+                .await?;
+        }
 
         let status = response.status();
 
         let response_body = response.bytes().await?;
 
+        log::debug!(
+            "response payload {}",
+            String::from_utf8_lossy(&response_body)
+        );
+        Ok((status, response_body))
+    }
+
+    /// Send a request and return the status and body of the response if it
+    /// succeeded, or the error it was answered with.
+    async fn request_bytes(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<(http::StatusCode, bytes::Bytes)> {
+        let (status, response_body) = self.request_with_status(method, uri, body).await?;
+
         if status.is_success() {
-            log::debug!(
-                "response payload {}",
-                String::from_utf8_lossy(&response_body)
-            );
-            let parsed_response = if status == http::StatusCode::NO_CONTENT
-                || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
-            {
-                serde_json::from_str("null")
-            } else {
-                serde_json::from_slice::<Out>(&response_body)
-            };
-            parsed_response.map_err(Error::from)
+            Ok((status, response_body))
         } else {
             let error: anyhow::Error = if response_body.is_empty() {
                 anyhow!("code: {}, empty response", status)
@@ -339,6 +529,27 @@ impl Client {
         }
     }
 
+    async fn request<Out>(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<Out>
+    where
+        Out: serde::de::DeserializeOwned + 'static + Send,
+    {
+        let (status, response_body) = self.request_bytes(method, uri, body).await?;
+
+        let parsed_response = if status == http::StatusCode::NO_CONTENT
+            || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
+        {
+            serde_json::from_str("null")
+        } else {
+            serde_json::from_slice::<Out>(&response_body)
+        };
+        parsed_response.map_err(Error::from)
+    }
+
     async fn request_entity<D>(
         &self,
         method: http::Method,
@@ -0,0 +1,816 @@
+//! Declarative project manifests.
+//!
+//! A [`Manifest`] describes the VPCs (with their subnets, routers, routes and
+//! firewall rules), disks and instances a project should contain, in YAML or
+//! JSON. Planning compares it with the project's current state and produces a
+//! [`Plan`] of create, update and delete steps in dependency order; applying
+//! runs those steps, reporting progress as it goes.
+//!
+//! ```yaml
+//! vpcs:
+//!   - name: prod
+//!     description: production network
+//!     dns_name: prod
+//!     subnets:
+//!       - name: web
+//!         description: web tier
+//!         ipv4_block: 10.1.0.0/24
+//!     routers:
+//!       - name: egress
+//!         description: egress routes
+//!         routes:
+//!           - name: db-via-nat
+//!             description: reach the db tier through nat
+//!             destination: { type: subnet, value: db }
+//!             target: { type: instance, value: nat }
+//! disks:
+//!   - name: db-data
+//!     description: database volume
+//!     disk_source: { type: blank, block_size: 4096 }
+//!     size: 107374182400
+//! ```
+//!
+//! Resources in the project but missing from the manifest are only deleted
+//! when planning with `prune`. What Nexus creates by itself is never pruned:
+//! the project's `default` VPC, the `default` subnet of each VPC and its system
+//! router. Disks and instances cannot be changed in place, so differences in
+//! their size or shape are reported as conflicts and a plan with conflicts is
+//! never applied.
+
+use std::fmt;
+
+use anyhow::{bail, Context, Result};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    disk_ops::{DiskOps, Wait},
+    types::{
+        Disk, DiskCreate, DiskState, Instance, InstanceCreate, NameSortMode, RouterRoute,
+        RouterRouteCreateParams, RouterRouteKind, RouterRouteUpdateParams, Vpc, VpcCreate,
+        VpcFirewallRule, VpcFirewallRuleUpdate, VpcFirewallRuleUpdateParams, VpcRouter,
+        VpcRouterCreate, VpcRouterKind, VpcRouterUpdate, VpcSubnet, VpcSubnetCreate,
+        VpcSubnetUpdate, VpcUpdate,
+    },
+};
+
+/// The VPC Nexus creates in every project and the subnet it creates in every
+/// VPC, which pruning leaves alone.
+const DEFAULT_VPC: &str = "default";
+const DEFAULT_SUBNET: &str = "default";
+
+/// The desired contents of a project.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct Manifest {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub vpcs: Vec<VpcManifest>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disks: Vec<DiskCreate>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub instances: Vec<InstanceCreate>,
+}
+
+/// A VPC and the resources inside it.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct VpcManifest {
+    #[serde(flatten)]
+    pub vpc: VpcCreate,
+
+    /// The VPC's subnets. Its `default` subnet is never pruned.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subnets: Vec<VpcSubnetCreate>,
+
+    /// Custom routers. The VPC's system router is never touched.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub routers: Vec<RouterManifest>,
+
+    /// The VPC's complete set of firewall rules. The rules are left alone when
+    /// this is not given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub firewall_rules: Option<Vec<VpcFirewallRuleUpdate>>,
+}
+
+/// A custom router and its routes.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct RouterManifest {
+    #[serde(flatten)]
+    pub router: VpcRouterCreate,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub routes: Vec<RouterRouteCreateParams>,
+}
+
+impl std::str::FromStr for Manifest {
+    type Err = anyhow::Error;
+
+    /// Parses a manifest from YAML, or JSON since JSON is also valid YAML.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_yaml::from_str(s).context("parsing manifest")
+    }
+}
+
+impl Manifest {
+    /// Reads a YAML or JSON manifest from a file.
+    pub fn from_path<P: AsRef<std::path::Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        std::fs::read_to_string(path)
+            .with_context(|| format!("reading {}", path.display()))?
+            .parse()
+            .with_context(|| format!("in {}", path.display()))
+    }
+}
+
+/// What currently exists in a project, as far as manifests are concerned.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct ProjectState {
+    pub vpcs: Vec<VpcState>,
+    pub disks: Vec<Disk>,
+    pub instances: Vec<Instance>,
+}
+
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct VpcState {
+    pub vpc: Vpc,
+    pub subnets: Vec<VpcSubnet>,
+    /// Custom routers only.
+    pub routers: Vec<RouterState>,
+    pub firewall_rules: Vec<VpcFirewallRule>,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct RouterState {
+    pub router: VpcRouter,
+    /// Custom routes only.
+    pub routes: Vec<RouterRoute>,
+}
+
+/// A single change to a project.
+#[derive(PartialEq, Debug, Clone)]
+pub enum Step {
+    CreateVpc(VpcCreate),
+    UpdateVpc {
+        vpc: String,
+        update: VpcUpdate,
+    },
+    DeleteVpc(String),
+    CreateSubnet {
+        vpc: String,
+        subnet: VpcSubnetCreate,
+    },
+    UpdateSubnet {
+        vpc: String,
+        name: String,
+        update: VpcSubnetUpdate,
+    },
+    DeleteSubnet {
+        vpc: String,
+        name: String,
+    },
+    CreateRouter {
+        vpc: String,
+        router: VpcRouterCreate,
+    },
+    UpdateRouter {
+        vpc: String,
+        name: String,
+        update: VpcRouterUpdate,
+    },
+    DeleteRouter {
+        vpc: String,
+        name: String,
+    },
+    CreateRoute {
+        vpc: String,
+        router: String,
+        route: RouterRouteCreateParams,
+    },
+    UpdateRoute {
+        vpc: String,
+        router: String,
+        name: String,
+        update: RouterRouteUpdateParams,
+    },
+    DeleteRoute {
+        vpc: String,
+        router: String,
+        name: String,
+    },
+    PutFirewallRules {
+        vpc: String,
+        rules: VpcFirewallRuleUpdateParams,
+    },
+    CreateDisk(DiskCreate),
+    DeleteDisk(String),
+    CreateInstance(InstanceCreate),
+    /// Stops the instance if needed, then deletes it.
+    DeleteInstance(String),
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::CreateVpc(v) => write!(f, "create vpc {}", v.name),
+            Step::UpdateVpc { vpc, .. } => write!(f, "update vpc {}", vpc),
+            Step::DeleteVpc(vpc) => write!(f, "delete vpc {}", vpc),
+            Step::CreateSubnet { vpc, subnet } => {
+                write!(f, "create subnet {}/{}", vpc, subnet.name)
+            }
+            Step::UpdateSubnet { vpc, name, .. } => write!(f, "update subnet {}/{}", vpc, name),
+            Step::DeleteSubnet { vpc, name } => write!(f, "delete subnet {}/{}", vpc, name),
+            Step::CreateRouter { vpc, router } => {
+                write!(f, "create router {}/{}", vpc, router.name)
+            }
+            Step::UpdateRouter { vpc, name, .. } => write!(f, "update router {}/{}", vpc, name),
+            Step::DeleteRouter { vpc, name } => write!(f, "delete router {}/{}", vpc, name),
+            Step::CreateRoute { vpc, router, route } => {
+                write!(f, "create route {}/{}/{}", vpc, router, route.name)
+            }
+            Step::UpdateRoute {
+                vpc, router, name, ..
+            } => write!(f, "update route {}/{}/{}", vpc, router, name),
+            Step::DeleteRoute { vpc, router, name } => {
+                write!(f, "delete route {}/{}/{}", vpc, router, name)
+            }
+            Step::PutFirewallRules { vpc, rules } => write!(
+                f,
+                "replace firewall rules of vpc {} ({} rules)",
+                vpc,
+                rules.rules.len()
+            ),
+            Step::CreateDisk(d) => write!(f, "create disk {} ({})", d.name, d.size),
+            Step::DeleteDisk(name) => write!(f, "delete disk {}", name),
+            Step::CreateInstance(i) => write!(f, "create instance {}", i.name),
+            Step::DeleteInstance(name) => write!(f, "delete instance {}", name),
+        }
+    }
+}
+
+/// The steps that bring a project in line with a manifest.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct Plan {
+    /// Deletions come first, children before parents, followed by creations
+    /// and updates, parents before children.
+    pub steps: Vec<Step>,
+    /// Differences that cannot be applied in place.
+    pub conflicts: Vec<String>,
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.steps.is_empty() && self.conflicts.is_empty() {
+            return writeln!(f, "no changes");
+        }
+        for step in &self.steps {
+            writeln!(f, "  {}", step)?;
+        }
+        for conflict in &self.conflicts {
+            writeln!(f, "  conflict: {}", conflict)?;
+        }
+        Ok(())
+    }
+}
+
+/// Steps grouped by phase, flattened in dependency order once planning is
+/// done.
+#[derive(Default)]
+struct Phases {
+    delete_instances: Vec<Step>,
+    delete_disks: Vec<Step>,
+    delete_routes: Vec<Step>,
+    delete_routers: Vec<Step>,
+    delete_subnets: Vec<Step>,
+    delete_vpcs: Vec<Step>,
+    vpcs: Vec<Step>,
+    subnets: Vec<Step>,
+    routers: Vec<Step>,
+    routes: Vec<Step>,
+    disks: Vec<Step>,
+    instances: Vec<Step>,
+    firewall_rules: Vec<Step>,
+}
+
+impl Phases {
+    fn into_steps(self) -> Vec<Step> {
+        vec![
+            self.delete_instances,
+            self.delete_disks,
+            self.delete_routes,
+            self.delete_routers,
+            self.delete_subnets,
+            self.delete_vpcs,
+            self.vpcs,
+            self.subnets,
+            self.routers,
+            self.routes,
+            self.disks,
+            self.instances,
+            self.firewall_rules,
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl Manifest {
+    /// Compute the steps that turn `state` into this manifest. With `prune`,
+    /// resources missing from the manifest are deleted.
+    pub fn plan(&self, state: &ProjectState, prune: bool) -> Result<Plan> {
+        let mut p = Phases::default();
+        let mut conflicts = Vec::new();
+
+        for want in &self.vpcs {
+            let vpc = &want.vpc.name;
+            let have = state.vpcs.iter().find(|v| v.vpc.name == *vpc);
+            let empty = VpcState::default();
+            let current = match have {
+                Some(have) => {
+                    if !want.vpc.ipv6_prefix.is_empty()
+                        && want.vpc.ipv6_prefix != have.vpc.ipv6_prefix
+                    {
+                        conflicts.push(format!(
+                            "vpc {} has ipv6 prefix {}, the manifest wants {}",
+                            vpc, have.vpc.ipv6_prefix, want.vpc.ipv6_prefix
+                        ));
+                    }
+                    if want.vpc.description != have.vpc.description
+                        || want.vpc.dns_name != have.vpc.dns_name
+                    {
+                        p.vpcs.push(Step::UpdateVpc {
+                            vpc: vpc.to_string(),
+                            update: VpcUpdate::builder()
+                                .name(vpc)
+                                .description(&want.vpc.description)
+                                .dns_name(&want.vpc.dns_name)
+                                .build()?,
+                        });
+                    }
+                    have
+                }
+                None => {
+                    p.vpcs.push(Step::CreateVpc(want.vpc.clone()));
+                    &empty
+                }
+            };
+
+            for subnet in &want.subnets {
+                match current.subnets.iter().find(|s| s.name == subnet.name) {
+                    Some(have) => {
+                        if subnet.ipv4_block != have.ipv4_block
+                            || (!subnet.ipv6_block.is_empty()
+                                && subnet.ipv6_block != have.ipv6_block)
+                        {
+                            conflicts.push(format!(
+                                "subnet {}/{} cannot change its address blocks",
+                                vpc, subnet.name
+                            ));
+                        }
+                        if subnet.description != have.description {
+                            p.subnets.push(Step::UpdateSubnet {
+                                vpc: vpc.to_string(),
+                                name: subnet.name.to_string(),
+                                update: VpcSubnetUpdate::builder()
+                                    .name(&subnet.name)
+                                    .description(&subnet.description)
+                                    .build()?,
+                            });
+                        }
+                    }
+                    None => p.subnets.push(Step::CreateSubnet {
+                        vpc: vpc.to_string(),
+                        subnet: subnet.clone(),
+                    }),
+                }
+            }
+            if prune {
+                for have in &current.subnets {
+                    if have.name != DEFAULT_SUBNET
+                        && !want.subnets.iter().any(|s| s.name == have.name)
+                    {
+                        p.delete_subnets.push(Step::DeleteSubnet {
+                            vpc: vpc.to_string(),
+                            name: have.name.to_string(),
+                        });
+                    }
+                }
+            }
+
+            for router in &want.routers {
+                let name = &router.router.name;
+                let no_routes = Vec::new();
+                let routes = match current.routers.iter().find(|r| r.router.name == *name) {
+                    Some(have) => {
+                        if router.router.description != have.router.description {
+                            p.routers.push(Step::UpdateRouter {
+                                vpc: vpc.to_string(),
+                                name: name.to_string(),
+                                update: VpcRouterUpdate::builder()
+                                    .name(name)
+                                    .description(&router.router.description)
+                                    .build()?,
+                            });
+                        }
+                        &have.routes
+                    }
+                    None => {
+                        p.routers.push(Step::CreateRouter {
+                            vpc: vpc.to_string(),
+                            router: router.router.clone(),
+                        });
+                        &no_routes
+                    }
+                };
+
+                for route in &router.routes {
+                    match routes.iter().find(|r| r.name == route.name) {
+                        Some(have) => {
+                            if route.description != have.description
+                                || route.destination != have.destination
+                                || route.target != have.target
+                            {
+                                p.routes.push(Step::UpdateRoute {
+                                    vpc: vpc.to_string(),
+                                    router: name.to_string(),
+                                    name: route.name.to_string(),
+                                    update: RouterRouteUpdateParams::builder()
+                                        .name(&route.name)
+                                        .description(&route.description)
+                                        .destination(route.destination.clone())
+                                        .target(route.target.clone())
+                                        .build()?,
+                                });
+                            }
+                        }
+                        None => p.routes.push(Step::CreateRoute {
+                            vpc: vpc.to_string(),
+                            router: name.to_string(),
+                            route: route.clone(),
+                        }),
+                    }
+                }
+                if prune {
+                    for have in routes {
+                        if !router.routes.iter().any(|r| r.name == have.name) {
+                            p.delete_routes.push(Step::DeleteRoute {
+                                vpc: vpc.to_string(),
+                                router: name.to_string(),
+                                name: have.name.to_string(),
+                            });
+                        }
+                    }
+                }
+            }
+            if prune {
+                for have in &current.routers {
+                    if !want
+                        .routers
+                        .iter()
+                        .any(|r| r.router.name == have.router.name)
+                    {
+                        delete_router(&mut p, vpc, have);
+                    }
+                }
+            }
+
+            if let Some(rules) = &want.firewall_rules {
+                let mut want_rules = rules.clone();
+                want_rules.sort_by(|a, b| a.name.cmp(&b.name));
+                let mut have_rules: Vec<VpcFirewallRuleUpdate> = current
+                    .firewall_rules
+                    .iter()
+                    .map(|r| {
+                        VpcFirewallRuleUpdate::builder()
+                            .name(&r.name)
+                            .description(&r.description)
+                            .action(r.action.clone())
+                            .direction(r.direction.clone())
+                            .filters(r.filters.clone())
+                            .priority(r.priority)
+                            .status(r.status.clone())
+                            .targets(r.targets.clone())
+                            .build()
+                    })
+                    .collect::<Result<_>>()?;
+                have_rules.sort_by(|a, b| a.name.cmp(&b.name));
+
+                if want_rules != have_rules {
+                    p.firewall_rules.push(Step::PutFirewallRules {
+                        vpc: vpc.to_string(),
+                        rules: VpcFirewallRuleUpdateParams::builder()
+                            .rules(want_rules)
+                            .build()?,
+                    });
+                }
+            }
+        }
+        if prune {
+            for have in &state.vpcs {
+                if have.vpc.name != DEFAULT_VPC
+                    && !self.vpcs.iter().any(|v| v.vpc.name == have.vpc.name)
+                {
+                    for subnet in &have.subnets {
+                        p.delete_subnets.push(Step::DeleteSubnet {
+                            vpc: have.vpc.name.to_string(),
+                            name: subnet.name.to_string(),
+                        });
+                    }
+                    for router in &have.routers {
+                        delete_router(&mut p, &have.vpc.name, router);
+                    }
+                    p.delete_vpcs
+                        .push(Step::DeleteVpc(have.vpc.name.to_string()));
+                }
+            }
+        }
+
+        for disk in &self.disks {
+            match state.disks.iter().find(|d| d.name == disk.name) {
+                Some(have) => {
+                    if have.size != disk.size {
+                        conflicts.push(format!(
+                            "disk {} is {}, the manifest wants {}",
+                            disk.name, have.size, disk.size
+                        ));
+                    }
+                }
+                None => p.disks.push(Step::CreateDisk(disk.clone())),
+            }
+        }
+        if prune {
+            for have in &state.disks {
+                if !self.disks.iter().any(|d| d.name == have.name) {
+                    p.delete_disks.push(Step::DeleteDisk(have.name.to_string()));
+                }
+            }
+        }
+
+        for instance in &self.instances {
+            match state.instances.iter().find(|i| i.name == instance.name) {
+                Some(have) => {
+                    if have.ncpus != instance.ncpus
+                        || have.memory != instance.memory
+                        || have.hostname != instance.hostname
+                    {
+                        conflicts.push(format!(
+                            "instance {} must be recreated to change its cpus, memory or hostname",
+                            instance.name
+                        ));
+                    }
+                }
+                None => p.instances.push(Step::CreateInstance(instance.clone())),
+            }
+        }
+        if prune {
+            for have in &state.instances {
+                if !self.instances.iter().any(|i| i.name == have.name) {
+                    p.delete_instances
+                        .push(Step::DeleteInstance(have.name.to_string()));
+                }
+            }
+        }
+
+        Ok(Plan {
+            steps: p.into_steps(),
+            conflicts,
+        })
+    }
+}
+
+fn delete_router(p: &mut Phases, vpc: &str, router: &RouterState) {
+    for route in &router.routes {
+        p.delete_routes.push(Step::DeleteRoute {
+            vpc: vpc.to_string(),
+            router: router.router.name.to_string(),
+            name: route.name.to_string(),
+        });
+    }
+    p.delete_routers.push(Step::DeleteRouter {
+        vpc: vpc.to_string(),
+        name: router.router.name.to_string(),
+    });
+}
+
+impl crate::projects::Projects {
+    /**
+     * Fetch everything in a project that a manifest can describe.
+     */
+    pub async fn manifest_state(
+        &self,
+        organization_name: &str,
+        project_name: &str,
+    ) -> Result<ProjectState> {
+        let sort = || NameSortMode::NameAscending;
+        let mut state = ProjectState {
+            disks: self
+                .client
+                .disks()
+                .get_all(organization_name, project_name, sort())
+                .await?,
+            instances: self
+                .client
+                .instances()
+                .get_all(organization_name, project_name, sort())
+                .await?,
+            ..Default::default()
+        };
+
+        for vpc in self
+            .client
+            .vpcs()
+            .get_all(organization_name, project_name, sort())
+            .await?
+        {
+            let subnets = self
+                .client
+                .subnets()
+                .get_all(organization_name, project_name, sort(), &vpc.name)
+                .await?;
+            let firewall_rules = self
+                .client
+                .vpcs()
+                .firewall_rules_get(organization_name, project_name, &vpc.name)
+                .await?
+                .rules;
+
+            let mut routers = Vec::new();
+            for router in self
+                .client
+                .routers()
+                .get_all(organization_name, project_name, sort(), &vpc.name)
+                .await?
+            {
+                if router.kind != VpcRouterKind::Custom {
+                    continue;
+                }
+                let routes = self
+                    .client
+                    .routes()
+                    .get_all(
+                        organization_name,
+                        project_name,
+                        &router.name,
+                        sort(),
+                        &vpc.name,
+                    )
+                    .await?
+                    .into_iter()
+                    .filter(|r| r.kind == RouterRouteKind::Custom)
+                    .collect();
+                routers.push(RouterState { router, routes });
+            }
+
+            state.vpcs.push(VpcState {
+                vpc,
+                subnets,
+                routers,
+                firewall_rules,
+            });
+        }
+
+        Ok(state)
+    }
+
+    /**
+     * Plan the changes that bring a project in line with `manifest`.
+     */
+    pub async fn plan_manifest(
+        &self,
+        organization_name: &str,
+        project_name: &str,
+        manifest: &Manifest,
+        prune: bool,
+    ) -> Result<Plan> {
+        let state = self.manifest_state(organization_name, project_name).await?;
+        manifest.plan(&state, prune)
+    }
+
+    /**
+     * Apply a plan, calling `progress` with the step number, the number of steps and the step
+     * before each step runs.
+     *
+     * Plans with conflicts are refused. Stops at the first failing step.
+     */
+    pub async fn apply_manifest<F>(
+        &self,
+        organization_name: &str,
+        project_name: &str,
+        plan: &Plan,
+        mut progress: F,
+    ) -> Result<()>
+    where
+        F: FnMut(usize, usize, &Step),
+    {
+        if !plan.conflicts.is_empty() {
+            bail!(
+                "refusing to apply a plan with conflicts: {}",
+                plan.conflicts.join("; ")
+            );
+        }
+
+        let (org, project) = (organization_name, project_name);
+        let disk_ops = DiskOps::new(self.client.clone(), org, project);
+        for (i, step) in plan.steps.iter().enumerate() {
+            progress(i + 1, plan.steps.len(), step);
+
+            match step {
+                Step::CreateVpc(vpc) => {
+                    self.client.vpcs().post(org, project, vpc).await?;
+                }
+                Step::UpdateVpc { vpc, update } => {
+                    self.client.vpcs().put(org, project, vpc, update).await?;
+                }
+                Step::DeleteVpc(vpc) => {
+                    self.client.vpcs().delete(org, project, vpc).await?;
+                }
+                Step::CreateSubnet { vpc, subnet } => {
+                    self.client
+                        .subnets()
+                        .post(org, project, vpc, subnet)
+                        .await?;
+                }
+                Step::UpdateSubnet { vpc, name, update } => {
+                    self.client
+                        .subnets()
+                        .put(org, project, name, vpc, update)
+                        .await?;
+                }
+                Step::DeleteSubnet { vpc, name } => {
+                    self.client
+                        .subnets()
+                        .delete(org, project, name, vpc)
+                        .await?;
+                }
+                Step::CreateRouter { vpc, router } => {
+                    self.client
+                        .routers()
+                        .post(org, project, vpc, router)
+                        .await?;
+                }
+                Step::UpdateRouter { vpc, name, update } => {
+                    self.client
+                        .routers()
+                        .put(org, project, name, vpc, update)
+                        .await?;
+                }
+                Step::DeleteRouter { vpc, name } => {
+                    self.client
+                        .routers()
+                        .delete(org, project, name, vpc)
+                        .await?;
+                }
+                Step::CreateRoute { vpc, router, route } => {
+                    self.client
+                        .routes()
+                        .post(org, project, router, vpc, route)
+                        .await?;
+                }
+                Step::UpdateRoute {
+                    vpc,
+                    router,
+                    name,
+                    update,
+                } => {
+                    self.client
+                        .routes()
+                        .put(org, project, name, router, vpc, update)
+                        .await?;
+                }
+                Step::DeleteRoute { vpc, router, name } => {
+                    self.client
+                        .routes()
+                        .delete(org, project, name, router, vpc)
+                        .await?;
+                }
+                Step::PutFirewallRules { vpc, rules } => {
+                    self.client
+                        .vpcs()
+                        .firewall_rules_put(org, project, vpc, rules)
+                        .await?;
+                }
+                Step::CreateDisk(disk) => {
+                    self.client.disks().post(org, project, disk).await?;
+                    disk_ops
+                        .wait_for_disk(&disk.name, "detached", |s| *s == DiskState::Detached)
+                        .await?;
+                }
+                Step::DeleteDisk(name) => {
+                    self.client.disks().delete(name, org, project).await?;
+                }
+                Step::CreateInstance(instance) => {
+                    self.client.instances().post(org, project, instance).await?;
+                }
+                Step::DeleteInstance(name) => {
+                    let instances = self.client.instances();
+                    instances
+                        .stop_and_wait(name, org, project, &Wait::default())
+                        .await?;
+                    instances.delete(name, org, project).await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,384 @@
+//! Network interface workflows: add a NIC, change the primary NIC, and move
+//! a NIC to another subnet.
+//!
+//! Interfaces can only be changed while their instance is stopped, and a new
+//! primary only takes effect when the instance boots. Each helper stops the
+//! instance if it is running, makes the change, starts the instance again, and
+//! then checks the instance's interfaces ended up as asked.
+//!
+//! ```no_run
+//! # async fn multi_home() -> anyhow::Result<()> {
+//! use oxide_api::{disk_ops::Wait, types::NetworkInterfaceCreate, Client};
+//!
+//! let oxide = Client::new_from_env();
+//! let instances = oxide.instances();
+//! let wait = Wait::default();
+//! instances
+//!     .add_nic_and_wait(
+//!         "fw-1",
+//!         "maze-war",
+//!         "prod",
+//!         &NetworkInterfaceCreate::builder()
+//!             .name("net1")
+//!             .description("dmz")
+//!             .vpc_name("default")
+//!             .subnet_name("dmz")
+//!             .build()?,
+//!         &wait,
+//!     )
+//!     .await?;
+//! instances
+//!     .set_primary_nic("fw-1", "net1", "maze-war", "prod", &wait)
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+
+use anyhow::{bail, Context, Result};
+
+use crate::{
+    disk_ops::Wait,
+    types::{NameSortMode, NetworkInterface, NetworkInterfaceCreate, NetworkInterfaceUpdate},
+};
+
+/// The instance's primary interface. Fails unless exactly one interface is
+/// primary.
+pub fn primary_interface(interfaces: &[NetworkInterface]) -> Result<&NetworkInterface> {
+    let primaries: Vec<&NetworkInterface> = interfaces.iter().filter(|i| i.primary).collect();
+    match primaries.as_slice() {
+        [primary] => Ok(primary),
+        [] => bail!("no primary network interface"),
+        _ => {
+            let names: Vec<&str> = primaries.iter().map(|i| i.name.as_str()).collect();
+            bail!(
+                "more than one primary network interface: {}",
+                names.join(", ")
+            )
+        }
+    }
+}
+
+fn find_interface<'a>(
+    interfaces: &'a [NetworkInterface],
+    interface_name: &str,
+) -> Result<&'a NetworkInterface> {
+    interfaces
+        .iter()
+        .find(|i| i.name == interface_name)
+        .with_context(|| format!("no network interface named {}", interface_name))
+}
+
+impl crate::instances::Instances {
+    /**
+     * Run `op` with the instance stopped, then start the instance again if it was running,
+     * whether or not `op` succeeded.
+     */
+    async fn while_stopped<T, Fut>(
+        &self,
+        instance_name: &str,
+        organization_name: &str,
+        project_name: &str,
+        wait: &Wait,
+        op: Fut,
+    ) -> Result<T>
+    where
+        Fut: Future<Output = Result<T>>,
+    {
+        let was_running = self
+            .stop_and_wait(instance_name, organization_name, project_name, wait)
+            .await?;
+
+        let result = op.await;
+
+        let restart = if was_running {
+            vec![instance_name]
+        } else {
+            vec![]
+        };
+        self.restart_all(&restart, organization_name, project_name, wait, result)
+            .await
+    }
+
+    /**
+     * Add a network interface to an instance, stopping and restarting the instance if needed.
+     *
+     * Returns the interface as the instance sees it once it is back up.
+     */
+    pub async fn add_nic_and_wait(
+        &self,
+        instance_name: &str,
+        organization_name: &str,
+        project_name: &str,
+        body: &NetworkInterfaceCreate,
+        wait: &Wait,
+    ) -> Result<NetworkInterface> {
+        let created = self
+            .while_stopped(
+                instance_name,
+                organization_name,
+                project_name,
+                wait,
+                async {
+                    self.network_interfaces_post(
+                        instance_name,
+                        organization_name,
+                        project_name,
+                        body,
+                    )
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "adding network interface {} to {}",
+                            body.name, instance_name
+                        )
+                    })
+                },
+            )
+            .await?;
+
+        let interfaces = self
+            .network_interfaces_get_all(
+                instance_name,
+                organization_name,
+                project_name,
+                NameSortMode::NameAscending,
+            )
+            .await?;
+        let nic = find_interface(&interfaces, &body.name)?;
+        if nic.id != created.id {
+            bail!(
+                "network interface {} on {} is not the one just created",
+                body.name,
+                instance_name
+            );
+        }
+        if let Some(ip) = body.ip {
+            if nic.ip != ip {
+                bail!(
+                    "network interface {} on {} has address {}, not {}",
+                    body.name,
+                    instance_name,
+                    nic.ip,
+                    ip
+                );
+            }
+        }
+        Ok(nic.clone())
+    }
+
+    /**
+     * Make one of an instance's interfaces its primary, stopping and restarting the instance so
+     * the change takes effect.
+     *
+     * Returns the instance's interfaces afterwards. Nothing is changed if the interface is
+     * already the primary.
+     */
+    pub async fn set_primary_nic(
+        &self,
+        instance_name: &str,
+        interface_name: &str,
+        organization_name: &str,
+        project_name: &str,
+        wait: &Wait,
+    ) -> Result<Vec<NetworkInterface>> {
+        let interfaces = self
+            .network_interfaces_get_all(
+                instance_name,
+                organization_name,
+                project_name,
+                NameSortMode::NameAscending,
+            )
+            .await?;
+        let nic = find_interface(&interfaces, interface_name)?;
+        if nic.primary {
+            return Ok(interfaces);
+        }
+
+        let body = NetworkInterfaceUpdate::builder()
+            .name(nic.name.as_str())
+            .description(nic.description.as_str())
+            .primary(true)
+            .build()?;
+        self.while_stopped(
+            instance_name,
+            organization_name,
+            project_name,
+            wait,
+            async {
+                self.network_interfaces_put_interface(
+                    instance_name,
+                    interface_name,
+                    organization_name,
+                    project_name,
+                    &body,
+                )
+                .await
+                .with_context(|| {
+                    format!(
+                        "making {} the primary network interface of {}",
+                        interface_name, instance_name
+                    )
+                })
+            },
+        )
+        .await?;
+
+        let interfaces = self
+            .network_interfaces_get_all(
+                instance_name,
+                organization_name,
+                project_name,
+                NameSortMode::NameAscending,
+            )
+            .await?;
+        let primary = primary_interface(&interfaces)
+            .with_context(|| format!("checking network interfaces of {}", instance_name))?;
+        if primary.name != interface_name {
+            bail!(
+                "{} is still the primary network interface of {}",
+                primary.name,
+                instance_name
+            );
+        }
+        Ok(interfaces)
+    }
+
+    /**
+     * Move an interface to another subnet of the same VPC.
+     *
+     * Interface addresses cannot be changed in place, so the interface is deleted and created
+     * again with the same name and description in `subnet_name`, with a new address. If it cannot
+     * be created in the new subnet it is recreated with its old address in its old subnet before
+     * the error is returned.
+     *
+     * The primary interface can only be moved while it is the instance's only interface; use
+     * `set_primary_nic` to make another interface the primary first.
+     */
+    pub async fn move_nic_to_subnet(
+        &self,
+        instance_name: &str,
+        interface_name: &str,
+        organization_name: &str,
+        project_name: &str,
+        subnet_name: &str,
+        wait: &Wait,
+    ) -> Result<NetworkInterface> {
+        let interfaces = self
+            .network_interfaces_get_all(
+                instance_name,
+                organization_name,
+                project_name,
+                NameSortMode::NameAscending,
+            )
+            .await?;
+        let nic = find_interface(&interfaces, interface_name)?.clone();
+        if nic.primary && interfaces.len() > 1 {
+            bail!(
+                "{} is the primary network interface of {}; make another interface the primary \
+                 before moving it",
+                interface_name,
+                instance_name
+            );
+        }
+
+        let vpcs = self.client.vpcs();
+        let vpc = vpcs.view(&nic.vpc_id).await?;
+        let old_subnet = vpcs.subnet_view(&nic.subnet_id).await?;
+        if old_subnet.name == subnet_name {
+            return Ok(nic);
+        }
+
+        let body = NetworkInterfaceCreate::builder()
+            .name(nic.name.as_str())
+            .description(nic.description.as_str())
+            .subnet_name(subnet_name)
+            .vpc_name(vpc.name.as_str())
+            .build()?;
+        let created = self
+            .while_stopped(
+                instance_name,
+                organization_name,
+                project_name,
+                wait,
+                async {
+                    self.network_interfaces_delete_interface(
+                        instance_name,
+                        interface_name,
+                        organization_name,
+                        project_name,
+                    )
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "deleting network interface {} of {}",
+                            interface_name, instance_name
+                        )
+                    })?;
+
+                    match self
+                        .network_interfaces_post(
+                            instance_name,
+                            organization_name,
+                            project_name,
+                            &body,
+                        )
+                        .await
+                    {
+                        Ok(created) => Ok(created),
+                        Err(e) => {
+                            log::warn!(
+                                "creating network interface {} in subnet {} failed, recreating it \
+                                 in {}: {}",
+                                interface_name,
+                                subnet_name,
+                                old_subnet.name,
+                                e
+                            );
+                            let restore = NetworkInterfaceCreate {
+                                ip: Some(nic.ip),
+                                subnet_name: old_subnet.name.to_string(),
+                                ..body.clone()
+                            };
+                            self.network_interfaces_post(
+                                instance_name,
+                                organization_name,
+                                project_name,
+                                &restore,
+                            )
+                            .await
+                            .with_context(|| {
+                                format!(
+                                    "network interface {} of {} is deleted: recreating it in {} \
+                                     failed after: {}",
+                                    interface_name, instance_name, old_subnet.name, e
+                                )
+                            })?;
+                            Err(e)
+                        }
+                    }
+                },
+            )
+            .await?;
+
+        let interfaces = self
+            .network_interfaces_get_all(
+                instance_name,
+                organization_name,
+                project_name,
+                NameSortMode::NameAscending,
+            )
+            .await?;
+        let moved = find_interface(&interfaces, interface_name)?;
+        if moved.id != created.id || moved.subnet_id != created.subnet_id {
+            bail!(
+                "network interface {} of {} was not moved to subnet {}",
+                interface_name,
+                instance_name,
+                subnet_name
+            );
+        }
+        Ok(moved.clone())
+    }
+}
@@ -0,0 +1,4429 @@
+//! Metadata of every method of the client: the operation it calls, where its
+//! parameters go and the types it sends and returns. It maps request paths
+//! back to operations without the API definition.
+
+/// Where a parameter goes in the request.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParameterLocation {
+    Path,
+    Query,
+    Header,
+    Cookie,
+}
+
+/// A parameter of an operation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParameterInfo {
+    /// The name in the API, which is also the name in the path template.
+    pub name: &'static str,
+    pub location: ParameterLocation,
+    /// The Rust type of the method's argument.
+    pub rust_type: &'static str,
+    pub required: bool,
+}
+
+/// A method of the client and the operation it calls.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OperationInfo {
+    /// The id in the API definition.
+    pub operation_id: &'static str,
+    /// The id the overrides gave the operation instead, which the generated
+    /// code is named after.
+    pub renamed_operation_id: Option<&'static str>,
+    /// The tag of the operation, which is also the client's accessor for the
+    /// method: `client.{tag}().{function}()`.
+    pub tag: &'static str,
+    pub function: &'static str,
+    /// The HTTP method, in upper case.
+    pub method: &'static str,
+    /// The path template, with parameters in braces.
+    pub path: &'static str,
+    /// The parameters of the method. Those that fetch every page leave out
+    /// the pagination parameters.
+    pub parameters: &'static [ParameterInfo],
+    /// The Rust type of the request body, if there is one.
+    pub body_type: Option<&'static str>,
+    pub response_type: &'static str,
+    /// Whether the operation answers with pages of items.
+    pub paginated: bool,
+}
+
+impl OperationInfo {
+    /// The values of the path parameters if `path` matches the path template,
+    /// ignoring any query string.
+    pub fn match_path(&self, path: &str) -> Option<Vec<(&'static str, String)>> {
+        let path = path.split('?').next().unwrap_or_default();
+        let segments: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+        let template: Vec<&'static str> = self.path.trim_start_matches('/').split('/').collect();
+        if template.len() != segments.len() {
+            return None;
+        }
+
+        let mut params = Vec::new();
+        for (t, s) in template.iter().zip(&segments) {
+            match t.strip_prefix('{').and_then(|t| t.strip_suffix('}')) {
+                Some(param) => params.push((
+                    param,
+                    percent_encoding::percent_decode_str(s)
+                        .decode_utf8_lossy()
+                        .to_string(),
+                )),
+                None if t == s => {}
+                None => return None,
+            }
+        }
+        Some(params)
+    }
+
+    fn literals(&self) -> usize {
+        self.path.split('/').filter(|s| !s.starts_with('{')).count()
+    }
+}
+
+/// The operation a request calls, preferring literal path segments over
+/// parameters. The method is compared without regard to case.
+///
+/// Operations with a method fetching a single page and one fetching every
+/// page are found as the former.
+pub fn find(method: &str, path: &str) -> Option<&'static OperationInfo> {
+    let mut best: Option<&'static OperationInfo> = None;
+    for o in ALL.iter().filter(|o| o.method.eq_ignore_ascii_case(method)) {
+        if o.match_path(path).is_some() && best.map(|b| o.literals() > b.literals()).unwrap_or(true)
+        {
+            best = Some(o);
+        }
+    }
+    best
+}
+
+/// The methods calling the operation with `operation_id` in the API
+/// definition.
+pub fn by_operation_id(operation_id: &str) -> impl Iterator<Item = &'static OperationInfo> + '_ {
+    ALL.iter().filter(move |o| o.operation_id == operation_id)
+}
+
+/// Every method of the client, in the order of the paths of the API definition.
+pub static ALL: &[OperationInfo] = &[
+    OperationInfo {
+        operation_id: "disk_view_by_id",
+        renamed_operation_id: None,
+        tag: "disks",
+        function: "view",
+        method: "GET",
+        path: "/by-id/disks/{id}",
+        parameters: &[ParameterInfo {
+            name: "id",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "crate::types::Disk",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "image_global_view_by_id",
+        renamed_operation_id: None,
+        tag: "images_global",
+        function: "image_global_view",
+        method: "GET",
+        path: "/by-id/global-images/{id}",
+        parameters: &[ParameterInfo {
+            name: "id",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "crate::types::GlobalImage",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "image_view_by_id",
+        renamed_operation_id: None,
+        tag: "images",
+        function: "view",
+        method: "GET",
+        path: "/by-id/images/{id}",
+        parameters: &[ParameterInfo {
+            name: "id",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "crate::types::Image",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "instance_view_by_id",
+        renamed_operation_id: None,
+        tag: "instances",
+        function: "view",
+        method: "GET",
+        path: "/by-id/instances/{id}",
+        parameters: &[ParameterInfo {
+            name: "id",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "crate::types::Instance",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "instance_network_interface_view_by_id",
+        renamed_operation_id: None,
+        tag: "instances",
+        function: "network_interface_view",
+        method: "GET",
+        path: "/by-id/network-interfaces/{id}",
+        parameters: &[ParameterInfo {
+            name: "id",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "crate::types::NetworkInterface",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "organization_view_by_id",
+        renamed_operation_id: None,
+        tag: "organizations",
+        function: "view",
+        method: "GET",
+        path: "/by-id/organizations/{id}",
+        parameters: &[ParameterInfo {
+            name: "id",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "crate::types::Organization",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "project_view_by_id",
+        renamed_operation_id: None,
+        tag: "projects",
+        function: "view",
+        method: "GET",
+        path: "/by-id/projects/{id}",
+        parameters: &[ParameterInfo {
+            name: "id",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "crate::types::Project",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "snapshot_view_by_id",
+        renamed_operation_id: None,
+        tag: "snapshots",
+        function: "view",
+        method: "GET",
+        path: "/by-id/snapshots/{id}",
+        parameters: &[ParameterInfo {
+            name: "id",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "crate::types::Snapshot",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "vpc_router_route_view_by_id",
+        renamed_operation_id: None,
+        tag: "vpcs",
+        function: "router_route_view",
+        method: "GET",
+        path: "/by-id/vpc-router-routes/{id}",
+        parameters: &[ParameterInfo {
+            name: "id",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "crate::types::RouterRoute",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "vpc_router_view_by_id",
+        renamed_operation_id: None,
+        tag: "vpcs",
+        function: "router_view",
+        method: "GET",
+        path: "/by-id/vpc-routers/{id}",
+        parameters: &[ParameterInfo {
+            name: "id",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "crate::types::VpcRouter",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "vpc_subnet_view_by_id",
+        renamed_operation_id: None,
+        tag: "vpcs",
+        function: "subnet_view",
+        method: "GET",
+        path: "/by-id/vpc-subnets/{id}",
+        parameters: &[ParameterInfo {
+            name: "id",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "crate::types::VpcSubnet",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "vpc_view_by_id",
+        renamed_operation_id: None,
+        tag: "vpcs",
+        function: "view",
+        method: "GET",
+        path: "/by-id/vpcs/{id}",
+        parameters: &[ParameterInfo {
+            name: "id",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "crate::types::Vpc",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "device_auth_request",
+        renamed_operation_id: None,
+        tag: "hidden",
+        function: "device_auth_request",
+        method: "POST",
+        path: "/device/auth",
+        parameters: &[],
+        body_type: None,
+        response_type: "()",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "device_auth_confirm",
+        renamed_operation_id: None,
+        tag: "hidden",
+        function: "device_auth_confirm",
+        method: "POST",
+        path: "/device/confirm",
+        parameters: &[],
+        body_type: Some("crate::types::DeviceAuthVerify"),
+        response_type: "()",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "device_access_token",
+        renamed_operation_id: None,
+        tag: "hidden",
+        function: "device_access_token",
+        method: "POST",
+        path: "/device/token",
+        parameters: &[],
+        body_type: None,
+        response_type: "()",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "global_policy_view",
+        renamed_operation_id: None,
+        tag: "policy",
+        function: "global_view",
+        method: "GET",
+        path: "/global/policy",
+        parameters: &[],
+        body_type: None,
+        response_type: "crate::types::FleetRolePolicy",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "global_policy_update",
+        renamed_operation_id: None,
+        tag: "policy",
+        function: "global_update",
+        method: "PUT",
+        path: "/global/policy",
+        parameters: &[],
+        body_type: Some("crate::types::FleetRolePolicy"),
+        response_type: "crate::types::FleetRolePolicy",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "rack_list",
+        renamed_operation_id: Some("hardware_racks_get"),
+        tag: "racks",
+        function: "get_page",
+        method: "GET",
+        path: "/hardware/racks",
+        parameters: &[
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::IdSortMode",
+                required: false,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::Rack>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "rack_list",
+        renamed_operation_id: Some("hardware_racks_get"),
+        tag: "racks",
+        function: "get_all",
+        method: "GET",
+        path: "/hardware/racks",
+        parameters: &[ParameterInfo {
+            name: "sort_by",
+            location: ParameterLocation::Query,
+            rust_type: "crate::types::IdSortMode",
+            required: false,
+        }],
+        body_type: None,
+        response_type: "Vec<crate::types::Rack>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "rack_view",
+        renamed_operation_id: Some("hardware_racks_get_rack"),
+        tag: "racks",
+        function: "get",
+        method: "GET",
+        path: "/hardware/racks/{rack_id}",
+        parameters: &[ParameterInfo {
+            name: "rack_id",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "crate::types::Rack",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "sled_list",
+        renamed_operation_id: Some("hardware_sleds_get"),
+        tag: "sleds",
+        function: "get_page",
+        method: "GET",
+        path: "/hardware/sleds",
+        parameters: &[
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::IdSortMode",
+                required: false,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::Sled>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "sled_list",
+        renamed_operation_id: Some("hardware_sleds_get"),
+        tag: "sleds",
+        function: "get_all",
+        method: "GET",
+        path: "/hardware/sleds",
+        parameters: &[ParameterInfo {
+            name: "sort_by",
+            location: ParameterLocation::Query,
+            rust_type: "crate::types::IdSortMode",
+            required: false,
+        }],
+        body_type: None,
+        response_type: "Vec<crate::types::Sled>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "sled_view",
+        renamed_operation_id: Some("hardware_sleds_get_sled"),
+        tag: "sleds",
+        function: "get",
+        method: "GET",
+        path: "/hardware/sleds/{sled_id}",
+        parameters: &[ParameterInfo {
+            name: "sled_id",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "crate::types::Sled",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "image_global_list",
+        renamed_operation_id: Some("images_get"),
+        tag: "images_global",
+        function: "images_get",
+        method: "GET",
+        path: "/images",
+        parameters: &[
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameSortMode",
+                required: false,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::GlobalImage>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "image_global_list",
+        renamed_operation_id: Some("images_get"),
+        tag: "images_global",
+        function: "images_get_all",
+        method: "GET",
+        path: "/images",
+        parameters: &[ParameterInfo {
+            name: "sort_by",
+            location: ParameterLocation::Query,
+            rust_type: "crate::types::NameSortMode",
+            required: false,
+        }],
+        body_type: None,
+        response_type: "Vec<crate::types::GlobalImage>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "image_global_create",
+        renamed_operation_id: Some("images_post"),
+        tag: "images_global",
+        function: "images_post",
+        method: "POST",
+        path: "/images",
+        parameters: &[],
+        body_type: Some("crate::types::GlobalImageCreate"),
+        response_type: "crate::types::GlobalImage",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "image_global_view",
+        renamed_operation_id: Some("images_get_image"),
+        tag: "images_global",
+        function: "images_get_image",
+        method: "GET",
+        path: "/images/{image_name}",
+        parameters: &[ParameterInfo {
+            name: "image_name",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "crate::types::GlobalImage",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "image_global_delete",
+        renamed_operation_id: Some("images_delete_image"),
+        tag: "images_global",
+        function: "images_delete_image",
+        method: "DELETE",
+        path: "/images/{image_name}",
+        parameters: &[ParameterInfo {
+            name: "image_name",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "()",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "ip_pool_list",
+        renamed_operation_id: Some("ip_pools_get"),
+        tag: "ip_pools",
+        function: "get_page",
+        method: "GET",
+        path: "/ip-pools",
+        parameters: &[
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameOrIdSortMode",
+                required: false,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::IpPool>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "ip_pool_list",
+        renamed_operation_id: Some("ip_pools_get"),
+        tag: "ip_pools",
+        function: "get_all",
+        method: "GET",
+        path: "/ip-pools",
+        parameters: &[ParameterInfo {
+            name: "sort_by",
+            location: ParameterLocation::Query,
+            rust_type: "crate::types::NameOrIdSortMode",
+            required: false,
+        }],
+        body_type: None,
+        response_type: "Vec<crate::types::IpPool>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "ip_pool_create",
+        renamed_operation_id: Some("ip_pools_post"),
+        tag: "ip_pools",
+        function: "post",
+        method: "POST",
+        path: "/ip-pools",
+        parameters: &[],
+        body_type: Some("crate::types::IpPoolCreate"),
+        response_type: "crate::types::IpPool",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "ip_pool_view",
+        renamed_operation_id: Some("ip_pools_get_ip_pool"),
+        tag: "ip_pools",
+        function: "get_pool",
+        method: "GET",
+        path: "/ip-pools/{pool_name}",
+        parameters: &[ParameterInfo {
+            name: "pool_name",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "crate::types::IpPool",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "ip_pool_update",
+        renamed_operation_id: Some("ip_pools_put_ip_pool"),
+        tag: "ip_pools",
+        function: "put_pool",
+        method: "PUT",
+        path: "/ip-pools/{pool_name}",
+        parameters: &[ParameterInfo {
+            name: "pool_name",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: Some("crate::types::IpPoolUpdate"),
+        response_type: "crate::types::IpPool",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "ip_pool_delete",
+        renamed_operation_id: Some("ip_pools_delete_ip_pool"),
+        tag: "ip_pools",
+        function: "delete_pool",
+        method: "DELETE",
+        path: "/ip-pools/{pool_name}",
+        parameters: &[ParameterInfo {
+            name: "pool_name",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "()",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "ip_pool_range_list",
+        renamed_operation_id: Some("ip_pool_ranges_get"),
+        tag: "ip_pools",
+        function: "ranges_get",
+        method: "GET",
+        path: "/ip-pools/{pool_name}/ranges",
+        parameters: &[
+            ParameterInfo {
+                name: "pool_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::IpPoolRange>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "ip_pool_range_list",
+        renamed_operation_id: Some("ip_pool_ranges_get"),
+        tag: "ip_pools",
+        function: "ranges_get_all",
+        method: "GET",
+        path: "/ip-pools/{pool_name}/ranges",
+        parameters: &[ParameterInfo {
+            name: "pool_name",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "Vec<crate::types::IpPoolRange>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "ip_pool_range_add",
+        renamed_operation_id: Some("ip_pool_ranges_add"),
+        tag: "ip_pools",
+        function: "ranges_add",
+        method: "POST",
+        path: "/ip-pools/{pool_name}/ranges/add",
+        parameters: &[ParameterInfo {
+            name: "pool_name",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: Some("crate::types::IpRange"),
+        response_type: "crate::types::IpPoolRange",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "ip_pool_range_remove",
+        renamed_operation_id: Some("ip_pool_ranges_delete"),
+        tag: "ip_pools",
+        function: "ranges_delete",
+        method: "POST",
+        path: "/ip-pools/{pool_name}/ranges/remove",
+        parameters: &[ParameterInfo {
+            name: "pool_name",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: Some("crate::types::IpRange"),
+        response_type: "()",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "ip_pool_service_view",
+        renamed_operation_id: None,
+        tag: "ip_pools",
+        function: "service_view",
+        method: "GET",
+        path: "/ip-pools-service/{rack_id}",
+        parameters: &[ParameterInfo {
+            name: "rack_id",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "crate::types::IpPool",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "ip_pool_service_range_list",
+        renamed_operation_id: None,
+        tag: "ip_pools",
+        function: "service_range_list",
+        method: "GET",
+        path: "/ip-pools-service/{rack_id}/ranges",
+        parameters: &[
+            ParameterInfo {
+                name: "rack_id",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::IpPoolRange>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "ip_pool_service_range_list",
+        renamed_operation_id: None,
+        tag: "ip_pools",
+        function: "service_range_list_all",
+        method: "GET",
+        path: "/ip-pools-service/{rack_id}/ranges",
+        parameters: &[ParameterInfo {
+            name: "rack_id",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "Vec<crate::types::IpPoolRange>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "ip_pool_service_range_add",
+        renamed_operation_id: None,
+        tag: "ip_pools",
+        function: "service_range_add",
+        method: "POST",
+        path: "/ip-pools-service/{rack_id}/ranges/add",
+        parameters: &[ParameterInfo {
+            name: "rack_id",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: Some("crate::types::IpRange"),
+        response_type: "crate::types::IpPoolRange",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "ip_pool_service_range_remove",
+        renamed_operation_id: None,
+        tag: "ip_pools",
+        function: "service_range_remove",
+        method: "POST",
+        path: "/ip-pools-service/{rack_id}/ranges/remove",
+        parameters: &[ParameterInfo {
+            name: "rack_id",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: Some("crate::types::IpRange"),
+        response_type: "()",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "spoof_login",
+        renamed_operation_id: None,
+        tag: "hidden",
+        function: "spoof_login",
+        method: "POST",
+        path: "/login",
+        parameters: &[],
+        body_type: Some("crate::types::SpoofLoginBody"),
+        response_type: "()",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "login",
+        renamed_operation_id: None,
+        tag: "login",
+        function: "get",
+        method: "GET",
+        path: "/login/{silo_name}/{provider_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "provider_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "silo_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "()",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "consume_credentials",
+        renamed_operation_id: None,
+        tag: "login",
+        function: "consume_credentials",
+        method: "POST",
+        path: "/login/{silo_name}/{provider_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "provider_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "silo_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: Some("reqwest::Body"),
+        response_type: "()",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "logout",
+        renamed_operation_id: None,
+        tag: "hidden",
+        function: "logout",
+        method: "POST",
+        path: "/logout",
+        parameters: &[],
+        body_type: None,
+        response_type: "()",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "organization_list",
+        renamed_operation_id: Some("organizations_get"),
+        tag: "organizations",
+        function: "get_page",
+        method: "GET",
+        path: "/organizations",
+        parameters: &[
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameOrIdSortMode",
+                required: false,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::Organization>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "organization_list",
+        renamed_operation_id: Some("organizations_get"),
+        tag: "organizations",
+        function: "get_all",
+        method: "GET",
+        path: "/organizations",
+        parameters: &[ParameterInfo {
+            name: "sort_by",
+            location: ParameterLocation::Query,
+            rust_type: "crate::types::NameOrIdSortMode",
+            required: false,
+        }],
+        body_type: None,
+        response_type: "Vec<crate::types::Organization>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "organization_create",
+        renamed_operation_id: Some("organizations_post"),
+        tag: "organizations",
+        function: "post",
+        method: "POST",
+        path: "/organizations",
+        parameters: &[],
+        body_type: Some("crate::types::OrganizationCreate"),
+        response_type: "crate::types::Organization",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "organization_view",
+        renamed_operation_id: Some("organizations_get_organization"),
+        tag: "organizations",
+        function: "get",
+        method: "GET",
+        path: "/organizations/{organization_name}",
+        parameters: &[ParameterInfo {
+            name: "organization_name",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "crate::types::Organization",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "organization_update",
+        renamed_operation_id: Some("organizations_put_organization"),
+        tag: "organizations",
+        function: "put",
+        method: "PUT",
+        path: "/organizations/{organization_name}",
+        parameters: &[ParameterInfo {
+            name: "organization_name",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: Some("crate::types::OrganizationUpdate"),
+        response_type: "crate::types::Organization",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "organization_delete",
+        renamed_operation_id: Some("organizations_delete_organization"),
+        tag: "organizations",
+        function: "delete",
+        method: "DELETE",
+        path: "/organizations/{organization_name}",
+        parameters: &[ParameterInfo {
+            name: "organization_name",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "()",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "organization_policy_view",
+        renamed_operation_id: Some("organization_get_policy"),
+        tag: "organizations",
+        function: "get_policy",
+        method: "GET",
+        path: "/organizations/{organization_name}/policy",
+        parameters: &[ParameterInfo {
+            name: "organization_name",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "crate::types::OrganizationRolePolicy",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "organization_policy_update",
+        renamed_operation_id: Some("organization_put_policy"),
+        tag: "organizations",
+        function: "put_policy",
+        method: "PUT",
+        path: "/organizations/{organization_name}/policy",
+        parameters: &[ParameterInfo {
+            name: "organization_name",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: Some("crate::types::OrganizationRolePolicy"),
+        response_type: "crate::types::OrganizationRolePolicy",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "project_list",
+        renamed_operation_id: Some("organization_projects_get"),
+        tag: "projects",
+        function: "get_page",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects",
+        parameters: &[
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameOrIdSortMode",
+                required: false,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::Project>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "project_list",
+        renamed_operation_id: Some("organization_projects_get"),
+        tag: "projects",
+        function: "get_all",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects",
+        parameters: &[
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameOrIdSortMode",
+                required: false,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::Project>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "project_create",
+        renamed_operation_id: Some("organization_projects_post"),
+        tag: "projects",
+        function: "post",
+        method: "POST",
+        path: "/organizations/{organization_name}/projects",
+        parameters: &[ParameterInfo {
+            name: "organization_name",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: Some("crate::types::ProjectCreate"),
+        response_type: "crate::types::Project",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "project_view",
+        renamed_operation_id: Some("organization_projects_get_project"),
+        tag: "projects",
+        function: "get",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "crate::types::Project",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "project_update",
+        renamed_operation_id: Some("organization_projects_put_project"),
+        tag: "projects",
+        function: "put",
+        method: "PUT",
+        path: "/organizations/{organization_name}/projects/{project_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: Some("crate::types::ProjectUpdate"),
+        response_type: "crate::types::Project",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "project_delete",
+        renamed_operation_id: Some("organization_projects_delete_project"),
+        tag: "projects",
+        function: "delete",
+        method: "DELETE",
+        path: "/organizations/{organization_name}/projects/{project_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "()",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "disk_list",
+        renamed_operation_id: Some("project_disks_get"),
+        tag: "disks",
+        function: "get_page",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/disks",
+        parameters: &[
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameSortMode",
+                required: false,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::Disk>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "disk_list",
+        renamed_operation_id: Some("project_disks_get"),
+        tag: "disks",
+        function: "get_all",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/disks",
+        parameters: &[
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameSortMode",
+                required: false,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::Disk>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "disk_create",
+        renamed_operation_id: Some("project_disks_post"),
+        tag: "disks",
+        function: "post",
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/disks",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: Some("crate::types::DiskCreate"),
+        response_type: "crate::types::Disk",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "disk_view",
+        renamed_operation_id: Some("project_disks_get_disk"),
+        tag: "disks",
+        function: "get",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/disks/{disk_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "disk_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "crate::types::Disk",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "disk_delete",
+        renamed_operation_id: Some("project_disks_delete_disk"),
+        tag: "disks",
+        function: "delete",
+        method: "DELETE",
+        path: "/organizations/{organization_name}/projects/{project_name}/disks/{disk_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "disk_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "()",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "disk_metrics_list",
+        renamed_operation_id: None,
+        tag: "disks",
+        function: "metrics_list",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/disks/{disk_name}/\
+               metrics/{metric_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "disk_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "metric_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "end_time",
+                location: ParameterLocation::Query,
+                rust_type: "Option<chrono::DateTime<chrono::Utc>>",
+                required: false,
+            },
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+            ParameterInfo {
+                name: "start_time",
+                location: ParameterLocation::Query,
+                rust_type: "Option<chrono::DateTime<chrono::Utc>>",
+                required: false,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::Measurement>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "disk_metrics_list",
+        renamed_operation_id: None,
+        tag: "disks",
+        function: "metrics_list_all",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/disks/{disk_name}/\
+               metrics/{metric_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "disk_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "metric_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "end_time",
+                location: ParameterLocation::Query,
+                rust_type: "Option<chrono::DateTime<chrono::Utc>>",
+                required: false,
+            },
+            ParameterInfo {
+                name: "start_time",
+                location: ParameterLocation::Query,
+                rust_type: "Option<chrono::DateTime<chrono::Utc>>",
+                required: false,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::Measurement>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "image_list",
+        renamed_operation_id: Some("project_images_get"),
+        tag: "images",
+        function: "get_page",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/images",
+        parameters: &[
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameSortMode",
+                required: false,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::Image>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "image_list",
+        renamed_operation_id: Some("project_images_get"),
+        tag: "images",
+        function: "get_all",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/images",
+        parameters: &[
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameSortMode",
+                required: false,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::Image>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "image_create",
+        renamed_operation_id: Some("project_images_post"),
+        tag: "images",
+        function: "post",
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/images",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: Some("crate::types::ImageCreate"),
+        response_type: "crate::types::Image",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "image_view",
+        renamed_operation_id: Some("project_images_get_image"),
+        tag: "images",
+        function: "get",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/images/{image_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "image_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "crate::types::Image",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "image_delete",
+        renamed_operation_id: Some("project_images_delete_image"),
+        tag: "images",
+        function: "delete",
+        method: "DELETE",
+        path: "/organizations/{organization_name}/projects/{project_name}/images/{image_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "image_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "()",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "instance_list",
+        renamed_operation_id: Some("project_instances_get"),
+        tag: "instances",
+        function: "get_page",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances",
+        parameters: &[
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameSortMode",
+                required: false,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::Instance>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "instance_list",
+        renamed_operation_id: Some("project_instances_get"),
+        tag: "instances",
+        function: "get_all",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances",
+        parameters: &[
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameSortMode",
+                required: false,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::Instance>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "instance_create",
+        renamed_operation_id: Some("project_instances_post"),
+        tag: "instances",
+        function: "post",
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: Some("crate::types::InstanceCreate"),
+        response_type: "crate::types::Instance",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "instance_view",
+        renamed_operation_id: Some("project_instances_get_instance"),
+        tag: "instances",
+        function: "get",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "instance_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "crate::types::Instance",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "instance_delete",
+        renamed_operation_id: Some("project_instances_delete_instance"),
+        tag: "instances",
+        function: "delete",
+        method: "DELETE",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "instance_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "()",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "instance_disk_list",
+        renamed_operation_id: Some("instance_disks_get"),
+        tag: "instances",
+        function: "disks_get",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/disks",
+        parameters: &[
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameSortMode",
+                required: false,
+            },
+            ParameterInfo {
+                name: "instance_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::Disk>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "instance_disk_list",
+        renamed_operation_id: Some("instance_disks_get"),
+        tag: "instances",
+        function: "disks_get_all",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/disks",
+        parameters: &[
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameSortMode",
+                required: false,
+            },
+            ParameterInfo {
+                name: "instance_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::Disk>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "instance_disk_attach",
+        renamed_operation_id: Some("instance_disks_attach"),
+        tag: "instances",
+        function: "disks_attach",
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/disks/attach",
+        parameters: &[
+            ParameterInfo {
+                name: "instance_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: Some("crate::types::DiskIdentifier"),
+        response_type: "crate::types::Disk",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "instance_disk_detach",
+        renamed_operation_id: Some("instance_disks_detach"),
+        tag: "instances",
+        function: "disks_detach",
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/disks/detach",
+        parameters: &[
+            ParameterInfo {
+                name: "instance_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: Some("crate::types::DiskIdentifier"),
+        response_type: "crate::types::Disk",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "instance_external_ip_list",
+        renamed_operation_id: None,
+        tag: "instances",
+        function: "external_ip_list",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/external-ips",
+        parameters: &[
+            ParameterInfo {
+                name: "instance_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::ExternalIp>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "instance_external_ip_list",
+        renamed_operation_id: None,
+        tag: "instances",
+        function: "external_ip_list_all",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/external-ips",
+        parameters: &[
+            ParameterInfo {
+                name: "instance_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::ExternalIp>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "instance_migrate",
+        renamed_operation_id: Some("project_instances_migrate_instance"),
+        tag: "instances",
+        function: "migrate",
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/migrate",
+        parameters: &[
+            ParameterInfo {
+                name: "instance_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: Some("crate::types::InstanceMigrate"),
+        response_type: "crate::types::Instance",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "instance_network_interface_list",
+        renamed_operation_id: Some("instance_network_interfaces_get"),
+        tag: "instances",
+        function: "network_interfaces_get",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/network-interfaces",
+        parameters: &[
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameSortMode",
+                required: false,
+            },
+            ParameterInfo {
+                name: "instance_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::NetworkInterface>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "instance_network_interface_list",
+        renamed_operation_id: Some("instance_network_interfaces_get"),
+        tag: "instances",
+        function: "network_interfaces_get_all",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/network-interfaces",
+        parameters: &[
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameSortMode",
+                required: false,
+            },
+            ParameterInfo {
+                name: "instance_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::NetworkInterface>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "instance_network_interface_create",
+        renamed_operation_id: Some("instance_network_interfaces_post"),
+        tag: "instances",
+        function: "network_interfaces_post",
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/network-interfaces",
+        parameters: &[
+            ParameterInfo {
+                name: "instance_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: Some("crate::types::NetworkInterfaceCreate"),
+        response_type: "crate::types::NetworkInterface",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "instance_network_interface_view",
+        renamed_operation_id: Some("instance_network_interfaces_get_interface"),
+        tag: "instances",
+        function: "network_interfaces_get_interface",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/network-interfaces/{interface_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "instance_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "interface_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "crate::types::NetworkInterface",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "instance_network_interface_update",
+        renamed_operation_id: Some("instance_network_interfaces_put_interface"),
+        tag: "instances",
+        function: "network_interfaces_put_interface",
+        method: "PUT",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/network-interfaces/{interface_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "instance_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "interface_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: Some("crate::types::NetworkInterfaceUpdate"),
+        response_type: "crate::types::NetworkInterface",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "instance_network_interface_delete",
+        renamed_operation_id: Some("instance_network_interfaces_delete_interface"),
+        tag: "instances",
+        function: "network_interfaces_delete_interface",
+        method: "DELETE",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/network-interfaces/{interface_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "instance_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "interface_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "()",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "instance_reboot",
+        renamed_operation_id: Some("project_instances_instance_reboot"),
+        tag: "instances",
+        function: "reboot",
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/reboot",
+        parameters: &[
+            ParameterInfo {
+                name: "instance_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "crate::types::Instance",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "instance_serial_console",
+        renamed_operation_id: Some("project_instances_instance_serial_get"),
+        tag: "instances",
+        function: "serial_get",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/serial-console",
+        parameters: &[
+            ParameterInfo {
+                name: "instance_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "from_start",
+                location: ParameterLocation::Query,
+                rust_type: "Option<u64>",
+                required: false,
+            },
+            ParameterInfo {
+                name: "max_bytes",
+                location: ParameterLocation::Query,
+                rust_type: "Option<u64>",
+                required: false,
+            },
+            ParameterInfo {
+                name: "most_recent",
+                location: ParameterLocation::Query,
+                rust_type: "Option<u64>",
+                required: false,
+            },
+        ],
+        body_type: None,
+        response_type: "crate::types::InstanceSerialConsoleData",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "instance_start",
+        renamed_operation_id: Some("project_instances_instance_start"),
+        tag: "instances",
+        function: "start",
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/start",
+        parameters: &[
+            ParameterInfo {
+                name: "instance_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "crate::types::Instance",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "instance_stop",
+        renamed_operation_id: Some("project_instances_instance_stop"),
+        tag: "instances",
+        function: "stop",
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/instances/\
+               {instance_name}/stop",
+        parameters: &[
+            ParameterInfo {
+                name: "instance_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "crate::types::Instance",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "project_policy_view",
+        renamed_operation_id: Some("organization_projects_get_project_policy"),
+        tag: "projects",
+        function: "get_policy",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/policy",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "crate::types::ProjectRolePolicy",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "project_policy_update",
+        renamed_operation_id: Some("organization_projects_put_project_policy"),
+        tag: "projects",
+        function: "put_policy",
+        method: "PUT",
+        path: "/organizations/{organization_name}/projects/{project_name}/policy",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: Some("crate::types::ProjectRolePolicy"),
+        response_type: "crate::types::ProjectRolePolicy",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "snapshot_list",
+        renamed_operation_id: Some("project_snapshots_get"),
+        tag: "snapshots",
+        function: "get_page",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/snapshots",
+        parameters: &[
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameSortMode",
+                required: false,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::Snapshot>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "snapshot_list",
+        renamed_operation_id: Some("project_snapshots_get"),
+        tag: "snapshots",
+        function: "get_all",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/snapshots",
+        parameters: &[
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameSortMode",
+                required: false,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::Snapshot>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "snapshot_create",
+        renamed_operation_id: Some("project_snapshots_post"),
+        tag: "snapshots",
+        function: "post",
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/snapshots",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: Some("crate::types::SnapshotCreate"),
+        response_type: "crate::types::Snapshot",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "snapshot_view",
+        renamed_operation_id: Some("project_snapshots_get_snapshot"),
+        tag: "snapshots",
+        function: "get",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/snapshots/\
+               {snapshot_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "snapshot_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "crate::types::Snapshot",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "snapshot_delete",
+        renamed_operation_id: Some("project_snapshots_delete_snapshot"),
+        tag: "snapshots",
+        function: "delete",
+        method: "DELETE",
+        path: "/organizations/{organization_name}/projects/{project_name}/snapshots/\
+               {snapshot_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "snapshot_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "()",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "vpc_list",
+        renamed_operation_id: Some("project_vpcs_get"),
+        tag: "vpcs",
+        function: "get_page",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs",
+        parameters: &[
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameSortMode",
+                required: false,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::Vpc>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "vpc_list",
+        renamed_operation_id: Some("project_vpcs_get"),
+        tag: "vpcs",
+        function: "get_all",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs",
+        parameters: &[
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameSortMode",
+                required: false,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::Vpc>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "vpc_create",
+        renamed_operation_id: Some("project_vpcs_post"),
+        tag: "vpcs",
+        function: "post",
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: Some("crate::types::VpcCreate"),
+        response_type: "crate::types::Vpc",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "vpc_view",
+        renamed_operation_id: Some("project_vpcs_get_vpc"),
+        tag: "vpcs",
+        function: "get",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "vpc_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "crate::types::Vpc",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "vpc_update",
+        renamed_operation_id: Some("project_vpcs_put_vpc"),
+        tag: "vpcs",
+        function: "put",
+        method: "PUT",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "vpc_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: Some("crate::types::VpcUpdate"),
+        response_type: "crate::types::Vpc",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "vpc_delete",
+        renamed_operation_id: Some("project_vpcs_delete_vpc"),
+        tag: "vpcs",
+        function: "delete",
+        method: "DELETE",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "vpc_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "()",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "vpc_firewall_rules_view",
+        renamed_operation_id: Some("vpc_firewall_rules_get"),
+        tag: "vpcs",
+        function: "firewall_rules_get",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/\
+               firewall/rules",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "vpc_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "crate::types::VpcFirewallRules",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "vpc_firewall_rules_update",
+        renamed_operation_id: Some("vpc_firewall_rules_put"),
+        tag: "vpcs",
+        function: "firewall_rules_put",
+        method: "PUT",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/\
+               firewall/rules",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "vpc_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: Some("crate::types::VpcFirewallRuleUpdateParams"),
+        response_type: "crate::types::VpcFirewallRules",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "vpc_router_list",
+        renamed_operation_id: Some("vpc_routers_get"),
+        tag: "routers",
+        function: "get_page",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/routers",
+        parameters: &[
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameSortMode",
+                required: false,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "vpc_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::VpcRouter>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "vpc_router_list",
+        renamed_operation_id: Some("vpc_routers_get"),
+        tag: "routers",
+        function: "get_all",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/routers",
+        parameters: &[
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameSortMode",
+                required: false,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "vpc_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::VpcRouter>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "vpc_router_create",
+        renamed_operation_id: Some("vpc_routers_post"),
+        tag: "routers",
+        function: "post",
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/routers",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "vpc_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: Some("crate::types::VpcRouterCreate"),
+        response_type: "crate::types::VpcRouter",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "vpc_router_view",
+        renamed_operation_id: Some("vpc_routers_get_router"),
+        tag: "routers",
+        function: "get",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/routers/\
+               {router_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "router_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "vpc_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "crate::types::VpcRouter",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "vpc_router_update",
+        renamed_operation_id: Some("vpc_routers_put_router"),
+        tag: "routers",
+        function: "put",
+        method: "PUT",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/routers/\
+               {router_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "router_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "vpc_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: Some("crate::types::VpcRouterUpdate"),
+        response_type: "crate::types::VpcRouter",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "vpc_router_delete",
+        renamed_operation_id: Some("vpc_routers_delete_router"),
+        tag: "routers",
+        function: "delete",
+        method: "DELETE",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/routers/\
+               {router_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "router_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "vpc_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "()",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "vpc_router_route_list",
+        renamed_operation_id: Some("routers_routes_get"),
+        tag: "routes",
+        function: "get_page",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/routers/\
+               {router_name}/routes",
+        parameters: &[
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameSortMode",
+                required: false,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "router_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "vpc_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::RouterRoute>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "vpc_router_route_list",
+        renamed_operation_id: Some("routers_routes_get"),
+        tag: "routes",
+        function: "get_all",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/routers/\
+               {router_name}/routes",
+        parameters: &[
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameSortMode",
+                required: false,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "router_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "vpc_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::RouterRoute>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "vpc_router_route_create",
+        renamed_operation_id: Some("routers_routes_post"),
+        tag: "routes",
+        function: "post",
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/routers/\
+               {router_name}/routes",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "router_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "vpc_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: Some("crate::types::RouterRouteCreateParams"),
+        response_type: "crate::types::RouterRoute",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "vpc_router_route_view",
+        renamed_operation_id: Some("routers_routes_get_route"),
+        tag: "routes",
+        function: "get",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/routers/\
+               {router_name}/routes/{route_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "route_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "router_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "vpc_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "crate::types::RouterRoute",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "vpc_router_route_update",
+        renamed_operation_id: Some("routers_routes_put_route"),
+        tag: "routes",
+        function: "put",
+        method: "PUT",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/routers/\
+               {router_name}/routes/{route_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "route_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "router_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "vpc_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: Some("crate::types::RouterRouteUpdateParams"),
+        response_type: "crate::types::RouterRoute",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "vpc_router_route_delete",
+        renamed_operation_id: Some("routers_routes_delete_route"),
+        tag: "routes",
+        function: "delete",
+        method: "DELETE",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/routers/\
+               {router_name}/routes/{route_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "route_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "router_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "vpc_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "()",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "vpc_subnet_list",
+        renamed_operation_id: Some("vpc_subnets_get"),
+        tag: "subnets",
+        function: "get_page",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/subnets",
+        parameters: &[
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameSortMode",
+                required: false,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "vpc_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::VpcSubnet>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "vpc_subnet_list",
+        renamed_operation_id: Some("vpc_subnets_get"),
+        tag: "subnets",
+        function: "get_all",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/subnets",
+        parameters: &[
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameSortMode",
+                required: false,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "vpc_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::VpcSubnet>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "vpc_subnet_create",
+        renamed_operation_id: Some("vpc_subnets_post"),
+        tag: "subnets",
+        function: "post",
+        method: "POST",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/subnets",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "vpc_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: Some("crate::types::VpcSubnetCreate"),
+        response_type: "crate::types::VpcSubnet",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "vpc_subnet_view",
+        renamed_operation_id: Some("vpc_subnets_get_subnet"),
+        tag: "subnets",
+        function: "get",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/subnets/\
+               {subnet_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "subnet_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "vpc_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "crate::types::VpcSubnet",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "vpc_subnet_update",
+        renamed_operation_id: Some("vpc_subnets_put_subnet"),
+        tag: "subnets",
+        function: "put",
+        method: "PUT",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/subnets/\
+               {subnet_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "subnet_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "vpc_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: Some("crate::types::VpcSubnetUpdate"),
+        response_type: "crate::types::VpcSubnet",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "vpc_subnet_delete",
+        renamed_operation_id: Some("vpc_subnets_delete_subnet"),
+        tag: "subnets",
+        function: "delete",
+        method: "DELETE",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/subnets/\
+               {subnet_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "subnet_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "vpc_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "()",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "vpc_subnet_list_network_interfaces",
+        renamed_operation_id: Some("subnet_network_interfaces_get"),
+        tag: "subnets",
+        function: "network_interfaces_get",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/subnets/\
+               {subnet_name}/network-interfaces",
+        parameters: &[
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameSortMode",
+                required: false,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "subnet_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "vpc_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::NetworkInterface>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "vpc_subnet_list_network_interfaces",
+        renamed_operation_id: Some("subnet_network_interfaces_get"),
+        tag: "subnets",
+        function: "network_interfaces_get_all",
+        method: "GET",
+        path: "/organizations/{organization_name}/projects/{project_name}/vpcs/{vpc_name}/subnets/\
+               {subnet_name}/network-interfaces",
+        parameters: &[
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameSortMode",
+                required: false,
+            },
+            ParameterInfo {
+                name: "organization_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "project_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "subnet_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "vpc_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::NetworkInterface>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "policy_view",
+        renamed_operation_id: Some("policy_get"),
+        tag: "silos",
+        function: "policy_get",
+        method: "GET",
+        path: "/policy",
+        parameters: &[],
+        body_type: None,
+        response_type: "crate::types::SiloRolePolicy",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "policy_update",
+        renamed_operation_id: Some("policy_put"),
+        tag: "silos",
+        function: "policy_put",
+        method: "PUT",
+        path: "/policy",
+        parameters: &[],
+        body_type: Some("crate::types::SiloRolePolicy"),
+        response_type: "crate::types::SiloRolePolicy",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "role_list",
+        renamed_operation_id: Some("roles_get"),
+        tag: "roles",
+        function: "get_page",
+        method: "GET",
+        path: "/roles",
+        parameters: &[
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::Role>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "role_list",
+        renamed_operation_id: Some("roles_get"),
+        tag: "roles",
+        function: "get_all",
+        method: "GET",
+        path: "/roles",
+        parameters: &[],
+        body_type: None,
+        response_type: "Vec<crate::types::Role>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "role_view",
+        renamed_operation_id: Some("roles_get_role"),
+        tag: "roles",
+        function: "get",
+        method: "GET",
+        path: "/roles/{role_name}",
+        parameters: &[ParameterInfo {
+            name: "role_name",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "crate::types::Role",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "saga_list",
+        renamed_operation_id: Some("sagas_get"),
+        tag: "sagas",
+        function: "get_page",
+        method: "GET",
+        path: "/sagas",
+        parameters: &[
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::IdSortMode",
+                required: false,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::Saga>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "saga_list",
+        renamed_operation_id: Some("sagas_get"),
+        tag: "sagas",
+        function: "get_all",
+        method: "GET",
+        path: "/sagas",
+        parameters: &[ParameterInfo {
+            name: "sort_by",
+            location: ParameterLocation::Query,
+            rust_type: "crate::types::IdSortMode",
+            required: false,
+        }],
+        body_type: None,
+        response_type: "Vec<crate::types::Saga>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "saga_view",
+        renamed_operation_id: Some("sagas_get_saga"),
+        tag: "sagas",
+        function: "get",
+        method: "GET",
+        path: "/sagas/{saga_id}",
+        parameters: &[ParameterInfo {
+            name: "saga_id",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "crate::types::Saga",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "session_me",
+        renamed_operation_id: None,
+        tag: "hidden",
+        function: "session_me",
+        method: "GET",
+        path: "/session/me",
+        parameters: &[],
+        body_type: None,
+        response_type: "crate::types::User",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "session_sshkey_list",
+        renamed_operation_id: Some("sshkeys_get"),
+        tag: "sshkeys",
+        function: "get_page",
+        method: "GET",
+        path: "/session/me/sshkeys",
+        parameters: &[
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameSortMode",
+                required: false,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::SshKey>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "session_sshkey_list",
+        renamed_operation_id: Some("sshkeys_get"),
+        tag: "sshkeys",
+        function: "get_all",
+        method: "GET",
+        path: "/session/me/sshkeys",
+        parameters: &[ParameterInfo {
+            name: "sort_by",
+            location: ParameterLocation::Query,
+            rust_type: "crate::types::NameSortMode",
+            required: false,
+        }],
+        body_type: None,
+        response_type: "Vec<crate::types::SshKey>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "session_sshkey_create",
+        renamed_operation_id: Some("sshkeys_post"),
+        tag: "sshkeys",
+        function: "post",
+        method: "POST",
+        path: "/session/me/sshkeys",
+        parameters: &[],
+        body_type: Some("crate::types::SshKeyCreate"),
+        response_type: "crate::types::SshKey",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "session_sshkey_view",
+        renamed_operation_id: Some("sshkeys_get_key"),
+        tag: "sshkeys",
+        function: "get_key",
+        method: "GET",
+        path: "/session/me/sshkeys/{ssh_key_name}",
+        parameters: &[ParameterInfo {
+            name: "ssh_key_name",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "crate::types::SshKey",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "session_sshkey_delete",
+        renamed_operation_id: Some("sshkeys_delete_key"),
+        tag: "sshkeys",
+        function: "delete_key",
+        method: "DELETE",
+        path: "/session/me/sshkeys/{ssh_key_name}",
+        parameters: &[ParameterInfo {
+            name: "ssh_key_name",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "()",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "silo_list",
+        renamed_operation_id: Some("silos_get"),
+        tag: "silos",
+        function: "get_page",
+        method: "GET",
+        path: "/silos",
+        parameters: &[
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameOrIdSortMode",
+                required: false,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::Silo>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "silo_list",
+        renamed_operation_id: Some("silos_get"),
+        tag: "silos",
+        function: "get_all",
+        method: "GET",
+        path: "/silos",
+        parameters: &[ParameterInfo {
+            name: "sort_by",
+            location: ParameterLocation::Query,
+            rust_type: "crate::types::NameOrIdSortMode",
+            required: false,
+        }],
+        body_type: None,
+        response_type: "Vec<crate::types::Silo>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "silo_create",
+        renamed_operation_id: Some("silos_post"),
+        tag: "silos",
+        function: "post",
+        method: "POST",
+        path: "/silos",
+        parameters: &[],
+        body_type: Some("crate::types::SiloCreate"),
+        response_type: "crate::types::Silo",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "silo_view",
+        renamed_operation_id: Some("silos_get_silo"),
+        tag: "silos",
+        function: "get",
+        method: "GET",
+        path: "/silos/{silo_name}",
+        parameters: &[ParameterInfo {
+            name: "silo_name",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "crate::types::Silo",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "silo_delete",
+        renamed_operation_id: Some("silos_delete_silo"),
+        tag: "silos",
+        function: "delete",
+        method: "DELETE",
+        path: "/silos/{silo_name}",
+        parameters: &[ParameterInfo {
+            name: "silo_name",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "()",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "silo_identity_provider_list",
+        renamed_operation_id: Some("silos_get_identity_providers"),
+        tag: "silos",
+        function: "get_identity_providers",
+        method: "GET",
+        path: "/silos/{silo_name}/identity-providers",
+        parameters: &[
+            ParameterInfo {
+                name: "silo_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameSortMode",
+                required: false,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::IdentityProvider>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "silo_identity_provider_list",
+        renamed_operation_id: Some("silos_get_identity_providers"),
+        tag: "silos",
+        function: "get_all_identity_providers",
+        method: "GET",
+        path: "/silos/{silo_name}/identity-providers",
+        parameters: &[
+            ParameterInfo {
+                name: "silo_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameSortMode",
+                required: false,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::IdentityProvider>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "silo_policy_view",
+        renamed_operation_id: Some("silos_get_silo_policy"),
+        tag: "silos",
+        function: "get_policy",
+        method: "GET",
+        path: "/silos/{silo_name}/policy",
+        parameters: &[ParameterInfo {
+            name: "silo_name",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "crate::types::SiloRolePolicy",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "silo_policy_update",
+        renamed_operation_id: Some("silos_put_silo_policy"),
+        tag: "silos",
+        function: "put_policy",
+        method: "PUT",
+        path: "/silos/{silo_name}/policy",
+        parameters: &[ParameterInfo {
+            name: "silo_name",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: Some("crate::types::SiloRolePolicy"),
+        response_type: "crate::types::SiloRolePolicy",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "silo_identity_provider_create",
+        renamed_operation_id: Some("silo_saml_idp_create"),
+        tag: "silos",
+        function: "saml_idp_create",
+        method: "POST",
+        path: "/silos/{silo_name}/saml-identity-providers",
+        parameters: &[ParameterInfo {
+            name: "silo_name",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: Some("crate::types::SamlIdentityProviderCreate"),
+        response_type: "crate::types::SamlIdentityProvider",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "silo_identity_provider_view",
+        renamed_operation_id: Some("silo_saml_idp_fetch"),
+        tag: "silos",
+        function: "saml_idp_fetch",
+        method: "GET",
+        path: "/silos/{silo_name}/saml-identity-providers/{provider_name}",
+        parameters: &[
+            ParameterInfo {
+                name: "provider_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+            ParameterInfo {
+                name: "silo_name",
+                location: ParameterLocation::Path,
+                rust_type: "&str",
+                required: true,
+            },
+        ],
+        body_type: None,
+        response_type: "crate::types::SamlIdentityProvider",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "system_user_list",
+        renamed_operation_id: None,
+        tag: "system",
+        function: "user_list",
+        method: "GET",
+        path: "/system/user",
+        parameters: &[
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::NameSortMode",
+                required: false,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::UserBuiltin>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "system_user_list",
+        renamed_operation_id: None,
+        tag: "system",
+        function: "user_list_all",
+        method: "GET",
+        path: "/system/user",
+        parameters: &[ParameterInfo {
+            name: "sort_by",
+            location: ParameterLocation::Query,
+            rust_type: "crate::types::NameSortMode",
+            required: false,
+        }],
+        body_type: None,
+        response_type: "Vec<crate::types::UserBuiltin>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "system_user_view",
+        renamed_operation_id: None,
+        tag: "system",
+        function: "user_view",
+        method: "GET",
+        path: "/system/user/{user_name}",
+        parameters: &[ParameterInfo {
+            name: "user_name",
+            location: ParameterLocation::Path,
+            rust_type: "&str",
+            required: true,
+        }],
+        body_type: None,
+        response_type: "crate::types::UserBuiltin",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "timeseries_schema_get",
+        renamed_operation_id: None,
+        tag: "metrics",
+        function: "timeseries_schema_get",
+        method: "GET",
+        path: "/timeseries/schema",
+        parameters: &[
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::TimeseriesSchema>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "timeseries_schema_get",
+        renamed_operation_id: None,
+        tag: "metrics",
+        function: "timeseries_schema_get_all",
+        method: "GET",
+        path: "/timeseries/schema",
+        parameters: &[],
+        body_type: None,
+        response_type: "Vec<crate::types::TimeseriesSchema>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "updates_refresh",
+        renamed_operation_id: None,
+        tag: "updates",
+        function: "refresh",
+        method: "POST",
+        path: "/updates/refresh",
+        parameters: &[],
+        body_type: None,
+        response_type: "()",
+        paginated: false,
+    },
+    OperationInfo {
+        operation_id: "user_list",
+        renamed_operation_id: Some("silo_users_get"),
+        tag: "silos",
+        function: "users_get",
+        method: "GET",
+        path: "/users",
+        parameters: &[
+            ParameterInfo {
+                name: "limit",
+                location: ParameterLocation::Query,
+                rust_type: "u32",
+                required: false,
+            },
+            ParameterInfo {
+                name: "page_token",
+                location: ParameterLocation::Query,
+                rust_type: "&str",
+                required: false,
+            },
+            ParameterInfo {
+                name: "sort_by",
+                location: ParameterLocation::Query,
+                rust_type: "crate::types::IdSortMode",
+                required: false,
+            },
+        ],
+        body_type: None,
+        response_type: "Vec<crate::types::User>",
+        paginated: true,
+    },
+    OperationInfo {
+        operation_id: "user_list",
+        renamed_operation_id: Some("silo_users_get"),
+        tag: "silos",
+        function: "users_get_all",
+        method: "GET",
+        path: "/users",
+        parameters: &[ParameterInfo {
+            name: "sort_by",
+            location: ParameterLocation::Query,
+            rust_type: "crate::types::IdSortMode",
+            required: false,
+        }],
+        body_type: None,
+        response_type: "Vec<crate::types::User>",
+        paginated: true,
+    },
+];
@@ -0,0 +1,302 @@
+//! An offline model of a VPC router's route table.
+//!
+//! [`RouteTable`] resolves each route's destination to address prefixes and
+//! answers which route wins for a destination address: the longest matching
+//! prefix, then the route kind (custom routes first, then subnet, peering and
+//! default routes). [`RouteTable::check`] reports routes whose targets no
+//! longer exist, destinations that cannot be resolved, and routes that are
+//! duplicated or shadowed by another route for the same prefix.
+//!
+//! ```no_run
+//! # async fn debug() -> anyhow::Result<()> {
+//! use oxide_api::Client;
+//!
+//! let oxide = Client::new_from_env();
+//! let table = oxide
+//!     .routes()
+//!     .table("maze-war", "prod", "default", "egress")
+//!     .await?;
+//! for finding in table.check() {
+//!     println!("{}", finding);
+//! }
+//! if let Some(route) = table.lookup("172.30.0.7".parse()?) {
+//!     println!("172.30.0.7 goes to {}", route.target);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt, net::IpAddr};
+
+use anyhow::Result;
+use ipnetwork::IpNetwork;
+
+use crate::types::{
+    Instance, IpNet, NameSortMode, RouteDestination, RouteTarget, RouterRoute, RouterRouteKind,
+    Vpc, VpcSubnet,
+};
+
+/// What route destinations and targets can refer to.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RouteContext {
+    pub vpcs: Vec<Vpc>,
+    /// Subnets of the router's VPC.
+    pub subnets: Vec<VpcSubnet>,
+    pub instances: Vec<Instance>,
+}
+
+/// Higher wins when two routes match with the same prefix length.
+fn kind_precedence(kind: &RouterRouteKind) -> u8 {
+    match kind {
+        RouterRouteKind::Custom => 4,
+        RouterRouteKind::VpcSubnet => 3,
+        RouterRouteKind::VpcPeering => 2,
+        RouterRouteKind::Default => 1,
+        RouterRouteKind::Noop | RouterRouteKind::FallthroughString => 0,
+    }
+}
+
+fn parse_block(block: &str) -> Option<IpNetwork> {
+    if block.is_empty() {
+        None
+    } else {
+        block.parse().ok()
+    }
+}
+
+/// The prefixes a destination covers. Empty if it cannot be resolved.
+fn resolve(destination: &RouteDestination, ctx: &RouteContext) -> Vec<IpNetwork> {
+    match destination {
+        RouteDestination::Ip(addr) => vec![IpNetwork::from(*addr)],
+        RouteDestination::IpNet(IpNet::V4(net)) => vec![IpNetwork::V4(net.0)],
+        RouteDestination::IpNet(IpNet::V6(net)) => vec![IpNetwork::V6(net.0)],
+        RouteDestination::Vpc(name) => ctx
+            .vpcs
+            .iter()
+            .filter(|v| v.name == *name)
+            .filter_map(|v| parse_block(&v.ipv6_prefix))
+            .collect(),
+        RouteDestination::Subnet(name) => ctx
+            .subnets
+            .iter()
+            .filter(|s| s.name == *name)
+            .flat_map(|s| vec![parse_block(&s.ipv4_block), parse_block(&s.ipv6_block)])
+            .flatten()
+            .collect(),
+    }
+}
+
+/// A problem found in a route table.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Finding {
+    /// The route's target refers to something that does not exist.
+    UnreachableTarget { route: String, target: RouteTarget },
+    /// The route's destination refers to something that does not exist or has
+    /// no addresses.
+    UnresolvedDestination {
+        route: String,
+        destination: RouteDestination,
+    },
+    /// Two routes of the same kind cover the same prefix, so which one wins
+    /// is undefined.
+    Duplicate {
+        route: String,
+        other: String,
+        prefix: IpNetwork,
+    },
+    /// The route never wins for this prefix because another route covers the
+    /// same prefix with higher precedence.
+    Shadowed {
+        route: String,
+        by: String,
+        prefix: IpNetwork,
+    },
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Finding::UnreachableTarget { route, target } => {
+                write!(f, "route {}: target {} does not exist", route, target)
+            }
+            Finding::UnresolvedDestination { route, destination } => write!(
+                f,
+                "route {}: destination {} does not resolve to any addresses",
+                route, destination
+            ),
+            Finding::Duplicate {
+                route,
+                other,
+                prefix,
+            } => write!(
+                f,
+                "route {}: duplicates route {} for {}",
+                route, other, prefix
+            ),
+            Finding::Shadowed { route, by, prefix } => {
+                write!(
+                    f,
+                    "route {}: shadowed by route {} for {}",
+                    route, by, prefix
+                )
+            }
+        }
+    }
+}
+
+/// A router's routes with their destinations resolved to prefixes.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RouteTable {
+    pub context: RouteContext,
+    routes: Vec<(RouterRoute, Vec<IpNetwork>)>,
+}
+
+impl RouteTable {
+    pub fn new(routes: Vec<RouterRoute>, context: RouteContext) -> Self {
+        let routes = routes
+            .into_iter()
+            .map(|r| {
+                let prefixes = resolve(&r.destination, &context);
+                (r, prefixes)
+            })
+            .collect();
+        RouteTable { context, routes }
+    }
+
+    pub fn routes(&self) -> impl Iterator<Item = &RouterRoute> {
+        self.routes.iter().map(|(r, _)| r)
+    }
+
+    /// Every route matching `addr` with the prefix it matched on, the winner
+    /// first.
+    pub fn matches(&self, addr: IpAddr) -> Vec<(&RouterRoute, IpNetwork)> {
+        let mut matches: Vec<(&RouterRoute, IpNetwork)> = self
+            .routes
+            .iter()
+            .filter_map(|(route, prefixes)| {
+                prefixes
+                    .iter()
+                    .filter(|p| p.contains(addr))
+                    .max_by_key(|p| p.prefix())
+                    .map(|p| (route, *p))
+            })
+            .collect();
+        matches.sort_by_key(|(route, prefix)| {
+            std::cmp::Reverse((prefix.prefix(), kind_precedence(&route.kind)))
+        });
+        matches
+    }
+
+    /// The route that wins for `addr`, if any.
+    pub fn lookup(&self, addr: IpAddr) -> Option<&RouterRoute> {
+        self.matches(addr).first().map(|(route, _)| *route)
+    }
+
+    /// Look for unreachable targets, unresolved destinations, and duplicate or
+    /// shadowed routes.
+    pub fn check(&self) -> Vec<Finding> {
+        let ctx = &self.context;
+        let mut findings = Vec::new();
+
+        for (route, prefixes) in &self.routes {
+            let target_exists = match &route.target {
+                RouteTarget::Instance(name) => ctx.instances.iter().any(|i| i.name == *name),
+                RouteTarget::Subnet(name) => ctx.subnets.iter().any(|s| s.name == *name),
+                RouteTarget::Vpc(name) => ctx.vpcs.iter().any(|v| v.name == *name),
+                RouteTarget::Ip(_) | RouteTarget::InternetGateway(_) => true,
+            };
+            if !target_exists {
+                findings.push(Finding::UnreachableTarget {
+                    route: route.name.to_string(),
+                    target: route.target.clone(),
+                });
+            }
+            if prefixes.is_empty() {
+                findings.push(Finding::UnresolvedDestination {
+                    route: route.name.to_string(),
+                    destination: route.destination.clone(),
+                });
+            }
+        }
+
+        for (i, (route, prefixes)) in self.routes.iter().enumerate() {
+            for (other, other_prefixes) in self.routes.iter().skip(i + 1) {
+                for prefix in prefixes.iter().filter(|p| other_prefixes.contains(p)) {
+                    let ours = kind_precedence(&route.kind);
+                    let theirs = kind_precedence(&other.kind);
+                    let finding = if ours == theirs {
+                        Finding::Duplicate {
+                            route: other.name.to_string(),
+                            other: route.name.to_string(),
+                            prefix: *prefix,
+                        }
+                    } else if ours > theirs {
+                        Finding::Shadowed {
+                            route: other.name.to_string(),
+                            by: route.name.to_string(),
+                            prefix: *prefix,
+                        }
+                    } else {
+                        Finding::Shadowed {
+                            route: route.name.to_string(),
+                            by: other.name.to_string(),
+                            prefix: *prefix,
+                        }
+                    };
+                    findings.push(finding);
+                }
+            }
+        }
+
+        findings
+    }
+}
+
+impl crate::routes::Routes {
+    /**
+     * Build the route table of a router, along with the VPCs, subnets and instances its routes
+     * can refer to.
+     */
+    pub async fn table(
+        &self,
+        organization_name: &str,
+        project_name: &str,
+        vpc_name: &str,
+        router_name: &str,
+    ) -> Result<RouteTable> {
+        let routes = self
+            .get_all(
+                organization_name,
+                project_name,
+                router_name,
+                NameSortMode::NameAscending,
+                vpc_name,
+            )
+            .await?;
+
+        let context = RouteContext {
+            vpcs: self
+                .client
+                .vpcs()
+                .get_all(organization_name, project_name, NameSortMode::NameAscending)
+                .await?,
+            subnets: self
+                .client
+                .subnets()
+                .get_all(
+                    organization_name,
+                    project_name,
+                    NameSortMode::NameAscending,
+                    vpc_name,
+                )
+                .await?,
+            instances: self
+                .client
+                .instances()
+                .get_all(organization_name, project_name, NameSortMode::NameAscending)
+                .await?,
+        };
+
+        Ok(RouteTable::new(routes, context))
+    }
+}
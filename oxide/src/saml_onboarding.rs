@@ -0,0 +1,329 @@
+//! Onboard a SAML identity provider from its metadata XML.
+//!
+//! [`IdpMetadata`] parses an IdP's metadata document locally and extracts its
+//! entity id, single sign-on and logout URLs, and signing certificates.
+//! [`SamlOnboarding`] holds the silo's side of the configuration and assembles
+//! the [`SamlIdentityProviderCreate`] request, refusing metadata whose signing
+//! certificates have all expired or are not yet valid. [`der_key_pair_from_pem`]
+//! converts a PEM request-signing certificate and key into the
+//! [`DerEncodedKeyPair`] the API expects.
+//!
+//! ```no_run
+//! # async fn onboard() -> anyhow::Result<()> {
+//! use oxide_api::{
+//!     saml_onboarding::{der_key_pair_from_pem, SamlOnboarding},
+//!     Client,
+//! };
+//!
+//! let oxide = Client::new_from_env();
+//! let onboarding = SamlOnboarding {
+//!     name: "okta".to_string(),
+//!     description: "Corporate Okta".to_string(),
+//!     acs_url: "https://corp.sys.oxide.example/login/corp/saml/okta".to_string(),
+//!     slo_url: "https://corp.sys.oxide.example/logout".to_string(),
+//!     sp_client_id: "oxide-corp".to_string(),
+//!     technical_contact_email: "it@example.com".to_string(),
+//!     signing_keypair: Some(der_key_pair_from_pem(
+//!         &std::fs::read_to_string("sp.crt")?,
+//!         &std::fs::read_to_string("sp.key")?,
+//!     )?),
+//!     ..Default::default()
+//! };
+//! let metadata = std::fs::read_to_string("okta-metadata.xml")?;
+//! let provider = oxide
+//!     .silos()
+//!     .saml_idp_onboard("corp", &onboarding, &metadata)
+//!     .await?;
+//! println!("created {}", provider.name);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{DateTime, TimeZone, Utc};
+use pem::Pem;
+use x509_parser::{certificate::X509Certificate, prelude::FromDer};
+
+use crate::types::{
+    DerEncodedKeyPair, IdpMetadataSource, SamlIdentityProvider, SamlIdentityProviderCreate,
+};
+
+const METADATA_NS: &str = "urn:oasis:names:tc:SAML:2.0:metadata";
+const XMLDSIG_NS: &str = "http://www.w3.org/2000/09/xmldsig#";
+const HTTP_REDIRECT: &str = "urn:oasis:names:tc:SAML:2.0:bindings:HTTP-Redirect";
+const HTTP_POST: &str = "urn:oasis:names:tc:SAML:2.0:bindings:HTTP-POST";
+
+/// An X.509 certificate the IdP signs assertions with.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IdpCertificate {
+    pub der: Vec<u8>,
+    pub subject: String,
+    pub not_before: DateTime<Utc>,
+    pub not_after: DateTime<Utc>,
+}
+
+impl IdpCertificate {
+    pub fn from_der(der: &[u8]) -> Result<Self> {
+        let (_, cert) = X509Certificate::from_der(der)
+            .map_err(|e| anyhow!("invalid X.509 certificate: {}", e))?;
+        let validity = cert.validity();
+        Ok(IdpCertificate {
+            der: der.to_vec(),
+            subject: cert.subject().to_string(),
+            not_before: Utc.timestamp(validity.not_before.timestamp(), 0),
+            not_after: Utc.timestamp(validity.not_after.timestamp(), 0),
+        })
+    }
+
+    /// Returns whether the certificate is valid at `at`.
+    pub fn is_valid_at(&self, at: DateTime<Utc>) -> bool {
+        self.not_before <= at && at <= self.not_after
+    }
+}
+
+/// What a silo needs to know about an IdP, taken from its metadata.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IdpMetadata {
+    pub entity_id: String,
+    /// Where to send authentication requests, preferring the HTTP-Redirect
+    /// binding over HTTP-POST.
+    pub sso_url: String,
+    pub slo_url: Option<String>,
+    pub signing_certs: Vec<IdpCertificate>,
+    /// The metadata document itself.
+    pub xml: String,
+}
+
+impl FromStr for IdpMetadata {
+    type Err = anyhow::Error;
+
+    /// Parses a SAML 2.0 metadata document with a single IdP entity. The
+    /// entity may be wrapped in an `EntitiesDescriptor`.
+    fn from_str(xml: &str) -> Result<Self, Self::Err> {
+        let doc = roxmltree::Document::parse(xml).context("parsing IdP metadata XML")?;
+
+        let is = |node: &roxmltree::Node, ns: &str, name: &str| {
+            node.is_element()
+                && node.tag_name().namespace() == Some(ns)
+                && node.tag_name().name() == name
+        };
+        let idps: Vec<(roxmltree::Node, roxmltree::Node)> = doc
+            .descendants()
+            .filter(|n| is(n, METADATA_NS, "EntityDescriptor"))
+            .filter_map(|entity| {
+                entity
+                    .children()
+                    .find(|n| is(n, METADATA_NS, "IDPSSODescriptor"))
+                    .map(|idp| (entity, idp))
+            })
+            .collect();
+        let (entity, idp) = match idps.as_slice() {
+            [one] => *one,
+            [] => bail!("metadata does not describe an identity provider"),
+            _ => bail!(
+                "metadata describes {} identity providers, expected one",
+                idps.len()
+            ),
+        };
+
+        let entity_id = entity
+            .attribute("entityID")
+            .filter(|id| !id.is_empty())
+            .context("EntityDescriptor has no entityID")?
+            .to_string();
+
+        let service = |name: &str| {
+            let services: Vec<roxmltree::Node> = idp
+                .children()
+                .filter(|n| is(n, METADATA_NS, name))
+                .collect();
+            [HTTP_REDIRECT, HTTP_POST]
+                .iter()
+                .find_map(|binding| {
+                    services
+                        .iter()
+                        .find(|n| n.attribute("Binding") == Some(binding))
+                })
+                .and_then(|n| n.attribute("Location"))
+                .map(|l| l.to_string())
+        };
+        let sso_url = service("SingleSignOnService").with_context(|| {
+            format!(
+                "identity provider {} has no HTTP-Redirect or HTTP-POST SingleSignOnService",
+                entity_id
+            )
+        })?;
+        let slo_url = service("SingleLogoutService");
+
+        let mut signing_certs = Vec::new();
+        for key in idp
+            .children()
+            .filter(|n| is(n, METADATA_NS, "KeyDescriptor"))
+            .filter(|n| n.attribute("use").unwrap_or("signing") == "signing")
+        {
+            for cert in key
+                .descendants()
+                .filter(|n| is(n, XMLDSIG_NS, "X509Certificate"))
+            {
+                let b64: String = cert
+                    .text()
+                    .unwrap_or_default()
+                    .chars()
+                    .filter(|c| !c.is_whitespace())
+                    .collect();
+                let der = base64::decode(&b64).context("decoding IdP signing certificate")?;
+                signing_certs.push(
+                    IdpCertificate::from_der(&der).context("parsing IdP signing certificate")?,
+                );
+            }
+        }
+        if signing_certs.is_empty() {
+            bail!("identity provider {} has no signing certificate", entity_id);
+        }
+
+        Ok(IdpMetadata {
+            entity_id,
+            sso_url,
+            slo_url,
+            signing_certs,
+            xml: xml.to_string(),
+        })
+    }
+}
+
+impl IdpMetadata {
+    /// The signing certificates valid at `at`. Fails if there are none, since
+    /// no assertion from the IdP could be verified.
+    pub fn valid_signing_certs(&self, at: DateTime<Utc>) -> Result<Vec<&IdpCertificate>> {
+        let valid: Vec<&IdpCertificate> = self
+            .signing_certs
+            .iter()
+            .filter(|c| c.is_valid_at(at))
+            .collect();
+        if valid.is_empty() {
+            let windows: Vec<String> = self
+                .signing_certs
+                .iter()
+                .map(|c| format!("{} ({} to {})", c.subject, c.not_before, c.not_after))
+                .collect();
+            bail!(
+                "no signing certificate of identity provider {} is valid at {}: {}",
+                self.entity_id,
+                at,
+                windows.join(", ")
+            );
+        }
+        Ok(valid)
+    }
+}
+
+/// Convert a PEM certificate and private key into the base64 DER key pair the
+/// API takes for signing requests.
+pub fn der_key_pair_from_pem(cert_pem: &str, key_pem: &str) -> Result<DerEncodedKeyPair> {
+    let cert = single_pem(cert_pem, &["CERTIFICATE"]).context("reading certificate")?;
+    IdpCertificate::from_der(&cert.contents)?;
+    let key = single_pem(
+        key_pem,
+        &["PRIVATE KEY", "RSA PRIVATE KEY", "EC PRIVATE KEY"],
+    )
+    .context("reading private key")?;
+
+    Ok(DerEncodedKeyPair {
+        public_cert: base64::encode(&cert.contents),
+        private_key: base64::encode(&key.contents),
+    })
+}
+
+/// The only PEM block in `text`, which must have one of `labels`.
+fn single_pem(text: &str, labels: &[&str]) -> Result<Pem> {
+    let blocks = pem::parse_many(text).map_err(|e| anyhow!("invalid PEM: {}", e))?;
+    let block = match blocks.as_slice() {
+        [block] => block,
+        [] => bail!("no PEM block found"),
+        _ => bail!("expected one PEM block, found {}", blocks.len()),
+    };
+    if !labels.contains(&block.tag.as_str()) {
+        bail!(
+            "expected a PEM {} block, found {}",
+            labels.join(" or "),
+            block.tag
+        );
+    }
+    Ok(block.clone())
+}
+
+/// The silo's side of a SAML identity provider's configuration.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SamlOnboarding {
+    pub name: String,
+    pub description: String,
+    pub acs_url: String,
+    pub slo_url: String,
+    pub sp_client_id: String,
+    pub technical_contact_email: String,
+    pub group_attribute_name: String,
+    pub signing_keypair: Option<DerEncodedKeyPair>,
+}
+
+impl SamlOnboarding {
+    /// Assemble the create request for the IdP described by `metadata_xml`.
+    ///
+    /// The metadata is sent as base64 XML, so the silo uses exactly the
+    /// document that was checked here.
+    pub fn create_request(
+        &self,
+        metadata_xml: &str,
+        now: DateTime<Utc>,
+    ) -> Result<SamlIdentityProviderCreate> {
+        for (field, value) in [
+            ("name", &self.name),
+            ("acs_url", &self.acs_url),
+            ("slo_url", &self.slo_url),
+            ("sp_client_id", &self.sp_client_id),
+            ("technical_contact_email", &self.technical_contact_email),
+        ] {
+            if value.is_empty() {
+                bail!("{} is required", field);
+            }
+        }
+
+        let metadata: IdpMetadata = metadata_xml.parse()?;
+        metadata.valid_signing_certs(now)?;
+
+        let mut builder = SamlIdentityProviderCreate::builder()
+            .name(self.name.as_str())
+            .description(self.description.as_str())
+            .acs_url(self.acs_url.as_str())
+            .group_attribute_name(self.group_attribute_name.as_str())
+            .idp_entity_id(metadata.entity_id)
+            .idp_metadata_source(IdpMetadataSource::Base64EncodedXml {
+                data: base64::encode(metadata.xml),
+            })
+            .slo_url(self.slo_url.as_str())
+            .sp_client_id(self.sp_client_id.as_str())
+            .technical_contact_email(self.technical_contact_email.as_str());
+        if let Some(keypair) = &self.signing_keypair {
+            builder = builder.signing_keypair(keypair.clone());
+        }
+        builder.build()
+    }
+}
+
+impl crate::silos::Silos {
+    /**
+     * Create a SAML identity provider in a silo from the IdP's metadata XML.
+     */
+    pub async fn saml_idp_onboard(
+        &self,
+        silo_name: &str,
+        onboarding: &SamlOnboarding,
+        metadata_xml: &str,
+    ) -> Result<SamlIdentityProvider> {
+        let body = onboarding.create_request(metadata_xml, Utc::now())?;
+        self.saml_idp_create(silo_name, &body)
+            .await
+            .with_context(|| format!("creating identity provider {}", onboarding.name))
+    }
+}
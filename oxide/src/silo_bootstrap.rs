@@ -0,0 +1,249 @@
+//! Bootstrap a silo: the silo itself, its identity provider, its role
+//! assignments, and an initial organization and project.
+//!
+//! If any step fails, everything created so far is deleted again, newest
+//! first, so a failed bootstrap can simply be retried.
+//!
+//! Organizations belong to the silo of the user that creates them, so the
+//! organization and project are created with a client authenticated as a user
+//! of the new silo. Such a client cannot exist before the silo does, so it is
+//! made by a function that is called once the silo, its identity provider and
+//! its roles are in place.
+//!
+//! ```no_run
+//! # async fn pilot() -> anyhow::Result<()> {
+//! use oxide_api::{
+//!     silo_bootstrap::SiloBootstrap,
+//!     types::{OrganizationCreate, ProjectCreate, SiloCreate, UserProvisionType},
+//!     Client,
+//! };
+//!
+//! let oxide = Client::new_from_env();
+//! let spec = SiloBootstrap {
+//!     silo: SiloCreate::builder()
+//!         .name("pilot")
+//!         .description("Pilot customer")
+//!         .admin_group_name("pilot-admins")
+//!         .discoverable(true)
+//!         .user_provision_type(UserProvisionType::Jit)
+//!         .build()?,
+//!     organization: Some(
+//!         OrganizationCreate::builder()
+//!             .name("pilot")
+//!             .description("Pilot customer")
+//!             .build()?,
+//!     ),
+//!     project: Some(
+//!         ProjectCreate::builder()
+//!             .name("default")
+//!             .description("Default project")
+//!             .build()?,
+//!     ),
+//!     ..Default::default()
+//! };
+//! let silo = oxide
+//!     .silos()
+//!     .bootstrap_silo(&spec, |silo| {
+//!         Client::new_with_password(
+//!             std::env::var("OXIDE_HOST").unwrap(),
+//!             silo.name.to_string(),
+//!             "pilot-admin",
+//!             std::env::var("PILOT_PASSWORD").unwrap(),
+//!         )
+//!     })
+//!     .await?;
+//! println!("bootstrapped silo {}", silo.silo.name);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+
+use anyhow::{bail, Context, Result};
+
+use crate::{
+    types::{
+        Organization, OrganizationCreate, Project, ProjectCreate, SamlIdentityProvider,
+        SamlIdentityProviderCreate, Silo, SiloCreate, SiloRoleAssignment, SiloRolePolicy,
+    },
+    Client,
+};
+
+/// Everything to create for a new silo.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SiloBootstrap {
+    /// The silo. If `admin_group_name` is set, that group is created and made
+    /// an admin of the silo.
+    pub silo: SiloCreate,
+    pub identity_provider: Option<SamlIdentityProviderCreate>,
+    /// Role assignments to add to the silo's policy.
+    pub role_assignments: Vec<SiloRoleAssignment>,
+    pub organization: Option<OrganizationCreate>,
+    /// Created in `organization`.
+    pub project: Option<ProjectCreate>,
+}
+
+/// What a bootstrap created.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BootstrappedSilo {
+    pub silo: Silo,
+    pub identity_provider: Option<SamlIdentityProvider>,
+    pub policy: SiloRolePolicy,
+    pub organization: Option<Organization>,
+    pub project: Option<Project>,
+}
+
+/// A resource created during a bootstrap, to delete if a later step fails.
+enum Created {
+    Silo(String),
+    /// The API cannot delete identity providers, they go with their silo.
+    IdentityProvider(String, String),
+    Organization(String),
+    Project(String, String),
+}
+
+impl crate::silos::Silos {
+    /**
+     * Create a silo with its identity provider, role assignments, organization and project.
+     *
+     * If the spec has an organization, `silo_client` is called with the new silo once its
+     * identity provider and roles exist, and must return a client authenticated as a user of
+     * that silo. Everything created is deleted again if any step fails.
+     */
+    pub async fn bootstrap_silo<F, Fut>(
+        &self,
+        spec: &SiloBootstrap,
+        silo_client: F,
+    ) -> Result<BootstrappedSilo>
+    where
+        F: FnOnce(&Silo) -> Fut,
+        Fut: Future<Output = Result<Client>>,
+    {
+        if spec.project.is_some() && spec.organization.is_none() {
+            bail!("a project needs an organization to be created in");
+        }
+
+        let mut created = Vec::new();
+        let mut client = None;
+        let e = match self
+            .bootstrap_steps(spec, silo_client, &mut client, &mut created)
+            .await
+        {
+            Ok(silo) => return Ok(silo),
+            Err(e) => e,
+        };
+
+        log::warn!(
+            "bootstrapping silo {} failed, rolling back: {}",
+            spec.silo.name,
+            e
+        );
+        let silo_client = client.as_ref().unwrap_or(&self.client);
+        let mut failed = Vec::new();
+        for resource in created.iter().rev() {
+            let (what, result) = match resource {
+                Created::Silo(name) => (format!("silo {}", name), self.delete(name).await),
+                Created::IdentityProvider(silo, name) => {
+                    log::debug!("identity provider {} is deleted with silo {}", name, silo);
+                    continue;
+                }
+                Created::Organization(name) => (
+                    format!("organization {}", name),
+                    silo_client.organizations().delete(name).await,
+                ),
+                Created::Project(org, name) => (
+                    format!("project {}/{}", org, name),
+                    silo_client.projects().delete(org, name).await,
+                ),
+            };
+            if let Err(delete_err) = result {
+                failed.push(format!("deleting {}: {}", what, delete_err));
+            }
+        }
+        if failed.is_empty() {
+            return Err(e);
+        }
+        Err(e.context(format!("rolling back failed too: {}", failed.join("; "))))
+    }
+
+    async fn bootstrap_steps<F, Fut>(
+        &self,
+        spec: &SiloBootstrap,
+        silo_client: F,
+        client: &mut Option<Client>,
+        created: &mut Vec<Created>,
+    ) -> Result<BootstrappedSilo>
+    where
+        F: FnOnce(&Silo) -> Fut,
+        Fut: Future<Output = Result<Client>>,
+    {
+        let silo_name = spec.silo.name.as_str();
+        let silo = self
+            .post(&spec.silo)
+            .await
+            .with_context(|| format!("creating silo {}", silo_name))?;
+        created.push(Created::Silo(silo.name.to_string()));
+
+        let identity_provider = match &spec.identity_provider {
+            Some(idp) => {
+                let idp = self
+                    .saml_idp_create(silo_name, idp)
+                    .await
+                    .with_context(|| format!("creating identity provider {}", idp.name))?;
+                created.push(Created::IdentityProvider(
+                    silo_name.to_string(),
+                    idp.name.to_string(),
+                ));
+                Some(idp)
+            }
+            None => None,
+        };
+
+        let mut policy = self.get_policy(silo_name).await?;
+        if !spec.role_assignments.is_empty() {
+            for assignment in &spec.role_assignments {
+                if !policy.role_assignments.contains(assignment) {
+                    policy.role_assignments.push(assignment.clone());
+                }
+            }
+            policy = self
+                .put_policy(silo_name, &policy)
+                .await
+                .with_context(|| format!("granting roles on silo {}", silo_name))?;
+        }
+
+        let (mut organization, mut project) = (None, None);
+        if let Some(org) = &spec.organization {
+            let silo_client = client.insert(
+                silo_client(&silo)
+                    .await
+                    .with_context(|| format!("authenticating in silo {}", silo_name))?,
+            );
+            let org = silo_client
+                .organizations()
+                .post(org)
+                .await
+                .with_context(|| format!("creating organization {}", org.name))?;
+            created.push(Created::Organization(org.name.to_string()));
+
+            if let Some(body) = &spec.project {
+                let p = silo_client
+                    .projects()
+                    .post(&org.name, body)
+                    .await
+                    .with_context(|| format!("creating project {}/{}", org.name, body.name))?;
+                created.push(Created::Project(org.name.to_string(), p.name.to_string()));
+                project = Some(p);
+            }
+            organization = Some(org);
+        }
+
+        Ok(BootstrappedSilo {
+            silo,
+            identity_provider,
+            policy,
+            organization,
+            project,
+        })
+    }
+}
@@ -0,0 +1,351 @@
+//! Snapshot retention policies.
+//!
+//! A [`SnapshotRetention`] pairs a [`RetentionPolicy`] such as "keep 24
+//! hourly, 7 daily, 4 weekly" with a naming convention. Given a disk and its
+//! project's snapshots it produces a [`RetentionPlan`]: whether a new snapshot
+//! is due, and which existing snapshots to keep or delete. Printing the plan is
+//! a dry run; `apply_retention` carries it out.
+//!
+//! Only snapshots of the disk whose name starts with the configured prefix are
+//! managed; anything else is never deleted.
+//!
+//! ```no_run
+//! # async fn nightly() -> anyhow::Result<()> {
+//! use oxide_api::{snapshot_retention::SnapshotRetention, Client};
+//!
+//! let oxide = Client::new_from_env();
+//! let retention = SnapshotRetention {
+//!     policy: "keep 24 hourly, 7 daily, 4 weekly".parse()?,
+//!     ..Default::default()
+//! };
+//!
+//! let snapshots = oxide.snapshots();
+//! let plan = snapshots
+//!     .plan_retention("maze-war", "prod", "db-1", &retention)
+//!     .await?;
+//! println!("{}", plan);
+//! snapshots.apply_retention("maze-war", "prod", &plan).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{collections::BTreeSet, fmt, str::FromStr};
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Datelike, Utc};
+
+use crate::types::{Disk, NameSortMode, Snapshot, SnapshotCreate};
+
+/// The longest name the API accepts.
+const MAX_NAME_LEN: usize = 63;
+
+/// How many snapshots to keep for each period.
+///
+/// For each period the newest snapshot of each of the last `n` periods that
+/// have a snapshot is kept, so gaps in the schedule do not shorten how far back
+/// the snapshots reach.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    pub hourly: usize,
+    pub daily: usize,
+    pub weekly: usize,
+}
+
+impl fmt::Display for RetentionPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts: Vec<String> = [
+            (self.hourly, "hourly"),
+            (self.daily, "daily"),
+            (self.weekly, "weekly"),
+        ]
+        .iter()
+        .filter(|(n, _)| *n > 0)
+        .map(|(n, period)| format!("{} {}", n, period))
+        .collect();
+        write!(f, "keep {}", parts.join(", "))
+    }
+}
+
+impl FromStr for RetentionPolicy {
+    type Err = anyhow::Error;
+
+    /// Parses policies like "keep 24 hourly, 7 daily, 4 weekly". The leading
+    /// "keep" is optional and periods may be given in any order.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s.strip_prefix("keep ").unwrap_or(s);
+
+        let mut policy = RetentionPolicy::default();
+        for part in s.split(',') {
+            let words: Vec<&str> = part.split_whitespace().collect();
+            let (count, period) = match words.as_slice() {
+                [count, period] => (count, period.to_lowercase()),
+                _ => bail!(
+                    "invalid retention `{}`, expected e.g. `24 hourly`",
+                    part.trim()
+                ),
+            };
+            let count: usize = count
+                .parse()
+                .with_context(|| format!("invalid count in `{}`", part.trim()))?;
+            match period.as_str() {
+                "hourly" => policy.hourly = count,
+                "daily" => policy.daily = count,
+                "weekly" => policy.weekly = count,
+                _ => bail!(
+                    "unknown retention period `{}`, expected hourly, daily or weekly",
+                    period
+                ),
+            }
+        }
+
+        if policy.is_empty() {
+            bail!("retention policy `{}` keeps no snapshots", s);
+        }
+        Ok(policy)
+    }
+}
+
+impl RetentionPolicy {
+    /// Returns whether the policy keeps nothing at all.
+    pub fn is_empty(&self) -> bool {
+        self.hourly == 0 && self.daily == 0 && self.weekly == 0
+    }
+}
+
+/// A retention policy plus the naming convention for the snapshots it
+/// manages: `{prefix}-{disk}-{YYYYMMDD}-{HHMMSS}`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SnapshotRetention {
+    pub policy: RetentionPolicy,
+    pub prefix: String,
+}
+
+impl Default for SnapshotRetention {
+    fn default() -> Self {
+        SnapshotRetention {
+            policy: Default::default(),
+            prefix: "auto".to_string(),
+        }
+    }
+}
+
+/// Why a snapshot is kept.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum KeepReason {
+    Hourly,
+    Daily,
+    Weekly,
+    /// The snapshot has no creation time, so its age is unknown.
+    Undated,
+}
+
+impl fmt::Display for KeepReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            KeepReason::Hourly => "hourly",
+            KeepReason::Daily => "daily",
+            KeepReason::Weekly => "weekly",
+            KeepReason::Undated => "undated",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// What to do to bring a disk's snapshots in line with a policy.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RetentionPlan {
+    pub disk_name: String,
+    pub policy: RetentionPolicy,
+    /// The snapshot to create, if one is due.
+    pub create: Option<SnapshotCreate>,
+    pub keep: Vec<(Snapshot, Vec<KeepReason>)>,
+    pub delete: Vec<Snapshot>,
+}
+
+impl fmt::Display for RetentionPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "disk {}: {}", self.disk_name, self.policy)?;
+        if let Some(create) = &self.create {
+            writeln!(f, "  create {}", create.name)?;
+        }
+        for (snapshot, reasons) in &self.keep {
+            let reasons: Vec<String> = reasons.iter().map(|r| r.to_string()).collect();
+            writeln!(f, "  keep   {} ({})", snapshot.name, reasons.join(", "))?;
+        }
+        for snapshot in &self.delete {
+            writeln!(f, "  delete {}", snapshot.name)?;
+        }
+        Ok(())
+    }
+}
+
+impl SnapshotRetention {
+    /// The name of the snapshot of `disk_name` taken at `at`.
+    ///
+    /// The disk name is shortened if needed to keep the whole name within the
+    /// API's 63 character limit.
+    pub fn snapshot_name(&self, disk_name: &str, at: DateTime<Utc>) -> String {
+        let stamp = at.format("%Y%m%d-%H%M%S").to_string();
+        let room = MAX_NAME_LEN.saturating_sub(self.prefix.len() + stamp.len() + 2);
+        let disk: String = disk_name.chars().take(room).collect();
+        format!("{}-{}-{}", self.prefix, disk.trim_end_matches('-'), stamp)
+    }
+
+    /// Returns whether `snapshot` is one of `disk`'s snapshots managed by this
+    /// retention.
+    pub fn manages(&self, disk: &Disk, snapshot: &Snapshot) -> bool {
+        snapshot.disk_id == disk.id && snapshot.name.starts_with(&format!("{}-", self.prefix))
+    }
+
+    /// Compute the plan for `disk` given all the snapshots in its project.
+    pub fn plan(
+        &self,
+        disk: &Disk,
+        snapshots: &[Snapshot],
+        now: DateTime<Utc>,
+    ) -> Result<RetentionPlan> {
+        if self.policy.is_empty() {
+            bail!("retention policy keeps no snapshots");
+        }
+
+        let mut plan = RetentionPlan {
+            disk_name: disk.name.to_string(),
+            policy: self.policy,
+            ..Default::default()
+        };
+
+        let mut dated: Vec<(DateTime<Utc>, &Snapshot)> = Vec::new();
+        for snapshot in snapshots.iter().filter(|s| self.manages(disk, s)) {
+            match snapshot.time_created.0 {
+                Some(t) => dated.push((t, snapshot)),
+                None => plan
+                    .keep
+                    .push((snapshot.clone(), vec![KeepReason::Undated])),
+            }
+        }
+        // Newest first.
+        dated.sort_by_key(|(t, _)| std::cmp::Reverse(*t));
+
+        let periods = [
+            (self.policy.hourly, KeepReason::Hourly),
+            (self.policy.daily, KeepReason::Daily),
+            (self.policy.weekly, KeepReason::Weekly),
+        ];
+
+        // A snapshot is due if there is none yet in the current shortest period.
+        let (_, shortest) = periods.iter().find(|(n, _)| *n > 0).unwrap();
+        let due = dated
+            .first()
+            .map(|(t, _)| period_key(*shortest, *t) != period_key(*shortest, now))
+            .unwrap_or(true);
+
+        // Index 0 stands for the snapshot about to be created, so it takes up
+        // a slot in each period just like an existing one.
+        let mut times: Vec<DateTime<Utc>> = Vec::new();
+        if due {
+            times.push(now);
+        }
+        let offset = times.len();
+        times.extend(dated.iter().map(|(t, _)| *t));
+
+        let mut reasons: Vec<BTreeSet<KeepReason>> = vec![BTreeSet::new(); times.len()];
+        for (count, reason) in periods.iter() {
+            let mut seen: Vec<String> = Vec::new();
+            for (i, t) in times.iter().enumerate() {
+                if seen.len() == *count {
+                    break;
+                }
+                let key = period_key(*reason, *t);
+                if !seen.contains(&key) {
+                    seen.push(key);
+                    reasons[i].insert(*reason);
+                }
+            }
+        }
+
+        if due {
+            plan.create = Some(
+                SnapshotCreate::builder()
+                    .name(self.snapshot_name(&disk.name, now))
+                    .description(format!("{} snapshot of disk {}", self.policy, disk.name))
+                    .disk(disk.name.as_str())
+                    .build()?,
+            );
+        }
+        for (i, (_, snapshot)) in dated.iter().enumerate() {
+            let reasons = &reasons[i + offset];
+            if reasons.is_empty() {
+                plan.delete.push((*snapshot).clone());
+            } else {
+                plan.keep
+                    .push(((*snapshot).clone(), reasons.iter().copied().collect()));
+            }
+        }
+
+        Ok(plan)
+    }
+}
+
+/// Identifies the hour, day or ISO week `t` falls in.
+fn period_key(reason: KeepReason, t: DateTime<Utc>) -> String {
+    match reason {
+        KeepReason::Hourly => t.format("%Y-%m-%d %H").to_string(),
+        KeepReason::Daily => t.format("%Y-%m-%d").to_string(),
+        KeepReason::Weekly => {
+            let week = t.iso_week();
+            format!("{}-W{}", week.year(), week.week())
+        }
+        KeepReason::Undated => String::new(),
+    }
+}
+
+impl crate::snapshots::Snapshots {
+    /**
+     * Compute the retention plan for a disk from the project's current snapshots.
+     */
+    pub async fn plan_retention(
+        &self,
+        organization_name: &str,
+        project_name: &str,
+        disk_name: &str,
+        retention: &SnapshotRetention,
+    ) -> Result<RetentionPlan> {
+        let disk = self
+            .client
+            .disks()
+            .get(disk_name, organization_name, project_name)
+            .await?;
+        let snapshots = self
+            .get_all(organization_name, project_name, NameSortMode::NameAscending)
+            .await?;
+
+        retention.plan(&disk, &snapshots, Utc::now())
+    }
+
+    /**
+     * Carry out a retention plan: create the due snapshot, then delete the expired ones.
+     *
+     * Nothing is deleted if creating the new snapshot fails.
+     */
+    pub async fn apply_retention(
+        &self,
+        organization_name: &str,
+        project_name: &str,
+        plan: &RetentionPlan,
+    ) -> Result<()> {
+        if let Some(create) = &plan.create {
+            self.post(organization_name, project_name, create)
+                .await
+                .with_context(|| format!("creating snapshot {}", create.name))?;
+        }
+
+        for snapshot in &plan.delete {
+            self.delete(organization_name, project_name, &snapshot.name)
+                .await
+                .with_context(|| format!("deleting snapshot {}", snapshot.name))?;
+        }
+
+        Ok(())
+    }
+}
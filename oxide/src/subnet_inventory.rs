@@ -0,0 +1,226 @@
+//! An inventory of the addresses in use in a VPC.
+//!
+//! A [`SubnetInventory`] has one [`AddressRow`] per network interface, mapping
+//! its address to the subnet, instance and interface that own it, and one
+//! [`BlockUsage`] per subnet address block counting the addresses left. Rows
+//! can be exported as CSV or JSON, and printing the inventory shows both as
+//! tables.
+//!
+//! ```no_run
+//! # async fn ipam_sync() -> anyhow::Result<()> {
+//! use oxide_api::Client;
+//!
+//! let oxide = Client::new_from_env();
+//! let inventory = oxide
+//!     .subnets()
+//!     .inventory("maze-war", "prod", "default")
+//!     .await?;
+//! println!("{}", inventory);
+//! std::fs::write("addresses.csv", inventory.addresses_csv()?)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{collections::HashMap, fmt, net::IpAddr};
+
+use anyhow::{Context, Result};
+use ipnetwork::IpNetwork;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tabled::{Table, Tabled};
+
+use crate::types::{Instance, NameSortMode, NetworkInterface, VpcSubnet};
+
+/// The control plane reserves the first addresses of every IPv4 subnet for
+/// itself, as well as the broadcast address.
+const IPV4_RESERVED_LOW: u128 = 5;
+
+/// Who owns an address.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema, Tabled)]
+pub struct AddressRow {
+    pub ip: IpAddr,
+    pub mac: String,
+    pub subnet: String,
+    /// The instance's name, or its id if it could not be found.
+    pub instance: String,
+    pub nic: String,
+    pub primary: bool,
+}
+
+/// How many addresses of a subnet's address block are in use.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema, Tabled)]
+pub struct BlockUsage {
+    pub subnet: String,
+    pub block: String,
+    /// Addresses that can be assigned to interfaces.
+    pub total: u128,
+    pub used: u128,
+    pub free: u128,
+}
+
+/// The addresses in use in a VPC's subnets.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SubnetInventory {
+    pub vpc: String,
+    /// Sorted by subnet, then address.
+    pub addresses: Vec<AddressRow>,
+    pub blocks: Vec<BlockUsage>,
+}
+
+/// The number of addresses in `block` that can be given to interfaces.
+fn assignable(block: &IpNetwork) -> u128 {
+    match block {
+        IpNetwork::V4(net) => {
+            let size = 1u128 << (32 - net.prefix());
+            size.saturating_sub(IPV4_RESERVED_LOW + 1)
+        }
+        IpNetwork::V6(net) => 1u128
+            .checked_shl(128 - net.prefix() as u32)
+            .unwrap_or(u128::MAX),
+    }
+}
+
+impl SubnetInventory {
+    /// Build the inventory of `vpc` from its subnets, the interfaces in them,
+    /// and the instances that own the interfaces.
+    pub fn new(
+        vpc: &str,
+        subnets: &[VpcSubnet],
+        interfaces: &[NetworkInterface],
+        instances: &[Instance],
+    ) -> Result<Self> {
+        let subnet_names: HashMap<&str, &str> = subnets
+            .iter()
+            .map(|s| (s.id.as_str(), s.name.as_str()))
+            .collect();
+        let instance_names: HashMap<&str, &str> = instances
+            .iter()
+            .map(|i| (i.id.as_str(), i.name.as_str()))
+            .collect();
+
+        let mut addresses: Vec<AddressRow> = interfaces
+            .iter()
+            .map(|nic| AddressRow {
+                ip: nic.ip,
+                mac: nic.mac.to_string(),
+                subnet: subnet_names
+                    .get(nic.subnet_id.as_str())
+                    .copied()
+                    .unwrap_or(&nic.subnet_id)
+                    .to_string(),
+                instance: instance_names
+                    .get(nic.instance_id.as_str())
+                    .copied()
+                    .unwrap_or(&nic.instance_id)
+                    .to_string(),
+                nic: nic.name.to_string(),
+                primary: nic.primary,
+            })
+            .collect();
+        addresses.sort_by(|a, b| (&a.subnet, a.ip).cmp(&(&b.subnet, b.ip)));
+
+        let mut blocks = Vec::new();
+        for subnet in subnets {
+            for block in [&subnet.ipv4_block, &subnet.ipv6_block] {
+                if block.is_empty() {
+                    continue;
+                }
+                let net: IpNetwork = block.parse().with_context(|| {
+                    format!("invalid address block {} of subnet {}", block, subnet.name)
+                })?;
+                let total = assignable(&net);
+                let used = addresses
+                    .iter()
+                    .filter(|a| a.subnet == subnet.name && net.contains(a.ip))
+                    .count() as u128;
+                blocks.push(BlockUsage {
+                    subnet: subnet.name.to_string(),
+                    block: block.to_string(),
+                    total,
+                    used,
+                    free: total.saturating_sub(used),
+                });
+            }
+        }
+
+        Ok(SubnetInventory {
+            vpc: vpc.to_string(),
+            addresses,
+            blocks,
+        })
+    }
+
+    /// The address rows as CSV, with a header row.
+    pub fn addresses_csv(&self) -> Result<String> {
+        to_csv(&self.addresses)
+    }
+
+    /// The block usage rows as CSV, with a header row.
+    pub fn blocks_csv(&self) -> Result<String> {
+        to_csv(&self.blocks)
+    }
+
+    /// The whole inventory as pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+fn to_csv<T: Serialize>(rows: &[T]) -> Result<String> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for row in rows {
+        writer.serialize(row)?;
+    }
+    Ok(String::from_utf8(writer.into_inner()?)?)
+}
+
+impl fmt::Display for SubnetInventory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "vpc {}", self.vpc)?;
+        writeln!(f, "{}", Table::new(&self.addresses))?;
+        write!(f, "{}", Table::new(&self.blocks))
+    }
+}
+
+impl crate::subnets::Subnets {
+    /**
+     * Build the address inventory of a VPC from its subnets' network interfaces.
+     */
+    pub async fn inventory(
+        &self,
+        organization_name: &str,
+        project_name: &str,
+        vpc_name: &str,
+    ) -> Result<SubnetInventory> {
+        let subnets = self
+            .get_all(
+                organization_name,
+                project_name,
+                NameSortMode::NameAscending,
+                vpc_name,
+            )
+            .await?;
+
+        let mut interfaces = Vec::new();
+        for subnet in &subnets {
+            interfaces.extend(
+                self.network_interfaces_get_all(
+                    organization_name,
+                    project_name,
+                    NameSortMode::NameAscending,
+                    &subnet.name,
+                    vpc_name,
+                )
+                .await?,
+            );
+        }
+
+        let instances = self
+            .client
+            .instances()
+            .get_all(organization_name, project_name, NameSortMode::NameAscending)
+            .await?;
+
+        SubnetInventory::new(vpc_name, &subnets, &interfaces, &instances)
+    }
+}
@@ -0,0 +1,384 @@
+//! Plan VPC subnet address blocks before asking the API for them.
+//!
+//! Overlapping subnets are only rejected by the server once the create
+//! request is made. A [`SubnetPlanner`] holds a VPC's IPv6 prefix and the
+//! blocks its subnets already use, hands out the next free block of a given
+//! prefix length, checks a proposed set of subnets for overlaps and reports
+//! how much of the address space is in use.
+//!
+//! ```no_run
+//! # async fn carve() -> anyhow::Result<()> {
+//! use oxide_api::{types::VpcSubnetCreate, Client};
+//!
+//! let oxide = Client::new_from_env();
+//! let subnets = oxide.subnets();
+//! let mut planner = subnets.planner("maze-war", "prod", "default").await?;
+//!
+//! let subnet = VpcSubnetCreate::builder()
+//!     .name("web")
+//!     .description("web tier")
+//!     .ipv4_block(planner.allocate_ipv4(24)?.to_string())
+//!     .ipv6_block(planner.allocate_ipv6(64)?.to_string())
+//!     .build()?;
+//! subnets.post("maze-war", "prod", "default", &subnet).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    fmt,
+    net::{Ipv4Addr, Ipv6Addr},
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+use crate::types::{Ipv4Net, Ipv6Net, NameSortMode, Vpc, VpcSubnet, VpcSubnetCreate};
+
+/// The address space IPv4 subnet blocks are allocated from unless told
+/// otherwise.
+pub const DEFAULT_IPV4_SPACE: &str = "10.0.0.0/8";
+
+/// What blocks reserved with `allocate_ipv4` and `allocate_ipv6` are called in
+/// overlap reports.
+const ALLOCATED: &str = "(allocated)";
+
+/// An inclusive range of addresses, as integers, shared by both address
+/// families.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Block {
+    first: u128,
+    last: u128,
+}
+
+impl Block {
+    fn new(network: u128, prefix: u8, bits: u8) -> Block {
+        let host_bits = u32::from(bits - prefix);
+        let size_less_one = if host_bits == 128 {
+            u128::MAX
+        } else {
+            (1u128 << host_bits) - 1
+        };
+        Block {
+            first: network,
+            last: network + size_less_one,
+        }
+    }
+
+    fn v4(net: &ipnetwork::Ipv4Network) -> Block {
+        Block::new(u32::from(net.network()).into(), net.prefix(), 32)
+    }
+
+    fn v6(net: &ipnetwork::Ipv6Network) -> Block {
+        Block::new(u128::from(net.network()), net.prefix(), 128)
+    }
+
+    fn overlaps(&self, other: &Block) -> bool {
+        self.first <= other.last && other.first <= self.last
+    }
+
+    fn size(&self) -> u128 {
+        (self.last - self.first).saturating_add(1)
+    }
+}
+
+/// Finds the lowest block of `prefix` bits inside `space` that does not overlap
+/// any of `used`.
+fn first_free(space: Block, prefix: u8, bits: u8, used: &[Block]) -> Option<u128> {
+    let size_less_one = Block::new(0, prefix, bits).last;
+    let mut candidate = space.first;
+    loop {
+        let block = Block {
+            first: candidate,
+            last: candidate.checked_add(size_less_one)?,
+        };
+        if block.last > space.last {
+            return None;
+        }
+        match used.iter().find(|u| u.overlaps(&block)) {
+            // Skip past the block in the way, keeping the candidate aligned.
+            Some(u) => {
+                candidate = u.last.checked_add(1)?.checked_add(size_less_one)? & !size_less_one
+            }
+            None => return Some(candidate),
+        }
+    }
+}
+
+/// Two subnet blocks that overlap.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Overlap {
+    pub subnet: String,
+    pub block: String,
+    pub other_subnet: String,
+    pub other_block: String,
+}
+
+impl fmt::Display for Overlap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "subnet {} ({}) overlaps subnet {} ({})",
+            self.subnet, self.block, self.other_subnet, self.other_block
+        )
+    }
+}
+
+/// How much of the VPC's address space its subnets use.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Utilization {
+    pub ipv4_space: Ipv4Net,
+    /// Addresses inside `ipv4_space` covered by a subnet.
+    pub ipv4_used: u64,
+    pub ipv4_total: u64,
+    pub ipv6_prefix: Option<Ipv6Net>,
+    /// /64 subnets carved out of `ipv6_prefix`.
+    pub ipv6_subnets_used: u64,
+    pub ipv6_subnets_total: u64,
+}
+
+impl fmt::Display for Utilization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let percent = |used: u64, total: u64| used as f64 * 100.0 / total as f64;
+        writeln!(
+            f,
+            "ipv4 {}: {} of {} addresses in use ({:.1}%)",
+            self.ipv4_space,
+            self.ipv4_used,
+            self.ipv4_total,
+            percent(self.ipv4_used, self.ipv4_total)
+        )?;
+        if let Some(prefix) = &self.ipv6_prefix {
+            writeln!(
+                f,
+                "ipv6 {}: {} of {} /64 subnets in use ({:.1}%)",
+                prefix,
+                self.ipv6_subnets_used,
+                self.ipv6_subnets_total,
+                percent(self.ipv6_subnets_used, self.ipv6_subnets_total)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Allocates subnet blocks within a VPC.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubnetPlanner {
+    ipv4_space: Ipv4Net,
+    ipv6_prefix: Option<Ipv6Net>,
+    /// Named blocks already taken, by existing subnets or earlier
+    /// allocations.
+    ipv4_used: Vec<(String, Ipv4Net)>,
+    ipv6_used: Vec<(String, Ipv6Net)>,
+}
+
+impl SubnetPlanner {
+    /// Create a planner for `vpc` that knows about its existing `subnets`.
+    /// IPv4 blocks are allocated from [`DEFAULT_IPV4_SPACE`].
+    pub fn new(vpc: &Vpc, subnets: &[VpcSubnet]) -> Result<Self> {
+        let ipv6_prefix = if vpc.ipv6_prefix.is_empty() {
+            None
+        } else {
+            Some(
+                vpc.ipv6_prefix
+                    .parse::<Ipv6Net>()
+                    .map_err(|e| anyhow!("vpc {} ipv6 prefix: {}", vpc.name, e))?,
+            )
+        };
+
+        let mut planner = SubnetPlanner {
+            ipv4_space: DEFAULT_IPV4_SPACE.parse().unwrap(),
+            ipv6_prefix,
+            ipv4_used: Vec::new(),
+            ipv6_used: Vec::new(),
+        };
+        for subnet in subnets {
+            planner.add(&subnet.name, &subnet.ipv4_block, &subnet.ipv6_block)?;
+        }
+        Ok(planner)
+    }
+
+    /// Allocate IPv4 blocks from `space` instead, e.g. `172.16.0.0/12`.
+    pub fn with_ipv4_space(mut self, space: Ipv4Net) -> Self {
+        self.ipv4_space = space;
+        self
+    }
+
+    /// Record blocks as taken by `name`. Empty blocks are ignored.
+    pub fn add(&mut self, name: &str, ipv4_block: &str, ipv6_block: &str) -> Result<()> {
+        if !ipv4_block.is_empty() {
+            let block = ipv4_block
+                .parse::<Ipv4Net>()
+                .map_err(|e| anyhow!("subnet {} ipv4 block: {}", name, e))?;
+            self.ipv4_used.push((name.to_string(), block));
+        }
+        if !ipv6_block.is_empty() {
+            let block = ipv6_block
+                .parse::<Ipv6Net>()
+                .map_err(|e| anyhow!("subnet {} ipv6 block: {}", name, e))?;
+            self.ipv6_used.push((name.to_string(), block));
+        }
+        Ok(())
+    }
+
+    /// The lowest free IPv4 block with the given prefix length, without
+    /// reserving it.
+    pub fn next_ipv4(&self, prefix: u8) -> Result<Ipv4Net> {
+        if prefix < self.ipv4_space.prefix() || prefix > 32 {
+            bail!("an ipv4 /{} does not fit in {}", prefix, self.ipv4_space);
+        }
+        let used: Vec<Block> = self.ipv4_used.iter().map(|(_, n)| Block::v4(n)).collect();
+        let network = first_free(Block::v4(&self.ipv4_space), prefix, 32, &used)
+            .ok_or_else(|| anyhow!("no free ipv4 /{} left in {}", prefix, self.ipv4_space))?;
+        let network = Ipv4Addr::from(network as u32);
+        Ok(Ipv4Net(ipnetwork::Ipv4Network::new(network, prefix)?))
+    }
+
+    /// The lowest free IPv6 block with the given prefix length inside the
+    /// VPC's prefix, without reserving it. VPC subnets must be /64s.
+    pub fn next_ipv6(&self, prefix: u8) -> Result<Ipv6Net> {
+        let space = self
+            .ipv6_prefix
+            .ok_or_else(|| anyhow!("the vpc has no ipv6 prefix"))?;
+        if prefix < space.prefix() || prefix > 128 {
+            bail!("an ipv6 /{} does not fit in {}", prefix, space);
+        }
+        let used: Vec<Block> = self.ipv6_used.iter().map(|(_, n)| Block::v6(n)).collect();
+        let network = first_free(Block::v6(&space), prefix, 128, &used)
+            .ok_or_else(|| anyhow!("no free ipv6 /{} left in {}", prefix, space))?;
+        Ok(Ipv6Net(ipnetwork::Ipv6Network::new(
+            Ipv6Addr::from(network),
+            prefix,
+        )?))
+    }
+
+    /// Reserve and return the lowest free IPv4 block with the given prefix
+    /// length.
+    pub fn allocate_ipv4(&mut self, prefix: u8) -> Result<Ipv4Net> {
+        let block = self.next_ipv4(prefix)?;
+        self.ipv4_used.push((ALLOCATED.to_string(), block));
+        Ok(block)
+    }
+
+    /// Reserve and return the lowest free IPv6 block with the given prefix
+    /// length.
+    pub fn allocate_ipv6(&mut self, prefix: u8) -> Result<Ipv6Net> {
+        let block = self.next_ipv6(prefix)?;
+        self.ipv6_used.push((ALLOCATED.to_string(), block));
+        Ok(block)
+    }
+
+    /// Check a proposed set of subnets for blocks that overlap each other or
+    /// the VPC's existing subnets.
+    pub fn overlaps(&self, proposed: &[VpcSubnetCreate]) -> Result<Vec<Overlap>> {
+        let mut planner = self.clone();
+        let mut overlaps = Vec::new();
+        for subnet in proposed {
+            let mut check = SubnetPlanner {
+                ipv4_used: Vec::new(),
+                ipv6_used: Vec::new(),
+                ..planner.clone()
+            };
+            check.add(&subnet.name, &subnet.ipv4_block, &subnet.ipv6_block)?;
+
+            for (_, block) in &check.ipv4_used {
+                for (other, other_block) in &planner.ipv4_used {
+                    if Block::v4(block).overlaps(&Block::v4(other_block)) {
+                        overlaps.push(Overlap {
+                            subnet: subnet.name.to_string(),
+                            block: block.to_string(),
+                            other_subnet: other.to_string(),
+                            other_block: other_block.to_string(),
+                        });
+                    }
+                }
+            }
+            for (_, block) in &check.ipv6_used {
+                for (other, other_block) in &planner.ipv6_used {
+                    if Block::v6(block).overlaps(&Block::v6(other_block)) {
+                        overlaps.push(Overlap {
+                            subnet: subnet.name.to_string(),
+                            block: block.to_string(),
+                            other_subnet: other.to_string(),
+                            other_block: other_block.to_string(),
+                        });
+                    }
+                }
+            }
+
+            planner.ipv4_used.append(&mut check.ipv4_used);
+            planner.ipv6_used.append(&mut check.ipv6_used);
+        }
+        Ok(overlaps)
+    }
+
+    /// How much of the IPv4 space and the VPC's IPv6 prefix is in use.
+    pub fn utilization(&self) -> Utilization {
+        let space = Block::v4(&self.ipv4_space);
+        let ipv4_used = self
+            .ipv4_used
+            .iter()
+            .map(|(_, n)| Block::v4(n))
+            .filter(|b| b.overlaps(&space))
+            .map(|b| {
+                Block {
+                    first: b.first.max(space.first),
+                    last: b.last.min(space.last),
+                }
+                .size() as u64
+            })
+            .sum();
+
+        let (ipv6_subnets_used, ipv6_subnets_total) = match &self.ipv6_prefix {
+            Some(prefix) => {
+                let space = Block::v6(prefix);
+                let used = self
+                    .ipv6_used
+                    .iter()
+                    .map(|(_, n)| Block::v6(n))
+                    .filter(|b| b.overlaps(&space))
+                    .map(|b| (b.size() >> 64).max(1) as u64)
+                    .sum();
+                (used, (space.size() >> 64) as u64)
+            }
+            None => (0, 0),
+        };
+
+        Utilization {
+            ipv4_space: self.ipv4_space,
+            ipv4_used,
+            ipv4_total: space.size() as u64,
+            ipv6_prefix: self.ipv6_prefix,
+            ipv6_subnets_used,
+            ipv6_subnets_total,
+        }
+    }
+}
+
+impl crate::subnets::Subnets {
+    /**
+     * Build a subnet planner from a VPC's IPv6 prefix and its existing subnets.
+     */
+    pub async fn planner(
+        &self,
+        organization_name: &str,
+        project_name: &str,
+        vpc_name: &str,
+    ) -> Result<SubnetPlanner> {
+        let vpc = self
+            .client
+            .vpcs()
+            .get(organization_name, project_name, vpc_name)
+            .await?;
+        let subnets = self
+            .get_all(
+                organization_name,
+                project_name,
+                NameSortMode::NameAscending,
+                vpc_name,
+            )
+            .await?;
+
+        SubnetPlanner::new(&vpc, &subnets).with_context(|| format!("planning vpc {}", vpc_name))
+    }
+}
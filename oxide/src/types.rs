@@ -6,84 +6,21 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use tabled::Tabled;
 
+/// A type storing a range over `T`.
+///
+/// This type supports ranges similar to the `RangeTo`, `Range` and `RangeFrom` types in the standard library. Those cover `(..end)`, `(start..end)`, and `(start..)` respectively.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 #[serde(rename_all = "snake_case")]
-#[serde(tag = "type", content = "start")]
+#[serde(tag = "type")]
 pub enum BinRangedouble {
-    RangeTo(f64),
+    /// A range unbounded below and exclusively above, `..end`.
+    RangeTo { end: f64 },
+    /// A range bounded inclusively below and exclusively above, `start..end`.
     Range { end: f64, start: f64 },
-    RangeFrom(f64),
-}
-
-impl fmt::Display for BinRangedouble {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let j = serde_json::json!(self);
-        let mut tag: String = serde_json::from_value(j["type"].clone()).unwrap_or_default();
-        let mut content: String = serde_json::from_value(j["start"].clone()).unwrap_or_default();
-        if content.is_empty() {
-            let map: std::collections::HashMap<String, String> =
-                serde_json::from_value(j["start"].clone()).unwrap_or_default();
-            if let Some((_, v)) = map.iter().next() {
-                content = v.to_string();
-            }
-        }
-        if tag == "internet_gateway" {
-            tag = "inetgw".to_string();
-        }
-        write!(f, "{}={}", tag, content)
-    }
+    /// A range bounded inclusively below and unbounded above, `start..`.
+    RangeFrom { start: f64 },
 }
 
-impl std::str::FromStr for BinRangedouble {
-    type Err = anyhow::Error;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts = s.split('=').collect::<Vec<&str>>();
-        if parts.len() != 2 {
-            anyhow::bail!("invalid format for BinRangedouble, got {}", s);
-        }
-        let tag = parts[0].to_string();
-        let content = parts[1].to_string();
-        let mut j = String::new();
-        if tag == "range_to" {
-            j = format!(
-                r#"{{
-"type": "range_to",
-"start": {}
-        }}"#,
-                serde_json::json!(f64::from_str(&content).unwrap())
-            );
-        }
-        if tag == "range" {
-            j = format!(
-                r#"{{
-"type": "range",
-"start": {}
-        }}"#,
-                serde_json::json!(f64::from_str(&content).unwrap())
-            );
-        }
-        if tag == "range" {
-            j = format!(
-                r#"{{
-"type": "range",
-"start": {}
-        }}"#,
-                serde_json::json!(f64::from_str(&content).unwrap())
-            );
-        }
-        if tag == "range_from" {
-            j = format!(
-                r#"{{
-"type": "range_from",
-"start": {}
-        }}"#,
-                serde_json::json!(f64::from_str(&content).unwrap())
-            );
-        }
-        let result = serde_json::from_str(&j)?;
-        Ok(result)
-    }
-}
 impl BinRangedouble {
     pub fn variants() -> Vec<String> {
         vec![
@@ -136,84 +73,21 @@ impl std::str::FromStr for BinRangedoubleType {
     }
 }
 
+/// A type storing a range over `T`.
+///
+/// This type supports ranges similar to the `RangeTo`, `Range` and `RangeFrom` types in the standard library. Those cover `(..end)`, `(start..end)`, and `(start..)` respectively.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 #[serde(rename_all = "snake_case")]
-#[serde(tag = "type", content = "start")]
+#[serde(tag = "type")]
 pub enum BinRangeint64 {
-    RangeTo(i64),
+    /// A range unbounded below and exclusively above, `..end`.
+    RangeTo { end: i64 },
+    /// A range bounded inclusively below and exclusively above, `start..end`.
     Range { end: i64, start: i64 },
-    RangeFrom(i64),
-}
-
-impl fmt::Display for BinRangeint64 {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let j = serde_json::json!(self);
-        let mut tag: String = serde_json::from_value(j["type"].clone()).unwrap_or_default();
-        let mut content: String = serde_json::from_value(j["start"].clone()).unwrap_or_default();
-        if content.is_empty() {
-            let map: std::collections::HashMap<String, String> =
-                serde_json::from_value(j["start"].clone()).unwrap_or_default();
-            if let Some((_, v)) = map.iter().next() {
-                content = v.to_string();
-            }
-        }
-        if tag == "internet_gateway" {
-            tag = "inetgw".to_string();
-        }
-        write!(f, "{}={}", tag, content)
-    }
+    /// A range bounded inclusively below and unbounded above, `start..`.
+    RangeFrom { start: i64 },
 }
 
-impl std::str::FromStr for BinRangeint64 {
-    type Err = anyhow::Error;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts = s.split('=').collect::<Vec<&str>>();
-        if parts.len() != 2 {
-            anyhow::bail!("invalid format for BinRangeint64, got {}", s);
-        }
-        let tag = parts[0].to_string();
-        let content = parts[1].to_string();
-        let mut j = String::new();
-        if tag == "range_to" {
-            j = format!(
-                r#"{{
-"type": "range_to",
-"start": {}
-        }}"#,
-                serde_json::json!(i64::from_str(&content).unwrap())
-            );
-        }
-        if tag == "range" {
-            j = format!(
-                r#"{{
-"type": "range",
-"start": {}
-        }}"#,
-                serde_json::json!(i64::from_str(&content).unwrap())
-            );
-        }
-        if tag == "range" {
-            j = format!(
-                r#"{{
-"type": "range",
-"start": {}
-        }}"#,
-                serde_json::json!(i64::from_str(&content).unwrap())
-            );
-        }
-        if tag == "range_from" {
-            j = format!(
-                r#"{{
-"type": "range_from",
-"start": {}
-        }}"#,
-                serde_json::json!(i64::from_str(&content).unwrap())
-            );
-        }
-        let result = serde_json::from_str(&j)?;
-        Ok(result)
-    }
-}
 impl BinRangeint64 {
     pub fn variants() -> Vec<String> {
         vec![
@@ -275,7 +149,13 @@ pub struct Bindouble {
     #[serde(default)]
     pub count: u64,
 
+    /**
+     * A type storing a range over `T`.
+     *  
+     *  This type supports ranges similar to the `RangeTo`, `Range` and `RangeFrom` types in the standard library. Those cover `(..end)`, `(start..end)`, and `(start..)` respectively.
+     */
     #[serde()]
+    #[header(hidden = true)]
     pub range: BinRangedouble,
 }
 
@@ -288,10 +168,191 @@ pub struct Binint64 {
     #[serde(default)]
     pub count: u64,
 
+    /**
+     * A type storing a range over `T`.
+     *  
+     *  This type supports ranges similar to the `RangeTo`, `Range` and `RangeFrom` types in the standard library. Those cover `(..end)`, `(start..end)`, and `(start..)` respectively.
+     */
     #[serde()]
+    #[header(hidden = true)]
     pub range: BinRangeint64,
 }
 
+/// A count of bytes, typically used either for memory or storage capacity
+///
+/// The maximum supported byte count is [`i64::MAX`].  This makes it somewhat inconvenient to define constructors: a u32 constructor can be infallible, but an i64 constructor can fail (if the value is negative) and a u64 constructor can fail (if the value is larger than i64::MAX).  We provide all of these for consumers' convenience.
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+#[serde(try_from = "u64", into = "u64")]
+pub struct ByteCount(u64);
+
+impl ByteCount {
+    const KIB: u64 = 1 << 10;
+    const MIB: u64 = 1 << 20;
+    const GIB: u64 = 1 << 30;
+    const TIB: u64 = 1 << 40;
+
+    pub fn from_kib(kib: u32) -> ByteCount {
+        ByteCount(u64::from(kib) * Self::KIB)
+    }
+
+    pub fn from_mib(mib: u32) -> ByteCount {
+        ByteCount(u64::from(mib) * Self::MIB)
+    }
+
+    pub fn from_gib(gib: u32) -> ByteCount {
+        ByteCount(u64::from(gib) * Self::GIB)
+    }
+
+    pub fn to_bytes(&self) -> u64 {
+        self.0
+    }
+
+    pub fn to_whole_kib(&self) -> u64 {
+        self.0 / Self::KIB
+    }
+
+    pub fn to_whole_mib(&self) -> u64 {
+        self.0 / Self::MIB
+    }
+
+    pub fn to_whole_gib(&self) -> u64 {
+        self.0 / Self::GIB
+    }
+
+    pub fn to_whole_tib(&self) -> u64 {
+        self.0 / Self::TIB
+    }
+}
+
+impl From<u32> for ByteCount {
+    fn from(bytes: u32) -> ByteCount {
+        ByteCount(u64::from(bytes))
+    }
+}
+
+impl std::convert::TryFrom<u64> for ByteCount {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: u64) -> Result<Self, Self::Error> {
+        if bytes > i64::MAX as u64 {
+            anyhow::bail!(
+                "byte count {} is larger than the maximum of {}",
+                bytes,
+                i64::MAX
+            );
+        }
+        Ok(ByteCount(bytes))
+    }
+}
+
+impl std::convert::TryFrom<i64> for ByteCount {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: i64) -> Result<Self, Self::Error> {
+        if bytes < 0 {
+            anyhow::bail!("byte count {} is negative", bytes);
+        }
+        Ok(ByteCount(bytes as u64))
+    }
+}
+
+impl From<ByteCount> for u64 {
+    fn from(b: ByteCount) -> u64 {
+        b.0
+    }
+}
+
+impl From<ByteCount> for i64 {
+    fn from(b: ByteCount) -> i64 {
+        // Every constructor enforces the i64::MAX limit.
+        b.0 as i64
+    }
+}
+
+impl fmt::Display for ByteCount {
+    /// Formats the count in the largest binary unit that represents it
+    /// exactly, e.g. `20 GiB` or `1536 MiB`, so the output parses back.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let units = [
+            (Self::TIB, "TiB"),
+            (Self::GIB, "GiB"),
+            (Self::MIB, "MiB"),
+            (Self::KIB, "KiB"),
+        ];
+        for (size, unit) in units {
+            // Every unit is a power of two.
+            if self.0 >= size && self.0.trailing_zeros() >= size.trailing_zeros() {
+                return write!(f, "{} {}", self.0 / size, unit);
+            }
+        }
+        write!(f, "{} B", self.0)
+    }
+}
+
+impl std::str::FromStr for ByteCount {
+    type Err = anyhow::Error;
+
+    /// Parses a count with an optional unit suffix, e.g. `20GiB`, `512M` or
+    /// `1073741824`. `K`, `M`, `G` and `T` (with or without `iB`) are binary
+    /// units, `KB`, `MB`, `GB` and `TB` are decimal units.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let (digits, unit) = s.split_at(split);
+        if digits.is_empty() {
+            anyhow::bail!("invalid byte count {:?}: missing number", s);
+        }
+        let count: u64 = digits.parse()?;
+
+        let multiplier: u64 = match unit.trim().to_ascii_lowercase().as_str() {
+            "" | "b" => 1,
+            "k" | "kib" => Self::KIB,
+            "m" | "mib" => Self::MIB,
+            "g" | "gib" => Self::GIB,
+            "t" | "tib" => Self::TIB,
+            "kb" => 1_000,
+            "mb" => 1_000_000,
+            "gb" => 1_000_000_000,
+            "tb" => 1_000_000_000_000,
+            u => anyhow::bail!("invalid byte count {:?}: unknown unit {:?}", s, u),
+        };
+
+        match count.checked_mul(multiplier) {
+            Some(bytes) => <ByteCount as std::convert::TryFrom<u64>>::try_from(bytes),
+            None => anyhow::bail!("byte count {:?} overflows", s),
+        }
+    }
+}
+
+impl JsonSchema for ByteCount {
+    fn schema_name() -> String {
+        "ByteCount".to_string()
+    }
+
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::Schema::Object(schemars::schema::SchemaObject {
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some(
+                    "A count of bytes, typically used either for memory or storage capacity"
+                        .to_string(),
+                ),
+                ..Default::default()
+            })),
+            instance_type: Some(schemars::schema::SingleOrVec::Single(Box::new(
+                schemars::schema::InstanceType::Integer,
+            ))),
+            format: Some("uint64".to_string()),
+            number: Some(Box::new(schemars::schema::NumberValidation {
+                minimum: Some(0.0),
+                maximum: Some(i64::MAX as f64),
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
 /// A cumulative or counter data type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct Cumulativedouble {
@@ -320,84 +381,35 @@ pub struct Cumulativeint64 {
     pub value: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Tabled)]
+/// A `Datum` is a single sampled data point from a metric.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 #[serde(rename_all = "snake_case")]
+#[serde(tag = "type", content = "datum")]
 pub enum Datum {
-    Bool,
-    Bytes,
-    CumulativeF64,
-    CumulativeI64,
-    F64,
-    HistogramF64,
-    HistogramI64,
-    I64,
-    String,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Datum {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Datum::Bool => "bool",
-            Datum::Bytes => "bytes",
-            Datum::CumulativeF64 => "cumulative_f_64",
-            Datum::CumulativeI64 => "cumulative_i_64",
-            Datum::F64 => "f_64",
-            Datum::HistogramF64 => "histogram_f_64",
-            Datum::HistogramI64 => "histogram_i_64",
-            Datum::I64 => "i_64",
-            Datum::String => "string",
-            Datum::Noop => "",
-            Datum::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
+    Bool(bool),
+    I64(i64),
+    F64(f64),
+    String(String),
+    Bytes(Vec<u8>),
+    CumulativeI64(Cumulativeint64),
+    CumulativeF64(Cumulativedouble),
+    HistogramI64(Histogramint64),
+    HistogramF64(Histogramdouble),
 }
 
-impl Default for Datum {
-    fn default() -> Datum {
-        Datum::Bool
-    }
-}
-impl std::str::FromStr for Datum {
-    type Err = anyhow::Error;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s == "bool" {
-            return Ok(Datum::Bool);
-        }
-        if s == "bytes" {
-            return Ok(Datum::Bytes);
-        }
-        if s == "cumulative_f_64" {
-            return Ok(Datum::CumulativeF64);
-        }
-        if s == "cumulative_i_64" {
-            return Ok(Datum::CumulativeI64);
-        }
-        if s == "f_64" {
-            return Ok(Datum::F64);
-        }
-        if s == "histogram_f_64" {
-            return Ok(Datum::HistogramF64);
-        }
-        if s == "histogram_i_64" {
-            return Ok(Datum::HistogramI64);
-        }
-        if s == "i_64" {
-            return Ok(Datum::I64);
-        }
-        if s == "string" {
-            return Ok(Datum::String);
-        }
-        anyhow::bail!("invalid string for Datum: {}", s);
-    }
-}
 impl Datum {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Datum::Noop)
+    pub fn variants() -> Vec<String> {
+        vec![
+            "bool".to_string(),
+            "bytes".to_string(),
+            "cumulative_f64".to_string(),
+            "cumulative_i64".to_string(),
+            "f64".to_string(),
+            "histogram_f64".to_string(),
+            "histogram_i64".to_string(),
+            "i64".to_string(),
+            "string".to_string(),
+        ]
     }
 }
 
@@ -508,6 +520,47 @@ pub struct DerEncodedKeyPair {
     pub public_cert: String,
 }
 
+impl DerEncodedKeyPair {
+    /// A builder for [`DerEncodedKeyPair`].
+    pub fn builder() -> DerEncodedKeyPairBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`DerEncodedKeyPair`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct DerEncodedKeyPairBuilder {
+    private_key: Option<String>,
+    public_cert: Option<String>,
+}
+
+impl DerEncodedKeyPairBuilder {
+    /// Sets [`DerEncodedKeyPair::private_key`].
+    pub fn private_key(mut self, value: impl Into<String>) -> Self {
+        self.private_key = Some(value.into());
+        self
+    }
+
+    /// Sets [`DerEncodedKeyPair::public_cert`].
+    pub fn public_cert(mut self, value: impl Into<String>) -> Self {
+        self.public_cert = Some(value.into());
+        self
+    }
+
+    /// Builds the [`DerEncodedKeyPair`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<DerEncodedKeyPair> {
+        Ok(DerEncodedKeyPair {
+            private_key: self
+                .private_key
+                .ok_or_else(|| anyhow::anyhow!("DerEncodedKeyPair requires private_key"))?,
+            public_cert: self
+                .public_cert
+                .ok_or_else(|| anyhow::anyhow!("DerEncodedKeyPair requires public_cert"))?,
+        })
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct DeviceAccessTokenRequest {
     #[serde(
@@ -532,6 +585,57 @@ pub struct DeviceAccessTokenRequest {
     pub grant_type: String,
 }
 
+impl DeviceAccessTokenRequest {
+    /// A builder for [`DeviceAccessTokenRequest`].
+    pub fn builder() -> DeviceAccessTokenRequestBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`DeviceAccessTokenRequest`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct DeviceAccessTokenRequestBuilder {
+    client_id: Option<String>,
+    device_code: Option<String>,
+    grant_type: Option<String>,
+}
+
+impl DeviceAccessTokenRequestBuilder {
+    /// Sets [`DeviceAccessTokenRequest::client_id`].
+    pub fn client_id(mut self, value: impl Into<String>) -> Self {
+        self.client_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeviceAccessTokenRequest::device_code`].
+    pub fn device_code(mut self, value: impl Into<String>) -> Self {
+        self.device_code = Some(value.into());
+        self
+    }
+
+    /// Sets [`DeviceAccessTokenRequest::grant_type`].
+    pub fn grant_type(mut self, value: impl Into<String>) -> Self {
+        self.grant_type = Some(value.into());
+        self
+    }
+
+    /// Builds the [`DeviceAccessTokenRequest`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<DeviceAccessTokenRequest> {
+        Ok(DeviceAccessTokenRequest {
+            client_id: self
+                .client_id
+                .ok_or_else(|| anyhow::anyhow!("DeviceAccessTokenRequest requires client_id"))?,
+            device_code: self
+                .device_code
+                .ok_or_else(|| anyhow::anyhow!("DeviceAccessTokenRequest requires device_code"))?,
+            grant_type: self
+                .grant_type
+                .ok_or_else(|| anyhow::anyhow!("DeviceAccessTokenRequest requires grant_type"))?,
+        })
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct DeviceAuthRequest {
     #[serde(
@@ -542,6 +646,37 @@ pub struct DeviceAuthRequest {
     pub client_id: String,
 }
 
+impl DeviceAuthRequest {
+    /// A builder for [`DeviceAuthRequest`].
+    pub fn builder() -> DeviceAuthRequestBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`DeviceAuthRequest`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct DeviceAuthRequestBuilder {
+    client_id: Option<String>,
+}
+
+impl DeviceAuthRequestBuilder {
+    /// Sets [`DeviceAuthRequest::client_id`].
+    pub fn client_id(mut self, value: impl Into<String>) -> Self {
+        self.client_id = Some(value.into());
+        self
+    }
+
+    /// Builds the [`DeviceAuthRequest`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<DeviceAuthRequest> {
+        Ok(DeviceAuthRequest {
+            client_id: self
+                .client_id
+                .ok_or_else(|| anyhow::anyhow!("DeviceAuthRequest requires client_id"))?,
+        })
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct DeviceAuthVerify {
     #[serde(
@@ -552,6 +687,37 @@ pub struct DeviceAuthVerify {
     pub user_code: String,
 }
 
+impl DeviceAuthVerify {
+    /// A builder for [`DeviceAuthVerify`].
+    pub fn builder() -> DeviceAuthVerifyBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`DeviceAuthVerify`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct DeviceAuthVerifyBuilder {
+    user_code: Option<String>,
+}
+
+impl DeviceAuthVerifyBuilder {
+    /// Sets [`DeviceAuthVerify::user_code`].
+    pub fn user_code(mut self, value: impl Into<String>) -> Self {
+        self.user_code = Some(value.into());
+        self
+    }
+
+    /// Builds the [`DeviceAuthVerify`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<DeviceAuthVerify> {
+        Ok(DeviceAuthVerify {
+            user_code: self
+                .user_code
+                .ok_or_else(|| anyhow::anyhow!("DeviceAuthVerify requires user_code"))?,
+        })
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 #[serde(tag = "type", content = "value")]
@@ -561,49 +727,29 @@ pub enum Digest {
 
 impl fmt::Display for Digest {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let j = serde_json::json!(self);
-        let mut tag: String = serde_json::from_value(j["type"].clone()).unwrap_or_default();
-        let mut content: String = serde_json::from_value(j["value"].clone()).unwrap_or_default();
-        if content.is_empty() {
-            let map: std::collections::HashMap<String, String> =
-                serde_json::from_value(j["value"].clone()).unwrap_or_default();
-            if let Some((_, v)) = map.iter().next() {
-                content = v.to_string();
-            }
-        }
-        if tag == "internet_gateway" {
-            tag = "inetgw".to_string();
+        match self {
+            Digest::Sha256(value) => write!(f, "sha256={}", value),
         }
-        write!(f, "{}={}", tag, content)
     }
 }
 
 impl std::str::FromStr for Digest {
     type Err = anyhow::Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts = s.split('=').collect::<Vec<&str>>();
-        if parts.len() != 2 {
-            anyhow::bail!("invalid format for Digest, got {}", s);
-        }
-        let tag = parts[0].to_string();
-        let content = parts[1].to_string();
-        let mut j = String::new();
-        if tag == "sha_256" {
-            j = format!(
-                r#"{{
-"type": "sha_256",
-"value": "{}"
-        }}"#,
-                content
-            );
-        }
-        let result = serde_json::from_str(&j)?;
-        Ok(result)
+        let (tag, value) = match s.split_once('=') {
+            Some((tag, value)) => (tag, Some(value)),
+            None => (s, None),
+        };
+        Ok(match (tag, value) {
+            ("sha256", Some(value)) => Digest::Sha256(value.to_string()),
+            _ => anyhow::bail!("invalid format for Digest, got {}", s),
+        })
     }
 }
+
 impl Digest {
     pub fn variants() -> Vec<String> {
-        vec!["sha_256".to_string()]
+        vec!["sha256".to_string()]
     }
 }
 /**
@@ -639,79 +785,61 @@ impl std::str::FromStr for DigestType {
     }
 }
 
+/// State of a Disk (primarily: attached or not)
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 #[serde(tag = "state", content = "instance")]
 pub enum DiskState {
+    /// Disk is being initialized
     Creating,
+    /// Disk is ready but detached from any Instance
     Detached,
+    /// Disk is being attached to the given Instance
     Attaching(String),
+    /// Disk is attached to the given Instance
     Attached(String),
+    /// Disk is being detached from the given Instance
     Detaching(String),
+    /// Disk has been destroyed
     Destroyed,
+    /// Disk is unavailable
     Faulted,
 }
 
 impl fmt::Display for DiskState {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let j = serde_json::json!(self);
-        let mut tag: String = serde_json::from_value(j["state"].clone()).unwrap_or_default();
-        let mut content: String = serde_json::from_value(j["instance"].clone()).unwrap_or_default();
-        if content.is_empty() {
-            let map: std::collections::HashMap<String, String> =
-                serde_json::from_value(j["instance"].clone()).unwrap_or_default();
-            if let Some((_, v)) = map.iter().next() {
-                content = v.to_string();
-            }
-        }
-        if tag == "internet_gateway" {
-            tag = "inetgw".to_string();
+        match self {
+            DiskState::Creating => write!(f, "creating"),
+            DiskState::Detached => write!(f, "detached"),
+            DiskState::Attaching(value) => write!(f, "attaching={}", value),
+            DiskState::Attached(value) => write!(f, "attached={}", value),
+            DiskState::Detaching(value) => write!(f, "detaching={}", value),
+            DiskState::Destroyed => write!(f, "destroyed"),
+            DiskState::Faulted => write!(f, "faulted"),
         }
-        write!(f, "{}={}", tag, content)
     }
 }
 
 impl std::str::FromStr for DiskState {
     type Err = anyhow::Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts = s.split('=').collect::<Vec<&str>>();
-        if parts.len() != 2 {
-            anyhow::bail!("invalid format for DiskState, got {}", s);
-        }
-        let tag = parts[0].to_string();
-        let content = parts[1].to_string();
-        let mut j = String::new();
-        if tag == "attaching" {
-            j = format!(
-                r#"{{
-"state": "attaching",
-"instance": "{}"
-        }}"#,
-                content
-            );
-        }
-        if tag == "attached" {
-            j = format!(
-                r#"{{
-"state": "attached",
-"instance": "{}"
-        }}"#,
-                content
-            );
-        }
-        if tag == "detaching" {
-            j = format!(
-                r#"{{
-"state": "detaching",
-"instance": "{}"
-        }}"#,
-                content
-            );
-        }
-        let result = serde_json::from_str(&j)?;
-        Ok(result)
+        let (tag, value) = match s.split_once('=') {
+            Some((tag, value)) => (tag, Some(value)),
+            None => (s, None),
+        };
+        Ok(match (tag, value) {
+            ("creating", None) => DiskState::Creating,
+            ("detached", None) => DiskState::Detached,
+            ("attaching", Some(value)) => DiskState::Attaching(value.to_string()),
+            ("attached", Some(value)) => DiskState::Attached(value.to_string()),
+            ("detaching", Some(value)) => DiskState::Detaching(value.to_string()),
+            ("destroyed", None) => DiskState::Destroyed,
+            ("faulted", None) => DiskState::Faulted,
+            _ => anyhow::bail!("invalid format for DiskState, got {}", s),
+        })
     }
 }
+
 impl DiskState {
     pub fn variants() -> Vec<String> {
         vec![
@@ -827,7 +955,7 @@ pub struct Disk {
      *  The maximum supported byte count is [`i64::MAX`].  This makes it somewhat inconvenient to define constructors: a u32 constructor can be infallible, but an i64 constructor can fail (if the value is negative) and a u64 constructor can fail (if the value is larger than i64::MAX).  We provide all of these for consumers' convenience.
      */
     #[serde(default)]
-    pub block_size: u64,
+    pub block_size: crate::types::ByteCount,
 
     #[serde(
         default,
@@ -856,7 +984,7 @@ pub struct Disk {
      *  The maximum supported byte count is [`i64::MAX`].  This makes it somewhat inconvenient to define constructors: a u32 constructor can be infallible, but an i64 constructor can fail (if the value is negative) and a u64 constructor can fail (if the value is larger than i64::MAX).  We provide all of these for consumers' convenience.
      */
     #[serde(default)]
-    pub size: u64,
+    pub size: crate::types::ByteCount,
 
     #[serde(
         default,
@@ -865,6 +993,9 @@ pub struct Disk {
     )]
     pub snapshot_id: String,
 
+    /**
+     * State of a Disk (primarily: attached or not)
+     */
     #[serde()]
     pub state: DiskState,
 
@@ -881,100 +1012,59 @@ pub struct Disk {
     pub time_modified: crate::utils::DisplayOptionDateTime,
 }
 
+/// Different sources for a disk
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 #[serde(tag = "type")]
 pub enum DiskSource {
+    /// Create a blank disk
     Blank { block_size: i64 },
+    /// Create a disk from a disk snapshot
     Snapshot { snapshot_id: String },
+    /// Create a disk from a project image
     Image { image_id: String },
+    /// Create a disk from a global image
     GlobalImage { image_id: String },
 }
 
 impl fmt::Display for DiskSource {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let j = serde_json::json!(self);
-        let mut tag: String = serde_json::from_value(j["type"].clone()).unwrap_or_default();
-
-        let mut value = "image_id";
-        if tag == *"blank" {
-            value = "block_size";
-        };
-        if tag == *"snapshot" {
-            value = "snapshot_id";
-        };
-
-        let mut content: String = match serde_json::from_value(j[value].clone()) {
-            Ok(v) => v,
-            Err(_) => {
-                let int: i64 = serde_json::from_value(j[value].clone()).unwrap_or_default();
-                format!("{}", int)
-            }
-        };
-        if content.is_empty() {
-            let map: std::collections::HashMap<String, String> =
-                serde_json::from_value(j[value].clone()).unwrap_or_default();
-            if let Some((_, v)) = map.iter().next() {
-                content = v.to_string();
-            }
-        }
-        if tag == "internet_gateway" {
-            tag = "inetgw".to_string();
+        match self {
+            DiskSource::Blank { block_size: value } => write!(f, "blank={}", value),
+            DiskSource::Snapshot { snapshot_id: value } => write!(f, "snapshot={}", value),
+            DiskSource::Image { image_id: value } => write!(f, "image={}", value),
+            DiskSource::GlobalImage { image_id: value } => write!(f, "global_image={}", value),
         }
-        write!(f, "{}={}", tag, content)
     }
 }
 
 impl std::str::FromStr for DiskSource {
     type Err = anyhow::Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts = s.split('=').collect::<Vec<&str>>();
-        if parts.len() != 2 {
-            anyhow::bail!("invalid format for DiskSource, got {}", s);
-        }
-        let tag = parts[0].to_string();
-        let content = parts[1].to_string();
-        let mut j = String::new();
-        if tag == "blank" {
-            j = format!(
-                r#"{{
-"type": "blank",
-"block_size": {}
-        }}"#,
-                serde_json::json!(i64::from_str(&content).unwrap())
-            );
-        }
-        if tag == "snapshot" {
-            j = format!(
-                r#"{{
-"type": "snapshot",
-"snapshot_id": "{}"
-        }}"#,
-                content
-            );
-        }
-        if tag == "image" {
-            j = format!(
-                r#"{{
-"type": "image",
-"image_id": "{}"
-        }}"#,
-                content
-            );
-        }
-        if tag == "global_image" {
-            j = format!(
-                r#"{{
-"type": "global_image",
-"image_id": "{}"
-        }}"#,
-                content
-            );
-        }
-        let result = serde_json::from_str(&j)?;
-        Ok(result)
+        let (tag, value) = match s.split_once('=') {
+            Some((tag, value)) => (tag, Some(value)),
+            None => (s, None),
+        };
+        Ok(match (tag, value) {
+            ("blank", Some(value)) => DiskSource::Blank {
+                block_size: value
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("invalid blank `{}`: {}", value, e))?,
+            },
+            ("snapshot", Some(value)) => DiskSource::Snapshot {
+                snapshot_id: value.to_string(),
+            },
+            ("image", Some(value)) => DiskSource::Image {
+                image_id: value.to_string(),
+            },
+            ("global_image", Some(value)) => DiskSource::GlobalImage {
+                image_id: value.to_string(),
+            },
+            _ => anyhow::bail!("invalid format for DiskSource, got {}", s),
+        })
     }
 }
+
 impl DiskSource {
     pub fn variants() -> Vec<String> {
         vec![
@@ -1053,6 +1143,9 @@ pub struct DiskCreate {
     )]
     pub description: String,
 
+    /**
+     * Different sources for a disk
+     */
     #[serde()]
     pub disk_source: DiskSource,
 
@@ -1062,7 +1155,68 @@ pub struct DiskCreate {
      *  The maximum supported byte count is [`i64::MAX`].  This makes it somewhat inconvenient to define constructors: a u32 constructor can be infallible, but an i64 constructor can fail (if the value is negative) and a u64 constructor can fail (if the value is larger than i64::MAX).  We provide all of these for consumers' convenience.
      */
     #[serde(default)]
-    pub size: u64,
+    pub size: crate::types::ByteCount,
+}
+
+impl DiskCreate {
+    /// A builder for [`DiskCreate`].
+    pub fn builder() -> DiskCreateBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`DiskCreate`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct DiskCreateBuilder {
+    name: Option<String>,
+    description: Option<String>,
+    disk_source: Option<DiskSource>,
+    size: Option<crate::types::ByteCount>,
+}
+
+impl DiskCreateBuilder {
+    /// Sets [`DiskCreate::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`DiskCreate::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets [`DiskCreate::disk_source`].
+    pub fn disk_source(mut self, value: impl Into<DiskSource>) -> Self {
+        self.disk_source = Some(value.into());
+        self
+    }
+
+    /// Sets [`DiskCreate::size`].
+    pub fn size(mut self, value: impl Into<crate::types::ByteCount>) -> Self {
+        self.size = Some(value.into());
+        self
+    }
+
+    /// Builds the [`DiskCreate`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<DiskCreate> {
+        Ok(DiskCreate {
+            name: self
+                .name
+                .ok_or_else(|| anyhow::anyhow!("DiskCreate requires name"))?,
+            description: self
+                .description
+                .ok_or_else(|| anyhow::anyhow!("DiskCreate requires description"))?,
+            disk_source: self
+                .disk_source
+                .ok_or_else(|| anyhow::anyhow!("DiskCreate requires disk_source"))?,
+            size: self
+                .size
+                .ok_or_else(|| anyhow::anyhow!("DiskCreate requires size"))?,
+        })
+    }
 }
 
 /// Parameters for the [`Disk`](omicron_common::api::external::Disk) to be attached or detached to an instance
@@ -1079,6 +1233,37 @@ pub struct DiskIdentifier {
     pub name: String,
 }
 
+impl DiskIdentifier {
+    /// A builder for [`DiskIdentifier`].
+    pub fn builder() -> DiskIdentifierBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`DiskIdentifier`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct DiskIdentifierBuilder {
+    name: Option<String>,
+}
+
+impl DiskIdentifierBuilder {
+    /// Sets [`DiskIdentifier::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the [`DiskIdentifier`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<DiskIdentifier> {
+        Ok(DiskIdentifier {
+            name: self
+                .name
+                .ok_or_else(|| anyhow::anyhow!("DiskIdentifier requires name"))?,
+        })
+    }
+}
+
 /// A single page of results
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct DiskResultsPage {
@@ -1128,6 +1313,47 @@ pub struct Distribution {
     pub version: String,
 }
 
+impl Distribution {
+    /// A builder for [`Distribution`].
+    pub fn builder() -> DistributionBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`Distribution`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct DistributionBuilder {
+    name: Option<String>,
+    version: Option<String>,
+}
+
+impl DistributionBuilder {
+    /// Sets [`Distribution::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`Distribution::version`].
+    pub fn version(mut self, value: impl Into<String>) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Builds the [`Distribution`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<Distribution> {
+        Ok(Distribution {
+            name: self
+                .name
+                .ok_or_else(|| anyhow::anyhow!("Distribution requires name"))?,
+            version: self
+                .version
+                .ok_or_else(|| anyhow::anyhow!("Distribution requires version"))?,
+        })
+    }
+}
+
 impl fmt::Display for Distribution {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}-{}", self.name, self.version)
@@ -1358,14 +1584,10 @@ impl IpKind {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Tabled)]
 pub struct ExternalIp {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ip: String,
+    #[serde()]
+    pub ip: std::net::IpAddr,
 
     /**
      * The kind of an external IP address for an instance
@@ -1374,56 +1596,18 @@ pub struct ExternalIp {
     pub kind: IpKind,
 }
 
+/// Parameters for creating an external IP address for instances.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 #[serde(rename_all = "snake_case")]
-#[serde(tag = "type", content = "pool_name")]
+#[serde(tag = "type")]
 pub enum ExternalIpCreate {
-    Ephemeral(String),
-}
-
-impl fmt::Display for ExternalIpCreate {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let j = serde_json::json!(self);
-        let mut tag: String = serde_json::from_value(j["type"].clone()).unwrap_or_default();
-        let mut content: String =
-            serde_json::from_value(j["pool_name"].clone()).unwrap_or_default();
-        if content.is_empty() {
-            let map: std::collections::HashMap<String, String> =
-                serde_json::from_value(j["pool_name"].clone()).unwrap_or_default();
-            if let Some((_, v)) = map.iter().next() {
-                content = v.to_string();
-            }
-        }
-        if tag == "internet_gateway" {
-            tag = "inetgw".to_string();
-        }
-        write!(f, "{}={}", tag, content)
-    }
+    /// An IP address providing both inbound and outbound access. The address is automatically-assigned from the provided IP Pool, or all available pools if not specified.
+    Ephemeral {
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        pool_name: String,
+    },
 }
 
-impl std::str::FromStr for ExternalIpCreate {
-    type Err = anyhow::Error;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts = s.split('=').collect::<Vec<&str>>();
-        if parts.len() != 2 {
-            anyhow::bail!("invalid format for ExternalIpCreate, got {}", s);
-        }
-        let tag = parts[0].to_string();
-        let content = parts[1].to_string();
-        let mut j = String::new();
-        if tag == "ephemeral" {
-            j = format!(
-                r#"{{
-"type": "ephemeral",
-"pool_name": "{}"
-        }}"#,
-                content
-            );
-        }
-        let result = serde_json::from_str(&j)?;
-        Ok(result)
-    }
-}
 impl ExternalIpCreate {
     pub fn variants() -> Vec<String> {
         vec!["ephemeral".to_string()]
@@ -1696,6 +1880,57 @@ pub struct FleetRoleAssignment {
     pub role_name: FleetRole,
 }
 
+impl FleetRoleAssignment {
+    /// A builder for [`FleetRoleAssignment`].
+    pub fn builder() -> FleetRoleAssignmentBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`FleetRoleAssignment`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct FleetRoleAssignmentBuilder {
+    identity_id: Option<String>,
+    identity_type: Option<IdentityType>,
+    role_name: Option<FleetRole>,
+}
+
+impl FleetRoleAssignmentBuilder {
+    /// Sets [`FleetRoleAssignment::identity_id`].
+    pub fn identity_id(mut self, value: impl Into<String>) -> Self {
+        self.identity_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`FleetRoleAssignment::identity_type`].
+    pub fn identity_type(mut self, value: impl Into<IdentityType>) -> Self {
+        self.identity_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`FleetRoleAssignment::role_name`].
+    pub fn role_name(mut self, value: impl Into<FleetRole>) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Builds the [`FleetRoleAssignment`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<FleetRoleAssignment> {
+        Ok(FleetRoleAssignment {
+            identity_id: self
+                .identity_id
+                .ok_or_else(|| anyhow::anyhow!("FleetRoleAssignment requires identity_id"))?,
+            identity_type: self
+                .identity_type
+                .ok_or_else(|| anyhow::anyhow!("FleetRoleAssignment requires identity_type"))?,
+            role_name: self
+                .role_name
+                .ok_or_else(|| anyhow::anyhow!("FleetRoleAssignment requires role_name"))?,
+        })
+    }
+}
+
 /// Client view of a [`Policy`], which describes how this resource may be accessed
 ///
 /// Note that the Policy only describes access granted explicitly for this resource.  The policies of parent resources can also cause a user to have access to this resource.
@@ -1713,6 +1948,37 @@ pub struct FleetRolePolicy {
     pub role_assignments: Vec<FleetRoleAssignment>,
 }
 
+impl FleetRolePolicy {
+    /// A builder for [`FleetRolePolicy`].
+    pub fn builder() -> FleetRolePolicyBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`FleetRolePolicy`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct FleetRolePolicyBuilder {
+    role_assignments: Option<Vec<FleetRoleAssignment>>,
+}
+
+impl FleetRolePolicyBuilder {
+    /// Sets [`FleetRolePolicy::role_assignments`].
+    pub fn role_assignments(mut self, value: impl Into<Vec<FleetRoleAssignment>>) -> Self {
+        self.role_assignments = Some(value.into());
+        self
+    }
+
+    /// Builds the [`FleetRolePolicy`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<FleetRolePolicy> {
+        Ok(FleetRolePolicy {
+            role_assignments: self
+                .role_assignments
+                .ok_or_else(|| anyhow::anyhow!("FleetRolePolicy requires role_assignments"))?,
+        })
+    }
+}
+
 /**
  * Describes what kind of identity is described by an id
  */
@@ -1801,7 +2067,7 @@ pub struct GlobalImage {
      *  The maximum supported byte count is [`i64::MAX`].  This makes it somewhat inconvenient to define constructors: a u32 constructor can be infallible, but an i64 constructor can fail (if the value is negative) and a u64 constructor can fail (if the value is larger than i64::MAX).  We provide all of these for consumers' convenience.
      */
     #[serde(default)]
-    pub block_size: u64,
+    pub block_size: crate::types::ByteCount,
 
     /**
      * Hash of the image contents, if applicable
@@ -1826,7 +2092,7 @@ pub struct GlobalImage {
      *  The maximum supported byte count is [`i64::MAX`].  This makes it somewhat inconvenient to define constructors: a u32 constructor can be infallible, but an i64 constructor can fail (if the value is negative) and a u64 constructor can fail (if the value is larger than i64::MAX).  We provide all of these for consumers' convenience.
      */
     #[serde(default)]
-    pub size: u64,
+    pub size: crate::types::ByteCount,
 
     /**
      * timestamp when this resource was created
@@ -1861,72 +2127,62 @@ pub struct GlobalImage {
     pub version: String,
 }
 
+/// The source of the underlying image.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 #[serde(rename_all = "snake_case")]
-#[serde(tag = "type", content = "id")]
+#[serde(tag = "type")]
 pub enum ImageSource {
-    Url(String),
-    Snapshot(String),
+    Url {
+        url: String,
+    },
+    Snapshot {
+        id: String,
+    },
+    /// Boot the Alpine ISO that ships with the Propolis zone. Intended for development purposes only.
+    #[serde(rename = "you_can_boot_anything_as_long_as_its_alpine")]
     YouCanBootAnythingAsLongItsAlpine,
 }
 
 impl fmt::Display for ImageSource {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let j = serde_json::json!(self);
-        let mut tag: String = serde_json::from_value(j["type"].clone()).unwrap_or_default();
-        let mut content: String = serde_json::from_value(j["id"].clone()).unwrap_or_default();
-        if content.is_empty() {
-            let map: std::collections::HashMap<String, String> =
-                serde_json::from_value(j["id"].clone()).unwrap_or_default();
-            if let Some((_, v)) = map.iter().next() {
-                content = v.to_string();
+        match self {
+            ImageSource::Url { url: value } => write!(f, "url={}", value),
+            ImageSource::Snapshot { id: value } => write!(f, "snapshot={}", value),
+            ImageSource::YouCanBootAnythingAsLongItsAlpine => {
+                write!(f, "you_can_boot_anything_as_long_as_its_alpine")
             }
         }
-        if tag == "internet_gateway" {
-            tag = "inetgw".to_string();
-        }
-        write!(f, "{}={}", tag, content)
     }
 }
 
 impl std::str::FromStr for ImageSource {
     type Err = anyhow::Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts = s.split('=').collect::<Vec<&str>>();
-        if parts.len() != 2 {
-            anyhow::bail!("invalid format for ImageSource, got {}", s);
-        }
-        let tag = parts[0].to_string();
-        let content = parts[1].to_string();
-        let mut j = String::new();
-        if tag == "url" {
-            j = format!(
-                r#"{{
-"type": "url",
-"id": "{}"
-        }}"#,
-                content
-            );
-        }
-        if tag == "snapshot" {
-            j = format!(
-                r#"{{
-"type": "snapshot",
-"id": "{}"
-        }}"#,
-                content
-            );
-        }
-        let result = serde_json::from_str(&j)?;
-        Ok(result)
+        let (tag, value) = match s.split_once('=') {
+            Some((tag, value)) => (tag, Some(value)),
+            None => (s, None),
+        };
+        Ok(match (tag, value) {
+            ("url", Some(value)) => ImageSource::Url {
+                url: value.to_string(),
+            },
+            ("snapshot", Some(value)) => ImageSource::Snapshot {
+                id: value.to_string(),
+            },
+            ("you_can_boot_anything_as_long_as_its_alpine", None) => {
+                ImageSource::YouCanBootAnythingAsLongItsAlpine
+            }
+            _ => anyhow::bail!("invalid format for ImageSource, got {}", s),
+        })
     }
 }
+
 impl ImageSource {
     pub fn variants() -> Vec<String> {
         vec![
             "snapshot".to_string(),
             "url".to_string(),
-            "you_can_boot_anything_as_long_its_alpine".to_string(),
+            "you_can_boot_anything_as_long_as_its_alpine".to_string(),
         ]
     }
 }
@@ -1938,6 +2194,7 @@ impl ImageSource {
 pub enum ImageSourceType {
     Snapshot,
     Url,
+    #[serde(rename = "you_can_boot_anything_as_long_as_its_alpine")]
     YouCanBootAnythingAsLongItsAlpine,
 }
 
@@ -1947,7 +2204,7 @@ impl std::fmt::Display for ImageSourceType {
             ImageSourceType::Snapshot => "snapshot",
             ImageSourceType::Url => "url",
             ImageSourceType::YouCanBootAnythingAsLongItsAlpine => {
-                "you_can_boot_anything_as_long_its_alpine"
+                "you_can_boot_anything_as_long_as_its_alpine"
             }
         }
         .fmt(f)
@@ -1968,7 +2225,7 @@ impl std::str::FromStr for ImageSourceType {
         if s == "url" {
             return Ok(ImageSourceType::Url);
         }
-        if s == "you_can_boot_anything_as_long_its_alpine" {
+        if s == "you_can_boot_anything_as_long_as_its_alpine" {
             return Ok(ImageSourceType::YouCanBootAnythingAsLongItsAlpine);
         }
         anyhow::bail!("invalid string for ImageSourceType: {}", s);
@@ -1995,21 +2252,95 @@ pub struct GlobalImageCreate {
     )]
     pub description: String,
 
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub block_size: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub block_size: i64,
+
+    /**
+     * OS image distribution
+     */
+    #[serde()]
+    pub distribution: Distribution,
+
+    /**
+     * The source of the underlying image.
+     */
+    #[serde()]
+    pub source: ImageSource,
+}
+
+impl GlobalImageCreate {
+    /// A builder for [`GlobalImageCreate`].
+    pub fn builder() -> GlobalImageCreateBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`GlobalImageCreate`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct GlobalImageCreateBuilder {
+    name: Option<String>,
+    description: Option<String>,
+    block_size: Option<i64>,
+    distribution: Option<Distribution>,
+    source: Option<ImageSource>,
+}
+
+impl GlobalImageCreateBuilder {
+    /// Sets [`GlobalImageCreate::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`GlobalImageCreate::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets [`GlobalImageCreate::block_size`].
+    pub fn block_size(mut self, value: impl Into<i64>) -> Self {
+        self.block_size = Some(value.into());
+        self
+    }
+
+    /// Sets [`GlobalImageCreate::distribution`].
+    pub fn distribution(mut self, value: impl Into<Distribution>) -> Self {
+        self.distribution = Some(value.into());
+        self
+    }
 
-    /**
-     * OS image distribution
-     */
-    #[serde()]
-    pub distribution: Distribution,
+    /// Sets [`GlobalImageCreate::source`].
+    pub fn source(mut self, value: impl Into<ImageSource>) -> Self {
+        self.source = Some(value.into());
+        self
+    }
 
-    #[serde()]
-    pub source: ImageSource,
+    /// Builds the [`GlobalImageCreate`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<GlobalImageCreate> {
+        Ok(GlobalImageCreate {
+            name: self
+                .name
+                .ok_or_else(|| anyhow::anyhow!("GlobalImageCreate requires name"))?,
+            description: self
+                .description
+                .ok_or_else(|| anyhow::anyhow!("GlobalImageCreate requires description"))?,
+            block_size: self
+                .block_size
+                .ok_or_else(|| anyhow::anyhow!("GlobalImageCreate requires block_size"))?,
+            distribution: self
+                .distribution
+                .ok_or_else(|| anyhow::anyhow!("GlobalImageCreate requires distribution"))?,
+            source: self
+                .source
+                .ok_or_else(|| anyhow::anyhow!("GlobalImageCreate requires source"))?,
+        })
+    }
 }
 
 /// A single page of results
@@ -2267,103 +2598,15 @@ impl IdentityProviderTypeSaml {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 #[serde(rename_all = "snake_case")]
-#[serde(tag = "type", content = "data")]
+#[serde(tag = "type")]
 pub enum IdpMetadataSource {
-    Url(String),
-    Base64EncodedXml(String),
-}
-
-impl fmt::Display for IdpMetadataSource {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let j = serde_json::json!(self);
-        let mut tag: String = serde_json::from_value(j["type"].clone()).unwrap_or_default();
-        let mut content: String = serde_json::from_value(j["data"].clone()).unwrap_or_default();
-        if content.is_empty() {
-            let map: std::collections::HashMap<String, String> =
-                serde_json::from_value(j["data"].clone()).unwrap_or_default();
-            if let Some((_, v)) = map.iter().next() {
-                content = v.to_string();
-            }
-        }
-        if tag == "internet_gateway" {
-            tag = "inetgw".to_string();
-        }
-        write!(f, "{}={}", tag, content)
-    }
+    Url { url: String },
+    Base64EncodedXml { data: String },
 }
 
-impl std::str::FromStr for IdpMetadataSource {
-    type Err = anyhow::Error;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts = s.split('=').collect::<Vec<&str>>();
-        if parts.len() != 2 {
-            anyhow::bail!("invalid format for IdpMetadataSource, got {}", s);
-        }
-        let tag = parts[0].to_string();
-        let content = parts[1].to_string();
-        let mut j = String::new();
-        if tag == "url" {
-            j = format!(
-                r#"{{
-"type": "url",
-"data": "{}"
-        }}"#,
-                content
-            );
-        }
-        if tag == "base_64_encoded_xml" {
-            j = format!(
-                r#"{{
-"type": "base_64_encoded_xml",
-"data": "{}"
-        }}"#,
-                content
-            );
-        }
-        let result = serde_json::from_str(&j)?;
-        Ok(result)
-    }
-}
 impl IdpMetadataSource {
     pub fn variants() -> Vec<String> {
-        vec!["base_64_encoded_xml".to_string(), "url".to_string()]
-    }
-}
-/**
- * The types for IdpMetadataSource.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Tabled)]
-#[serde(rename_all = "snake_case")]
-pub enum IdpMetadataSourceType {
-    Base64EncodedXml,
-    Url,
-}
-
-impl std::fmt::Display for IdpMetadataSourceType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            IdpMetadataSourceType::Base64EncodedXml => "base_64_encoded_xml",
-            IdpMetadataSourceType::Url => "url",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for IdpMetadataSourceType {
-    fn default() -> IdpMetadataSourceType {
-        IdpMetadataSourceType::Base64EncodedXml
-    }
-}
-impl std::str::FromStr for IdpMetadataSourceType {
-    type Err = anyhow::Error;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s == "base_64_encoded_xml" {
-            return Ok(IdpMetadataSourceType::Base64EncodedXml);
-        }
-        if s == "url" {
-            return Ok(IdpMetadataSourceType::Url);
-        }
-        anyhow::bail!("invalid string for IdpMetadataSourceType: {}", s);
+        vec!["base64_encoded_xml".to_string(), "url".to_string()]
     }
 }
 
@@ -2406,7 +2649,7 @@ pub struct Image {
      *  The maximum supported byte count is [`i64::MAX`].  This makes it somewhat inconvenient to define constructors: a u32 constructor can be infallible, but an i64 constructor can fail (if the value is negative) and a u64 constructor can fail (if the value is larger than i64::MAX).  We provide all of these for consumers' convenience.
      */
     #[serde(default)]
-    pub block_size: u64,
+    pub block_size: crate::types::ByteCount,
 
     /**
      * Hash of the image contents, if applicable
@@ -2431,7 +2674,7 @@ pub struct Image {
      *  The maximum supported byte count is [`i64::MAX`].  This makes it somewhat inconvenient to define constructors: a u32 constructor can be infallible, but an i64 constructor can fail (if the value is negative) and a u64 constructor can fail (if the value is larger than i64::MAX).  We provide all of these for consumers' convenience.
      */
     #[serde(default)]
-    pub size: u64,
+    pub size: crate::types::ByteCount,
 
     /**
      * timestamp when this resource was created
@@ -2493,10 +2736,74 @@ pub struct ImageCreate {
     )]
     pub block_size: i64,
 
+    /**
+     * The source of the underlying image.
+     */
     #[serde()]
     pub source: ImageSource,
 }
 
+impl ImageCreate {
+    /// A builder for [`ImageCreate`].
+    pub fn builder() -> ImageCreateBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`ImageCreate`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct ImageCreateBuilder {
+    name: Option<String>,
+    description: Option<String>,
+    block_size: Option<i64>,
+    source: Option<ImageSource>,
+}
+
+impl ImageCreateBuilder {
+    /// Sets [`ImageCreate::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`ImageCreate::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets [`ImageCreate::block_size`].
+    pub fn block_size(mut self, value: impl Into<i64>) -> Self {
+        self.block_size = Some(value.into());
+        self
+    }
+
+    /// Sets [`ImageCreate::source`].
+    pub fn source(mut self, value: impl Into<ImageSource>) -> Self {
+        self.source = Some(value.into());
+        self
+    }
+
+    /// Builds the [`ImageCreate`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<ImageCreate> {
+        Ok(ImageCreate {
+            name: self
+                .name
+                .ok_or_else(|| anyhow::anyhow!("ImageCreate requires name"))?,
+            description: self
+                .description
+                .ok_or_else(|| anyhow::anyhow!("ImageCreate requires description"))?,
+            block_size: self
+                .block_size
+                .ok_or_else(|| anyhow::anyhow!("ImageCreate requires block_size"))?,
+            source: self
+                .source
+                .ok_or_else(|| anyhow::anyhow!("ImageCreate requires source"))?,
+        })
+    }
+}
+
 /// A single page of results
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct ImageResultsPage {
@@ -2662,7 +2969,7 @@ pub struct Instance {
      *  The maximum supported byte count is [`i64::MAX`].  This makes it somewhat inconvenient to define constructors: a u32 constructor can be infallible, but an i64 constructor can fail (if the value is negative) and a u64 constructor can fail (if the value is larger than i64::MAX).  We provide all of these for consumers' convenience.
      */
     #[serde(default)]
-    pub memory: u64,
+    pub memory: crate::types::ByteCount,
 
     /**
      * The number of CPUs in an Instance
@@ -2704,97 +3011,22 @@ pub struct Instance {
     pub time_run_state_updated: crate::utils::DisplayOptionDateTime,
 }
 
+/// Describe the instance's disks at creation time
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 #[serde(rename_all = "snake_case")]
-#[serde(tag = "type", content = "name")]
+#[serde(tag = "type")]
 pub enum InstanceDiskAttachment {
+    /// During instance creation, create and attach disks
     Create {
         description: String,
         disk_source: DiskSource,
         name: String,
-        size: u64,
+        size: crate::types::ByteCount,
     },
-    Attach(String),
-}
-
-impl fmt::Display for InstanceDiskAttachment {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let j = serde_json::json!(self);
-        let mut tag: String = serde_json::from_value(j["type"].clone()).unwrap_or_default();
-        let mut content: String = serde_json::from_value(j["name"].clone()).unwrap_or_default();
-        if content.is_empty() {
-            let map: std::collections::HashMap<String, String> =
-                serde_json::from_value(j["name"].clone()).unwrap_or_default();
-            if let Some((_, v)) = map.iter().next() {
-                content = v.to_string();
-            }
-        }
-        if tag == "internet_gateway" {
-            tag = "inetgw".to_string();
-        }
-        write!(f, "{}={}", tag, content)
-    }
+    /// During instance creation, attach this disk
+    Attach { name: String },
 }
 
-impl std::str::FromStr for InstanceDiskAttachment {
-    type Err = anyhow::Error;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts = s.split('=').collect::<Vec<&str>>();
-        if parts.len() != 2 {
-            anyhow::bail!("invalid format for InstanceDiskAttachment, got {}", s);
-        }
-        let tag = parts[0].to_string();
-        let content = parts[1].to_string();
-        let mut j = String::new();
-        if tag == "create" {
-            j = format!(
-                r#"{{
-"type": "create",
-"name": "{}"
-        }}"#,
-                content
-            );
-        }
-        if tag == "create" {
-            j = format!(
-                r#"{{
-"type": "create",
-"name": {}
-        }}"#,
-                serde_json::json!(DiskSource::from_str(&content).unwrap())
-            );
-        }
-        if tag == "create" {
-            j = format!(
-                r#"{{
-"type": "create",
-"name": "{}"
-        }}"#,
-                content
-            );
-        }
-        if tag == "create" {
-            j = format!(
-                r#"{{
-"type": "create",
-"name": {}
-        }}"#,
-                serde_json::json!(u64::from_str(&content).unwrap())
-            );
-        }
-        if tag == "attach" {
-            j = format!(
-                r#"{{
-"type": "attach",
-"name": "{}"
-        }}"#,
-                content
-            );
-        }
-        let result = serde_json::from_str(&j)?;
-        Ok(result)
-    }
-}
 impl InstanceDiskAttachment {
     pub fn variants() -> Vec<String> {
         vec!["attach".to_string(), "create".to_string()]
@@ -2838,60 +3070,21 @@ impl std::str::FromStr for InstanceDiskAttachmentType {
     }
 }
 
+/// Describes an attachment of a `NetworkInterface` to an `Instance`, at the time the instance is created.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 #[serde(tag = "type", content = "params")]
 pub enum InstanceNetworkInterfaceAttachment {
+    /// Create one or more `NetworkInterface`s for the `Instance`.
+    ///
+    /// If more than one interface is provided, then the first will be designated the primary interface for the instance.
     Create(Vec<NetworkInterfaceCreate>),
+    /// The default networking configuration for an instance is to create a single primary interface with an automatically-assigned IP address. The IP will be pulled from the Project's default VPC / VPC Subnet.
     Default,
+    /// No network interfaces at all will be created for the instance.
     None,
 }
 
-impl fmt::Display for InstanceNetworkInterfaceAttachment {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let j = serde_json::json!(self);
-        let mut tag: String = serde_json::from_value(j["type"].clone()).unwrap_or_default();
-        let mut content: String = serde_json::from_value(j["params"].clone()).unwrap_or_default();
-        if content.is_empty() {
-            let map: std::collections::HashMap<String, String> =
-                serde_json::from_value(j["params"].clone()).unwrap_or_default();
-            if let Some((_, v)) = map.iter().next() {
-                content = v.to_string();
-            }
-        }
-        if tag == "internet_gateway" {
-            tag = "inetgw".to_string();
-        }
-        write!(f, "{}={}", tag, content)
-    }
-}
-
-impl std::str::FromStr for InstanceNetworkInterfaceAttachment {
-    type Err = anyhow::Error;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts = s.split('=').collect::<Vec<&str>>();
-        if parts.len() != 2 {
-            anyhow::bail!(
-                "invalid format for InstanceNetworkInterfaceAttachment, got {}",
-                s
-            );
-        }
-        let tag = parts[0].to_string();
-        let content = parts[1].to_string();
-        let mut j = String::new();
-        if tag == "create" {
-            j = format!(
-                r#"{{
-"type": "create",
-"params": "{}"
-        }}"#,
-                content
-            );
-        }
-        let result = serde_json::from_str(&j)?;
-        Ok(result)
-    }
-}
 impl InstanceNetworkInterfaceAttachment {
     pub fn variants() -> Vec<String> {
         vec![
@@ -3004,7 +3197,7 @@ pub struct InstanceCreate {
      *  The maximum supported byte count is [`i64::MAX`].  This makes it somewhat inconvenient to define constructors: a u32 constructor can be infallible, but an i64 constructor can fail (if the value is negative) and a u64 constructor can fail (if the value is larger than i64::MAX).  We provide all of these for consumers' convenience.
      */
     #[serde(default)]
-    pub memory: u64,
+    pub memory: crate::types::ByteCount,
 
     /**
      * The number of CPUs in an Instance
@@ -3036,6 +3229,123 @@ pub struct InstanceCreate {
     pub user_data: String,
 }
 
+impl InstanceCreate {
+    /// A builder for [`InstanceCreate`].
+    pub fn builder() -> InstanceCreateBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`InstanceCreate`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct InstanceCreateBuilder {
+    name: Option<String>,
+    description: Option<String>,
+    disks: Option<Vec<InstanceDiskAttachment>>,
+    external_ips: Option<Vec<ExternalIpCreate>>,
+    hostname: Option<String>,
+    memory: Option<crate::types::ByteCount>,
+    ncpus: Option<u16>,
+    network_interfaces: Option<Option<InstanceNetworkInterfaceAttachment>>,
+    start: Option<bool>,
+    user_data: Option<String>,
+}
+
+impl InstanceCreateBuilder {
+    /// Sets [`InstanceCreate::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`InstanceCreate::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets [`InstanceCreate::disks`].
+    pub fn disks(mut self, value: impl Into<Vec<InstanceDiskAttachment>>) -> Self {
+        self.disks = Some(value.into());
+        self
+    }
+
+    /// Sets [`InstanceCreate::external_ips`].
+    pub fn external_ips(mut self, value: impl Into<Vec<ExternalIpCreate>>) -> Self {
+        self.external_ips = Some(value.into());
+        self
+    }
+
+    /// Sets [`InstanceCreate::hostname`].
+    pub fn hostname(mut self, value: impl Into<String>) -> Self {
+        self.hostname = Some(value.into());
+        self
+    }
+
+    /// Sets [`InstanceCreate::memory`].
+    pub fn memory(mut self, value: impl Into<crate::types::ByteCount>) -> Self {
+        self.memory = Some(value.into());
+        self
+    }
+
+    /// Sets [`InstanceCreate::ncpus`].
+    pub fn ncpus(mut self, value: impl Into<u16>) -> Self {
+        self.ncpus = Some(value.into());
+        self
+    }
+
+    /// Sets [`InstanceCreate::network_interfaces`].
+    pub fn network_interfaces(
+        mut self,
+        value: impl Into<InstanceNetworkInterfaceAttachment>,
+    ) -> Self {
+        self.network_interfaces = Some(Some(value.into()));
+        self
+    }
+
+    /// Sets [`InstanceCreate::start`].
+    pub fn start(mut self, value: impl Into<bool>) -> Self {
+        self.start = Some(value.into());
+        self
+    }
+
+    /// Sets [`InstanceCreate::user_data`].
+    pub fn user_data(mut self, value: impl Into<String>) -> Self {
+        self.user_data = Some(value.into());
+        self
+    }
+
+    /// Builds the [`InstanceCreate`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<InstanceCreate> {
+        Ok(InstanceCreate {
+            name: self
+                .name
+                .ok_or_else(|| anyhow::anyhow!("InstanceCreate requires name"))?,
+            description: self
+                .description
+                .ok_or_else(|| anyhow::anyhow!("InstanceCreate requires description"))?,
+            disks: self.disks.unwrap_or_default(),
+            external_ips: self.external_ips.unwrap_or_default(),
+            hostname: self
+                .hostname
+                .ok_or_else(|| anyhow::anyhow!("InstanceCreate requires hostname"))?,
+            memory: self
+                .memory
+                .ok_or_else(|| anyhow::anyhow!("InstanceCreate requires memory"))?,
+            ncpus: self
+                .ncpus
+                .ok_or_else(|| anyhow::anyhow!("InstanceCreate requires ncpus"))?,
+            network_interfaces: match self.network_interfaces {
+                Some(value) => value,
+                None => serde_json::from_value(serde_json::json!({"type":"default"}))?,
+            },
+            start: self.start.unwrap_or(true),
+            user_data: self.user_data.unwrap_or_default(),
+        })
+    }
+}
+
 /// Migration parameters for an [`Instance`](omicron_common::api::external::Instance)
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct InstanceMigrate {
@@ -3047,6 +3357,37 @@ pub struct InstanceMigrate {
     pub dst_sled_id: String,
 }
 
+impl InstanceMigrate {
+    /// A builder for [`InstanceMigrate`].
+    pub fn builder() -> InstanceMigrateBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`InstanceMigrate`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct InstanceMigrateBuilder {
+    dst_sled_id: Option<String>,
+}
+
+impl InstanceMigrateBuilder {
+    /// Sets [`InstanceMigrate::dst_sled_id`].
+    pub fn dst_sled_id(mut self, value: impl Into<String>) -> Self {
+        self.dst_sled_id = Some(value.into());
+        self
+    }
+
+    /// Builds the [`InstanceMigrate`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<InstanceMigrate> {
+        Ok(InstanceMigrate {
+            dst_sled_id: self
+                .dst_sled_id
+                .ok_or_else(|| anyhow::anyhow!("InstanceMigrate requires dst_sled_id"))?,
+        })
+    }
+}
+
 /// A single page of results
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct InstanceResultsPage {
@@ -3095,6 +3436,7 @@ pub struct InstanceSerialConsoleData {
 
 /// An `IpNet` represents an IP network, either IPv4 or IPv6.
 #[derive(Clone, Copy, Debug, Deserialize, PartialEq, Hash, JsonSchema, Serialize)]
+#[serde(untagged)]
 pub enum IpNet {
     V4(Ipv4Net),
     V6(Ipv6Net),
@@ -3247,23 +3589,120 @@ pub struct IpPoolCreate {
     pub project: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+impl IpPoolCreate {
+    /// A builder for [`IpPoolCreate`].
+    pub fn builder() -> IpPoolCreateBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`IpPoolCreate`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct IpPoolCreateBuilder {
+    name: Option<String>,
+    description: Option<String>,
+    organization: Option<String>,
+    project: Option<String>,
+}
+
+impl IpPoolCreateBuilder {
+    /// Sets [`IpPoolCreate::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`IpPoolCreate::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets [`IpPoolCreate::organization`].
+    pub fn organization(mut self, value: impl Into<String>) -> Self {
+        self.organization = Some(value.into());
+        self
+    }
+
+    /// Sets [`IpPoolCreate::project`].
+    pub fn project(mut self, value: impl Into<String>) -> Self {
+        self.project = Some(value.into());
+        self
+    }
+
+    /// Builds the [`IpPoolCreate`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<IpPoolCreate> {
+        Ok(IpPoolCreate {
+            name: self
+                .name
+                .ok_or_else(|| anyhow::anyhow!("IpPoolCreate requires name"))?,
+            description: self
+                .description
+                .ok_or_else(|| anyhow::anyhow!("IpPoolCreate requires description"))?,
+            organization: self.organization.unwrap_or_default(),
+            project: self.project.unwrap_or_default(),
+        })
+    }
+}
+
+/// A range of IPv4 or IPv6 addresses, inclusive of both ends.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
 pub enum IpRange {
-    Ipv4Range,
-    #[default]
-    Ipv6Range,
+    V4(Ipv4Range),
+    V6(Ipv6Range),
+}
+
+impl Default for IpRange {
+    fn default() -> Self {
+        IpRange::V4(Ipv4Range {
+            first: std::net::Ipv4Addr::UNSPECIFIED,
+            last: std::net::Ipv4Addr::UNSPECIFIED,
+        })
+    }
+}
+
+impl From<Ipv4Range> for IpRange {
+    fn from(r: Ipv4Range) -> IpRange {
+        IpRange::V4(r)
+    }
+}
+
+impl From<Ipv6Range> for IpRange {
+    fn from(r: Ipv6Range) -> IpRange {
+        IpRange::V6(r)
+    }
 }
 
 impl fmt::Display for IpRange {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", serde_json::json!(self))
+        match self {
+            IpRange::V4(r) => write!(f, "{}-{}", r.first, r.last),
+            IpRange::V6(r) => write!(f, "{}-{}", r.first, r.last),
+        }
     }
 }
 
 impl std::str::FromStr for IpRange {
     type Err = anyhow::Error;
+
+    /// Parses `first-last`, e.g. `192.168.1.10-192.168.1.20`.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(serde_json::from_str(s)?)
+        let (first, last) = s
+            .split_once('-')
+            .ok_or_else(|| anyhow::anyhow!("invalid ip range `{}`, expected `first-last`", s))?;
+        let first: std::net::IpAddr = first.trim().parse()?;
+        let last: std::net::IpAddr = last.trim().parse()?;
+        match (first, last) {
+            (std::net::IpAddr::V4(first), std::net::IpAddr::V4(last)) => {
+                Ok(IpRange::V4(Ipv4Range::new(first, last)?))
+            }
+            (std::net::IpAddr::V6(first), std::net::IpAddr::V6(last)) => {
+                Ok(IpRange::V6(Ipv6Range::new(first, last)?))
+            }
+            _ => anyhow::bail!("ip range `{}` mixes IPv4 and IPv6 addresses", s),
+        }
     }
 }
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
@@ -3350,6 +3789,43 @@ pub struct IpPoolUpdate {
     pub description: String,
 }
 
+impl IpPoolUpdate {
+    /// A builder for [`IpPoolUpdate`].
+    pub fn builder() -> IpPoolUpdateBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`IpPoolUpdate`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct IpPoolUpdateBuilder {
+    name: Option<String>,
+    description: Option<String>,
+}
+
+impl IpPoolUpdateBuilder {
+    /// Sets [`IpPoolUpdate::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`IpPoolUpdate::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Builds the [`IpPoolUpdate`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<IpPoolUpdate> {
+        Ok(IpPoolUpdate {
+            name: self.name.unwrap_or_default(),
+            description: self.description.unwrap_or_default(),
+        })
+    }
+}
+
 /// An `Ipv4Net` represents a IPv4 subnetwork, including the address and network mask.
 #[derive(Clone, Copy, Debug, Deserialize, Hash, PartialEq, Serialize)]
 pub struct Ipv4Net(pub ipnetwork::Ipv4Network);
@@ -3429,14 +3905,48 @@ impl JsonSchema for Ipv4Net {
 ///
 /// The first address must be less than or equal to the last address.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Tabled)]
+#[serde(try_from = "AnyIpv4Range")]
 pub struct Ipv4Range {
-    #[serde()]
-    pub first: std::net::Ipv4Addr,
+    first: std::net::Ipv4Addr,
+    last: std::net::Ipv4Addr,
+}
 
-    #[serde()]
-    pub last: std::net::Ipv4Addr,
+impl Ipv4Range {
+    /// Returns an error if `first` comes after `last`.
+    pub fn new(first: std::net::Ipv4Addr, last: std::net::Ipv4Addr) -> anyhow::Result<Self> {
+        if first > last {
+            anyhow::bail!(
+                "invalid IPv4 range {}-{}: the first address must not come after the last",
+                first,
+                last
+            );
+        }
+        Ok(Ipv4Range { first, last })
+    }
+
+    pub fn first_address(&self) -> std::net::Ipv4Addr {
+        self.first
+    }
+
+    pub fn last_address(&self) -> std::net::Ipv4Addr {
+        self.last
+    }
+}
+
+/// An unvalidated `Ipv4Range`, as it comes off the wire.
+#[derive(Deserialize)]
+struct AnyIpv4Range {
+    first: std::net::Ipv4Addr,
+    last: std::net::Ipv4Addr,
 }
 
+impl std::convert::TryFrom<AnyIpv4Range> for Ipv4Range {
+    type Error = anyhow::Error;
+
+    fn try_from(r: AnyIpv4Range) -> Result<Self, Self::Error> {
+        Ipv4Range::new(r.first, r.last)
+    }
+}
 /// An `Ipv6Net` represents a IPv6 subnetwork, including the address and network mask.
 #[derive(Clone, Copy, Debug, Deserialize, Hash, PartialEq, Serialize)]
 pub struct Ipv6Net(pub ipnetwork::Ipv6Network);
@@ -3535,18 +4045,56 @@ impl JsonSchema for Ipv6Net {
 ///
 /// The first address must be less than or equal to the last address.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Tabled)]
+#[serde(try_from = "AnyIpv6Range")]
 pub struct Ipv6Range {
-    #[serde()]
-    pub first: std::net::Ipv6Addr,
+    first: std::net::Ipv6Addr,
+    last: std::net::Ipv6Addr,
+}
 
-    #[serde()]
-    pub last: std::net::Ipv6Addr,
+impl Ipv6Range {
+    /// Returns an error if `first` comes after `last`.
+    pub fn new(first: std::net::Ipv6Addr, last: std::net::Ipv6Addr) -> anyhow::Result<Self> {
+        if first > last {
+            anyhow::bail!(
+                "invalid IPv6 range {}-{}: the first address must not come after the last",
+                first,
+                last
+            );
+        }
+        Ok(Ipv6Range { first, last })
+    }
+
+    pub fn first_address(&self) -> std::net::Ipv6Addr {
+        self.first
+    }
+
+    pub fn last_address(&self) -> std::net::Ipv6Addr {
+        self.last
+    }
+}
+
+/// An unvalidated `Ipv6Range`, as it comes off the wire.
+#[derive(Deserialize)]
+struct AnyIpv6Range {
+    first: std::net::Ipv6Addr,
+    last: std::net::Ipv6Addr,
 }
 
+impl std::convert::TryFrom<AnyIpv6Range> for Ipv6Range {
+    type Error = anyhow::Error;
+
+    fn try_from(r: AnyIpv6Range) -> Result<Self, Self::Error> {
+        Ipv6Range::new(r.first, r.last)
+    }
+}
 /// A `Measurement` is a timestamped datum from a single metric
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Tabled)]
 pub struct Measurement {
+    /**
+     * A `Datum` is a single sampled data point from a metric.
+     */
     #[serde()]
+    #[header(hidden = true)]
     pub datum: Datum,
 
     #[serde()]
@@ -3579,7 +4127,7 @@ pub struct MeasurementResultsPage {
 }
 
 /// A `NetworkInterface` represents a virtual network interface device.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Tabled)]
 pub struct NetworkInterface {
     /**
      * unique, immutable, system-controlled identifier for each resource
@@ -3624,12 +4172,8 @@ pub struct NetworkInterface {
     /**
      * The IP address assigned to this interface.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ip: String,
+    #[serde()]
+    pub ip: std::net::IpAddr,
 
     /**
      * A Media Access Control address, in EUI-48 format
@@ -3706,12 +4250,9 @@ pub struct NetworkInterfaceCreate {
     /**
      * The IP address for the interface. One will be auto-assigned if not provided.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ip: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[header(hidden = true)]
+    pub ip: Option<std::net::IpAddr>,
 
     /**
      * Names must begin with a lower case ASCII letter, be composed exclusively of lowercase ASCII, uppercase ASCII, numbers, and '-', and may not end with a '-'. Names cannot be a UUID though they may contain a UUID.
@@ -3734,6 +4275,75 @@ pub struct NetworkInterfaceCreate {
     pub vpc_name: String,
 }
 
+impl NetworkInterfaceCreate {
+    /// A builder for [`NetworkInterfaceCreate`].
+    pub fn builder() -> NetworkInterfaceCreateBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`NetworkInterfaceCreate`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct NetworkInterfaceCreateBuilder {
+    name: Option<String>,
+    description: Option<String>,
+    ip: Option<Option<std::net::IpAddr>>,
+    subnet_name: Option<String>,
+    vpc_name: Option<String>,
+}
+
+impl NetworkInterfaceCreateBuilder {
+    /// Sets [`NetworkInterfaceCreate::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`NetworkInterfaceCreate::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets [`NetworkInterfaceCreate::ip`].
+    pub fn ip(mut self, value: impl Into<std::net::IpAddr>) -> Self {
+        self.ip = Some(Some(value.into()));
+        self
+    }
+
+    /// Sets [`NetworkInterfaceCreate::subnet_name`].
+    pub fn subnet_name(mut self, value: impl Into<String>) -> Self {
+        self.subnet_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`NetworkInterfaceCreate::vpc_name`].
+    pub fn vpc_name(mut self, value: impl Into<String>) -> Self {
+        self.vpc_name = Some(value.into());
+        self
+    }
+
+    /// Builds the [`NetworkInterfaceCreate`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<NetworkInterfaceCreate> {
+        Ok(NetworkInterfaceCreate {
+            name: self
+                .name
+                .ok_or_else(|| anyhow::anyhow!("NetworkInterfaceCreate requires name"))?,
+            description: self
+                .description
+                .ok_or_else(|| anyhow::anyhow!("NetworkInterfaceCreate requires description"))?,
+            ip: self.ip.unwrap_or_default(),
+            subnet_name: self
+                .subnet_name
+                .ok_or_else(|| anyhow::anyhow!("NetworkInterfaceCreate requires subnet_name"))?,
+            vpc_name: self
+                .vpc_name
+                .ok_or_else(|| anyhow::anyhow!("NetworkInterfaceCreate requires vpc_name"))?,
+        })
+    }
+}
+
 /// A single page of results
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct NetworkInterfaceResultsPage {
@@ -3792,6 +4402,51 @@ pub struct NetworkInterfaceUpdate {
     pub primary: bool,
 }
 
+impl NetworkInterfaceUpdate {
+    /// A builder for [`NetworkInterfaceUpdate`].
+    pub fn builder() -> NetworkInterfaceUpdateBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`NetworkInterfaceUpdate`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct NetworkInterfaceUpdateBuilder {
+    name: Option<String>,
+    description: Option<String>,
+    primary: Option<bool>,
+}
+
+impl NetworkInterfaceUpdateBuilder {
+    /// Sets [`NetworkInterfaceUpdate::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`NetworkInterfaceUpdate::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets [`NetworkInterfaceUpdate::primary`].
+    pub fn primary(mut self, value: impl Into<bool>) -> Self {
+        self.primary = Some(value.into());
+        self
+    }
+
+    /// Builds the [`NetworkInterfaceUpdate`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<NetworkInterfaceUpdate> {
+        Ok(NetworkInterfaceUpdate {
+            name: self.name.unwrap_or_default(),
+            description: self.description.unwrap_or_default(),
+            primary: self.primary.unwrap_or(false),
+        })
+    }
+}
+
 /// Client view of an [`Organization`]
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct Organization {
@@ -3859,6 +4514,47 @@ pub struct OrganizationCreate {
     pub description: String,
 }
 
+impl OrganizationCreate {
+    /// A builder for [`OrganizationCreate`].
+    pub fn builder() -> OrganizationCreateBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`OrganizationCreate`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct OrganizationCreateBuilder {
+    name: Option<String>,
+    description: Option<String>,
+}
+
+impl OrganizationCreateBuilder {
+    /// Sets [`OrganizationCreate::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`OrganizationCreate::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Builds the [`OrganizationCreate`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<OrganizationCreate> {
+        Ok(OrganizationCreate {
+            name: self
+                .name
+                .ok_or_else(|| anyhow::anyhow!("OrganizationCreate requires name"))?,
+            description: self
+                .description
+                .ok_or_else(|| anyhow::anyhow!("OrganizationCreate requires description"))?,
+        })
+    }
+}
+
 /// A single page of results
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct OrganizationResultsPage {
@@ -3957,6 +4653,57 @@ pub struct OrganizationRoleAssignment {
     pub role_name: OrganizationRole,
 }
 
+impl OrganizationRoleAssignment {
+    /// A builder for [`OrganizationRoleAssignment`].
+    pub fn builder() -> OrganizationRoleAssignmentBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`OrganizationRoleAssignment`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct OrganizationRoleAssignmentBuilder {
+    identity_id: Option<String>,
+    identity_type: Option<IdentityType>,
+    role_name: Option<OrganizationRole>,
+}
+
+impl OrganizationRoleAssignmentBuilder {
+    /// Sets [`OrganizationRoleAssignment::identity_id`].
+    pub fn identity_id(mut self, value: impl Into<String>) -> Self {
+        self.identity_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`OrganizationRoleAssignment::identity_type`].
+    pub fn identity_type(mut self, value: impl Into<IdentityType>) -> Self {
+        self.identity_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`OrganizationRoleAssignment::role_name`].
+    pub fn role_name(mut self, value: impl Into<OrganizationRole>) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Builds the [`OrganizationRoleAssignment`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<OrganizationRoleAssignment> {
+        Ok(OrganizationRoleAssignment {
+            identity_id: self.identity_id.ok_or_else(|| {
+                anyhow::anyhow!("OrganizationRoleAssignment requires identity_id")
+            })?,
+            identity_type: self.identity_type.ok_or_else(|| {
+                anyhow::anyhow!("OrganizationRoleAssignment requires identity_type")
+            })?,
+            role_name: self
+                .role_name
+                .ok_or_else(|| anyhow::anyhow!("OrganizationRoleAssignment requires role_name"))?,
+        })
+    }
+}
+
 /// Client view of a [`Policy`], which describes how this resource may be accessed
 ///
 /// Note that the Policy only describes access granted explicitly for this resource.  The policies of parent resources can also cause a user to have access to this resource.
@@ -3974,6 +4721,37 @@ pub struct OrganizationRolePolicy {
     pub role_assignments: Vec<OrganizationRoleAssignment>,
 }
 
+impl OrganizationRolePolicy {
+    /// A builder for [`OrganizationRolePolicy`].
+    pub fn builder() -> OrganizationRolePolicyBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`OrganizationRolePolicy`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct OrganizationRolePolicyBuilder {
+    role_assignments: Option<Vec<OrganizationRoleAssignment>>,
+}
+
+impl OrganizationRolePolicyBuilder {
+    /// Sets [`OrganizationRolePolicy::role_assignments`].
+    pub fn role_assignments(mut self, value: impl Into<Vec<OrganizationRoleAssignment>>) -> Self {
+        self.role_assignments = Some(value.into());
+        self
+    }
+
+    /// Builds the [`OrganizationRolePolicy`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<OrganizationRolePolicy> {
+        Ok(OrganizationRolePolicy {
+            role_assignments: self.role_assignments.ok_or_else(|| {
+                anyhow::anyhow!("OrganizationRolePolicy requires role_assignments")
+            })?,
+        })
+    }
+}
+
 /// Updateable properties of an [`Organization`](crate::external_api::views::Organization)
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct OrganizationUpdate {
@@ -3992,6 +4770,43 @@ pub struct OrganizationUpdate {
     pub description: String,
 }
 
+impl OrganizationUpdate {
+    /// A builder for [`OrganizationUpdate`].
+    pub fn builder() -> OrganizationUpdateBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`OrganizationUpdate`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct OrganizationUpdateBuilder {
+    name: Option<String>,
+    description: Option<String>,
+}
+
+impl OrganizationUpdateBuilder {
+    /// Sets [`OrganizationUpdate::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`OrganizationUpdate::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Builds the [`OrganizationUpdate`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<OrganizationUpdate> {
+        Ok(OrganizationUpdate {
+            name: self.name.unwrap_or_default(),
+            description: self.description.unwrap_or_default(),
+        })
+    }
+}
+
 /// Client view of a [`Project`]
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct Project {
@@ -4066,6 +4881,47 @@ pub struct ProjectCreate {
     pub description: String,
 }
 
+impl ProjectCreate {
+    /// A builder for [`ProjectCreate`].
+    pub fn builder() -> ProjectCreateBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`ProjectCreate`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct ProjectCreateBuilder {
+    name: Option<String>,
+    description: Option<String>,
+}
+
+impl ProjectCreateBuilder {
+    /// Sets [`ProjectCreate::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`ProjectCreate::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Builds the [`ProjectCreate`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<ProjectCreate> {
+        Ok(ProjectCreate {
+            name: self
+                .name
+                .ok_or_else(|| anyhow::anyhow!("ProjectCreate requires name"))?,
+            description: self
+                .description
+                .ok_or_else(|| anyhow::anyhow!("ProjectCreate requires description"))?,
+        })
+    }
+}
+
 /// A single page of results
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct ProjectResultsPage {
@@ -4164,6 +5020,57 @@ pub struct ProjectRoleAssignment {
     pub role_name: ProjectRole,
 }
 
+impl ProjectRoleAssignment {
+    /// A builder for [`ProjectRoleAssignment`].
+    pub fn builder() -> ProjectRoleAssignmentBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`ProjectRoleAssignment`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct ProjectRoleAssignmentBuilder {
+    identity_id: Option<String>,
+    identity_type: Option<IdentityType>,
+    role_name: Option<ProjectRole>,
+}
+
+impl ProjectRoleAssignmentBuilder {
+    /// Sets [`ProjectRoleAssignment::identity_id`].
+    pub fn identity_id(mut self, value: impl Into<String>) -> Self {
+        self.identity_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`ProjectRoleAssignment::identity_type`].
+    pub fn identity_type(mut self, value: impl Into<IdentityType>) -> Self {
+        self.identity_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`ProjectRoleAssignment::role_name`].
+    pub fn role_name(mut self, value: impl Into<ProjectRole>) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Builds the [`ProjectRoleAssignment`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<ProjectRoleAssignment> {
+        Ok(ProjectRoleAssignment {
+            identity_id: self
+                .identity_id
+                .ok_or_else(|| anyhow::anyhow!("ProjectRoleAssignment requires identity_id"))?,
+            identity_type: self
+                .identity_type
+                .ok_or_else(|| anyhow::anyhow!("ProjectRoleAssignment requires identity_type"))?,
+            role_name: self
+                .role_name
+                .ok_or_else(|| anyhow::anyhow!("ProjectRoleAssignment requires role_name"))?,
+        })
+    }
+}
+
 /// Client view of a [`Policy`], which describes how this resource may be accessed
 ///
 /// Note that the Policy only describes access granted explicitly for this resource.  The policies of parent resources can also cause a user to have access to this resource.
@@ -4181,6 +5088,37 @@ pub struct ProjectRolePolicy {
     pub role_assignments: Vec<ProjectRoleAssignment>,
 }
 
+impl ProjectRolePolicy {
+    /// A builder for [`ProjectRolePolicy`].
+    pub fn builder() -> ProjectRolePolicyBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`ProjectRolePolicy`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct ProjectRolePolicyBuilder {
+    role_assignments: Option<Vec<ProjectRoleAssignment>>,
+}
+
+impl ProjectRolePolicyBuilder {
+    /// Sets [`ProjectRolePolicy::role_assignments`].
+    pub fn role_assignments(mut self, value: impl Into<Vec<ProjectRoleAssignment>>) -> Self {
+        self.role_assignments = Some(value.into());
+        self
+    }
+
+    /// Builds the [`ProjectRolePolicy`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<ProjectRolePolicy> {
+        Ok(ProjectRolePolicy {
+            role_assignments: self
+                .role_assignments
+                .ok_or_else(|| anyhow::anyhow!("ProjectRolePolicy requires role_assignments"))?,
+        })
+    }
+}
+
 /// Updateable properties of a [`Project`](crate::external_api::views::Project)
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct ProjectUpdate {
@@ -4199,6 +5137,43 @@ pub struct ProjectUpdate {
     pub description: String,
 }
 
+impl ProjectUpdate {
+    /// A builder for [`ProjectUpdate`].
+    pub fn builder() -> ProjectUpdateBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`ProjectUpdate`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct ProjectUpdateBuilder {
+    name: Option<String>,
+    description: Option<String>,
+}
+
+impl ProjectUpdateBuilder {
+    /// Sets [`ProjectUpdate::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`ProjectUpdate::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Builds the [`ProjectUpdate`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<ProjectUpdate> {
+        Ok(ProjectUpdate {
+            name: self.name.unwrap_or_default(),
+            description: self.description.unwrap_or_default(),
+        })
+    }
+}
+
 /// Client view of an [`Rack`]
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct Rack {
@@ -4296,85 +5271,59 @@ pub struct RoleResultsPage {
     pub next_page: String,
 }
 
+/// A `RouteDestination` is used to match traffic with a routing rule, on the destination of that traffic.
+///
+/// When traffic is to be sent to a destination that is within a given `RouteDestination`, the corresponding [`RouterRoute`] applies, and traffic will be forward to the [`RouteTarget`] for that rule.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 #[serde(tag = "type", content = "value")]
 pub enum RouteDestination {
-    Ip(String),
+    /// Route applies to traffic destined for a specific IP address
+    Ip(std::net::IpAddr),
+    /// Route applies to traffic destined for a specific IP subnet
     IpNet(IpNet),
+    /// Route applies to traffic destined for the given VPC.
     Vpc(String),
+    /// Route applies to traffic
     Subnet(String),
 }
 
 impl fmt::Display for RouteDestination {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let j = serde_json::json!(self);
-        let mut tag: String = serde_json::from_value(j["type"].clone()).unwrap_or_default();
-        let mut content: String = serde_json::from_value(j["value"].clone()).unwrap_or_default();
-        if content.is_empty() {
-            let map: std::collections::HashMap<String, String> =
-                serde_json::from_value(j["value"].clone()).unwrap_or_default();
-            if let Some((_, v)) = map.iter().next() {
-                content = v.to_string();
-            }
-        }
-        if tag == "internet_gateway" {
-            tag = "inetgw".to_string();
+        match self {
+            RouteDestination::Ip(value) => write!(f, "ip={}", value),
+            RouteDestination::IpNet(value) => write!(f, "ip_net={}", value),
+            RouteDestination::Vpc(value) => write!(f, "vpc={}", value),
+            RouteDestination::Subnet(value) => write!(f, "subnet={}", value),
         }
-        write!(f, "{}={}", tag, content)
     }
 }
 
 impl std::str::FromStr for RouteDestination {
     type Err = anyhow::Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts = s.split('=').collect::<Vec<&str>>();
-        if parts.len() != 2 {
-            anyhow::bail!("invalid format for RouteDestination, got {}", s);
-        }
-        let tag = parts[0].to_string();
-        let content = parts[1].to_string();
-        let mut j = String::new();
-        if tag == "ip" {
-            j = format!(
-                r#"{{
-"type": "ip",
-"value": "{}"
-        }}"#,
-                content
-            );
-        }
-        if tag == "ip_net" {
-            j = format!(
-                r#"{{
-"type": "ip_net",
-"value": {}
-        }}"#,
-                serde_json::json!(IpNet::from_str(&content).unwrap())
-            );
-        }
-        if tag == "vpc" {
-            j = format!(
-                r#"{{
-"type": "vpc",
-"value": "{}"
-        }}"#,
-                content
-            );
-        }
-        if tag == "subnet" {
-            j = format!(
-                r#"{{
-"type": "subnet",
-"value": "{}"
-        }}"#,
-                content
-            );
-        }
-        let result = serde_json::from_str(&j)?;
-        Ok(result)
+        let (tag, value) = match s.split_once('=') {
+            Some((tag, value)) => (tag, Some(value)),
+            None => (s, None),
+        };
+        Ok(match (tag, value) {
+            ("ip", Some(value)) => RouteDestination::Ip(
+                value
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("invalid ip `{}`: {}", value, e))?,
+            ),
+            ("ip_net", Some(value)) => RouteDestination::IpNet(
+                value
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("invalid ip_net `{}`: {}", value, e))?,
+            ),
+            ("vpc", Some(value)) => RouteDestination::Vpc(value.to_string()),
+            ("subnet", Some(value)) => RouteDestination::Subnet(value.to_string()),
+            _ => anyhow::bail!("invalid format for RouteDestination, got {}", s),
+        })
     }
 }
+
 impl RouteDestination {
     pub fn variants() -> Vec<String> {
         vec![
@@ -4433,95 +5382,57 @@ impl std::str::FromStr for RouteDestinationType {
     }
 }
 
+/// A `RouteTarget` describes the possible locations that traffic matching a route destination can be sent.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 #[serde(tag = "type", content = "value")]
 pub enum RouteTarget {
-    Ip(String),
+    /// Forward traffic to a particular IP address.
+    Ip(std::net::IpAddr),
+    /// Forward traffic to a VPC
     Vpc(String),
+    /// Forward traffic to a VPC Subnet
     Subnet(String),
+    /// Forward traffic to a specific instance
     Instance(String),
+    /// Forward traffic to an internet gateway
     InternetGateway(String),
 }
 
 impl fmt::Display for RouteTarget {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let j = serde_json::json!(self);
-        let mut tag: String = serde_json::from_value(j["type"].clone()).unwrap_or_default();
-        let mut content: String = serde_json::from_value(j["value"].clone()).unwrap_or_default();
-        if content.is_empty() {
-            let map: std::collections::HashMap<String, String> =
-                serde_json::from_value(j["value"].clone()).unwrap_or_default();
-            if let Some((_, v)) = map.iter().next() {
-                content = v.to_string();
-            }
-        }
-        if tag == "internet_gateway" {
-            tag = "inetgw".to_string();
+        match self {
+            RouteTarget::Ip(value) => write!(f, "ip={}", value),
+            RouteTarget::Vpc(value) => write!(f, "vpc={}", value),
+            RouteTarget::Subnet(value) => write!(f, "subnet={}", value),
+            RouteTarget::Instance(value) => write!(f, "instance={}", value),
+            RouteTarget::InternetGateway(value) => write!(f, "inetgw={}", value),
         }
-        write!(f, "{}={}", tag, content)
     }
 }
 
 impl std::str::FromStr for RouteTarget {
     type Err = anyhow::Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts = s.split('=').collect::<Vec<&str>>();
-        if parts.len() != 2 {
-            anyhow::bail!("invalid format for RouteTarget, got {}", s);
-        }
-        let tag = parts[0].to_string();
-        let content = parts[1].to_string();
-        let mut j = String::new();
-        if tag == "ip" {
-            j = format!(
-                r#"{{
-"type": "ip",
-"value": "{}"
-        }}"#,
-                content
-            );
-        }
-        if tag == "vpc" {
-            j = format!(
-                r#"{{
-"type": "vpc",
-"value": "{}"
-        }}"#,
-                content
-            );
-        }
-        if tag == "subnet" {
-            j = format!(
-                r#"{{
-"type": "subnet",
-"value": "{}"
-        }}"#,
-                content
-            );
-        }
-        if tag == "instance" {
-            j = format!(
-                r#"{{
-"type": "instance",
-"value": "{}"
-        }}"#,
-                content
-            );
-        }
-        if tag == "inetgw" {
-            j = format!(
-                r#"{{
-"type": "internet_gateway",
-"value": "{}"
-        }}"#,
-                content
-            );
-        }
-        let result = serde_json::from_str(&j)?;
-        Ok(result)
+        let (tag, value) = match s.split_once('=') {
+            Some((tag, value)) => (tag, Some(value)),
+            None => (s, None),
+        };
+        Ok(match (tag, value) {
+            ("ip", Some(value)) => RouteTarget::Ip(
+                value
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("invalid ip `{}`: {}", value, e))?,
+            ),
+            ("vpc", Some(value)) => RouteTarget::Vpc(value.to_string()),
+            ("subnet", Some(value)) => RouteTarget::Subnet(value.to_string()),
+            ("instance", Some(value)) => RouteTarget::Instance(value.to_string()),
+            ("inetgw", Some(value)) => RouteTarget::InternetGateway(value.to_string()),
+            _ => anyhow::bail!("invalid format for RouteTarget, got {}", s),
+        })
     }
 }
+
 impl RouteTarget {
     pub fn variants() -> Vec<String> {
         vec![
@@ -4680,6 +5591,11 @@ pub struct RouterRoute {
     )]
     pub description: String,
 
+    /**
+     * A `RouteDestination` is used to match traffic with a routing rule, on the destination of that traffic.
+     *  
+     *  When traffic is to be sent to a destination that is within a given `RouteDestination`, the corresponding [`RouterRoute`] applies, and traffic will be forward to the [`RouteTarget`] for that rule.
+     */
     #[serde()]
     pub destination: RouteDestination,
 
@@ -4691,6 +5607,9 @@ pub struct RouterRoute {
     #[serde(default, skip_serializing_if = "RouterRouteKind::is_noop")]
     pub kind: RouterRouteKind,
 
+    /**
+     * A `RouteTarget` describes the possible locations that traffic matching a route destination can be sent.
+     */
     #[serde()]
     pub target: RouteTarget,
 
@@ -4737,13 +5656,82 @@ pub struct RouterRouteCreateParams {
     )]
     pub description: String,
 
+    /**
+     * A `RouteDestination` is used to match traffic with a routing rule, on the destination of that traffic.
+     *  
+     *  When traffic is to be sent to a destination that is within a given `RouteDestination`, the corresponding [`RouterRoute`] applies, and traffic will be forward to the [`RouteTarget`] for that rule.
+     */
     #[serde()]
     pub destination: RouteDestination,
 
+    /**
+     * A `RouteTarget` describes the possible locations that traffic matching a route destination can be sent.
+     */
     #[serde()]
     pub target: RouteTarget,
 }
 
+impl RouterRouteCreateParams {
+    /// A builder for [`RouterRouteCreateParams`].
+    pub fn builder() -> RouterRouteCreateParamsBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`RouterRouteCreateParams`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct RouterRouteCreateParamsBuilder {
+    name: Option<String>,
+    description: Option<String>,
+    destination: Option<RouteDestination>,
+    target: Option<RouteTarget>,
+}
+
+impl RouterRouteCreateParamsBuilder {
+    /// Sets [`RouterRouteCreateParams::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`RouterRouteCreateParams::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets [`RouterRouteCreateParams::destination`].
+    pub fn destination(mut self, value: impl Into<RouteDestination>) -> Self {
+        self.destination = Some(value.into());
+        self
+    }
+
+    /// Sets [`RouterRouteCreateParams::target`].
+    pub fn target(mut self, value: impl Into<RouteTarget>) -> Self {
+        self.target = Some(value.into());
+        self
+    }
+
+    /// Builds the [`RouterRouteCreateParams`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<RouterRouteCreateParams> {
+        Ok(RouterRouteCreateParams {
+            name: self
+                .name
+                .ok_or_else(|| anyhow::anyhow!("RouterRouteCreateParams requires name"))?,
+            description: self
+                .description
+                .ok_or_else(|| anyhow::anyhow!("RouterRouteCreateParams requires description"))?,
+            destination: self
+                .destination
+                .ok_or_else(|| anyhow::anyhow!("RouterRouteCreateParams requires destination"))?,
+            target: self
+                .target
+                .ok_or_else(|| anyhow::anyhow!("RouterRouteCreateParams requires target"))?,
+        })
+    }
+}
+
 /// A single page of results
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct RouterRouteResultsPage {
@@ -4786,13 +5774,78 @@ pub struct RouterRouteUpdateParams {
     )]
     pub description: String,
 
+    /**
+     * A `RouteDestination` is used to match traffic with a routing rule, on the destination of that traffic.
+     *  
+     *  When traffic is to be sent to a destination that is within a given `RouteDestination`, the corresponding [`RouterRoute`] applies, and traffic will be forward to the [`RouteTarget`] for that rule.
+     */
     #[serde()]
     pub destination: RouteDestination,
 
+    /**
+     * A `RouteTarget` describes the possible locations that traffic matching a route destination can be sent.
+     */
     #[serde()]
     pub target: RouteTarget,
 }
 
+impl RouterRouteUpdateParams {
+    /// A builder for [`RouterRouteUpdateParams`].
+    pub fn builder() -> RouterRouteUpdateParamsBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`RouterRouteUpdateParams`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct RouterRouteUpdateParamsBuilder {
+    name: Option<String>,
+    description: Option<String>,
+    destination: Option<RouteDestination>,
+    target: Option<RouteTarget>,
+}
+
+impl RouterRouteUpdateParamsBuilder {
+    /// Sets [`RouterRouteUpdateParams::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`RouterRouteUpdateParams::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets [`RouterRouteUpdateParams::destination`].
+    pub fn destination(mut self, value: impl Into<RouteDestination>) -> Self {
+        self.destination = Some(value.into());
+        self
+    }
+
+    /// Sets [`RouterRouteUpdateParams::target`].
+    pub fn target(mut self, value: impl Into<RouteTarget>) -> Self {
+        self.target = Some(value.into());
+        self
+    }
+
+    /// Builds the [`RouterRouteUpdateParams`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<RouterRouteUpdateParams> {
+        Ok(RouterRouteUpdateParams {
+            name: self.name.unwrap_or_default(),
+            description: self.description.unwrap_or_default(),
+            destination: self
+                .destination
+                .ok_or_else(|| anyhow::anyhow!("RouterRouteUpdateParams requires destination"))?,
+            target: self
+                .target
+                .ok_or_else(|| anyhow::anyhow!("RouterRouteUpdateParams requires target"))?,
+        })
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 #[serde(tag = "state")]
@@ -4805,18 +5858,6 @@ pub enum SagaState {
     },
 }
 
-impl fmt::Display for SagaState {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", serde_json::json!(self))
-    }
-}
-
-impl std::str::FromStr for SagaState {
-    type Err = anyhow::Error;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(serde_json::from_str(s)?)
-    }
-}
 impl SagaState {
     pub fn variants() -> Vec<String> {
         vec![
@@ -4879,89 +5920,21 @@ pub struct Saga {
     pub id: String,
 
     #[serde()]
+    #[header(hidden = true)]
     pub state: SagaState,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 #[serde(rename_all = "snake_case")]
-#[serde(tag = "error", content = "message")]
+#[serde(tag = "error")]
 pub enum SagaErrorInfo {
-    ActionFailed(serde_json::Value),
-    DeserializeFailed(String),
+    ActionFailed { source_error: serde_json::Value },
+    DeserializeFailed { message: String },
     InjectedError,
-    SerializeFailed(String),
-    SubsagaCreateFailed(String),
-}
-
-impl fmt::Display for SagaErrorInfo {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let j = serde_json::json!(self);
-        let mut tag: String = serde_json::from_value(j["error"].clone()).unwrap_or_default();
-        let mut content: String = serde_json::from_value(j["message"].clone()).unwrap_or_default();
-        if content.is_empty() {
-            let map: std::collections::HashMap<String, String> =
-                serde_json::from_value(j["message"].clone()).unwrap_or_default();
-            if let Some((_, v)) = map.iter().next() {
-                content = v.to_string();
-            }
-        }
-        if tag == "internet_gateway" {
-            tag = "inetgw".to_string();
-        }
-        write!(f, "{}={}", tag, content)
-    }
+    SerializeFailed { message: String },
+    SubsagaCreateFailed { message: String },
 }
 
-impl std::str::FromStr for SagaErrorInfo {
-    type Err = anyhow::Error;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts = s.split('=').collect::<Vec<&str>>();
-        if parts.len() != 2 {
-            anyhow::bail!("invalid format for SagaErrorInfo, got {}", s);
-        }
-        let tag = parts[0].to_string();
-        let content = parts[1].to_string();
-        let mut j = String::new();
-        if tag == "action_failed" {
-            j = format!(
-                r#"{{
-"error": "action_failed",
-"message": {}
-        }}"#,
-                serde_json::json!(serde_json::Value::from_str(&content).unwrap())
-            );
-        }
-        if tag == "deserialize_failed" {
-            j = format!(
-                r#"{{
-"error": "deserialize_failed",
-"message": "{}"
-        }}"#,
-                content
-            );
-        }
-        if tag == "serialize_failed" {
-            j = format!(
-                r#"{{
-"error": "serialize_failed",
-"message": "{}"
-        }}"#,
-                content
-            );
-        }
-        if tag == "subsaga_create_failed" {
-            j = format!(
-                r#"{{
-"error": "subsaga_create_failed",
-"message": "{}"
-        }}"#,
-                content
-            );
-        }
-        let result = serde_json::from_str(&j)?;
-        Ok(result)
-    }
-}
 impl SagaErrorInfo {
     pub fn variants() -> Vec<String> {
         vec![
@@ -5208,6 +6181,7 @@ pub struct SamlIdentityProviderCreate {
     pub idp_entity_id: String,
 
     #[serde()]
+    #[header(hidden = true)]
     pub idp_metadata_source: IdpMetadataSource,
 
     /**
@@ -5237,15 +6211,132 @@ pub struct SamlIdentityProviderCreate {
     )]
     pub sp_client_id: String,
 
-    /**
-     * customer's technical contact for saml configuration
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub technical_contact_email: String,
+    /**
+     * customer's technical contact for saml configuration
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub technical_contact_email: String,
+}
+
+impl SamlIdentityProviderCreate {
+    /// A builder for [`SamlIdentityProviderCreate`].
+    pub fn builder() -> SamlIdentityProviderCreateBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`SamlIdentityProviderCreate`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct SamlIdentityProviderCreateBuilder {
+    name: Option<String>,
+    description: Option<String>,
+    acs_url: Option<String>,
+    group_attribute_name: Option<String>,
+    idp_entity_id: Option<String>,
+    idp_metadata_source: Option<IdpMetadataSource>,
+    signing_keypair: Option<Option<DerEncodedKeyPair>>,
+    slo_url: Option<String>,
+    sp_client_id: Option<String>,
+    technical_contact_email: Option<String>,
+}
+
+impl SamlIdentityProviderCreateBuilder {
+    /// Sets [`SamlIdentityProviderCreate::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`SamlIdentityProviderCreate::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets [`SamlIdentityProviderCreate::acs_url`].
+    pub fn acs_url(mut self, value: impl Into<String>) -> Self {
+        self.acs_url = Some(value.into());
+        self
+    }
+
+    /// Sets [`SamlIdentityProviderCreate::group_attribute_name`].
+    pub fn group_attribute_name(mut self, value: impl Into<String>) -> Self {
+        self.group_attribute_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`SamlIdentityProviderCreate::idp_entity_id`].
+    pub fn idp_entity_id(mut self, value: impl Into<String>) -> Self {
+        self.idp_entity_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`SamlIdentityProviderCreate::idp_metadata_source`].
+    pub fn idp_metadata_source(mut self, value: impl Into<IdpMetadataSource>) -> Self {
+        self.idp_metadata_source = Some(value.into());
+        self
+    }
+
+    /// Sets [`SamlIdentityProviderCreate::signing_keypair`].
+    pub fn signing_keypair(mut self, value: impl Into<DerEncodedKeyPair>) -> Self {
+        self.signing_keypair = Some(Some(value.into()));
+        self
+    }
+
+    /// Sets [`SamlIdentityProviderCreate::slo_url`].
+    pub fn slo_url(mut self, value: impl Into<String>) -> Self {
+        self.slo_url = Some(value.into());
+        self
+    }
+
+    /// Sets [`SamlIdentityProviderCreate::sp_client_id`].
+    pub fn sp_client_id(mut self, value: impl Into<String>) -> Self {
+        self.sp_client_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`SamlIdentityProviderCreate::technical_contact_email`].
+    pub fn technical_contact_email(mut self, value: impl Into<String>) -> Self {
+        self.technical_contact_email = Some(value.into());
+        self
+    }
+
+    /// Builds the [`SamlIdentityProviderCreate`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<SamlIdentityProviderCreate> {
+        Ok(SamlIdentityProviderCreate {
+            name: self
+                .name
+                .ok_or_else(|| anyhow::anyhow!("SamlIdentityProviderCreate requires name"))?,
+            description: self.description.ok_or_else(|| {
+                anyhow::anyhow!("SamlIdentityProviderCreate requires description")
+            })?,
+            acs_url: self
+                .acs_url
+                .ok_or_else(|| anyhow::anyhow!("SamlIdentityProviderCreate requires acs_url"))?,
+            group_attribute_name: self.group_attribute_name.unwrap_or_default(),
+            idp_entity_id: self.idp_entity_id.ok_or_else(|| {
+                anyhow::anyhow!("SamlIdentityProviderCreate requires idp_entity_id")
+            })?,
+            idp_metadata_source: self.idp_metadata_source.ok_or_else(|| {
+                anyhow::anyhow!("SamlIdentityProviderCreate requires idp_metadata_source")
+            })?,
+            signing_keypair: self.signing_keypair.unwrap_or_default(),
+            slo_url: self
+                .slo_url
+                .ok_or_else(|| anyhow::anyhow!("SamlIdentityProviderCreate requires slo_url"))?,
+            sp_client_id: self.sp_client_id.ok_or_else(|| {
+                anyhow::anyhow!("SamlIdentityProviderCreate requires sp_client_id")
+            })?,
+            technical_contact_email: self.technical_contact_email.ok_or_else(|| {
+                anyhow::anyhow!("SamlIdentityProviderCreate requires technical_contact_email")
+            })?,
+        })
+    }
 }
 
 /**
@@ -5403,6 +6494,75 @@ pub struct SiloCreate {
     pub user_provision_type: UserProvisionType,
 }
 
+impl SiloCreate {
+    /// A builder for [`SiloCreate`].
+    pub fn builder() -> SiloCreateBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`SiloCreate`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct SiloCreateBuilder {
+    name: Option<String>,
+    description: Option<String>,
+    admin_group_name: Option<String>,
+    discoverable: Option<bool>,
+    user_provision_type: Option<UserProvisionType>,
+}
+
+impl SiloCreateBuilder {
+    /// Sets [`SiloCreate::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`SiloCreate::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets [`SiloCreate::admin_group_name`].
+    pub fn admin_group_name(mut self, value: impl Into<String>) -> Self {
+        self.admin_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`SiloCreate::discoverable`].
+    pub fn discoverable(mut self, value: impl Into<bool>) -> Self {
+        self.discoverable = Some(value.into());
+        self
+    }
+
+    /// Sets [`SiloCreate::user_provision_type`].
+    pub fn user_provision_type(mut self, value: impl Into<UserProvisionType>) -> Self {
+        self.user_provision_type = Some(value.into());
+        self
+    }
+
+    /// Builds the [`SiloCreate`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<SiloCreate> {
+        Ok(SiloCreate {
+            name: self
+                .name
+                .ok_or_else(|| anyhow::anyhow!("SiloCreate requires name"))?,
+            description: self
+                .description
+                .ok_or_else(|| anyhow::anyhow!("SiloCreate requires description"))?,
+            admin_group_name: self.admin_group_name.unwrap_or_default(),
+            discoverable: self
+                .discoverable
+                .ok_or_else(|| anyhow::anyhow!("SiloCreate requires discoverable"))?,
+            user_provision_type: self
+                .user_provision_type
+                .ok_or_else(|| anyhow::anyhow!("SiloCreate requires user_provision_type"))?,
+        })
+    }
+}
+
 /// A single page of results
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct SiloResultsPage {
@@ -5501,6 +6661,57 @@ pub struct SiloRoleAssignment {
     pub role_name: SiloRole,
 }
 
+impl SiloRoleAssignment {
+    /// A builder for [`SiloRoleAssignment`].
+    pub fn builder() -> SiloRoleAssignmentBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`SiloRoleAssignment`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct SiloRoleAssignmentBuilder {
+    identity_id: Option<String>,
+    identity_type: Option<IdentityType>,
+    role_name: Option<SiloRole>,
+}
+
+impl SiloRoleAssignmentBuilder {
+    /// Sets [`SiloRoleAssignment::identity_id`].
+    pub fn identity_id(mut self, value: impl Into<String>) -> Self {
+        self.identity_id = Some(value.into());
+        self
+    }
+
+    /// Sets [`SiloRoleAssignment::identity_type`].
+    pub fn identity_type(mut self, value: impl Into<IdentityType>) -> Self {
+        self.identity_type = Some(value.into());
+        self
+    }
+
+    /// Sets [`SiloRoleAssignment::role_name`].
+    pub fn role_name(mut self, value: impl Into<SiloRole>) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Builds the [`SiloRoleAssignment`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<SiloRoleAssignment> {
+        Ok(SiloRoleAssignment {
+            identity_id: self
+                .identity_id
+                .ok_or_else(|| anyhow::anyhow!("SiloRoleAssignment requires identity_id"))?,
+            identity_type: self
+                .identity_type
+                .ok_or_else(|| anyhow::anyhow!("SiloRoleAssignment requires identity_type"))?,
+            role_name: self
+                .role_name
+                .ok_or_else(|| anyhow::anyhow!("SiloRoleAssignment requires role_name"))?,
+        })
+    }
+}
+
 /// Client view of a [`Policy`], which describes how this resource may be accessed
 ///
 /// Note that the Policy only describes access granted explicitly for this resource.  The policies of parent resources can also cause a user to have access to this resource.
@@ -5518,6 +6729,37 @@ pub struct SiloRolePolicy {
     pub role_assignments: Vec<SiloRoleAssignment>,
 }
 
+impl SiloRolePolicy {
+    /// A builder for [`SiloRolePolicy`].
+    pub fn builder() -> SiloRolePolicyBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`SiloRolePolicy`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct SiloRolePolicyBuilder {
+    role_assignments: Option<Vec<SiloRoleAssignment>>,
+}
+
+impl SiloRolePolicyBuilder {
+    /// Sets [`SiloRolePolicy::role_assignments`].
+    pub fn role_assignments(mut self, value: impl Into<Vec<SiloRoleAssignment>>) -> Self {
+        self.role_assignments = Some(value.into());
+        self
+    }
+
+    /// Builds the [`SiloRolePolicy`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<SiloRolePolicy> {
+        Ok(SiloRolePolicy {
+            role_assignments: self
+                .role_assignments
+                .ok_or_else(|| anyhow::anyhow!("SiloRolePolicy requires role_assignments"))?,
+        })
+    }
+}
+
 /// Client view of an [`Sled`]
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct Sled {
@@ -5629,7 +6871,7 @@ pub struct Snapshot {
      *  The maximum supported byte count is [`i64::MAX`].  This makes it somewhat inconvenient to define constructors: a u32 constructor can be infallible, but an i64 constructor can fail (if the value is negative) and a u64 constructor can fail (if the value is larger than i64::MAX).  We provide all of these for consumers' convenience.
      */
     #[serde(default)]
-    pub size: u64,
+    pub size: crate::types::ByteCount,
 
     /**
      * timestamp when this resource was created
@@ -5675,6 +6917,57 @@ pub struct SnapshotCreate {
     pub disk: String,
 }
 
+impl SnapshotCreate {
+    /// A builder for [`SnapshotCreate`].
+    pub fn builder() -> SnapshotCreateBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`SnapshotCreate`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct SnapshotCreateBuilder {
+    name: Option<String>,
+    description: Option<String>,
+    disk: Option<String>,
+}
+
+impl SnapshotCreateBuilder {
+    /// Sets [`SnapshotCreate::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`SnapshotCreate::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets [`SnapshotCreate::disk`].
+    pub fn disk(mut self, value: impl Into<String>) -> Self {
+        self.disk = Some(value.into());
+        self
+    }
+
+    /// Builds the [`SnapshotCreate`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<SnapshotCreate> {
+        Ok(SnapshotCreate {
+            name: self
+                .name
+                .ok_or_else(|| anyhow::anyhow!("SnapshotCreate requires name"))?,
+            description: self
+                .description
+                .ok_or_else(|| anyhow::anyhow!("SnapshotCreate requires description"))?,
+            disk: self
+                .disk
+                .ok_or_else(|| anyhow::anyhow!("SnapshotCreate requires disk"))?,
+        })
+    }
+}
+
 /// A single page of results
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct SnapshotResultsPage {
@@ -5710,6 +7003,37 @@ pub struct SpoofLoginBody {
     pub username: String,
 }
 
+impl SpoofLoginBody {
+    /// A builder for [`SpoofLoginBody`].
+    pub fn builder() -> SpoofLoginBodyBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`SpoofLoginBody`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct SpoofLoginBodyBuilder {
+    username: Option<String>,
+}
+
+impl SpoofLoginBodyBuilder {
+    /// Sets [`SpoofLoginBody::username`].
+    pub fn username(mut self, value: impl Into<String>) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the [`SpoofLoginBody`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<SpoofLoginBody> {
+        Ok(SpoofLoginBody {
+            username: self
+                .username
+                .ok_or_else(|| anyhow::anyhow!("SpoofLoginBody requires username"))?,
+        })
+    }
+}
+
 /// Client view of a [`SshKey`]
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct SshKey {
@@ -5807,6 +7131,57 @@ pub struct SshKeyCreate {
     pub public_key: String,
 }
 
+impl SshKeyCreate {
+    /// A builder for [`SshKeyCreate`].
+    pub fn builder() -> SshKeyCreateBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`SshKeyCreate`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct SshKeyCreateBuilder {
+    name: Option<String>,
+    description: Option<String>,
+    public_key: Option<String>,
+}
+
+impl SshKeyCreateBuilder {
+    /// Sets [`SshKeyCreate::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`SshKeyCreate::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets [`SshKeyCreate::public_key`].
+    pub fn public_key(mut self, value: impl Into<String>) -> Self {
+        self.public_key = Some(value.into());
+        self
+    }
+
+    /// Builds the [`SshKeyCreate`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<SshKeyCreate> {
+        Ok(SshKeyCreate {
+            name: self
+                .name
+                .ok_or_else(|| anyhow::anyhow!("SshKeyCreate requires name"))?,
+            description: self
+                .description
+                .ok_or_else(|| anyhow::anyhow!("SshKeyCreate requires description"))?,
+            public_key: self
+                .public_key
+                .ok_or_else(|| anyhow::anyhow!("SshKeyCreate requires public_key"))?,
+        })
+    }
+}
+
 /// A single page of results
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct SshKeyResultsPage {
@@ -6136,6 +7511,65 @@ pub struct VpcCreate {
     pub ipv6_prefix: String,
 }
 
+impl VpcCreate {
+    /// A builder for [`VpcCreate`].
+    pub fn builder() -> VpcCreateBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`VpcCreate`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct VpcCreateBuilder {
+    name: Option<String>,
+    description: Option<String>,
+    dns_name: Option<String>,
+    ipv6_prefix: Option<String>,
+}
+
+impl VpcCreateBuilder {
+    /// Sets [`VpcCreate::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`VpcCreate::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets [`VpcCreate::dns_name`].
+    pub fn dns_name(mut self, value: impl Into<String>) -> Self {
+        self.dns_name = Some(value.into());
+        self
+    }
+
+    /// Sets [`VpcCreate::ipv6_prefix`].
+    pub fn ipv6_prefix(mut self, value: impl Into<String>) -> Self {
+        self.ipv6_prefix = Some(value.into());
+        self
+    }
+
+    /// Builds the [`VpcCreate`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<VpcCreate> {
+        Ok(VpcCreate {
+            name: self
+                .name
+                .ok_or_else(|| anyhow::anyhow!("VpcCreate requires name"))?,
+            description: self
+                .description
+                .ok_or_else(|| anyhow::anyhow!("VpcCreate requires description"))?,
+            dns_name: self
+                .dns_name
+                .ok_or_else(|| anyhow::anyhow!("VpcCreate requires dns_name"))?,
+            ipv6_prefix: self.ipv6_prefix.unwrap_or_default(),
+        })
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Tabled)]
 #[serde(rename_all = "snake_case")]
 pub enum VpcFirewallRuleAction {
@@ -6262,6 +7696,51 @@ pub struct VpcFirewallRuleFilter {
     pub protocols: Vec<VpcFirewallRuleProtocol>,
 }
 
+impl VpcFirewallRuleFilter {
+    /// A builder for [`VpcFirewallRuleFilter`].
+    pub fn builder() -> VpcFirewallRuleFilterBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`VpcFirewallRuleFilter`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct VpcFirewallRuleFilterBuilder {
+    hosts: Option<Vec<VpcFirewallRuleHostFilter>>,
+    ports: Option<Vec<String>>,
+    protocols: Option<Vec<VpcFirewallRuleProtocol>>,
+}
+
+impl VpcFirewallRuleFilterBuilder {
+    /// Sets [`VpcFirewallRuleFilter::hosts`].
+    pub fn hosts(mut self, value: impl Into<Vec<VpcFirewallRuleHostFilter>>) -> Self {
+        self.hosts = Some(value.into());
+        self
+    }
+
+    /// Sets [`VpcFirewallRuleFilter::ports`].
+    pub fn ports(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.ports = Some(value.into());
+        self
+    }
+
+    /// Sets [`VpcFirewallRuleFilter::protocols`].
+    pub fn protocols(mut self, value: impl Into<Vec<VpcFirewallRuleProtocol>>) -> Self {
+        self.protocols = Some(value.into());
+        self
+    }
+
+    /// Builds the [`VpcFirewallRuleFilter`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<VpcFirewallRuleFilter> {
+        Ok(VpcFirewallRuleFilter {
+            hosts: self.hosts.unwrap_or_default(),
+            ports: self.ports.unwrap_or_default(),
+            protocols: self.protocols.unwrap_or_default(),
+        })
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Tabled)]
 #[serde(rename_all = "snake_case")]
 pub enum VpcFirewallRuleStatus {
@@ -6308,95 +7787,61 @@ impl VpcFirewallRuleStatus {
     }
 }
 
+/// A `VpcFirewallRuleTarget` is used to specify the set of [`Instance`]s to which a firewall rule applies.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 #[serde(tag = "type", content = "value")]
 pub enum VpcFirewallRuleTarget {
+    /// The rule applies to all instances in the VPC
     Vpc(String),
+    /// The rule applies to all instances in the VPC Subnet
     Subnet(String),
+    /// The rule applies to this specific instance
     Instance(String),
-    Ip(String),
+    /// The rule applies to a specific IP address
+    Ip(std::net::IpAddr),
+    /// The rule applies to a specific IP subnet
     IpNet(IpNet),
 }
 
 impl fmt::Display for VpcFirewallRuleTarget {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let j = serde_json::json!(self);
-        let mut tag: String = serde_json::from_value(j["type"].clone()).unwrap_or_default();
-        let mut content: String = serde_json::from_value(j["value"].clone()).unwrap_or_default();
-        if content.is_empty() {
-            let map: std::collections::HashMap<String, String> =
-                serde_json::from_value(j["value"].clone()).unwrap_or_default();
-            if let Some((_, v)) = map.iter().next() {
-                content = v.to_string();
-            }
-        }
-        if tag == "internet_gateway" {
-            tag = "inetgw".to_string();
+        match self {
+            VpcFirewallRuleTarget::Vpc(value) => write!(f, "vpc={}", value),
+            VpcFirewallRuleTarget::Subnet(value) => write!(f, "subnet={}", value),
+            VpcFirewallRuleTarget::Instance(value) => write!(f, "instance={}", value),
+            VpcFirewallRuleTarget::Ip(value) => write!(f, "ip={}", value),
+            VpcFirewallRuleTarget::IpNet(value) => write!(f, "ip_net={}", value),
         }
-        write!(f, "{}={}", tag, content)
     }
 }
 
 impl std::str::FromStr for VpcFirewallRuleTarget {
     type Err = anyhow::Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts = s.split('=').collect::<Vec<&str>>();
-        if parts.len() != 2 {
-            anyhow::bail!("invalid format for VpcFirewallRuleTarget, got {}", s);
-        }
-        let tag = parts[0].to_string();
-        let content = parts[1].to_string();
-        let mut j = String::new();
-        if tag == "vpc" {
-            j = format!(
-                r#"{{
-"type": "vpc",
-"value": "{}"
-        }}"#,
-                content
-            );
-        }
-        if tag == "subnet" {
-            j = format!(
-                r#"{{
-"type": "subnet",
-"value": "{}"
-        }}"#,
-                content
-            );
-        }
-        if tag == "instance" {
-            j = format!(
-                r#"{{
-"type": "instance",
-"value": "{}"
-        }}"#,
-                content
-            );
-        }
-        if tag == "ip" {
-            j = format!(
-                r#"{{
-"type": "ip",
-"value": "{}"
-        }}"#,
-                content
-            );
-        }
-        if tag == "ip_net" {
-            j = format!(
-                r#"{{
-"type": "ip_net",
-"value": {}
-        }}"#,
-                serde_json::json!(IpNet::from_str(&content).unwrap())
-            );
-        }
-        let result = serde_json::from_str(&j)?;
-        Ok(result)
+        let (tag, value) = match s.split_once('=') {
+            Some((tag, value)) => (tag, Some(value)),
+            None => (s, None),
+        };
+        Ok(match (tag, value) {
+            ("vpc", Some(value)) => VpcFirewallRuleTarget::Vpc(value.to_string()),
+            ("subnet", Some(value)) => VpcFirewallRuleTarget::Subnet(value.to_string()),
+            ("instance", Some(value)) => VpcFirewallRuleTarget::Instance(value.to_string()),
+            ("ip", Some(value)) => VpcFirewallRuleTarget::Ip(
+                value
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("invalid ip `{}`: {}", value, e))?,
+            ),
+            ("ip_net", Some(value)) => VpcFirewallRuleTarget::IpNet(
+                value
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("invalid ip_net `{}`: {}", value, e))?,
+            ),
+            _ => anyhow::bail!("invalid format for VpcFirewallRuleTarget, got {}", s),
+        })
     }
 }
+
 impl VpcFirewallRuleTarget {
     pub fn variants() -> Vec<String> {
         vec![
@@ -6550,95 +7995,61 @@ pub struct VpcFirewallRule {
     pub vpc_id: String,
 }
 
+/// The `VpcFirewallRuleHostFilter` is used to filter traffic on the basis of its source or destination host.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 #[serde(tag = "type", content = "value")]
 pub enum VpcFirewallRuleHostFilter {
+    /// The rule applies to traffic from/to all instances in the VPC
     Vpc(String),
+    /// The rule applies to traffic from/to all instances in the VPC Subnet
     Subnet(String),
+    /// The rule applies to traffic from/to this specific instance
     Instance(String),
-    Ip(String),
+    /// The rule applies to traffic from/to a specific IP address
+    Ip(std::net::IpAddr),
+    /// The rule applies to traffic from/to a specific IP subnet
     IpNet(IpNet),
 }
 
 impl fmt::Display for VpcFirewallRuleHostFilter {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let j = serde_json::json!(self);
-        let mut tag: String = serde_json::from_value(j["type"].clone()).unwrap_or_default();
-        let mut content: String = serde_json::from_value(j["value"].clone()).unwrap_or_default();
-        if content.is_empty() {
-            let map: std::collections::HashMap<String, String> =
-                serde_json::from_value(j["value"].clone()).unwrap_or_default();
-            if let Some((_, v)) = map.iter().next() {
-                content = v.to_string();
-            }
-        }
-        if tag == "internet_gateway" {
-            tag = "inetgw".to_string();
+        match self {
+            VpcFirewallRuleHostFilter::Vpc(value) => write!(f, "vpc={}", value),
+            VpcFirewallRuleHostFilter::Subnet(value) => write!(f, "subnet={}", value),
+            VpcFirewallRuleHostFilter::Instance(value) => write!(f, "instance={}", value),
+            VpcFirewallRuleHostFilter::Ip(value) => write!(f, "ip={}", value),
+            VpcFirewallRuleHostFilter::IpNet(value) => write!(f, "ip_net={}", value),
         }
-        write!(f, "{}={}", tag, content)
     }
 }
 
 impl std::str::FromStr for VpcFirewallRuleHostFilter {
     type Err = anyhow::Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts = s.split('=').collect::<Vec<&str>>();
-        if parts.len() != 2 {
-            anyhow::bail!("invalid format for VpcFirewallRuleHostFilter, got {}", s);
-        }
-        let tag = parts[0].to_string();
-        let content = parts[1].to_string();
-        let mut j = String::new();
-        if tag == "vpc" {
-            j = format!(
-                r#"{{
-"type": "vpc",
-"value": "{}"
-        }}"#,
-                content
-            );
-        }
-        if tag == "subnet" {
-            j = format!(
-                r#"{{
-"type": "subnet",
-"value": "{}"
-        }}"#,
-                content
-            );
-        }
-        if tag == "instance" {
-            j = format!(
-                r#"{{
-"type": "instance",
-"value": "{}"
-        }}"#,
-                content
-            );
-        }
-        if tag == "ip" {
-            j = format!(
-                r#"{{
-"type": "ip",
-"value": "{}"
-        }}"#,
-                content
-            );
-        }
-        if tag == "ip_net" {
-            j = format!(
-                r#"{{
-"type": "ip_net",
-"value": {}
-        }}"#,
-                serde_json::json!(IpNet::from_str(&content).unwrap())
-            );
-        }
-        let result = serde_json::from_str(&j)?;
-        Ok(result)
+        let (tag, value) = match s.split_once('=') {
+            Some((tag, value)) => (tag, Some(value)),
+            None => (s, None),
+        };
+        Ok(match (tag, value) {
+            ("vpc", Some(value)) => VpcFirewallRuleHostFilter::Vpc(value.to_string()),
+            ("subnet", Some(value)) => VpcFirewallRuleHostFilter::Subnet(value.to_string()),
+            ("instance", Some(value)) => VpcFirewallRuleHostFilter::Instance(value.to_string()),
+            ("ip", Some(value)) => VpcFirewallRuleHostFilter::Ip(
+                value
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("invalid ip `{}`: {}", value, e))?,
+            ),
+            ("ip_net", Some(value)) => VpcFirewallRuleHostFilter::IpNet(
+                value
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("invalid ip_net `{}`: {}", value, e))?,
+            ),
+            _ => anyhow::bail!("invalid format for VpcFirewallRuleHostFilter, got {}", s),
+        })
     }
 }
+
 impl VpcFirewallRuleHostFilter {
     pub fn variants() -> Vec<String> {
         vec![
@@ -6709,8 +8120,11 @@ impl std::str::FromStr for VpcFirewallRuleHostFilterType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Tabled)]
 #[serde(rename_all = "snake_case")]
 pub enum VpcFirewallRuleProtocol {
+    #[serde(rename = "ICMP")]
     Icmp,
+    #[serde(rename = "TCP")]
     Tcp,
+    #[serde(rename = "UDP")]
     Udp,
     #[serde(rename = "")]
     Noop,
@@ -6814,6 +8228,107 @@ pub struct VpcFirewallRuleUpdate {
     pub targets: Vec<VpcFirewallRuleTarget>,
 }
 
+impl VpcFirewallRuleUpdate {
+    /// A builder for [`VpcFirewallRuleUpdate`].
+    pub fn builder() -> VpcFirewallRuleUpdateBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`VpcFirewallRuleUpdate`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct VpcFirewallRuleUpdateBuilder {
+    name: Option<String>,
+    description: Option<String>,
+    action: Option<VpcFirewallRuleAction>,
+    direction: Option<VpcFirewallRuleDirection>,
+    filters: Option<VpcFirewallRuleFilter>,
+    priority: Option<u16>,
+    status: Option<VpcFirewallRuleStatus>,
+    targets: Option<Vec<VpcFirewallRuleTarget>>,
+}
+
+impl VpcFirewallRuleUpdateBuilder {
+    /// Sets [`VpcFirewallRuleUpdate::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`VpcFirewallRuleUpdate::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets [`VpcFirewallRuleUpdate::action`].
+    pub fn action(mut self, value: impl Into<VpcFirewallRuleAction>) -> Self {
+        self.action = Some(value.into());
+        self
+    }
+
+    /// Sets [`VpcFirewallRuleUpdate::direction`].
+    pub fn direction(mut self, value: impl Into<VpcFirewallRuleDirection>) -> Self {
+        self.direction = Some(value.into());
+        self
+    }
+
+    /// Sets [`VpcFirewallRuleUpdate::filters`].
+    pub fn filters(mut self, value: impl Into<VpcFirewallRuleFilter>) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets [`VpcFirewallRuleUpdate::priority`].
+    pub fn priority(mut self, value: impl Into<u16>) -> Self {
+        self.priority = Some(value.into());
+        self
+    }
+
+    /// Sets [`VpcFirewallRuleUpdate::status`].
+    pub fn status(mut self, value: impl Into<VpcFirewallRuleStatus>) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Sets [`VpcFirewallRuleUpdate::targets`].
+    pub fn targets(mut self, value: impl Into<Vec<VpcFirewallRuleTarget>>) -> Self {
+        self.targets = Some(value.into());
+        self
+    }
+
+    /// Builds the [`VpcFirewallRuleUpdate`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<VpcFirewallRuleUpdate> {
+        Ok(VpcFirewallRuleUpdate {
+            name: self
+                .name
+                .ok_or_else(|| anyhow::anyhow!("VpcFirewallRuleUpdate requires name"))?,
+            description: self
+                .description
+                .ok_or_else(|| anyhow::anyhow!("VpcFirewallRuleUpdate requires description"))?,
+            action: self
+                .action
+                .ok_or_else(|| anyhow::anyhow!("VpcFirewallRuleUpdate requires action"))?,
+            direction: self
+                .direction
+                .ok_or_else(|| anyhow::anyhow!("VpcFirewallRuleUpdate requires direction"))?,
+            filters: self
+                .filters
+                .ok_or_else(|| anyhow::anyhow!("VpcFirewallRuleUpdate requires filters"))?,
+            priority: self
+                .priority
+                .ok_or_else(|| anyhow::anyhow!("VpcFirewallRuleUpdate requires priority"))?,
+            status: self
+                .status
+                .ok_or_else(|| anyhow::anyhow!("VpcFirewallRuleUpdate requires status"))?,
+            targets: self
+                .targets
+                .ok_or_else(|| anyhow::anyhow!("VpcFirewallRuleUpdate requires targets"))?,
+        })
+    }
+}
+
 /// Updateable properties of a `Vpc`'s firewall Note that VpcFirewallRules are implicitly created along with a Vpc, so there is no explicit creation.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct VpcFirewallRuleUpdateParams {
@@ -6826,6 +8341,37 @@ pub struct VpcFirewallRuleUpdateParams {
     pub rules: Vec<VpcFirewallRuleUpdate>,
 }
 
+impl VpcFirewallRuleUpdateParams {
+    /// A builder for [`VpcFirewallRuleUpdateParams`].
+    pub fn builder() -> VpcFirewallRuleUpdateParamsBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`VpcFirewallRuleUpdateParams`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct VpcFirewallRuleUpdateParamsBuilder {
+    rules: Option<Vec<VpcFirewallRuleUpdate>>,
+}
+
+impl VpcFirewallRuleUpdateParamsBuilder {
+    /// Sets [`VpcFirewallRuleUpdateParams::rules`].
+    pub fn rules(mut self, value: impl Into<Vec<VpcFirewallRuleUpdate>>) -> Self {
+        self.rules = Some(value.into());
+        self
+    }
+
+    /// Builds the [`VpcFirewallRuleUpdateParams`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<VpcFirewallRuleUpdateParams> {
+        Ok(VpcFirewallRuleUpdateParams {
+            rules: self
+                .rules
+                .ok_or_else(|| anyhow::anyhow!("VpcFirewallRuleUpdateParams requires rules"))?,
+        })
+    }
+}
+
 /// Collection of a Vpc's firewall rules
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct VpcFirewallRules {
@@ -6989,6 +8535,47 @@ pub struct VpcRouterCreate {
     pub description: String,
 }
 
+impl VpcRouterCreate {
+    /// A builder for [`VpcRouterCreate`].
+    pub fn builder() -> VpcRouterCreateBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`VpcRouterCreate`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct VpcRouterCreateBuilder {
+    name: Option<String>,
+    description: Option<String>,
+}
+
+impl VpcRouterCreateBuilder {
+    /// Sets [`VpcRouterCreate::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`VpcRouterCreate::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Builds the [`VpcRouterCreate`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<VpcRouterCreate> {
+        Ok(VpcRouterCreate {
+            name: self
+                .name
+                .ok_or_else(|| anyhow::anyhow!("VpcRouterCreate requires name"))?,
+            description: self
+                .description
+                .ok_or_else(|| anyhow::anyhow!("VpcRouterCreate requires description"))?,
+        })
+    }
+}
+
 /// A single page of results
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct VpcRouterResultsPage {
@@ -7032,6 +8619,43 @@ pub struct VpcRouterUpdate {
     pub description: String,
 }
 
+impl VpcRouterUpdate {
+    /// A builder for [`VpcRouterUpdate`].
+    pub fn builder() -> VpcRouterUpdateBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`VpcRouterUpdate`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct VpcRouterUpdateBuilder {
+    name: Option<String>,
+    description: Option<String>,
+}
+
+impl VpcRouterUpdateBuilder {
+    /// Sets [`VpcRouterUpdate::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`VpcRouterUpdate::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Builds the [`VpcRouterUpdate`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<VpcRouterUpdate> {
+        Ok(VpcRouterUpdate {
+            name: self.name.unwrap_or_default(),
+            description: self.description.unwrap_or_default(),
+        })
+    }
+}
+
 /// A VPC subnet represents a logical grouping for instances that allows network traffic between them, within a IPv4 subnetwork or optionall an IPv6 subnetwork.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct VpcSubnet {
@@ -7151,6 +8775,65 @@ pub struct VpcSubnetCreate {
     pub ipv6_block: String,
 }
 
+impl VpcSubnetCreate {
+    /// A builder for [`VpcSubnetCreate`].
+    pub fn builder() -> VpcSubnetCreateBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`VpcSubnetCreate`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct VpcSubnetCreateBuilder {
+    name: Option<String>,
+    description: Option<String>,
+    ipv4_block: Option<String>,
+    ipv6_block: Option<String>,
+}
+
+impl VpcSubnetCreateBuilder {
+    /// Sets [`VpcSubnetCreate::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`VpcSubnetCreate::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets [`VpcSubnetCreate::ipv4_block`].
+    pub fn ipv4_block(mut self, value: impl Into<String>) -> Self {
+        self.ipv4_block = Some(value.into());
+        self
+    }
+
+    /// Sets [`VpcSubnetCreate::ipv6_block`].
+    pub fn ipv6_block(mut self, value: impl Into<String>) -> Self {
+        self.ipv6_block = Some(value.into());
+        self
+    }
+
+    /// Builds the [`VpcSubnetCreate`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<VpcSubnetCreate> {
+        Ok(VpcSubnetCreate {
+            name: self
+                .name
+                .ok_or_else(|| anyhow::anyhow!("VpcSubnetCreate requires name"))?,
+            description: self
+                .description
+                .ok_or_else(|| anyhow::anyhow!("VpcSubnetCreate requires description"))?,
+            ipv4_block: self
+                .ipv4_block
+                .ok_or_else(|| anyhow::anyhow!("VpcSubnetCreate requires ipv4_block"))?,
+            ipv6_block: self.ipv6_block.unwrap_or_default(),
+        })
+    }
+}
+
 /// A single page of results
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct VpcSubnetResultsPage {
@@ -7194,6 +8877,43 @@ pub struct VpcSubnetUpdate {
     pub description: String,
 }
 
+impl VpcSubnetUpdate {
+    /// A builder for [`VpcSubnetUpdate`].
+    pub fn builder() -> VpcSubnetUpdateBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`VpcSubnetUpdate`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct VpcSubnetUpdateBuilder {
+    name: Option<String>,
+    description: Option<String>,
+}
+
+impl VpcSubnetUpdateBuilder {
+    /// Sets [`VpcSubnetUpdate::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`VpcSubnetUpdate::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Builds the [`VpcSubnetUpdate`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<VpcSubnetUpdate> {
+        Ok(VpcSubnetUpdate {
+            name: self.name.unwrap_or_default(),
+            description: self.description.unwrap_or_default(),
+        })
+    }
+}
+
 /// Updateable properties of a [`Vpc`](crate::external_api::views::Vpc)
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default, Tabled)]
 pub struct VpcUpdate {
@@ -7219,6 +8939,51 @@ pub struct VpcUpdate {
     pub dns_name: String,
 }
 
+impl VpcUpdate {
+    /// A builder for [`VpcUpdate`].
+    pub fn builder() -> VpcUpdateBuilder {
+        Default::default()
+    }
+}
+
+/// A builder of [`VpcUpdate`] values, which starts from the defaults of the API
+/// and fails to build without the required fields.
+#[derive(Clone, Debug, Default)]
+pub struct VpcUpdateBuilder {
+    name: Option<String>,
+    description: Option<String>,
+    dns_name: Option<String>,
+}
+
+impl VpcUpdateBuilder {
+    /// Sets [`VpcUpdate::name`].
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets [`VpcUpdate::description`].
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets [`VpcUpdate::dns_name`].
+    pub fn dns_name(mut self, value: impl Into<String>) -> Self {
+        self.dns_name = Some(value.into());
+        self
+    }
+
+    /// Builds the [`VpcUpdate`], failing if a required field is not set.
+    pub fn build(self) -> anyhow::Result<VpcUpdate> {
+        Ok(VpcUpdate {
+            name: self.name.unwrap_or_default(),
+            description: self.description.unwrap_or_default(),
+            dns_name: self.dns_name.unwrap_or_default(),
+        })
+    }
+}
+
 /**
  * Supported set of sort modes for scanning by id only.
  *   
@@ -7432,10 +9197,6 @@ impl DiskMetricName {
 }
 
 pub type BlockSize = i64;
-/// A count of bytes, typically used either for memory or storage capacity
-///
-/// The maximum supported byte count is [`i64::MAX`].  This makes it somewhat inconvenient to define constructors: a u32 constructor can be infallible, but an i64 constructor can fail (if the value is negative) and a u64 constructor can fail (if the value is larger than i64::MAX).  We provide all of these for consumers' convenience.
-pub type ByteCount = u64;
 /// The number of CPUs in an Instance
 pub type InstanceCpuCount = u16;
 /// An inclusive-inclusive range of IP ports. The second port may be omitted to represent a single port
@@ -0,0 +1,1209 @@
+//! Round trips of samples of every type through JSON, generated from the
+//! examples and schemas of the API definition.
+
+use pretty_assertions::assert_eq;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+fn round_trip<T>(samples: &[&str])
+where
+    T: DeserializeOwned + Serialize + schemars::JsonSchema,
+{
+    let schema = serde_json::to_value(schemars::schema_for!(T)).unwrap();
+    for sample in samples {
+        let expected: Value = serde_json::from_str(sample).unwrap();
+        let value: T = serde_json::from_value(expected.clone())
+            .unwrap_or_else(|e| panic!("deserializing {}: {}", sample, e));
+        let actual = serde_json::to_value(&value).unwrap();
+        assert_eq!(actual, expected);
+        if let Err(e) = validate(&schema, &schema, &actual) {
+            panic!("{} does not match the schema: {}", sample, e);
+        }
+    }
+}
+
+/// Check a value against the parts of JSON schema that schemars generates.
+fn validate(root: &Value, schema: &Value, value: &Value) -> Result<(), String> {
+    if let Some(reference) = schema.get("$ref").and_then(|r| r.as_str()) {
+        let name = reference.trim_start_matches("#/definitions/");
+        return validate(root, &root["definitions"][name], value);
+    }
+
+    if let Some(types) = schema.get("type") {
+        let types: Vec<&str> = match types {
+            Value::Array(types) => types.iter().filter_map(|t| t.as_str()).collect(),
+            t => t.as_str().into_iter().collect(),
+        };
+        let matches = |t: &&str| match *t {
+            "null" => value.is_null(),
+            "boolean" => value.is_boolean(),
+            "integer" => value.is_i64() || value.is_u64(),
+            "number" => value.is_number(),
+            "string" => value.is_string(),
+            "array" => value.is_array(),
+            "object" => value.is_object(),
+            _ => true,
+        };
+        if !types.iter().any(matches) {
+            return Err(format!("{} is not of type {:?}", value, types));
+        }
+    }
+    if let Some(values) = schema.get("enum").and_then(|e| e.as_array()) {
+        if !values.contains(value) {
+            return Err(format!("{} is not one of {:?}", value, values));
+        }
+    }
+    if let Some(constant) = schema.get("const") {
+        if constant != value {
+            return Err(format!("{} is not {}", value, constant));
+        }
+    }
+
+    if let Some(object) = value.as_object() {
+        let properties = schema.get("properties").and_then(|p| p.as_object());
+        for required in schema
+            .get("required")
+            .and_then(|r| r.as_array())
+            .into_iter()
+            .flatten()
+        {
+            if !object.contains_key(required.as_str().unwrap_or_default()) {
+                return Err(format!("{} is missing {}", value, required));
+            }
+        }
+        for (k, v) in object {
+            match (
+                properties.and_then(|p| p.get(k)),
+                schema.get("additionalProperties"),
+            ) {
+                (Some(property), _) => validate(root, property, v)?,
+                (None, Some(Value::Bool(false))) => {
+                    return Err(format!("{} has unknown property {}", value, k));
+                }
+                (None, Some(additional)) => validate(root, additional, v)?,
+                (None, None) => {}
+            }
+        }
+    }
+    if let (Some(items), Some(array)) = (schema.get("items"), value.as_array()) {
+        for item in array {
+            validate(root, items, item)?;
+        }
+    }
+
+    if let Some(all) = schema.get("allOf").and_then(|a| a.as_array()) {
+        for s in all {
+            validate(root, s, value)?;
+        }
+    }
+    if let Some(any) = schema.get("anyOf").and_then(|a| a.as_array()) {
+        if !any.iter().any(|s| validate(root, s, value).is_ok()) {
+            return Err(format!(
+                "{} matches none of {}",
+                value,
+                Value::from(any.clone())
+            ));
+        }
+    }
+    if let Some(one) = schema.get("oneOf").and_then(|o| o.as_array()) {
+        let matching = one
+            .iter()
+            .filter(|s| validate(root, s, value).is_ok())
+            .count();
+        if matching != 1 {
+            return Err(format!(
+                "{} matches {} of {}",
+                value,
+                matching,
+                Value::from(one.clone())
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_round_trip_bin_rangedouble() {
+    round_trip::<crate::types::BinRangedouble>(&[
+        r##"{"end":1.5,"type":"range_to"}"##,
+        r##"{"end":1.5,"start":1.5,"type":"range"}"##,
+        r##"{"start":1.5,"type":"range_from"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_bin_rangeint_64() {
+    round_trip::<crate::types::BinRangeint64>(&[
+        r##"{"end":1,"type":"range_to"}"##,
+        r##"{"end":1,"start":1,"type":"range"}"##,
+        r##"{"start":1,"type":"range_from"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_bindouble() {
+    round_trip::<crate::types::Bindouble>(&[
+        r##"{"count":1,"range":{"end":1.5,"type":"range_to"}}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_binint_64() {
+    round_trip::<crate::types::Binint64>(&[r##"{"count":1,"range":{"end":1,"type":"range_to"}}"##]);
+}
+
+#[test]
+fn test_round_trip_byte_count() {
+    round_trip::<crate::types::ByteCount>(&[r##"1"##]);
+}
+
+#[test]
+fn test_round_trip_cumulativedouble() {
+    round_trip::<crate::types::Cumulativedouble>(&[
+        r##"{"start_time":"2022-06-01T12:30:00Z","value":1.5}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_cumulativeint_64() {
+    round_trip::<crate::types::Cumulativeint64>(&[
+        r##"{"start_time":"2022-06-01T12:30:00Z","value":1}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_datum() {
+    round_trip::<crate::types::Datum>(&[
+        r##"{"datum":true,"type":"bool"}"##,
+        r##"{"datum":1,"type":"i64"}"##,
+        r##"{"datum":1.5,"type":"f64"}"##,
+        r##"{"datum":"sample","type":"string"}"##,
+        r##"{"datum":[1],"type":"bytes"}"##,
+        r##"{"datum":{"start_time":"2022-06-01T12:30:00Z","value":1},"type":"cumulative_i64"}"##,
+        r##"{"datum":{"start_time":"2022-06-01T12:30:00Z","value":1.5},"type":"cumulative_f64"}"##,
+        r##"{"datum":{"bins":[{"count":1,"range":{"end":1,"type":"range_to"}}],"n_samples":1,"start_time":"2022-06-01T12:30:00Z"},"type":"histogram_i64"}"##,
+        r##"{"datum":{"bins":[{"count":1,"range":{"end":1.5,"type":"range_to"}}],"n_samples":1,"start_time":"2022-06-01T12:30:00Z"},"type":"histogram_f64"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_datum_type() {
+    round_trip::<crate::types::DatumType>(&[
+        r##""bool""##,
+        r##""i64""##,
+        r##""f64""##,
+        r##""string""##,
+        r##""bytes""##,
+        r##""cumulative_i64""##,
+        r##""cumulative_f64""##,
+        r##""histogram_i64""##,
+        r##""histogram_f64""##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_der_encoded_key_pair() {
+    round_trip::<crate::types::DerEncodedKeyPair>(&[
+        r##"{"private_key":"sample","public_cert":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_device_access_token_request() {
+    round_trip::<crate::types::DeviceAccessTokenRequest>(&[
+        r##"{"client_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","device_code":"sample","grant_type":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_device_auth_request() {
+    round_trip::<crate::types::DeviceAuthRequest>(&[
+        r##"{"client_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_device_auth_verify() {
+    round_trip::<crate::types::DeviceAuthVerify>(&[r##"{"user_code":"sample"}"##]);
+}
+
+#[test]
+fn test_round_trip_digest() {
+    round_trip::<crate::types::Digest>(&[r##"{"type":"sha256","value":"sample"}"##]);
+}
+
+#[test]
+fn test_round_trip_disk() {
+    round_trip::<crate::types::Disk>(&[
+        r##"{"block_size":1,"description":"sample","device_path":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","image_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","name":"sample","project_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","size":1,"snapshot_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","state":{"state":"creating"},"time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_disk_create() {
+    round_trip::<crate::types::DiskCreate>(&[
+        r##"{"description":"sample","disk_source":{"block_size":512,"type":"blank"},"name":"sample","size":1}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_disk_identifier() {
+    round_trip::<crate::types::DiskIdentifier>(&[r##"{"name":"sample"}"##]);
+}
+
+#[test]
+fn test_round_trip_disk_results_page() {
+    round_trip::<crate::types::DiskResultsPage>(&[
+        r##"{"items":[{"block_size":1,"description":"sample","device_path":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","image_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","name":"sample","project_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","size":1,"snapshot_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","state":{"state":"creating"},"time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z"}],"next_page":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_disk_source() {
+    round_trip::<crate::types::DiskSource>(&[
+        r##"{"block_size":512,"type":"blank"}"##,
+        r##"{"snapshot_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","type":"snapshot"}"##,
+        r##"{"image_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","type":"image"}"##,
+        r##"{"image_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","type":"global_image"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_disk_state() {
+    round_trip::<crate::types::DiskState>(&[
+        r##"{"state":"creating"}"##,
+        r##"{"state":"detached"}"##,
+        r##"{"instance":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","state":"attaching"}"##,
+        r##"{"instance":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","state":"attached"}"##,
+        r##"{"instance":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","state":"detaching"}"##,
+        r##"{"state":"destroyed"}"##,
+        r##"{"state":"faulted"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_distribution() {
+    round_trip::<crate::types::Distribution>(&[r##"{"name":"sample","version":"sample"}"##]);
+}
+
+#[test]
+fn test_round_trip_error_response() {
+    round_trip::<crate::types::ErrorResponse>(&[
+        r##"{"error_code":"sample","message":"sample","request_id":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_external_ip() {
+    round_trip::<crate::types::ExternalIp>(&[r##"{"ip":"10.0.0.1","kind":"ephemeral"}"##]);
+}
+
+#[test]
+fn test_round_trip_external_ip_create() {
+    round_trip::<crate::types::ExternalIpCreate>(&[
+        r##"{"pool_name":"sample","type":"ephemeral"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_external_ip_results_page() {
+    round_trip::<crate::types::ExternalIpResultsPage>(&[
+        r##"{"items":[{"ip":"10.0.0.1","kind":"ephemeral"}],"next_page":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_field_schema() {
+    round_trip::<crate::types::FieldSchema>(&[
+        r##"{"name":"sample","source":"target","ty":"string"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_field_source() {
+    round_trip::<crate::types::FieldSource>(&[r##""target""##, r##""metric""##]);
+}
+
+#[test]
+fn test_round_trip_field_type() {
+    round_trip::<crate::types::FieldType>(&[
+        r##""string""##,
+        r##""i64""##,
+        r##""ip_addr""##,
+        r##""uuid""##,
+        r##""bool""##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_fleet_role() {
+    round_trip::<crate::types::FleetRole>(&[
+        r##""admin""##,
+        r##""collaborator""##,
+        r##""viewer""##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_fleet_role_policy() {
+    round_trip::<crate::types::FleetRolePolicy>(&[
+        r##"{"role_assignments":[{"identity_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","identity_type":"silo_user","role_name":"admin"}]}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_fleet_role_assignment() {
+    round_trip::<crate::types::FleetRoleAssignment>(&[
+        r##"{"identity_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","identity_type":"silo_user","role_name":"admin"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_global_image() {
+    round_trip::<crate::types::GlobalImage>(&[
+        r##"{"block_size":1,"description":"sample","digest":{"type":"sha256","value":"sample"},"distribution":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","name":"sample","size":1,"time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z","url":"sample","version":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_global_image_create() {
+    round_trip::<crate::types::GlobalImageCreate>(&[
+        r##"{"block_size":512,"description":"sample","distribution":{"name":"sample","version":"sample"},"name":"sample","source":{"type":"url","url":"sample"}}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_global_image_results_page() {
+    round_trip::<crate::types::GlobalImageResultsPage>(&[
+        r##"{"items":[{"block_size":1,"description":"sample","digest":{"type":"sha256","value":"sample"},"distribution":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","name":"sample","size":1,"time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z","url":"sample","version":"sample"}],"next_page":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_histogramdouble() {
+    round_trip::<crate::types::Histogramdouble>(&[
+        r##"{"bins":[{"count":1,"range":{"end":1.5,"type":"range_to"}}],"n_samples":1,"start_time":"2022-06-01T12:30:00Z"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_histogramint_64() {
+    round_trip::<crate::types::Histogramint64>(&[
+        r##"{"bins":[{"count":1,"range":{"end":1,"type":"range_to"}}],"n_samples":1,"start_time":"2022-06-01T12:30:00Z"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_identity_provider() {
+    round_trip::<crate::types::IdentityProvider>(&[
+        r##"{"description":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","name":"sample","provider_type":"saml","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_identity_provider_results_page() {
+    round_trip::<crate::types::IdentityProviderResultsPage>(&[
+        r##"{"items":[{"description":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","name":"sample","provider_type":"saml","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z"}],"next_page":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_identity_provider_type() {
+    round_trip::<crate::types::IdentityProviderType>(&[r##""saml""##]);
+}
+
+#[test]
+fn test_round_trip_identity_type() {
+    round_trip::<crate::types::IdentityType>(&[r##""silo_user""##, r##""silo_group""##]);
+}
+
+#[test]
+fn test_round_trip_idp_metadata_source() {
+    round_trip::<crate::types::IdpMetadataSource>(&[
+        r##"{"type":"url","url":"sample"}"##,
+        r##"{"data":"sample","type":"base64_encoded_xml"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_image() {
+    round_trip::<crate::types::Image>(&[
+        r##"{"block_size":1,"description":"sample","digest":{"type":"sha256","value":"sample"},"id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","name":"sample","project_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","size":1,"time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z","url":"sample","version":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_image_create() {
+    round_trip::<crate::types::ImageCreate>(&[
+        r##"{"block_size":512,"description":"sample","name":"sample","source":{"type":"url","url":"sample"}}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_image_results_page() {
+    round_trip::<crate::types::ImageResultsPage>(&[
+        r##"{"items":[{"block_size":1,"description":"sample","digest":{"type":"sha256","value":"sample"},"id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","name":"sample","project_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","size":1,"time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z","url":"sample","version":"sample"}],"next_page":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_image_source() {
+    round_trip::<crate::types::ImageSource>(&[
+        r##"{"type":"url","url":"sample"}"##,
+        r##"{"id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","type":"snapshot"}"##,
+        r##"{"type":"you_can_boot_anything_as_long_as_its_alpine"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_instance() {
+    round_trip::<crate::types::Instance>(&[
+        r##"{"description":"sample","hostname":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","memory":1,"name":"sample","ncpus":1,"project_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","run_state":"creating","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z","time_run_state_updated":"2022-06-01T12:30:00Z"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_instance_create() {
+    round_trip::<crate::types::InstanceCreate>(&[
+        r##"{"description":"sample","disks":[{"description":"sample","disk_source":{"block_size":512,"type":"blank"},"name":"sample","size":1,"type":"create"}],"external_ips":[{"pool_name":"sample","type":"ephemeral"}],"hostname":"sample","memory":1,"name":"sample","ncpus":1,"network_interfaces":{"params":[{"description":"sample","ip":"10.0.0.1","name":"sample","subnet_name":"sample","vpc_name":"sample"}],"type":"create"},"start":true,"user_data":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_instance_disk_attachment() {
+    round_trip::<crate::types::InstanceDiskAttachment>(&[
+        r##"{"description":"sample","disk_source":{"block_size":512,"type":"blank"},"name":"sample","size":1,"type":"create"}"##,
+        r##"{"name":"sample","type":"attach"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_instance_migrate() {
+    round_trip::<crate::types::InstanceMigrate>(&[
+        r##"{"dst_sled_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_instance_network_interface_attachment() {
+    round_trip::<crate::types::InstanceNetworkInterfaceAttachment>(&[
+        r##"{"params":[{"description":"sample","ip":"10.0.0.1","name":"sample","subnet_name":"sample","vpc_name":"sample"}],"type":"create"}"##,
+        r##"{"type":"default"}"##,
+        r##"{"type":"none"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_instance_results_page() {
+    round_trip::<crate::types::InstanceResultsPage>(&[
+        r##"{"items":[{"description":"sample","hostname":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","memory":1,"name":"sample","ncpus":1,"project_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","run_state":"creating","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z","time_run_state_updated":"2022-06-01T12:30:00Z"}],"next_page":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_instance_serial_console_data() {
+    round_trip::<crate::types::InstanceSerialConsoleData>(&[
+        r##"{"data":[1],"last_byte_offset":1}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_instance_state() {
+    round_trip::<crate::types::InstanceState>(&[
+        r##""creating""##,
+        r##""starting""##,
+        r##""running""##,
+        r##""stopping""##,
+        r##""stopped""##,
+        r##""rebooting""##,
+        r##""migrating""##,
+        r##""repairing""##,
+        r##""failed""##,
+        r##""destroyed""##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_ip_kind() {
+    round_trip::<crate::types::IpKind>(&[r##""ephemeral""##, r##""floating""##]);
+}
+
+#[test]
+fn test_round_trip_ip_net() {
+    round_trip::<crate::types::IpNet>(&[r##""192.168.1.0/24""##, r##""fd12:3456::/64""##]);
+}
+
+#[test]
+fn test_round_trip_ip_pool() {
+    round_trip::<crate::types::IpPool>(&[
+        r##"{"description":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","name":"sample","project_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_ip_pool_create() {
+    round_trip::<crate::types::IpPoolCreate>(&[
+        r##"{"description":"sample","name":"sample","organization":"sample","project":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_ip_pool_range() {
+    round_trip::<crate::types::IpPoolRange>(&[
+        r##"{"id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","range":{"first":"10.0.0.1","last":"10.0.0.1"},"time_created":"2022-06-01T12:30:00Z"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_ip_pool_range_results_page() {
+    round_trip::<crate::types::IpPoolRangeResultsPage>(&[
+        r##"{"items":[{"id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","range":{"first":"10.0.0.1","last":"10.0.0.1"},"time_created":"2022-06-01T12:30:00Z"}],"next_page":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_ip_pool_results_page() {
+    round_trip::<crate::types::IpPoolResultsPage>(&[
+        r##"{"items":[{"description":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","name":"sample","project_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z"}],"next_page":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_ip_pool_update() {
+    round_trip::<crate::types::IpPoolUpdate>(&[r##"{"description":"sample","name":"sample"}"##]);
+}
+
+#[test]
+fn test_round_trip_ip_range() {
+    round_trip::<crate::types::IpRange>(&[
+        r##"{"first":"10.0.0.1","last":"10.0.0.1"}"##,
+        r##"{"first":"fd00::1","last":"fd00::1"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_ipv_4_range() {
+    round_trip::<crate::types::Ipv4Range>(&[r##"{"first":"10.0.0.1","last":"10.0.0.1"}"##]);
+}
+
+#[test]
+fn test_round_trip_ipv_6_range() {
+    round_trip::<crate::types::Ipv6Range>(&[r##"{"first":"fd00::1","last":"fd00::1"}"##]);
+}
+
+#[test]
+fn test_round_trip_measurement() {
+    round_trip::<crate::types::Measurement>(&[
+        r##"{"datum":{"datum":true,"type":"bool"},"timestamp":"2022-06-01T12:30:00Z"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_measurement_results_page() {
+    round_trip::<crate::types::MeasurementResultsPage>(&[
+        r##"{"items":[{"datum":{"datum":true,"type":"bool"},"timestamp":"2022-06-01T12:30:00Z"}],"next_page":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_network_interface() {
+    round_trip::<crate::types::NetworkInterface>(&[
+        r##"{"description":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","instance_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","ip":"10.0.0.1","mac":"ff:ff:ff:ff:ff:ff","name":"sample","primary":true,"subnet_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z","vpc_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_network_interface_create() {
+    round_trip::<crate::types::NetworkInterfaceCreate>(&[
+        r##"{"description":"sample","ip":"10.0.0.1","name":"sample","subnet_name":"sample","vpc_name":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_network_interface_results_page() {
+    round_trip::<crate::types::NetworkInterfaceResultsPage>(&[
+        r##"{"items":[{"description":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","instance_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","ip":"10.0.0.1","mac":"ff:ff:ff:ff:ff:ff","name":"sample","primary":true,"subnet_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z","vpc_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71"}],"next_page":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_network_interface_update() {
+    round_trip::<crate::types::NetworkInterfaceUpdate>(&[
+        r##"{"description":"sample","name":"sample","primary":true}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_organization() {
+    round_trip::<crate::types::Organization>(&[
+        r##"{"description":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","name":"sample","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_organization_create() {
+    round_trip::<crate::types::OrganizationCreate>(&[
+        r##"{"description":"sample","name":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_organization_results_page() {
+    round_trip::<crate::types::OrganizationResultsPage>(&[
+        r##"{"items":[{"description":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","name":"sample","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z"}],"next_page":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_organization_role() {
+    round_trip::<crate::types::OrganizationRole>(&[
+        r##""admin""##,
+        r##""collaborator""##,
+        r##""viewer""##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_organization_role_policy() {
+    round_trip::<crate::types::OrganizationRolePolicy>(&[
+        r##"{"role_assignments":[{"identity_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","identity_type":"silo_user","role_name":"admin"}]}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_organization_role_assignment() {
+    round_trip::<crate::types::OrganizationRoleAssignment>(&[
+        r##"{"identity_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","identity_type":"silo_user","role_name":"admin"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_organization_update() {
+    round_trip::<crate::types::OrganizationUpdate>(&[
+        r##"{"description":"sample","name":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_project() {
+    round_trip::<crate::types::Project>(&[
+        r##"{"description":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","name":"sample","organization_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_project_create() {
+    round_trip::<crate::types::ProjectCreate>(&[r##"{"description":"sample","name":"sample"}"##]);
+}
+
+#[test]
+fn test_round_trip_project_results_page() {
+    round_trip::<crate::types::ProjectResultsPage>(&[
+        r##"{"items":[{"description":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","name":"sample","organization_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z"}],"next_page":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_project_role() {
+    round_trip::<crate::types::ProjectRole>(&[
+        r##""admin""##,
+        r##""collaborator""##,
+        r##""viewer""##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_project_role_policy() {
+    round_trip::<crate::types::ProjectRolePolicy>(&[
+        r##"{"role_assignments":[{"identity_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","identity_type":"silo_user","role_name":"admin"}]}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_project_role_assignment() {
+    round_trip::<crate::types::ProjectRoleAssignment>(&[
+        r##"{"identity_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","identity_type":"silo_user","role_name":"admin"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_project_update() {
+    round_trip::<crate::types::ProjectUpdate>(&[r##"{"description":"sample","name":"sample"}"##]);
+}
+
+#[test]
+fn test_round_trip_rack() {
+    round_trip::<crate::types::Rack>(&[
+        r##"{"id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_rack_results_page() {
+    round_trip::<crate::types::RackResultsPage>(&[
+        r##"{"items":[{"id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z"}],"next_page":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_role() {
+    round_trip::<crate::types::Role>(&[r##"{"description":"sample","name":"sample"}"##]);
+}
+
+#[test]
+fn test_round_trip_role_results_page() {
+    round_trip::<crate::types::RoleResultsPage>(&[
+        r##"{"items":[{"description":"sample","name":"sample"}],"next_page":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_route_destination() {
+    round_trip::<crate::types::RouteDestination>(&[
+        r##"{"type":"ip","value":"10.0.0.1"}"##,
+        r##"{"type":"ip_net","value":"192.168.1.0/24"}"##,
+        r##"{"type":"vpc","value":"sample"}"##,
+        r##"{"type":"subnet","value":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_route_target() {
+    round_trip::<crate::types::RouteTarget>(&[
+        r##"{"type":"ip","value":"10.0.0.1"}"##,
+        r##"{"type":"vpc","value":"sample"}"##,
+        r##"{"type":"subnet","value":"sample"}"##,
+        r##"{"type":"instance","value":"sample"}"##,
+        r##"{"type":"internet_gateway","value":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_router_route() {
+    round_trip::<crate::types::RouterRoute>(&[
+        r##"{"description":"sample","destination":{"type":"ip","value":"10.0.0.1"},"id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","kind":"default","name":"sample","target":{"type":"ip","value":"10.0.0.1"},"time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z","vpc_router_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_router_route_create_params() {
+    round_trip::<crate::types::RouterRouteCreateParams>(&[
+        r##"{"description":"sample","destination":{"type":"ip","value":"10.0.0.1"},"name":"sample","target":{"type":"ip","value":"10.0.0.1"}}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_router_route_kind() {
+    round_trip::<crate::types::RouterRouteKind>(&[
+        r##""default""##,
+        r##""vpc_subnet""##,
+        r##""vpc_peering""##,
+        r##""custom""##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_router_route_results_page() {
+    round_trip::<crate::types::RouterRouteResultsPage>(&[
+        r##"{"items":[{"description":"sample","destination":{"type":"ip","value":"10.0.0.1"},"id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","kind":"default","name":"sample","target":{"type":"ip","value":"10.0.0.1"},"time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z","vpc_router_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71"}],"next_page":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_router_route_update_params() {
+    round_trip::<crate::types::RouterRouteUpdateParams>(&[
+        r##"{"description":"sample","destination":{"type":"ip","value":"10.0.0.1"},"name":"sample","target":{"type":"ip","value":"10.0.0.1"}}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_saga() {
+    round_trip::<crate::types::Saga>(&[
+        r##"{"id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","state":{"state":"running"}}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_saga_error_info() {
+    round_trip::<crate::types::SagaErrorInfo>(&[
+        r##"{"error":"action_failed","source_error":null}"##,
+        r##"{"error":"deserialize_failed","message":"sample"}"##,
+        r##"{"error":"injected_error"}"##,
+        r##"{"error":"serialize_failed","message":"sample"}"##,
+        r##"{"error":"subsaga_create_failed","message":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_saga_results_page() {
+    round_trip::<crate::types::SagaResultsPage>(&[
+        r##"{"items":[{"id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","state":{"state":"running"}}],"next_page":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_saga_state() {
+    round_trip::<crate::types::SagaState>(&[
+        r##"{"state":"running"}"##,
+        r##"{"state":"succeeded"}"##,
+        r##"{"error_info":{"error":"action_failed","source_error":null},"error_node_name":"sample","state":"failed"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_saml_identity_provider() {
+    round_trip::<crate::types::SamlIdentityProvider>(&[
+        r##"{"acs_url":"sample","description":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","idp_entity_id":"sample","name":"sample","public_cert":"sample","slo_url":"sample","sp_client_id":"sample","technical_contact_email":"sample","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_saml_identity_provider_create() {
+    round_trip::<crate::types::SamlIdentityProviderCreate>(&[
+        r##"{"acs_url":"sample","description":"sample","group_attribute_name":"sample","idp_entity_id":"sample","idp_metadata_source":{"type":"url","url":"sample"},"name":"sample","signing_keypair":{"private_key":"sample","public_cert":"sample"},"slo_url":"sample","sp_client_id":"sample","technical_contact_email":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_silo() {
+    round_trip::<crate::types::Silo>(&[
+        r##"{"description":"sample","discoverable":true,"id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","name":"sample","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z","user_provision_type":"fixed"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_silo_create() {
+    round_trip::<crate::types::SiloCreate>(&[
+        r##"{"admin_group_name":"sample","description":"sample","discoverable":true,"name":"sample","user_provision_type":"fixed"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_silo_results_page() {
+    round_trip::<crate::types::SiloResultsPage>(&[
+        r##"{"items":[{"description":"sample","discoverable":true,"id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","name":"sample","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z","user_provision_type":"fixed"}],"next_page":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_silo_role() {
+    round_trip::<crate::types::SiloRole>(&[r##""admin""##, r##""collaborator""##, r##""viewer""##]);
+}
+
+#[test]
+fn test_round_trip_silo_role_policy() {
+    round_trip::<crate::types::SiloRolePolicy>(&[
+        r##"{"role_assignments":[{"identity_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","identity_type":"silo_user","role_name":"admin"}]}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_silo_role_assignment() {
+    round_trip::<crate::types::SiloRoleAssignment>(&[
+        r##"{"identity_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","identity_type":"silo_user","role_name":"admin"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_sled() {
+    round_trip::<crate::types::Sled>(&[
+        r##"{"id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","service_address":"sample","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_sled_results_page() {
+    round_trip::<crate::types::SledResultsPage>(&[
+        r##"{"items":[{"id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","service_address":"sample","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z"}],"next_page":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_snapshot() {
+    round_trip::<crate::types::Snapshot>(&[
+        r##"{"description":"sample","disk_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","name":"sample","project_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","size":1,"time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_snapshot_create() {
+    round_trip::<crate::types::SnapshotCreate>(&[
+        r##"{"description":"sample","disk":"sample","name":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_snapshot_results_page() {
+    round_trip::<crate::types::SnapshotResultsPage>(&[
+        r##"{"items":[{"description":"sample","disk_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","name":"sample","project_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","size":1,"time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z"}],"next_page":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_spoof_login_body() {
+    round_trip::<crate::types::SpoofLoginBody>(&[r##"{"username":"sample"}"##]);
+}
+
+#[test]
+fn test_round_trip_ssh_key() {
+    round_trip::<crate::types::SshKey>(&[
+        r##"{"description":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","name":"sample","public_key":"sample","silo_user_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_ssh_key_create() {
+    round_trip::<crate::types::SshKeyCreate>(&[
+        r##"{"description":"sample","name":"sample","public_key":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_ssh_key_results_page() {
+    round_trip::<crate::types::SshKeyResultsPage>(&[
+        r##"{"items":[{"description":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","name":"sample","public_key":"sample","silo_user_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z"}],"next_page":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_timeseries_schema() {
+    round_trip::<crate::types::TimeseriesSchema>(&[
+        r##"{"created":"2022-06-01T12:30:00Z","datum_type":"bool","field_schema":[{"name":"sample","source":"target","ty":"string"}],"timeseries_name":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_timeseries_schema_results_page() {
+    round_trip::<crate::types::TimeseriesSchemaResultsPage>(&[
+        r##"{"items":[{"created":"2022-06-01T12:30:00Z","datum_type":"bool","field_schema":[{"name":"sample","source":"target","ty":"string"}],"timeseries_name":"sample"}],"next_page":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_user() {
+    round_trip::<crate::types::User>(&[
+        r##"{"display_name":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_user_builtin() {
+    round_trip::<crate::types::UserBuiltin>(&[
+        r##"{"description":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","name":"sample","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_user_builtin_results_page() {
+    round_trip::<crate::types::UserBuiltinResultsPage>(&[
+        r##"{"items":[{"description":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","name":"sample","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z"}],"next_page":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_user_provision_type() {
+    round_trip::<crate::types::UserProvisionType>(&[r##""fixed""##, r##""jit""##]);
+}
+
+#[test]
+fn test_round_trip_user_results_page() {
+    round_trip::<crate::types::UserResultsPage>(&[
+        r##"{"items":[{"display_name":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71"}],"next_page":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_vpc() {
+    round_trip::<crate::types::Vpc>(&[
+        r##"{"description":"sample","dns_name":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","ipv6_prefix":"fd12:3456::/64","name":"sample","project_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","system_router_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_vpc_create() {
+    round_trip::<crate::types::VpcCreate>(&[
+        r##"{"description":"sample","dns_name":"sample","ipv6_prefix":"fd12:3456::/64","name":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_vpc_firewall_rule() {
+    round_trip::<crate::types::VpcFirewallRule>(&[
+        r##"{"action":"allow","description":"sample","direction":"inbound","filters":{"hosts":[{"type":"vpc","value":"sample"}],"ports":["22"],"protocols":["TCP"]},"id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","name":"sample","priority":1,"status":"disabled","targets":[{"type":"vpc","value":"sample"}],"time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z","vpc_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_vpc_firewall_rule_action() {
+    round_trip::<crate::types::VpcFirewallRuleAction>(&[r##""allow""##, r##""deny""##]);
+}
+
+#[test]
+fn test_round_trip_vpc_firewall_rule_direction() {
+    round_trip::<crate::types::VpcFirewallRuleDirection>(&[r##""inbound""##, r##""outbound""##]);
+}
+
+#[test]
+fn test_round_trip_vpc_firewall_rule_filter() {
+    round_trip::<crate::types::VpcFirewallRuleFilter>(&[
+        r##"{"hosts":[{"type":"vpc","value":"sample"}],"ports":["22"],"protocols":["TCP"]}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_vpc_firewall_rule_host_filter() {
+    round_trip::<crate::types::VpcFirewallRuleHostFilter>(&[
+        r##"{"type":"vpc","value":"sample"}"##,
+        r##"{"type":"subnet","value":"sample"}"##,
+        r##"{"type":"instance","value":"sample"}"##,
+        r##"{"type":"ip","value":"10.0.0.1"}"##,
+        r##"{"type":"ip_net","value":"192.168.1.0/24"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_vpc_firewall_rule_protocol() {
+    round_trip::<crate::types::VpcFirewallRuleProtocol>(&[
+        r##""TCP""##,
+        r##""UDP""##,
+        r##""ICMP""##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_vpc_firewall_rule_status() {
+    round_trip::<crate::types::VpcFirewallRuleStatus>(&[r##""disabled""##, r##""enabled""##]);
+}
+
+#[test]
+fn test_round_trip_vpc_firewall_rule_target() {
+    round_trip::<crate::types::VpcFirewallRuleTarget>(&[
+        r##"{"type":"vpc","value":"sample"}"##,
+        r##"{"type":"subnet","value":"sample"}"##,
+        r##"{"type":"instance","value":"sample"}"##,
+        r##"{"type":"ip","value":"10.0.0.1"}"##,
+        r##"{"type":"ip_net","value":"192.168.1.0/24"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_vpc_firewall_rule_update() {
+    round_trip::<crate::types::VpcFirewallRuleUpdate>(&[
+        r##"{"action":"allow","description":"sample","direction":"inbound","filters":{"hosts":[{"type":"vpc","value":"sample"}],"ports":["22"],"protocols":["TCP"]},"name":"sample","priority":1,"status":"disabled","targets":[{"type":"vpc","value":"sample"}]}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_vpc_firewall_rule_update_params() {
+    round_trip::<crate::types::VpcFirewallRuleUpdateParams>(&[
+        r##"{"rules":[{"action":"allow","description":"sample","direction":"inbound","filters":{"hosts":[{"type":"vpc","value":"sample"}],"ports":["22"],"protocols":["TCP"]},"name":"sample","priority":1,"status":"disabled","targets":[{"type":"vpc","value":"sample"}]}]}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_vpc_firewall_rules() {
+    round_trip::<crate::types::VpcFirewallRules>(&[
+        r##"{"rules":[{"action":"allow","description":"sample","direction":"inbound","filters":{"hosts":[{"type":"vpc","value":"sample"}],"ports":["22"],"protocols":["TCP"]},"id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","name":"sample","priority":1,"status":"disabled","targets":[{"type":"vpc","value":"sample"}],"time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z","vpc_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71"}]}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_vpc_results_page() {
+    round_trip::<crate::types::VpcResultsPage>(&[
+        r##"{"items":[{"description":"sample","dns_name":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","ipv6_prefix":"fd12:3456::/64","name":"sample","project_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","system_router_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z"}],"next_page":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_vpc_router() {
+    round_trip::<crate::types::VpcRouter>(&[
+        r##"{"description":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","kind":"system","name":"sample","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z","vpc_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_vpc_router_create() {
+    round_trip::<crate::types::VpcRouterCreate>(&[r##"{"description":"sample","name":"sample"}"##]);
+}
+
+#[test]
+fn test_round_trip_vpc_router_kind() {
+    round_trip::<crate::types::VpcRouterKind>(&[r##""system""##, r##""custom""##]);
+}
+
+#[test]
+fn test_round_trip_vpc_router_results_page() {
+    round_trip::<crate::types::VpcRouterResultsPage>(&[
+        r##"{"items":[{"description":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","kind":"system","name":"sample","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z","vpc_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71"}],"next_page":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_vpc_router_update() {
+    round_trip::<crate::types::VpcRouterUpdate>(&[r##"{"description":"sample","name":"sample"}"##]);
+}
+
+#[test]
+fn test_round_trip_vpc_subnet() {
+    round_trip::<crate::types::VpcSubnet>(&[
+        r##"{"description":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","ipv4_block":"192.168.1.0/24","ipv6_block":"fd12:3456::/64","name":"sample","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z","vpc_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_vpc_subnet_create() {
+    round_trip::<crate::types::VpcSubnetCreate>(&[
+        r##"{"description":"sample","ipv4_block":"192.168.1.0/24","ipv6_block":"fd12:3456::/64","name":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_vpc_subnet_results_page() {
+    round_trip::<crate::types::VpcSubnetResultsPage>(&[
+        r##"{"items":[{"description":"sample","id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71","ipv4_block":"192.168.1.0/24","ipv6_block":"fd12:3456::/64","name":"sample","time_created":"2022-06-01T12:30:00Z","time_modified":"2022-06-01T12:30:00Z","vpc_id":"6a4f5d0e-8f1b-4c3a-9e2d-7b1c0a9f8e71"}],"next_page":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_vpc_subnet_update() {
+    round_trip::<crate::types::VpcSubnetUpdate>(&[r##"{"description":"sample","name":"sample"}"##]);
+}
+
+#[test]
+fn test_round_trip_vpc_update() {
+    round_trip::<crate::types::VpcUpdate>(&[
+        r##"{"description":"sample","dns_name":"sample","name":"sample"}"##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_id_sort_mode() {
+    round_trip::<crate::types::IdSortMode>(&[r##""id_ascending""##]);
+}
+
+#[test]
+fn test_round_trip_name_sort_mode() {
+    round_trip::<crate::types::NameSortMode>(&[r##""name_ascending""##]);
+}
+
+#[test]
+fn test_round_trip_name_or_id_sort_mode() {
+    round_trip::<crate::types::NameOrIdSortMode>(&[
+        r##""name_ascending""##,
+        r##""name_descending""##,
+        r##""id_ascending""##,
+    ]);
+}
+
+#[test]
+fn test_round_trip_disk_metric_name() {
+    round_trip::<crate::types::DiskMetricName>(&[
+        r##""activated""##,
+        r##""flush""##,
+        r##""read""##,
+        r##""read_bytes""##,
+        r##""write""##,
+        r##""write_bytes""##,
+    ]);
+}